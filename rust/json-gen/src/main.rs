@@ -85,6 +85,7 @@ fn main() {
     gen_json_schema!(ProposedProtocolParameterUpdates);
     gen_json_schema!(ProtocolVersion);
     gen_json_schema!(ProtocolParamUpdate);
+    gen_json_schema!(ProtocolParameters);
     gen_json_schema!(TransactionBodies);
     gen_json_schema!(TransactionWitnessSets);
     gen_json_schema!(AuxiliaryDataSet);
@@ -0,0 +1,176 @@
+use crate::*;
+
+/// Number of consecutive unused addresses after which BIP44 wallets stop scanning a chain
+pub const BIP44_GAP_LIMIT: u32 = 20;
+
+/// Tells which addresses have been used on chain, usually backed by a chain indexer.
+/// The discovery only asks about base addresses delegated to the first stake key of the account,
+/// oracles that also want to find enterprise or differently delegated addresses of the wallet
+/// can look up the payment credential of the given addresses instead.
+/// NOTE: this trait is not available through wasm bindings.
+pub trait AddressUsageOracle {
+    /// Must return one flag per address, in the order of {addresses}
+    fn used_addresses(&self, addresses: &[Address]) -> Result<Vec<bool>, JsError>;
+}
+
+/// NOTE: not available through wasm bindings, like discover_addresses().
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiscoveredAddress {
+    account: u32,
+    role: Cip1852Role,
+    index: u32,
+    address: BaseAddress,
+}
+
+impl DiscoveredAddress {
+    pub fn account(&self) -> u32 {
+        self.account
+    }
+
+    /// Cip1852Role::External or Cip1852Role::Internal
+    pub fn role(&self) -> Cip1852Role {
+        self.role
+    }
+
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn address(&self) -> BaseAddress {
+        self.address.clone()
+    }
+
+    /// Full path of the payment key, like m/1852'/1815'/0'/1/4 for the change address 4 of the account 0
+    pub fn derivation_path(&self) -> String {
+        format!(
+            "m/1852'/1815'/{}'/{}/{}",
+            self.account, self.role as u32, self.index
+        )
+    }
+}
+
+/// NOTE: not available through wasm bindings, like discover_addresses().
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiscoveredAddresses {
+    addresses: Vec<DiscoveredAddress>,
+    next_external_index: u32,
+    next_internal_index: u32,
+}
+
+impl DiscoveredAddresses {
+    /// Used addresses, the receiving addresses first, then the change addresses, by index
+    pub fn addresses(&self) -> Vec<DiscoveredAddress> {
+        self.addresses.clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.addresses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty()
+    }
+
+    /// Index of the first receiving address after the last used one
+    pub fn next_external_index(&self) -> u32 {
+        self.next_external_index
+    }
+
+    /// Index of the first change address after the last used one
+    pub fn next_internal_index(&self) -> u32 {
+        self.next_internal_index
+    }
+}
+
+/// Scans the receiving and the change addresses of the account until {gap_limit} consecutive
+/// addresses of the chain are unused, see BIP44_GAP_LIMIT. Works with public accounts.
+/// The oracle is asked about at most {gap_limit} addresses at a time.
+/// NOTE: this is a Rust-only API, JS wallets can scan the same way by deriving the addresses
+/// with Cip1852Account::base_address() and Cip1852Account::change_address().
+pub fn discover_addresses(
+    account: &Cip1852Account,
+    network: u8,
+    gap_limit: u32,
+    oracle: &dyn AddressUsageOracle,
+) -> Result<DiscoveredAddresses, JsError> {
+    if gap_limit == 0 {
+        return Err(JsError::from_str("the gap limit must be at least 1"));
+    }
+    let mut addresses = Vec::new();
+    let next_external_index = discover_chain(
+        account,
+        network,
+        Cip1852Role::External,
+        gap_limit,
+        oracle,
+        &mut addresses,
+    )?;
+    let next_internal_index = discover_chain(
+        account,
+        network,
+        Cip1852Role::Internal,
+        gap_limit,
+        oracle,
+        &mut addresses,
+    )?;
+    Ok(DiscoveredAddresses {
+        addresses,
+        next_external_index,
+        next_internal_index,
+    })
+}
+
+// returns the index following the last used address of the chain
+fn discover_chain(
+    account: &Cip1852Account,
+    network: u8,
+    role: Cip1852Role,
+    gap_limit: u32,
+    oracle: &dyn AddressUsageOracle,
+    discovered: &mut Vec<DiscoveredAddress>,
+) -> Result<u32, JsError> {
+    let mut next_index = 0u32;
+    let mut unused = 0u32;
+    let mut next_unused_index = 0u32;
+    while unused < gap_limit {
+        // only the addresses still needed to reach the gap limit are asked for
+        let batch = (next_index..next_index.saturating_add(gap_limit - unused))
+            .map(|index| {
+                let address = match role {
+                    Cip1852Role::Internal => account.change_address(network, index)?,
+                    _ => account.base_address(network, index)?,
+                };
+                Ok((index, address))
+            })
+            .collect::<Result<Vec<(u32, BaseAddress)>, JsError>>()?;
+        let used = oracle.used_addresses(
+            &batch
+                .iter()
+                .map(|(_, address)| address.to_address())
+                .collect::<Vec<Address>>(),
+        )?;
+        if used.len() != batch.len() {
+            return Err(JsError::from_str(&format!(
+                "the address usage oracle returned {} flags for {} addresses",
+                used.len(),
+                batch.len()
+            )));
+        }
+        for ((index, address), used) in batch.into_iter().zip(used) {
+            if used {
+                discovered.push(DiscoveredAddress {
+                    account: account.account(),
+                    role,
+                    index,
+                    address,
+                });
+                unused = 0;
+                next_unused_index = index + 1;
+            } else {
+                unused += 1;
+            }
+        }
+        next_index = next_unused_index + unused;
+    }
+    Ok(next_unused_index)
+}
@@ -0,0 +1,233 @@
+mod english;
+mod wordlist;
+pub use wordlist::*;
+
+use crate::*;
+use cryptoxide::digest::Digest;
+use cryptoxide::sha2::Sha256;
+use rand_os::rand_core::RngCore;
+use rand_os::OsRng;
+
+// BIP39 mnemonics: the entropy followed by the first ENT / 32 bits of its SHA-256 as checksum,
+// split in groups of 11 bits that index the word list.
+// https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki
+
+const BITS_PER_WORD: usize = 11;
+const VALID_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+const MAX_SUGGESTIONS: usize = 5;
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Bip39ErrorKind {
+    /// The phrase doesn't have 12, 15, 18, 21 or 24 words
+    WordCount,
+    /// A word is not in the word list, the error lists the closest words of the list
+    UnknownWord,
+    /// The checksum in the last word doesn't match the entropy of the phrase,
+    /// one of the words is wrong or the words are not in the right order
+    Checksum,
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Bip39Error {
+    pub(crate) kind: Bip39ErrorKind,
+    pub(crate) position: Option<usize>,
+    pub(crate) word: Option<String>,
+    pub(crate) suggestions: Strings,
+    pub(crate) message: String,
+}
+
+#[wasm_bindgen]
+impl Bip39Error {
+    pub fn kind(&self) -> Bip39ErrorKind {
+        self.kind
+    }
+
+    /// Index of the word in the phrase, starting at 0
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    pub fn word(&self) -> Option<String> {
+        self.word.clone()
+    }
+
+    /// Words of the word list that are close to an unknown word
+    pub fn suggestions(&self) -> Strings {
+        self.suggestions.clone()
+    }
+
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+}
+
+impl Display for Bip39Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.kind, self.message)
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Bip39Errors(pub(crate) Vec<Bip39Error>);
+
+#[wasm_bindgen]
+impl Bip39Errors {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> Bip39Error {
+        self.0[index].clone()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Generates a random mnemonic of {word_count} words: 12, 15, 18, 21 or 24.
+/// Cardano wallets use 15 or 24 words.
+#[wasm_bindgen]
+pub fn generate_bip39_mnemonic(
+    word_count: usize,
+    wordlist: &Bip39Wordlist,
+) -> Result<String, JsError> {
+    if !VALID_WORD_COUNTS.contains(&word_count) {
+        return Err(JsError::from_str(&format!(
+            "a BIP39 mnemonic has 12, 15, 18, 21 or 24 words, not {}",
+            word_count
+        )));
+    }
+    let mut entropy = vec![0; entropy_len(word_count)];
+    OsRng::new()
+        .map_err(|e| JsError::from_str(&format!("{}", e)))?
+        .fill_bytes(&mut entropy);
+    bip39_entropy_to_mnemonic(&entropy, wordlist)
+}
+
+/// {entropy} must be 16, 20, 24, 28 or 32 bytes long, the words are separated by a single space
+#[wasm_bindgen]
+pub fn bip39_entropy_to_mnemonic(
+    entropy: &[u8],
+    wordlist: &Bip39Wordlist,
+) -> Result<String, JsError> {
+    let word_count = entropy.len() * 8 * 33 / 32 / BITS_PER_WORD;
+    if !entropy.len().is_multiple_of(4) || !VALID_WORD_COUNTS.contains(&word_count) {
+        return Err(JsError::from_str(&format!(
+            "BIP39 entropy is 16, 20, 24, 28 or 32 bytes long, found {} bytes",
+            entropy.len()
+        )));
+    }
+    let mut bits = entropy.to_vec();
+    bits.push(checksum(entropy));
+    let words: Vec<&str> = (0..word_count)
+        .map(|i| {
+            let index = (0..BITS_PER_WORD).fold(0u16, |index, j| {
+                (index << 1) | bit(&bits, i * BITS_PER_WORD + j) as u16
+            });
+            wordlist.word(index)
+        })
+        .collect();
+    Ok(words.join(" "))
+}
+
+/// Returns the entropy encoded by the phrase, see from_bip39_entropy() of Bip32PrivateKey.
+/// Fails with the message of the first error reported by validate_bip39_mnemonic().
+#[wasm_bindgen]
+pub fn bip39_mnemonic_to_entropy(
+    phrase: &str,
+    wordlist: &Bip39Wordlist,
+) -> Result<Vec<u8>, JsError> {
+    decode_mnemonic(phrase, wordlist).map_err(|errors| JsError::from_str(&errors.0[0].to_string()))
+}
+
+/// Checks the words, the number of words and the checksum of the phrase.
+/// Every unknown word is reported with its position and the closest words of the list.
+/// The checksum is only checked when all words are known. An empty list means the phrase is valid.
+#[wasm_bindgen]
+pub fn validate_bip39_mnemonic(phrase: &str, wordlist: &Bip39Wordlist) -> Bip39Errors {
+    match decode_mnemonic(phrase, wordlist) {
+        Ok(_) => Bip39Errors(Vec::new()),
+        Err(errors) => errors,
+    }
+}
+
+fn entropy_len(word_count: usize) -> usize {
+    word_count * BITS_PER_WORD * 32 / 33 / 8
+}
+
+fn checksum(entropy: &[u8]) -> u8 {
+    let mut hasher = Sha256::new();
+    hasher.input(entropy);
+    let mut hash = [0; 32];
+    hasher.result(&mut hash);
+    hash[0]
+}
+
+fn bit(bytes: &[u8], index: usize) -> bool {
+    bytes[index / 8] & (0x80 >> (index % 8)) != 0
+}
+
+fn decode_mnemonic(phrase: &str, wordlist: &Bip39Wordlist) -> Result<Vec<u8>, Bip39Errors> {
+    let words: Vec<String> = phrase.split_whitespace().map(str::to_lowercase).collect();
+    let mut errors = Vec::new();
+    if !VALID_WORD_COUNTS.contains(&words.len()) {
+        errors.push(Bip39Error {
+            kind: Bip39ErrorKind::WordCount,
+            position: None,
+            word: None,
+            suggestions: Strings::new(),
+            message: format!(
+                "a BIP39 mnemonic has 12, 15, 18, 21 or 24 words, found {}",
+                words.len()
+            ),
+        });
+    }
+    let mut indices = Vec::with_capacity(words.len());
+    for (position, word) in words.iter().enumerate() {
+        match wordlist.index_of(word) {
+            Some(index) => indices.push(index),
+            None => errors.push(Bip39Error {
+                kind: Bip39ErrorKind::UnknownWord,
+                position: Some(position),
+                word: Some(word.clone()),
+                suggestions: Strings(wordlist.suggestions(word, MAX_SUGGESTIONS)),
+                message: format!("word {} \"{}\" is not in the word list", position + 1, word),
+            }),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(Bip39Errors(errors));
+    }
+
+    // the checksum has at most 8 bits
+    let mut bits = vec![0u8; entropy_len(indices.len()) + 1];
+    for (i, index) in indices.iter().enumerate() {
+        for j in 0..BITS_PER_WORD {
+            if index & (1 << (BITS_PER_WORD - 1 - j)) != 0 {
+                let position = i * BITS_PER_WORD + j;
+                bits[position / 8] |= 0x80 >> (position % 8);
+            }
+        }
+    }
+    let entropy = bits[..entropy_len(words.len())].to_vec();
+    let checksum_len = entropy.len() / 4;
+    let expected = checksum(&entropy) >> (8 - checksum_len);
+    let found = (0..checksum_len).fold(0u8, |found, j| {
+        (found << 1) | bit(&bits, entropy.len() * 8 + j) as u8
+    });
+    if found != expected {
+        let position = words.len() - 1;
+        return Err(Bip39Errors(vec![Bip39Error {
+            kind: Bip39ErrorKind::Checksum,
+            position: Some(position),
+            word: Some(words[position].clone()),
+            suggestions: Strings::new(),
+            message: "the checksum in the last word doesn't match the other words".to_string(),
+        }]));
+    }
+    Ok(entropy)
+}
@@ -0,0 +1,122 @@
+use super::english::ENGLISH_WORDS;
+use crate::*;
+use std::collections::HashMap;
+
+pub(crate) const WORDLIST_SIZE: usize = 2048;
+
+/// A BIP39 word list of 2048 words. The English list is built in, other lists can be loaded from text.
+/// Words are looked up in lowercase, lists with accented or non-latin words must be given in NFKD form
+/// and phrases must be normalized the same way.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bip39Wordlist {
+    words: Vec<String>,
+    indices: HashMap<String, u16>,
+}
+
+#[wasm_bindgen]
+impl Bip39Wordlist {
+    pub fn english() -> Bip39Wordlist {
+        Self::from_words(ENGLISH_WORDS.iter().map(|word| word.to_string()).collect())
+            .expect("the English word list is valid")
+    }
+
+    /// {words} holds one word per line like the word list files of the BIP39 repository
+    pub fn from_text(words: &str) -> Result<Bip39Wordlist, JsError> {
+        Self::from_words(
+            words
+                .lines()
+                .map(|word| word.trim().to_lowercase())
+                .filter(|word| !word.is_empty())
+                .collect(),
+        )
+    }
+
+    /// None if {index} is not below 2048
+    pub fn get(&self, index: usize) -> Option<String> {
+        self.words.get(index).cloned()
+    }
+
+    pub fn index_of(&self, word: &str) -> Option<u16> {
+        self.indices.get(&word.to_lowercase()).copied()
+    }
+}
+
+impl Bip39Wordlist {
+    fn from_words(words: Vec<String>) -> Result<Bip39Wordlist, JsError> {
+        if words.len() != WORDLIST_SIZE {
+            return Err(JsError::from_str(&format!(
+                "a BIP39 word list has {} words, found {}",
+                WORDLIST_SIZE,
+                words.len()
+            )));
+        }
+        let mut indices = HashMap::with_capacity(WORDLIST_SIZE);
+        for (index, word) in words.iter().enumerate() {
+            if indices.insert(word.clone(), index as u16).is_some() {
+                return Err(JsError::from_str(&format!(
+                    "the word \"{}\" is in the word list twice",
+                    word
+                )));
+            }
+        }
+        Ok(Bip39Wordlist { words, indices })
+    }
+
+    pub(crate) fn word(&self, index: u16) -> &str {
+        &self.words[index as usize]
+    }
+
+    // closest words first: words starting with {word}, then the ones at an edit distance of at most 2
+    pub(crate) fn suggestions(&self, word: &str, max: usize) -> Vec<String> {
+        let mut candidates: Vec<(usize, &String)> = self
+            .words
+            .iter()
+            .filter_map(|candidate| {
+                if word.chars().count() >= 3 && candidate.starts_with(word) {
+                    Some((0, candidate))
+                } else {
+                    let distance = edit_distance(word, candidate);
+                    if distance <= 2 {
+                        Some((distance, candidate))
+                    } else {
+                        None
+                    }
+                }
+            })
+            .collect();
+        // the sort is stable, words at the same distance stay in the order of the list
+        candidates.sort_by_key(|(distance, _)| *distance);
+        candidates
+            .into_iter()
+            .take(max)
+            .map(|(_, candidate)| candidate.clone())
+            .collect()
+    }
+}
+
+// Levenshtein distance where swapping two adjacent letters counts as one edit
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
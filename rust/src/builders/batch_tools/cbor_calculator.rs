@@ -100,6 +100,10 @@ impl CborCalculator {
                 TxBodyNames::CollateralReturn => false,
                 TxBodyNames::TotalCollateral => false,
                 TxBodyNames::ReferenceInputs => true,
+                TxBodyNames::VotingProcedures => false,
+                TxBodyNames::ProposalProcedures => true,
+                TxBodyNames::CurrentTreasuryValue => false,
+                TxBodyNames::Donation => false,
             };
             if wrapped {
                 size += CborCalculator::get_wrapped_struct_size(field.to_u64().unwrap());
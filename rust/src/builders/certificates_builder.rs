@@ -103,6 +103,15 @@ impl CertificatesBuilder {
         scripts
     }
 
+    pub(crate) fn set_evaluated_ex_units(&mut self, evaluated: &EvaluatedExUnits) {
+        let tag = RedeemerTag::new_cert();
+        for (i, (_, script_wit)) in self.certs.iter_mut().enumerate() {
+            if let Some(ScriptWitnessType::PlutusScriptWitness(s)) = script_wit {
+                s.set_evaluated_ex_units(&BigNum::from(i), &tag, evaluated);
+            }
+        }
+    }
+
     pub fn get_ref_inputs(&self) -> TransactionInputs {
         let mut inputs = Vec::new();
         for (_, script_wit) in self.certs.iter() {
@@ -234,7 +243,7 @@ impl CertificatesBuilder {
 }
 
 // comes from witsVKeyNeeded in the Ledger spec
-fn witness_keys_for_cert(cert_enum: &Certificate) -> RequiredSigners {
+pub(crate) fn witness_keys_for_cert(cert_enum: &Certificate) -> RequiredSigners {
     let mut set = RequiredSigners::new();
     match &cert_enum.0 {
         // stake key registrations do not require a witness
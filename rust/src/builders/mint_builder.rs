@@ -321,6 +321,18 @@ impl MintBuilder {
         Ok(mint)
     }
 
+    pub(crate) fn get_required_signers(&self) -> Ed25519KeyHashes {
+        let mut set = Ed25519KeyHashes::new();
+        for script_mint in self.mints.values() {
+            if let ScriptMint::Native(native_mints) = script_mint {
+                if let Some(signers) = native_mints.script.required_signers() {
+                    set.extend_move(signers);
+                }
+            }
+        }
+        set
+    }
+
     pub fn get_native_scripts(&self) -> NativeScripts {
         let mut native_scripts = Vec::new();
         for script_mint in self.mints.values() {
@@ -356,6 +368,17 @@ impl MintBuilder {
         PlutusWitnesses(plutus_witnesses)
     }
 
+    pub(crate) fn set_evaluated_ex_units(&mut self, evaluated: &EvaluatedExUnits) {
+        let tag = RedeemerTag::new_mint();
+        for (index, (_, script_mint)) in self.mints.iter_mut().enumerate() {
+            if let ScriptMint::Plutus(plutus_mints) = script_mint {
+                if let Some(ex_units) = evaluated.get(&(tag.clone(), BigNum::from(index))) {
+                    plutus_mints.redeemer.ex_units = ex_units.clone();
+                }
+            }
+        }
+    }
+
     pub fn get_ref_inputs(&self) -> TransactionInputs {
         let mut reference_inputs = Vec::new();
         for script_mint in self.mints.values() {
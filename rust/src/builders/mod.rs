@@ -30,6 +30,9 @@ pub use output_builder::*;
 mod tx_builder;
 pub use tx_builder::*;
 
+mod script_evaluator;
+pub use script_evaluator::*;
+
 mod tx_builder_constants;
 #[allow(unused_imports)]
 pub(crate) use tx_builder_constants::*;
@@ -132,6 +132,7 @@ pub fn with_asset_and_min_required_coin_by_utxo_cost(
             plutus_data: self.data.clone(),
             script_ref: self.script_ref.clone(),
             serialization_format: None,
+            original_encoding: OriginalEncoding::default(),
         })
     }
 }
@@ -0,0 +1,102 @@
+use crate::*;
+use std::collections::BTreeMap;
+
+/// How many evaluate/re-balance rounds `TransactionBuilder::build_tx_with_evaluator` will run
+/// before giving up on the execution budgets converging.
+pub(crate) const MAX_SCRIPT_EVALUATION_ROUNDS: usize = 10;
+
+/// Execution budgets returned by a script evaluator, keyed by redeemer tag and index.
+pub(crate) type EvaluatedExUnits = BTreeMap<(RedeemerTag, BigNum), ExUnits>;
+
+/// Computes the execution budget of every redeemer of a draft transaction.
+/// The transaction passed to the evaluator is fully balanced, but its redeemers may carry
+/// placeholder or outdated `ExUnits`. `utxos` contains the resolved outputs for the
+/// transaction's inputs, collateral and reference inputs.
+/// The evaluator must return one redeemer per redeemer of the draft (matched by tag and index)
+/// with the evaluated `ExUnits`. Any other field of the returned redeemers is ignored.
+/// NOTE: this trait is not available through wasm bindings.
+pub trait ScriptEvaluator {
+    fn evaluate(
+        &self,
+        tx: &Transaction,
+        utxos: &TransactionUnspentOutputs,
+    ) -> Result<Redeemers, JsError>;
+}
+
+/// Deterministic stand-in for a Plutus evaluator. It doesn't run any script, it returns
+/// the default budget for every redeemer unless a specific budget has been set for its tag and index.
+/// It only checks that every spend redeemer points to an input which is present in the resolved utxos.
+/// Intended for tests and for offline fee estimation.
+#[derive(Clone, Debug)]
+pub struct StaticScriptEvaluator {
+    default_ex_units: ExUnits,
+    ex_units: EvaluatedExUnits,
+}
+
+impl StaticScriptEvaluator {
+    pub fn new(default_ex_units: &ExUnits) -> Self {
+        Self {
+            default_ex_units: default_ex_units.clone(),
+            ex_units: BTreeMap::new(),
+        }
+    }
+
+    pub fn set_ex_units(&mut self, tag: &RedeemerTag, index: &BigNum, ex_units: &ExUnits) {
+        self.ex_units
+            .insert((tag.clone(), *index), ex_units.clone());
+    }
+}
+
+impl ScriptEvaluator for StaticScriptEvaluator {
+    fn evaluate(
+        &self,
+        tx: &Transaction,
+        utxos: &TransactionUnspentOutputs,
+    ) -> Result<Redeemers, JsError> {
+        let mut result = Redeemers::new();
+        let redeemers = match &tx.witness_set.redeemers {
+            Some(redeemers) => redeemers,
+            None => return Ok(result),
+        };
+        for redeemer in &redeemers.redeemers {
+            if redeemer.tag.kind() == RedeemerTagKind::Spend {
+                let input = tx
+                    .body
+                    .inputs
+                    .inputs
+                    .get(usize::from(redeemer.index))
+                    .ok_or_else(|| {
+                        JsError::from_str(&format!(
+                            "Spend redeemer index {} is out of the inputs range",
+                            redeemer.index
+                        ))
+                    })?;
+                if !utxos.into_iter().any(|utxo| utxo.input == **input) {
+                    return Err(JsError::from_str(&format!(
+                        "Input {} is not present in the resolved utxos",
+                        input
+                    )));
+                }
+            }
+            let ex_units = self
+                .ex_units
+                .get(&(redeemer.tag.clone(), redeemer.index))
+                .unwrap_or(&self.default_ex_units);
+            result.add(&Redeemer::new(
+                &redeemer.tag,
+                &redeemer.index,
+                &redeemer.data,
+                ex_units,
+            ));
+        }
+        Ok(result)
+    }
+}
+
+pub(crate) fn evaluated_ex_units_from_redeemers(redeemers: &Redeemers) -> EvaluatedExUnits {
+    redeemers
+        .redeemers
+        .iter()
+        .map(|r| ((r.tag.clone(), r.index), r.ex_units.clone()))
+        .collect()
+}
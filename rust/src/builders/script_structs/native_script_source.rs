@@ -19,7 +19,7 @@ impl NativeScriptSourceEnum {
             NativeScriptSourceEnum::NativeScript(script, required_signers) => {
                 match required_signers {
                     Some(signers) => Some(signers.clone()),
-                    None => Some(script.into())
+                    None => Some(script.smallest_signer_set())
                 }
             }
             NativeScriptSourceEnum::RefInput(_, _, required_signers, _) => required_signers.clone(),
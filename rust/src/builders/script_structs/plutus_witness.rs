@@ -76,6 +76,17 @@ impl PlutusWitness {
         }
     }
 
+    pub(crate) fn set_evaluated_ex_units(
+        &mut self,
+        index: &BigNum,
+        tag: &RedeemerTag,
+        evaluated: &EvaluatedExUnits,
+    ) {
+        if let Some(ex_units) = evaluated.get(&(tag.clone(), *index)) {
+            self.redeemer.ex_units = ex_units.clone();
+        }
+    }
+
     pub(crate) fn get_required_signers(&self) -> Option<Ed25519KeyHashes> {
         self.script.get_required_signers()
     }
@@ -13,7 +13,7 @@ fn count_needed_vkeys(tx_builder: &TransactionBuilder) -> usize {
     input_hashes.extend_move(Ed25519KeyHashes::from(&tx_builder.collateral));
     input_hashes.extend_move(tx_builder.required_signers.clone());
     if let Some(mint_builder) = &tx_builder.mint {
-        input_hashes.extend_move(Ed25519KeyHashes::from(&mint_builder.get_native_scripts()));
+        input_hashes.extend_move(mint_builder.get_required_signers());
     }
     if let Some(withdrawals_builder) = &tx_builder.withdrawals {
         input_hashes.extend_move(withdrawals_builder.get_required_signers());
@@ -93,6 +93,7 @@ pub(crate) fn fake_full_tx(
         witness_set,
         is_valid: true,
         auxiliary_data: tx_builder.auxiliary_data.clone(),
+        original_encoding: OriginalEncoding::default(),
     })
 }
 
@@ -166,6 +167,117 @@ pub enum CoinSelectionStrategyCIP2 {
     LargestFirstMultiAsset,
     /// Same as RandomImprove, but before adding ADA, will insert by random-improve for each asset type.
     RandomImproveMultiAsset,
+    /// Branch-and-bound ada-only selection. Searches for a set of inputs whose excess over the outputs and fee
+    /// is too small for a change output, so no change is produced. Falls back to LargestFirst if no such set is found.
+    /// Will error if outputs contain non-ADA assets.
+    BranchAndBound,
+    /// Same as BranchAndBound, but before adding ADA, will insert inputs for each asset type preferring
+    /// the inputs which bring the least amount of new policies into the change.
+    BranchAndBoundMultiAsset,
+}
+
+// limit of visited nodes in the branch-and-bound search tree, after which the best found selection is used
+const BRANCH_AND_BOUND_MAX_TRIES: usize = 100_000;
+
+// Depth-first search over inclusion/omission of each value (sorted in descending order)
+// for a subset with sum in [target, target + window] with the smallest excess.
+struct BranchAndBoundSearch<'a> {
+    values: &'a [u64],
+    target: u64,
+    upper_bound: u64,
+    tries: usize,
+    selected: Vec<usize>,
+    best: Option<(u64, Vec<usize>)>,
+}
+
+impl<'a> BranchAndBoundSearch<'a> {
+    fn run(values: &'a [u64], target: u64, window: u64) -> Option<Vec<usize>> {
+        let mut search = Self {
+            values,
+            target,
+            upper_bound: target.saturating_add(window),
+            tries: BRANCH_AND_BOUND_MAX_TRIES,
+            selected: Vec::new(),
+            best: None,
+        };
+        let total = values.iter().fold(0u64, |acc, v| acc.saturating_add(*v));
+        search.step(0, 0, total);
+        search.best.map(|(_, selected)| selected)
+    }
+
+    // returns true when the search has to stop: exact match is found or the tries limit is reached
+    fn step(&mut self, pos: usize, current: u64, remaining: u64) -> bool {
+        if self.tries == 0 {
+            return true;
+        }
+        self.tries -= 1;
+        if current >= self.target {
+            let excess = current - self.target;
+            if self
+                .best
+                .as_ref()
+                .is_none_or(|(best_excess, _)| excess < *best_excess)
+            {
+                self.best = Some((excess, self.selected.clone()));
+            }
+            return excess == 0;
+        }
+        if pos == self.values.len() || current.saturating_add(remaining) < self.target {
+            return false;
+        }
+        let value = self.values[pos];
+        let remaining = remaining - value;
+        if current.saturating_add(value) <= self.upper_bound {
+            self.selected.push(pos);
+            if self.step(pos + 1, current + value, remaining) {
+                return true;
+            }
+            self.selected.pop();
+        }
+        self.step(pos + 1, current, remaining)
+    }
+}
+
+fn count_new_policies(value: &Value, known_policies: &BTreeSet<PolicyID>) -> usize {
+    value.multiasset.as_ref().map_or(0, |ma| {
+        ma.0.keys()
+            .filter(|policy_id| !known_policies.contains(policy_id))
+            .count()
+    })
+}
+
+// Utxos which can be used as collateral: pure ADA utxos first and then, if allowed,
+// utxos with assets. Both groups are sorted from the biggest coin to the smallest.
+fn collateral_candidates(
+    utxos: &TransactionUnspentOutputs,
+    allow_assets: bool,
+) -> Vec<&TransactionUnspentOutput> {
+    let is_key_locked = |utxo: &&TransactionUnspentOutput| match utxo.output.address.kind() {
+        AddressKind::Base | AddressKind::Enterprise | AddressKind::Pointer => utxo
+            .output
+            .address
+            .payment_cred()
+            .is_some_and(|cred| !cred.has_script_hash()),
+        _ => false,
+    };
+    let (mut pure, mut with_assets): (Vec<&TransactionUnspentOutput>, Vec<_>) = utxos
+        .0
+        .iter()
+        .filter(is_key_locked)
+        .partition(|utxo| {
+            utxo.output
+                .amount
+                .multiasset
+                .as_ref()
+                .is_none_or(|ma| ma.len() == 0)
+        });
+    pure.sort_by_key(|utxo| std::cmp::Reverse(utxo.output.amount.coin));
+    if !allow_assets {
+        return pure;
+    }
+    with_assets.sort_by_key(|utxo| std::cmp::Reverse(utxo.output.amount.coin));
+    pure.extend(with_assets);
+    pure
 }
 
 #[wasm_bindgen]
@@ -179,9 +291,12 @@ pub struct TransactionBuilderConfig {
     pub(crate) data_cost: DataCost, // protocol parameter
     pub(crate) ex_unit_prices: Option<ExUnitPrices>, // protocol parameter
     pub(crate) ref_script_coins_per_byte: Option<UnitInterval>, // protocol parameter
+    pub(crate) collateral_percentage: Option<u32>, // protocol parameter
+    pub(crate) max_collateral_inputs: Option<u32>, // protocol parameter
     pub(crate) prefer_pure_change: bool,
     pub(crate) deduplicate_explicit_ref_inputs_with_regular_inputs: bool,
     pub(crate) do_not_burn_extra_change: bool,
+    pub(crate) allow_collateral_with_assets: bool,
 }
 
 impl TransactionBuilderConfig {
@@ -201,9 +316,12 @@ pub struct TransactionBuilderConfigBuilder {
     data_cost: Option<DataCost>,                     // protocol parameter
     ex_unit_prices: Option<ExUnitPrices>,            // protocol parameter
     ref_script_coins_per_byte: Option<UnitInterval>, // protocol parameter
+    collateral_percentage: Option<u32>,              // protocol parameter
+    max_collateral_inputs: Option<u32>,              // protocol parameter
     prefer_pure_change: bool,
     deduplicate_explicit_ref_inputs_with_regular_inputs: bool,
     do_not_burn_extra_change: bool,
+    allow_collateral_with_assets: bool,
 }
 
 #[wasm_bindgen]
@@ -218,9 +336,12 @@ impl TransactionBuilderConfigBuilder {
             data_cost: None,
             ex_unit_prices: None,
             ref_script_coins_per_byte: None,
+            collateral_percentage: None,
+            max_collateral_inputs: None,
             prefer_pure_change: false,
             deduplicate_explicit_ref_inputs_with_regular_inputs: false,
             do_not_burn_extra_change: false,
+            allow_collateral_with_assets: false,
         }
     }
 
@@ -272,6 +393,18 @@ impl TransactionBuilderConfigBuilder {
         cfg
     }
 
+    pub fn collateral_percentage(&self, collateral_percentage: u32) -> Self {
+        let mut cfg = self.clone();
+        cfg.collateral_percentage = Some(collateral_percentage);
+        cfg
+    }
+
+    pub fn max_collateral_inputs(&self, max_collateral_inputs: u32) -> Self {
+        let mut cfg = self.clone();
+        cfg.max_collateral_inputs = Some(max_collateral_inputs);
+        cfg
+    }
+
     pub fn prefer_pure_change(&self, prefer_pure_change: bool) -> Self {
         let mut cfg = self.clone();
         cfg.prefer_pure_change = prefer_pure_change;
@@ -293,6 +426,14 @@ impl TransactionBuilderConfigBuilder {
         cfg
     }
 
+    ///If set to true, TransactionBuilder::add_collateral_from may also select utxos with assets as collateral
+    ///when pure ADA utxos are not enough. The assets are sent back with the collateral return output.
+    pub fn allow_collateral_with_assets(&self, allow_collateral_with_assets: bool) -> Self {
+        let mut cfg = self.clone();
+        cfg.allow_collateral_with_assets = allow_collateral_with_assets;
+        cfg
+    }
+
     pub fn build(&self) -> Result<TransactionBuilderConfig, JsError> {
         let cfg: Self = self.clone();
         Ok(TransactionBuilderConfig {
@@ -316,9 +457,12 @@ impl TransactionBuilderConfigBuilder {
             ))?,
             ex_unit_prices: cfg.ex_unit_prices,
             ref_script_coins_per_byte: cfg.ref_script_coins_per_byte,
+            collateral_percentage: cfg.collateral_percentage,
+            max_collateral_inputs: cfg.max_collateral_inputs,
             prefer_pure_change: cfg.prefer_pure_change,
             deduplicate_explicit_ref_inputs_with_regular_inputs: cfg.deduplicate_explicit_ref_inputs_with_regular_inputs,
             do_not_burn_extra_change: cfg.do_not_burn_extra_change,
+            allow_collateral_with_assets: cfg.allow_collateral_with_assets,
         })
     }
 }
@@ -329,6 +473,9 @@ pub struct ChangeConfig {
     address: Address,
     plutus_data: Option<OutputDatum>,
     script_ref: Option<ScriptRef>,
+    max_assets_per_output: Option<u32>,
+    pure_ada_output: bool,
+    target_outputs: Option<u32>,
 }
 
 #[wasm_bindgen]
@@ -338,6 +485,9 @@ impl ChangeConfig {
             address: address.clone(),
             plutus_data: None,
             script_ref: None,
+            max_assets_per_output: None,
+            pure_ada_output: false,
+            target_outputs: None,
         }
     }
 
@@ -358,6 +508,91 @@ impl ChangeConfig {
         c_cfg.script_ref = Some(script_ref.clone());
         c_cfg
     }
+
+    /// Limits the number of different assets in one change output.
+    /// Each change output holds at least one asset, so 0 acts as 1.
+    pub fn change_max_assets_per_output(&self, max_assets_per_output: u32) -> Self {
+        let mut c_cfg = self.clone();
+        c_cfg.max_assets_per_output = Some(max_assets_per_output);
+        c_cfg
+    }
+
+    /// Puts the ADA of the change that is not needed to cover min ada of the asset outputs
+    /// into a separate output without assets, if it's enough for such an output.
+    pub fn change_pure_ada_output(&self, pure_ada_output: bool) -> Self {
+        let mut c_cfg = self.clone();
+        c_cfg.pure_ada_output = pure_ada_output;
+        c_cfg
+    }
+
+    /// Splits the change into this number of outputs by adding pure ADA outputs of equal value.
+    /// The change is never split into less outputs than required by max_value_size and
+    /// the max assets per output limit, and pure ADA outputs are dropped when the ADA is not enough
+    /// to cover their min ada.
+    pub fn change_target_outputs(&self, target_outputs: u32) -> Self {
+        let mut c_cfg = self.clone();
+        c_cfg.target_outputs = Some(target_outputs);
+        c_cfg
+    }
+}
+
+impl ChangeConfig {
+    fn has_split_policy(&self) -> bool {
+        self.max_assets_per_output.is_some() || self.pure_ada_output || self.target_outputs.is_some()
+    }
+
+    fn make_output(&self, amount: &Value) -> TransactionOutput {
+        TransactionOutput {
+            address: self.address.clone(),
+            amount: amount.clone(),
+            plutus_data: self.plutus_data.clone().map(|od| od.0),
+            script_ref: self.script_ref.clone(),
+            serialization_format: None,
+            original_encoding: OriginalEncoding::default(),
+        }
+    }
+}
+
+// Greedily packs the change assets into bundles, each of them fits into one change output.
+// Sizes are checked with the whole change coin, since the coin of an output can't be bigger.
+fn pack_change_assets(
+    multiasset: &MultiAsset,
+    max_assets_per_output: Option<u32>,
+    coin: &Coin,
+    max_value_size: u32,
+) -> Result<Vec<MultiAsset>, JsError> {
+    let fits = |assets: &MultiAsset| -> bool {
+        Value::new_with_assets(coin, assets).to_bytes().len() <= max_value_size as usize
+    };
+    let mut bundles = Vec::new();
+    let mut current = MultiAsset::new();
+    let mut current_count = 0;
+    for (policy_id, assets) in multiasset.0.iter() {
+        for (asset_name, amount) in assets.0.iter() {
+            let mut candidate = current.clone();
+            candidate.set_asset(policy_id, asset_name, amount);
+            let limit_reached =
+                max_assets_per_output.is_some_and(|max| current_count >= max as usize);
+            if current_count > 0 && (limit_reached || !fits(&candidate)) {
+                bundles.push(current);
+                current_count = 0;
+                candidate = MultiAsset::new();
+                candidate.set_asset(policy_id, asset_name, amount);
+            }
+            if !fits(&candidate) {
+                return Err(JsError::from_str(&format!(
+                    "Asset {} of policy {} doesn't fit into a change output",
+                    asset_name, policy_id
+                )));
+            }
+            current = candidate;
+            current_count += 1;
+        }
+    }
+    if current_count > 0 {
+        bundles.push(current);
+    }
+    Ok(bundles)
 }
 
 #[derive(Clone, Debug)]
@@ -404,6 +639,7 @@ pub struct TransactionBuilder {
     pub(crate) required_signers: Ed25519KeyHashes,
     pub(crate) collateral_return: Option<TransactionOutput>,
     pub(crate) total_collateral: Option<Coin>,
+    pub(crate) collateral_return_address: Option<Address>,
     pub(crate) reference_inputs: HashMap<TransactionInput, usize>,
     pub(crate) extra_datums: Option<PlutusList>,
     pub(crate) voting_procedures: Option<VotingBuilder>,
@@ -587,8 +823,218 @@ impl TransactionBuilder {
                     output_total = output_total.checked_add(&Value::new(&input_fee))?;
                 }
             }
+            CoinSelectionStrategyCIP2::BranchAndBound => {
+                if self
+                    .outputs
+                    .0
+                    .iter()
+                    .any(|output| output.amount.multiasset.is_some())
+                {
+                    return Err(JsError::from_str("Multiasset values not supported by BranchAndBound. Please use BranchAndBoundMultiAsset"));
+                }
+                let mut available_indices = (0..available_inputs.len()).collect::<Vec<usize>>();
+                // inputs with assets would need a change output to return the assets
+                let candidates = available_indices
+                    .iter()
+                    .filter(|i| {
+                        available_inputs[**i]
+                            .output
+                            .amount
+                            .multiasset
+                            .as_ref()
+                            .is_none_or(|ma| ma.len() == 0)
+                    })
+                    .cloned()
+                    .collect::<Vec<usize>>();
+                let found = self.branch_and_bound_by_coin(
+                    &available_inputs,
+                    &mut available_indices,
+                    &candidates,
+                    &mut input_total,
+                    &mut output_total,
+                )?;
+                if !found {
+                    self.cip2_largest_first_by(
+                        &available_inputs,
+                        &mut available_indices,
+                        &mut input_total,
+                        &mut output_total,
+                        |value| Some(value.coin),
+                    )?;
+                }
+            }
+            CoinSelectionStrategyCIP2::BranchAndBoundMultiAsset => {
+                let mut available_indices = (0..available_inputs.len()).collect::<Vec<usize>>();
+                // policies which end up in the tx anyway, so inputs holding them don't make the change bigger
+                let mut known_policies: BTreeSet<PolicyID> = BTreeSet::new();
+                for value in self
+                    .outputs
+                    .0
+                    .iter()
+                    .map(|output| &output.amount)
+                    .chain(self.inputs.iter().map(|input| &input.amount))
+                {
+                    if let Some(ma) = &value.multiasset {
+                        known_policies.extend(ma.0.keys().cloned());
+                    }
+                }
+                if let Some(ma) = output_total.multiasset.clone() {
+                    for (policy_id, assets) in ma.0.iter() {
+                        for (asset_name, _) in assets.0.iter() {
+                            self.select_with_fewest_new_policies_by(
+                                &available_inputs,
+                                &mut available_indices,
+                                &mut known_policies,
+                                &mut input_total,
+                                &mut output_total,
+                                |value| value.multiasset.as_ref()?.get(policy_id)?.get(asset_name),
+                            )?;
+                        }
+                    }
+                }
+                // search for ADA only among the inputs which don't bring new policies
+                let candidates = available_indices
+                    .iter()
+                    .filter(|i| {
+                        count_new_policies(&available_inputs[**i].output.amount, &known_policies)
+                            == 0
+                    })
+                    .cloned()
+                    .collect::<Vec<usize>>();
+                let found = self.branch_and_bound_by_coin(
+                    &available_inputs,
+                    &mut available_indices,
+                    &candidates,
+                    &mut input_total,
+                    &mut output_total,
+                )?;
+                if !found {
+                    self.select_with_fewest_new_policies_by(
+                        &available_inputs,
+                        &mut available_indices,
+                        &mut known_policies,
+                        &mut input_total,
+                        &mut output_total,
+                        |value| Some(value.coin),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Searches among {candidates} for a set of inputs that covers the missing ADA
+    /// with an excess small enough to be burned as fee instead of creating a change output.
+    /// Returns false and doesn't add any inputs if there is no such set.
+    fn branch_and_bound_by_coin(
+        &mut self,
+        available_inputs: &[&TransactionUnspentOutput],
+        available_indices: &mut Vec<usize>,
+        candidates: &[usize],
+        input_total: &mut Value,
+        output_total: &mut Value,
+    ) -> Result<bool, JsError> {
+        if input_total.coin >= output_total.coin {
+            return Ok(true);
+        }
+        if candidates.is_empty() {
+            return Ok(false);
+        }
+        let target: u64 = output_total.coin.checked_sub(&input_total.coin)?.into();
+        // the change is expected to go to the same wallet, so the first candidate address is
+        // used to estimate the size of the change output
+        let window: u64 = self
+            .change_avoidance_window(&available_inputs[candidates[0]].output.address)?
+            .into();
+
+        // (index, effective value, fee for input) in descending order by effective value
+        let mut weighted: Vec<(usize, u64, Coin)> = Vec::new();
+        for i in candidates.iter() {
+            let input = &available_inputs[*i];
+            let input_fee =
+                self.fee_for_input(&input.output.address, &input.input, &input.output.amount)?;
+            if input.output.amount.coin > input_fee {
+                let effective_value = input.output.amount.coin.checked_sub(&input_fee)?;
+                weighted.push((*i, effective_value.into(), input_fee));
+            }
+        }
+        weighted.sort_by_key(|w| std::cmp::Reverse(w.1));
+        let values = weighted.iter().map(|w| w.1).collect::<Vec<u64>>();
+
+        match BranchAndBoundSearch::run(&values, target, window) {
+            Some(selected) => {
+                for pos in selected {
+                    let (i, _, input_fee) = &weighted[pos];
+                    let input = &available_inputs[*i];
+                    self.inputs.add_regular_utxo(input)?;
+                    *input_total = input_total.checked_add(&input.output.amount)?;
+                    *output_total = output_total.checked_add(&Value::new(input_fee))?;
+                    available_indices.retain(|j| j != i);
+                }
+                Ok(true)
+            }
+            None => Ok(false),
         }
+    }
 
+    /// The biggest excess of ADA which is cheaper to burn as fee than to return as a change output.
+    /// Zero in case the builder is not allowed to burn the extra change.
+    fn change_avoidance_window(&self, change_address: &Address) -> Result<Coin, JsError> {
+        if self.config.do_not_burn_extra_change {
+            return Ok(Coin::zero());
+        }
+        let mut change_output = TransactionOutput::new(change_address, &Value::zero());
+        let min_ada = min_ada_for_output(&change_output, &self.config.utxo_cost())?;
+        change_output.amount.coin = min_ada;
+        let fee_for_change = self.fee_for_output(&change_output)?;
+        Ok(min_ada
+            .checked_add(&fee_for_change)?
+            .clamped_sub(&BigNum::one()))
+    }
+
+    /// Adds inputs containing the asset chosen by {by} until it's covered.
+    /// The inputs which bring the least amount of policies not present in {known_policies} are preferred,
+    /// then the ones with the biggest amount of the asset.
+    fn select_with_fewest_new_policies_by<F>(
+        &mut self,
+        available_inputs: &[&TransactionUnspentOutput],
+        available_indices: &mut Vec<usize>,
+        known_policies: &mut BTreeSet<PolicyID>,
+        input_total: &mut Value,
+        output_total: &mut Value,
+        by: F,
+    ) -> Result<(), JsError>
+    where
+        F: Fn(&Value) -> Option<BigNum>,
+    {
+        while by(input_total).unwrap_or(BigNum::zero())
+            < by(output_total).expect("do not call on asset types that aren't in the output")
+        {
+            let best = available_indices
+                .iter()
+                .enumerate()
+                .filter_map(|(pos, i)| {
+                    let amount = &available_inputs[*i].output.amount;
+                    by(amount).map(|value| {
+                        (pos, count_new_policies(amount, known_policies), value)
+                    })
+                })
+                .min_by(|a, b| a.1.cmp(&b.1).then(b.2.cmp(&a.2)));
+            let pos = match best {
+                Some((pos, _, _)) => pos,
+                None => return Err(JsError::from_str("UTxO Balance Insufficient")),
+            };
+            let input = &available_inputs[available_indices.swap_remove(pos)];
+            let input_fee =
+                self.fee_for_input(&input.output.address, &input.input, &input.output.amount)?;
+            self.inputs.add_regular_utxo(input)?;
+            *input_total = input_total.checked_add(&input.output.amount)?;
+            *output_total = output_total.checked_add(&Value::new(&input_fee))?;
+            if let Some(ma) = &input.output.amount.multiasset {
+                known_policies.extend(ma.0.keys().cloned());
+            }
+        }
         Ok(())
     }
 
@@ -756,10 +1202,12 @@ impl TransactionBuilder {
 
     pub fn set_collateral(&mut self, collateral: &TxInputsBuilder) {
         self.collateral = collateral.clone();
+        self.collateral_return_address = None;
     }
 
     pub fn set_collateral_return(&mut self, collateral_return: &TransactionOutput) {
         self.collateral_return = Some(collateral_return.clone());
+        self.collateral_return_address = None;
     }
 
     pub fn remove_collateral_return(&mut self) {
@@ -817,6 +1265,18 @@ impl TransactionBuilder {
         &mut self,
         total_collateral: &Coin,
         return_address: &Address,
+    ) -> Result<(), JsError> {
+        self.set_collateral_return_for_total(total_collateral, return_address)?;
+        self.collateral_return_address = None;
+        Ok(())
+    }
+
+    // Same as `.set_total_collateral_and_return`, but keeps the address the collateral return
+    // is recalculated for when the change is calculated
+    fn set_collateral_return_for_total(
+        &mut self,
+        total_collateral: &Coin,
+        return_address: &Address,
     ) -> Result<(), JsError> {
         let collateral = &self.collateral;
         if collateral.len() == 0 {
@@ -943,14 +1403,7 @@ impl TransactionBuilder {
             ))
         }
         let mut add_change_result = self
-            .add_change_if_needed_with_optional_script_and_datum(
-                &change_config.address,
-                change_config
-                    .plutus_data
-                    .clone()
-                    .map_or(None, |od| Some(od.0)),
-                change_config.script_ref.clone(),
-            );
+            .add_change_if_needed_with_config(change_config);
         match add_change_result {
             Ok(v) => Ok(v),
             Err(e) => {
@@ -980,14 +1433,7 @@ impl TransactionBuilder {
                         Some(input) => {
                             self.inputs.add_regular_utxo(&input)?;
                             add_change_result = self
-                                .add_change_if_needed_with_optional_script_and_datum(
-                                    &change_config.address,
-                                    change_config
-                                        .plutus_data
-                                        .clone()
-                                        .map_or(None, |od| Some(od.0)),
-                                    change_config.script_ref.clone(),
-                                );
+                                .add_change_if_needed_with_config(change_config);
                             if let Ok(value) = add_change_result {
                                 return Ok(value);
                             }
@@ -1054,6 +1500,195 @@ impl TransactionBuilder {
         Ok(())
     }
 
+    /// Selects collateral inputs from {utxos} and sets the total collateral and the collateral return.
+    /// Only utxos locked by a payment key are used. Pure ADA utxos are preferred, utxos with assets
+    /// are used only if `allow_collateral_with_assets` is set in the config.
+    /// At most `max_collateral_inputs` inputs are selected, largest first, to cover `collateral_percentage`
+    /// of the fee and the min ada of the collateral return output sent to {return_address}.
+    /// Previously set collateral is replaced.
+    /// If the fee is not calculated yet, the collateral is selected against an upper bound of the fee
+    /// and the total collateral and the collateral return are recalculated with the final fee
+    /// every time the change is calculated.
+    pub fn add_collateral_from(
+        &mut self,
+        utxos: &TransactionUnspentOutputs,
+        return_address: &Address,
+    ) -> Result<(), JsError> {
+        if self.config.collateral_percentage.is_none() {
+            return Err(JsError::from_str(
+                "uninitialized field: collateral_percentage",
+            ));
+        }
+        let max_inputs = self
+            .config
+            .max_collateral_inputs
+            .ok_or(JsError::from_str("uninitialized field: max_collateral_inputs"))?
+            as usize;
+        let candidates = collateral_candidates(utxos, self.config.allow_collateral_with_assets);
+        if candidates.is_empty() {
+            return Err(JsError::from_str("No utxos suitable for collateral"));
+        }
+
+        let previous_collateral = self.collateral.clone();
+        let previous_return = self.collateral_return.clone();
+        let previous_total = self.total_collateral;
+        let previous_return_address = self.collateral_return_address.clone();
+        let result = self.select_collateral(&candidates, max_inputs, return_address);
+        if result.is_err() {
+            self.collateral = previous_collateral;
+            self.collateral_return = previous_return;
+            self.total_collateral = previous_total;
+            self.collateral_return_address = previous_return_address;
+        }
+        result
+    }
+
+    fn select_collateral(
+        &mut self,
+        candidates: &[&TransactionUnspentOutput],
+        max_inputs: usize,
+        return_address: &Address,
+    ) -> Result<(), JsError> {
+        self.collateral = TxInputsBuilder::new();
+        self.remove_collateral_return();
+        self.remove_total_collateral();
+        self.collateral_return_address = None;
+
+        let mut fee = self.collateral_fee_upper_bound(return_address)?;
+        // every round can only add inputs, so the fee stops growing after max_inputs rounds
+        for _ in 0..=max_inputs {
+            let required = self.required_collateral(&fee)?;
+            self.collateral =
+                self.collateral_inputs_for(candidates, max_inputs, &required, return_address)?;
+
+            let fee_is_final = self.fee.is_some();
+            let new_fee = self.collateral_fee_upper_bound(return_address)?;
+            if fee_is_final || new_fee <= fee {
+                self.set_collateral_return_for_total(&required, return_address)?;
+                if fee_is_final {
+                    return self.validate_fee().map_err(|e| {
+                        JsError::from_str(&format!(
+                            "The selected collateral doesn't fit into the already calculated fee. \
+                             Call .add_collateral_from before calculating change. {}",
+                            e
+                        ))
+                    });
+                }
+                self.collateral_return_address = Some(return_address.clone());
+                return Ok(());
+            }
+            fee = new_fee;
+        }
+        Err(JsError::from_str("Unable to select collateral for the transaction fee"))
+    }
+
+    fn collateral_inputs_for(
+        &self,
+        candidates: &[&TransactionUnspentOutput],
+        max_inputs: usize,
+        required: &Coin,
+        return_address: &Address,
+    ) -> Result<TxInputsBuilder, JsError> {
+        let mut selected = TxInputsBuilder::new();
+        let mut selected_value = Value::zero();
+        for utxo in candidates.iter().take(max_inputs) {
+            selected.add_regular_utxo(utxo)?;
+            selected_value = selected_value.checked_add(&utxo.output.amount)?;
+            if self.can_return_collateral(&selected_value, required, return_address)? {
+                return Ok(selected);
+            }
+        }
+        Err(JsError::from_str(&format!(
+            "Not enough collateral. Up to {} collateral inputs can't cover \
+             the required collateral {} and the min ada of the collateral return",
+            max_inputs, required
+        )))
+    }
+
+    fn can_return_collateral(
+        &self,
+        collateral_value: &Value,
+        required: &Coin,
+        return_address: &Address,
+    ) -> Result<bool, JsError> {
+        if collateral_value.coin < *required {
+            return Ok(false);
+        }
+        let return_value = collateral_value.checked_sub(&Value::new(required))?;
+        if return_value.multiasset.is_none() && return_value.coin.is_zero() {
+            return Ok(true);
+        }
+        let min_ada = min_ada_for_output(
+            &TransactionOutput::new(return_address, &return_value),
+            &self.config.utxo_cost(),
+        )?;
+        Ok(min_ada <= return_value.coin)
+    }
+
+    fn required_collateral(&self, fee: &Coin) -> Result<Coin, JsError> {
+        let collateral_percentage = self.config.collateral_percentage.ok_or(
+            JsError::from_str("uninitialized field: collateral_percentage"),
+        )?;
+        Ok(fee
+            .checked_mul(&BigNum::from(collateral_percentage))?
+            .checked_add(&BigNum(99))?
+            .div_floor(&BigNum(100)))
+    }
+
+    // The fee once the change is added can't exceed this value, unless the change is split
+    // into several outputs. Uses the final or the exact fee if it is known.
+    fn collateral_fee_upper_bound(&self, return_address: &Address) -> Result<Coin, JsError> {
+        if let Some(fee) = self.fee {
+            return Ok(fee);
+        }
+        if let TxBuilderFee::Exactly(fee) = self.fee_request {
+            return Ok(fee);
+        }
+        let mut self_copy = self.clone();
+        self_copy.set_max_collateral_return(return_address)?;
+        let fee = self_copy.min_fee()?;
+
+        let mut max_change = self_copy.get_total_input()?;
+        max_change.coin = BigNum(u64::MAX);
+        let change_size = TransactionOutput::new(return_address, &max_change)
+            .to_bytes()
+            .len();
+        let change_fee = self
+            .config
+            .fee_algo
+            .coefficient()
+            .checked_mul(&BigNum::from(change_size))?;
+        fee.checked_add(&change_fee)
+    }
+
+    // Sets the biggest possible total collateral and collateral return, so any fee calculated
+    // after that stays valid when the real values are set
+    fn set_max_collateral_return(&mut self, return_address: &Address) -> Result<(), JsError> {
+        if self.collateral.len() == 0 {
+            return Ok(());
+        }
+        let collateral_value = self.collateral.total_value()?;
+        self.set_total_collateral(&collateral_value.coin);
+        self.collateral_return = Some(TransactionOutput::new(return_address, &collateral_value));
+        Ok(())
+    }
+
+    fn update_collateral_return(&mut self, return_address: &Address) -> Result<(), JsError> {
+        let fee = self.get_fee_if_set().ok_or(JsError::from_str(
+            "Cannot calculate collateral return if fee was not set",
+        ))?;
+        let required = self.required_collateral(&fee)?;
+        if !self.can_return_collateral(&self.collateral.total_value()?, &required, return_address)? {
+            return Err(JsError::from_str(&format!(
+                "Selected collateral doesn't cover the required collateral {} for the final fee {}. \
+                 Call .add_collateral_from again with more utxos",
+                required, fee
+            )));
+        }
+        self.remove_collateral_return();
+        self.set_collateral_return_for_total(&required, return_address)
+    }
+
     /// Returns a copy of the current script input witness scripts in the builder
     #[deprecated(since = "10.2.0", note = "Use `.set_inputs`")]
     pub fn get_native_input_scripts(&self) -> Option<NativeScripts> {
@@ -1189,6 +1824,52 @@ impl TransactionBuilder {
         self.validity_start_interval = None;
     }
 
+    /// Sets the ttl to the latest slot which starts not later than the POSIX time (in milliseconds).
+    /// The ledger uses the start time of the ttl slot as the exclusive upper bound of the
+    /// validity range in the Plutus script context, so scripts never see a later time.
+    pub fn set_ttl_posix(
+        &mut self,
+        posix_time: &BigNum,
+        slot_config: &SlotConfig,
+    ) -> Result<(), JsError> {
+        self.ttl = Some(slot_config.posix_time_to_slot(posix_time)?);
+        Ok(())
+    }
+
+    /// Sets the validity start to the earliest slot which starts not earlier than the POSIX time
+    /// (in milliseconds). The ledger uses the start time of that slot as the inclusive lower bound
+    /// of the validity range in the Plutus script context, so scripts never see an earlier time.
+    pub fn set_validity_start_posix(
+        &mut self,
+        posix_time: &BigNum,
+        slot_config: &SlotConfig,
+    ) -> Result<(), JsError> {
+        self.validity_start_interval = Some(slot_config.posix_time_to_slot_ceil(posix_time)?);
+        Ok(())
+    }
+
+    /// Sets the validity start and the ttl so the validity range seen by Plutus scripts
+    /// is the biggest range of slot start times within [{valid_from}, {valid_to}).
+    /// Times are POSIX times in milliseconds. Raises an error if the range contains no slot start.
+    pub fn set_validity_interval_posix(
+        &mut self,
+        valid_from: &BigNum,
+        valid_to: &BigNum,
+        slot_config: &SlotConfig,
+    ) -> Result<(), JsError> {
+        let start = slot_config.posix_time_to_slot_ceil(valid_from)?;
+        let ttl = slot_config.posix_time_to_slot(valid_to)?;
+        if start >= ttl {
+            return Err(JsError::from_str(&format!(
+                "The validity interval [{}, {}) doesn't contain any slot start",
+                valid_from, valid_to
+            )));
+        }
+        self.validity_start_interval = Some(start);
+        self.ttl = Some(ttl);
+        Ok(())
+    }
+
     /// !!! DEPRECATED !!!
     /// Can emit error if add a cert with script credential.
     /// Use set_certs_builder instead.
@@ -1551,6 +2232,7 @@ impl TransactionBuilder {
             required_signers: Ed25519KeyHashes::new(),
             collateral_return: None,
             total_collateral: None,
+            collateral_return_address: None,
             reference_inputs: HashMap::new(),
             extra_datums: None,
             voting_procedures: None,
@@ -1824,7 +2506,7 @@ impl TransactionBuilder {
     /// Editing inputs, outputs, mint, etc. after change been calculated
     /// might cause a mismatch in calculated fee versus the required fee
     pub fn add_change_if_needed(&mut self, address: &Address) -> Result<bool, JsError> {
-        self.add_change_if_needed_with_optional_script_and_datum(address, None, None)
+        self.add_change_if_needed_with_config(&ChangeConfig::new(address))
     }
 
     pub fn add_change_if_needed_with_datum(
@@ -1832,19 +2514,138 @@ impl TransactionBuilder {
         address: &Address,
         plutus_data: &OutputDatum,
     ) -> Result<bool, JsError> {
-        self.add_change_if_needed_with_optional_script_and_datum(
-            address,
-            Some(plutus_data.0.clone()),
-            None,
+        self.add_change_if_needed_with_config(
+            &ChangeConfig::new(address).change_plutus_data(plutus_data),
         )
     }
 
-    fn add_change_if_needed_with_optional_script_and_datum(
+    /// Same as `.add_change_if_needed`, but the change outputs are created according to the change config:
+    /// with its address, datum and script ref, and split into several outputs if a split policy is set.
+    /// Warning: this function will mutate the /fee/ field
+    pub fn add_change_if_needed_with_config(
         &mut self,
-        address: &Address,
-        plutus_data: Option<DataOption>,
-        script_ref: Option<ScriptRef>,
+        change_config: &ChangeConfig,
+    ) -> Result<bool, JsError> {
+        let return_address = match self.collateral_return_address.clone() {
+            Some(return_address) => return_address,
+            None => return self.add_change_output_if_needed(change_config),
+        };
+
+        // the change is calculated with the biggest collateral return,
+        // the real one is set when the final fee is known
+        let previous_return = self.collateral_return.clone();
+        let previous_total = self.total_collateral;
+        let previous_return_address = self.collateral_return_address.clone();
+        self.set_max_collateral_return(&return_address)?;
+        let result = self
+            .add_change_output_if_needed(change_config)
+            .and_then(|added| {
+                self.update_collateral_return(&return_address)?;
+                Ok(added)
+            });
+        if result.is_err() {
+            self.collateral_return = previous_return;
+            self.total_collateral = previous_total;
+            self.collateral_return_address = previous_return_address;
+        }
+        result
+    }
+
+    // Adds the change split according to the split policy of the change config.
+    // Returns None if the change ends up as a single output without assets,
+    // that case is handled by the regular change calculation.
+    fn add_split_change(
+        &mut self,
+        change_config: &ChangeConfig,
+        change: &Value,
+    ) -> Result<Option<bool>, JsError> {
+        let bundles = match &change.multiasset {
+            Some(multiasset) if multiasset.len() > 0 => pack_change_assets(
+                multiasset,
+                change_config.max_assets_per_output,
+                &change.coin,
+                self.config.max_value_size,
+            )?,
+            _ => Vec::new(),
+        };
+        let separate_pure_ada = change_config.pure_ada_output || self.config.prefer_pure_change;
+        let min_pure_outputs = usize::from(separate_pure_ada || bundles.is_empty());
+        let target_outputs = change_config.target_outputs.unwrap_or(0) as usize;
+        let mut pure_outputs =
+            std::cmp::max(min_pure_outputs, target_outputs.saturating_sub(bundles.len()));
+
+        // outputs with the whole change coin are the biggest possible ones,
+        // so the fee and min ada calculated with them stay valid for the real outputs
+        let utxo_cost = self.config.utxo_cost();
+        let asset_outputs: Vec<TransactionOutput> = bundles
+            .iter()
+            .map(|assets| change_config.make_output(&Value::new_with_assets(&change.coin, assets)))
+            .collect();
+        let mut assets_min_ada = Vec::with_capacity(asset_outputs.len());
+        for output in asset_outputs.iter() {
+            assets_min_ada.push(min_ada_for_output(output, &utxo_cost)?);
+        }
+        let assets_min_ada_total = assets_min_ada
+            .iter()
+            .try_fold(Coin::zero(), |acc, min_ada| acc.checked_add(min_ada))?;
+        let pure_output = change_config.make_output(&Value::new(&change.coin));
+        let pure_min_ada = min_ada_for_output(&pure_output, &utxo_cost)?;
+
+        loop {
+            if bundles.is_empty() && pure_outputs <= 1 {
+                return Ok(None);
+            }
+            let mut self_copy = self.clone();
+            for output in asset_outputs.iter() {
+                self_copy.add_output(output)?;
+            }
+            for _ in 0..pure_outputs {
+                self_copy.add_output(&pure_output)?;
+            }
+            let fee = self_copy.min_fee()?;
+            let required = fee
+                .checked_add(&assets_min_ada_total)?
+                .checked_add(&pure_min_ada.checked_mul(&BigNum::from(pure_outputs))?)?;
+            if change.coin < required {
+                if pure_outputs == 0 {
+                    return Err(JsError::from_str(
+                        "Not enough ADA leftover to include non-ADA assets in a change address",
+                    ));
+                }
+                pure_outputs -= 1;
+                continue;
+            }
+
+            let leftover = change.coin.checked_sub(&fee)?.checked_sub(&assets_min_ada_total)?;
+            self.set_final_fee(fee);
+            for (i, (assets, min_ada)) in bundles.iter().zip(assets_min_ada.iter()).enumerate() {
+                let coin = if pure_outputs == 0 && i == bundles.len() - 1 {
+                    min_ada.checked_add(&leftover)?
+                } else {
+                    *min_ada
+                };
+                self.add_output(&change_config.make_output(&Value::new_with_assets(&coin, assets)))?;
+            }
+            if pure_outputs > 0 {
+                let outputs_count = BigNum::from(pure_outputs);
+                let share = leftover.div_floor(&outputs_count);
+                let remainder = leftover.checked_sub(&share.checked_mul(&outputs_count)?)?;
+                for i in 0..pure_outputs {
+                    let coin = if i == 0 { share.checked_add(&remainder)? } else { share };
+                    self.add_output(&change_config.make_output(&Value::new(&coin)))?;
+                }
+            }
+            return Ok(Some(true));
+        }
+    }
+
+    fn add_change_output_if_needed(
+        &mut self,
+        change_config: &ChangeConfig,
     ) -> Result<bool, JsError> {
+        let address = &change_config.address;
+        let plutus_data = change_config.plutus_data.clone().map(|od| od.0);
+        let script_ref = change_config.script_ref.clone();
         let fee = match &self.fee {
             None => self.min_fee(),
             // generating the change output involves changing the fee
@@ -1875,6 +2676,12 @@ impl TransactionBuilder {
             }
             Some(Ordering::Less) => Err(JsError::from_str("Insufficient input in transaction")),
             Some(Ordering::Greater) => {
+                if change_config.has_split_policy() {
+                    let change = input_total.checked_sub(&output_total)?;
+                    if let Some(added) = self.add_split_change(change_config, &change)? {
+                        return Ok(added);
+                    }
+                }
                 fn has_assets(ma: Option<MultiAsset>) -> bool {
                     ma.map(|assets| assets.len() > 0).unwrap_or(false)
                 }
@@ -1926,6 +2733,7 @@ impl TransactionBuilder {
                             plutus_data: plutus_data.clone(),
                             script_ref: script_ref.clone(),
                             serialization_format: None,
+                            original_encoding: OriginalEncoding::default(),
                         };
                         // If this becomes slow on large TXs we can optimize it like the following
                         // to avoid cloning + reserializing the entire output.
@@ -1986,6 +2794,7 @@ impl TransactionBuilder {
                                         plutus_data: plutus_data.clone(),
                                         script_ref: script_ref.clone(),
                                         serialization_format: None,
+                                        original_encoding: OriginalEncoding::default(),
                                     };
 
                                     // 3. continue building the new output from the asset we stopped
@@ -2079,6 +2888,7 @@ impl TransactionBuilder {
                                 plutus_data: plutus_data.clone(),
                                 script_ref: script_ref.clone(),
                                 serialization_format: None,
+                                original_encoding: OriginalEncoding::default(),
                             };
 
                             // increase fee
@@ -2101,6 +2911,7 @@ impl TransactionBuilder {
                             plutus_data: plutus_data.clone(),
                             script_ref: script_ref.clone(),
                             serialization_format: None,
+                            original_encoding: OriginalEncoding::default(),
                         };
                         let additional_fee = self.fee_for_output(&pure_output)?;
                         let potential_pure_value =
@@ -2116,6 +2927,7 @@ impl TransactionBuilder {
                                 plutus_data: plutus_data.clone(),
                                 script_ref: script_ref.clone(),
                                 serialization_format: None,
+                                original_encoding: OriginalEncoding::default(),
                             })?;
                         }
                     }
@@ -2176,6 +2988,7 @@ impl TransactionBuilder {
                                 plutus_data: plutus_data.clone(),
                                 script_ref: script_ref.clone(),
                                 serialization_format: None,
+                                original_encoding: OriginalEncoding::default(),
                             })?;
 
                             let new_fee = fee.checked_add(&fee_for_change)?;
@@ -2194,6 +3007,7 @@ impl TransactionBuilder {
                                         plutus_data: plutus_data.clone(),
                                         script_ref: script_ref.clone(),
                                         serialization_format: None,
+                                        original_encoding: OriginalEncoding::default(),
                                     })?;
 
                                     Ok(true)
@@ -2346,6 +3160,7 @@ impl TransactionBuilder {
             voting_proposals: self.voting_proposals.as_ref().map(|x| x.build()),
             donation: self.donation.clone(),
             current_treasury_value: self.current_treasury_value.clone(),
+            original_encoding: OriginalEncoding::default(),
         };
         // we must build a tx with fake data (of correct size) to check the final Transaction size
         let full_tx = fake_full_tx(self, built)?;
@@ -2587,6 +3402,7 @@ impl TransactionBuilder {
             witness_set: self.get_witness_set(),
             is_valid: true,
             auxiliary_data: self.auxiliary_data.clone(),
+            original_encoding: OriginalEncoding::default(),
         })
     }
 
@@ -2599,3 +3415,88 @@ impl TransactionBuilder {
         Ok(self.fee_request.get_new_fee(min_fee(&self_copy)?))
     }
 }
+
+impl TransactionBuilder {
+    /// Balances the transaction and fills the execution units of all redeemers using the evaluator.
+    /// The draft transaction is balanced with the change config and the script data hash is
+    /// calculated with the passed cost models, then the draft is passed to the evaluator together
+    /// with the resolved utxos. Evaluated execution units are written into the plutus witnesses
+    /// and the whole process is repeated until the execution units stop changing, because the fee,
+    /// the change output and the script data hash depend on them.
+    /// `utxos` must contain the outputs of all regular, collateral and reference inputs.
+    /// This should be called after adding all inputs/outputs/certs/etc. and instead of
+    /// `.add_change_if_needed` and `.calc_script_data_hash`.
+    /// On success the builder keeps the balanced state, the same way `.add_change_if_needed` does.
+    /// NOTE: this function is not available through wasm bindings
+    pub fn build_tx_with_evaluator(
+        &mut self,
+        evaluator: &dyn ScriptEvaluator,
+        utxos: &TransactionUnspentOutputs,
+        cost_models: &Costmdls,
+        change_config: &ChangeConfig,
+    ) -> Result<Transaction, JsError> {
+        if self.fee.is_some() {
+            return Err(JsError::from_str(
+                "Cannot evaluate scripts if change was calculated before",
+            ));
+        }
+        for _ in 0..MAX_SCRIPT_EVALUATION_ROUNDS {
+            let mut draft = self.clone();
+            draft.calc_script_data_hash(cost_models)?;
+            draft.add_change_if_needed_with_config(change_config)?;
+            let draft_tx = draft.build_tx()?;
+            let current = match &draft_tx.witness_set.redeemers {
+                Some(redeemers) => evaluated_ex_units_from_redeemers(redeemers),
+                None => EvaluatedExUnits::new(),
+            };
+            if current.is_empty() {
+                *self = draft;
+                return Ok(draft_tx);
+            }
+
+            let evaluated =
+                evaluated_ex_units_from_redeemers(&evaluator.evaluate(&draft_tx, utxos)?);
+            let mut changed = false;
+            for (key, ex_units) in current.iter() {
+                match evaluated.get(key) {
+                    Some(evaluated_ex_units) => changed |= evaluated_ex_units != ex_units,
+                    None => {
+                        return Err(JsError::from_str(&format!(
+                            "Script evaluator returned no execution units for redeemer {:?} with index {}",
+                            key.0.kind(),
+                            key.1
+                        )))
+                    }
+                }
+            }
+            if !changed {
+                *self = draft;
+                return Ok(draft_tx);
+            }
+            self.set_evaluated_ex_units(&evaluated);
+        }
+        Err(JsError::from_str(&format!(
+            "Script execution units did not converge after {} evaluation rounds",
+            MAX_SCRIPT_EVALUATION_ROUNDS
+        )))
+    }
+
+    fn set_evaluated_ex_units(&mut self, evaluated: &EvaluatedExUnits) {
+        self.inputs.set_evaluated_ex_units(evaluated);
+        if let Some(mint_builder) = &mut self.mint {
+            mint_builder.set_evaluated_ex_units(evaluated);
+        }
+        if let Some(certs_builder) = &mut self.certs {
+            certs_builder.set_evaluated_ex_units(evaluated);
+        }
+        if let Some(withdrawals_builder) = &mut self.withdrawals {
+            withdrawals_builder.set_evaluated_ex_units(evaluated);
+        }
+        if let Some(voting_builder) = &mut self.voting_procedures {
+            voting_builder.set_evaluated_ex_units(evaluated);
+        }
+        if let Some(voting_proposal_builder) = &mut self.voting_proposals {
+            voting_proposal_builder.set_evaluated_ex_units(evaluated);
+        }
+    }
+}
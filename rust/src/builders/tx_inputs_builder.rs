@@ -363,6 +363,28 @@ impl TxInputsBuilder {
         }
     }
 
+    pub(crate) fn set_evaluated_ex_units(&mut self, evaluated: &EvaluatedExUnits) {
+        let tag = RedeemerTag::new_spend();
+        let script_input_indexes: BTreeMap<TransactionInput, BigNum> = self
+            .inputs
+            .values()
+            .enumerate()
+            .filter(|(_, (_, hash_option))| hash_option.is_some())
+            .map(|(i, (tx_in, _))| (tx_in.input.clone(), BigNum::from(i)))
+            .collect();
+        self.required_witnesses
+            .scripts
+            .iter_mut()
+            .flat_map(|x| x.1.iter_mut())
+            .for_each(|(input, option)| {
+                if let Some(ScriptWitnessType::PlutusScriptWitness(s)) = option {
+                    if let Some(idx) = script_input_indexes.get(input) {
+                        s.set_evaluated_ex_units(idx, &tag, evaluated);
+                    }
+                }
+            });
+    }
+
     pub(crate) fn has_plutus_scripts(&self) -> bool {
         self.required_witnesses.scripts.values().any(|x| {
             x.iter()
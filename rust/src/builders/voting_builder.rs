@@ -131,6 +131,15 @@ impl VotingBuilder {
         scripts
     }
 
+    pub(crate) fn set_evaluated_ex_units(&mut self, evaluated: &EvaluatedExUnits) {
+        let tag = RedeemerTag::new_vote();
+        for (i, (_, voter_votes)) in self.votes.iter_mut().enumerate() {
+            if let Some(ScriptWitnessType::PlutusScriptWitness(s)) = &mut voter_votes.script_witness {
+                s.set_evaluated_ex_units(&BigNum::from(i), &tag, evaluated);
+            }
+        }
+    }
+
     pub fn get_ref_inputs(&self) -> TransactionInputs {
         let mut inputs = Vec::new();
         for (_, voter_votes) in &self.votes {
@@ -47,6 +47,15 @@ impl VotingProposalBuilder {
         scripts
     }
 
+    pub(crate) fn set_evaluated_ex_units(&mut self, evaluated: &EvaluatedExUnits) {
+        let tag = RedeemerTag::new_voting_proposal();
+        for (i, (_, script_wit)) in self.proposals.iter_mut().enumerate() {
+            if let Some(ScriptWitnessType::PlutusScriptWitness(s)) = script_wit {
+                s.set_evaluated_ex_units(&BigNum::from(i), &tag, evaluated);
+            }
+        }
+    }
+
     pub fn get_ref_inputs(&self) -> TransactionInputs {
         let mut inputs = Vec::new();
         for (_, script_wit) in &self.proposals {
@@ -106,6 +106,15 @@ impl WithdrawalsBuilder {
         scripts
     }
 
+    pub(crate) fn set_evaluated_ex_units(&mut self, evaluated: &EvaluatedExUnits) {
+        let tag = RedeemerTag::new_reward();
+        for (i, (_, (_, script_wit))) in self.withdrawals.iter_mut().enumerate() {
+            if let Some(ScriptWitnessType::PlutusScriptWitness(s)) = script_wit {
+                s.set_evaluated_ex_units(&BigNum::from(i), &tag, evaluated);
+            }
+        }
+    }
+
     pub fn get_ref_inputs(&self) -> TransactionInputs {
         let mut inputs = Vec::new();
         for (_, (_, script_wit)) in self.withdrawals.iter() {
@@ -0,0 +1,194 @@
+use crate::*;
+
+// HD wallet accounts m/1852'/1815'/account'/role/index
+// https://github.com/cardano-foundation/CIPs/tree/master/CIP-1852
+// with the governance roles of https://github.com/cardano-foundation/CIPs/tree/master/CIP-0105
+
+const PURPOSE: u32 = 1852;
+const COIN_TYPE: u32 = 1815;
+const HARDENED: u32 = 0x80_00_00_00;
+
+/// Chain of a CIP-1852 account, the value is the role index of the derivation path
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Cip1852Role {
+    /// Payment keys of receiving addresses
+    External = 0,
+    /// Payment keys of change addresses
+    Internal = 1,
+    Stake = 2,
+    DRep = 3,
+    CommitteeCold = 4,
+    CommitteeHot = 5,
+}
+
+/// A CIP-1852 account. Accounts built from an account public key can derive the public keys,
+/// the addresses and the credentials but not the private keys.
+#[wasm_bindgen]
+pub struct Cip1852Account {
+    account: u32,
+    account_key: Option<Bip32PrivateKey>,
+    account_public_key: Bip32PublicKey,
+}
+
+#[wasm_bindgen]
+impl Cip1852Account {
+    /// Derives the account m/1852'/1815'/{account}' of the root key, {account} is not hardened yet
+    pub fn from_root_key(
+        root_key: &Bip32PrivateKey,
+        account: u32,
+    ) -> Result<Cip1852Account, JsError> {
+        check_soft_index("account", account)?;
+        let account_key = root_key
+            .derive(harden(PURPOSE))
+            .derive(harden(COIN_TYPE))
+            .derive(harden(account));
+        Self::from_account_key(&account_key, account)
+    }
+
+    /// {account_key} is the key m/1852'/1815'/{account}', {account} is not hardened
+    pub fn from_account_key(
+        account_key: &Bip32PrivateKey,
+        account: u32,
+    ) -> Result<Cip1852Account, JsError> {
+        check_soft_index("account", account)?;
+        Ok(Cip1852Account {
+            account,
+            account_key: Some(Bip32PrivateKey(account_key.0.clone())),
+            account_public_key: account_key.to_public(),
+        })
+    }
+
+    /// {account_public_key} is the public key of m/1852'/1815'/{account}', {account} is not hardened
+    pub fn from_account_public_key(
+        account_public_key: &Bip32PublicKey,
+        account: u32,
+    ) -> Result<Cip1852Account, JsError> {
+        check_soft_index("account", account)?;
+        Ok(Cip1852Account {
+            account,
+            account_key: None,
+            account_public_key: Bip32PublicKey(account_public_key.0.clone()),
+        })
+    }
+
+    /// Index of the account in its derivation path, not hardened
+    pub fn account(&self) -> u32 {
+        self.account
+    }
+
+    /// None if the account was built from an account public key
+    pub fn account_key(&self) -> Option<Bip32PrivateKey> {
+        self.account_key
+            .as_ref()
+            .map(|key| Bip32PrivateKey(key.0.clone()))
+    }
+
+    pub fn account_public_key(&self) -> Bip32PublicKey {
+        Bip32PublicKey(self.account_public_key.0.clone())
+    }
+
+    pub fn has_private_keys(&self) -> bool {
+        self.account_key.is_some()
+    }
+
+    /// Fails if the account was built from an account public key or if {index} is hardened
+    pub fn private_key(&self, role: Cip1852Role, index: u32) -> Result<Bip32PrivateKey, JsError> {
+        check_soft_index("address", index)?;
+        match &self.account_key {
+            Some(account_key) => Ok(account_key.derive(role as u32).derive(index)),
+            None => Err(JsError::from_str(
+                "the account was built from a public key, its private keys are unknown",
+            )),
+        }
+    }
+
+    /// Fails if {index} is hardened
+    pub fn public_key(&self, role: Cip1852Role, index: u32) -> Result<Bip32PublicKey, JsError> {
+        check_soft_index("address", index)?;
+        self.account_public_key.derive(role as u32)?.derive(index)
+    }
+
+    pub fn external_key(&self, index: u32) -> Result<Bip32PublicKey, JsError> {
+        self.public_key(Cip1852Role::External, index)
+    }
+
+    pub fn internal_key(&self, index: u32) -> Result<Bip32PublicKey, JsError> {
+        self.public_key(Cip1852Role::Internal, index)
+    }
+
+    pub fn stake_key(&self, index: u32) -> Result<Bip32PublicKey, JsError> {
+        self.public_key(Cip1852Role::Stake, index)
+    }
+
+    pub fn drep_key(&self, index: u32) -> Result<Bip32PublicKey, JsError> {
+        self.public_key(Cip1852Role::DRep, index)
+    }
+
+    pub fn committee_cold_key(&self, index: u32) -> Result<Bip32PublicKey, JsError> {
+        self.public_key(Cip1852Role::CommitteeCold, index)
+    }
+
+    pub fn committee_hot_key(&self, index: u32) -> Result<Bip32PublicKey, JsError> {
+        self.public_key(Cip1852Role::CommitteeHot, index)
+    }
+
+    /// Receiving address {index} delegated with the first stake key of the account like wallets do
+    pub fn base_address(&self, network: u8, index: u32) -> Result<BaseAddress, JsError> {
+        Ok(BaseAddress::new(
+            network,
+            &self.credential(Cip1852Role::External, index)?,
+            &self.credential(Cip1852Role::Stake, 0)?,
+        ))
+    }
+
+    /// Change address {index} delegated with the first stake key of the account like wallets do
+    pub fn change_address(&self, network: u8, index: u32) -> Result<BaseAddress, JsError> {
+        Ok(BaseAddress::new(
+            network,
+            &self.credential(Cip1852Role::Internal, index)?,
+            &self.credential(Cip1852Role::Stake, 0)?,
+        ))
+    }
+
+    pub fn reward_address(&self, network: u8, index: u32) -> Result<RewardAddress, JsError> {
+        Ok(RewardAddress::new(
+            network,
+            &self.credential(Cip1852Role::Stake, index)?,
+        ))
+    }
+
+    pub fn drep(&self, index: u32) -> Result<DRep, JsError> {
+        Ok(DRep::new_from_credential(
+            &self.credential(Cip1852Role::DRep, index)?,
+        ))
+    }
+
+    pub fn committee_cold_credential(&self, index: u32) -> Result<Credential, JsError> {
+        self.credential(Cip1852Role::CommitteeCold, index)
+    }
+
+    pub fn committee_hot_credential(&self, index: u32) -> Result<Credential, JsError> {
+        self.credential(Cip1852Role::CommitteeHot, index)
+    }
+
+    /// Key hash credential of the public key {index} of {role}
+    pub fn credential(&self, role: Cip1852Role, index: u32) -> Result<Credential, JsError> {
+        let key = self.public_key(role, index)?;
+        Ok(Credential::from_keyhash(&key.to_raw_key().hash()))
+    }
+}
+
+fn harden(index: u32) -> u32 {
+    index | HARDENED
+}
+
+fn check_soft_index(name: &str, index: u32) -> Result<(), JsError> {
+    if index >= HARDENED {
+        return Err(JsError::from_str(&format!(
+            "the {} index must be below 2^31, found {}",
+            name, index
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use crate::*;
+
+// Message signing of CIP-8 as used by the signData endpoint of CIP-30 wallets:
+// a COSE_Sign1 (RFC 8152) with an EdDSA signature and the address in the protected header,
+// the public key is given next to it as a COSE_Key.
+// https://github.com/cardano-foundation/CIPs/tree/master/CIP-0008
+// https://github.com/cardano-foundation/CIPs/tree/master/CIP-0030
+
+pub(crate) const ALGORITHM_EDDSA: i128 = -8;
+pub(crate) const KEY_TYPE_OKP: i128 = 1;
+pub(crate) const CURVE_ED25519: i128 = 6;
+
+/// COSE_Key of an Ed25519 public key
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoseKey {
+    pub(crate) public_key: PublicKey,
+}
+
+to_from_bytes!(CoseKey);
+
+#[wasm_bindgen]
+impl CoseKey {
+    pub fn new(public_key: &PublicKey) -> CoseKey {
+        CoseKey {
+            public_key: public_key.clone(),
+        }
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key.clone()
+    }
+}
+
+/// COSE_Sign1 signed message. The signature covers the protected header, the external AAD
+/// and the payload. With a hashed payload the Blake2b-224 hash of the message is signed
+/// and carried instead of the message.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoseSign1 {
+    // the protected header is signed as it is encoded
+    pub(crate) protected_header: Vec<u8>,
+    pub(crate) algorithm: Option<i128>,
+    pub(crate) address: Option<Vec<u8>>,
+    pub(crate) hashed: bool,
+    pub(crate) payload: Option<Vec<u8>>,
+    pub(crate) signature: Ed25519Signature,
+}
+
+to_from_bytes!(CoseSign1);
+
+#[wasm_bindgen]
+impl CoseSign1 {
+    /// Signs {payload} with {key} like signData of CIP-30, {address} is the address the key belongs to.
+    /// {external_aad} is signed but not included in the message, the verifier has to provide it.
+    pub fn new_signed(
+        key: &PrivateKey,
+        address: &Address,
+        payload: &[u8],
+        external_aad: &[u8],
+        hash_payload: bool,
+    ) -> CoseSign1 {
+        let payload = if hash_payload {
+            blake2b224(payload).to_vec()
+        } else {
+            payload.to_vec()
+        };
+        let protected_header = protected_header_bytes(&address.to_bytes());
+        let signature = key.sign(&sig_structure(&protected_header, external_aad, &payload));
+        CoseSign1 {
+            protected_header,
+            algorithm: Some(ALGORITHM_EDDSA),
+            address: Some(address.to_bytes()),
+            hashed: hash_payload,
+            payload: Some(payload),
+            signature,
+        }
+    }
+
+    /// Address of the protected header, None if the header has no address
+    pub fn address(&self) -> Result<Option<Address>, JsError> {
+        match &self.address {
+            Some(address) => Ok(Some(Address::from_bytes(address.clone())?)),
+            None => Ok(None),
+        }
+    }
+
+    /// The signed payload, the hash of the message if is_hashed(). None if the payload is detached.
+    pub fn payload(&self) -> Option<Vec<u8>> {
+        self.payload.clone()
+    }
+
+    pub fn is_hashed(&self) -> bool {
+        self.hashed
+    }
+
+    pub fn signature(&self) -> Ed25519Signature {
+        self.signature.clone()
+    }
+
+    /// True if the payload is {message}, or its hash if is_hashed()
+    pub fn payload_matches(&self, message: &[u8]) -> bool {
+        match &self.payload {
+            Some(payload) if self.hashed => payload[..] == blake2b224(message)[..],
+            Some(payload) => payload[..] == message[..],
+            None => false,
+        }
+    }
+
+    /// The Sig_structure bytes the signature is made on
+    pub fn signed_data(&self, external_aad: &[u8]) -> Result<Vec<u8>, JsError> {
+        match &self.payload {
+            Some(payload) => Ok(sig_structure(&self.protected_header, external_aad, payload)),
+            None => Err(JsError::from_str("the payload is detached")),
+        }
+    }
+
+    /// Checks that the signature is an EdDSA signature of {key} and that {key} is the payment key
+    /// or the stake key of the address of the protected header.
+    /// Returns false if the key doesn't match the address or the signature is wrong,
+    /// fails if the message can't be checked: no address, another algorithm or a detached payload.
+    pub fn verify(&self, key: &CoseKey, external_aad: &[u8]) -> Result<bool, JsError> {
+        if self.algorithm != Some(ALGORITHM_EDDSA) {
+            return Err(JsError::from_str(
+                "only messages signed with EdDSA (-8) can be verified",
+            ));
+        }
+        let address = self
+            .address()?
+            .ok_or_else(|| JsError::from_str("the protected header has no address"))?;
+        if address.kind() == AddressKind::Byron || address.is_malformed() {
+            return Err(JsError::from_str(
+                "only Shelley addresses can be checked against the key",
+            ));
+        }
+        let data = self.signed_data(external_aad)?;
+        let key_hash = key.public_key.hash();
+        let stake_cred = BaseAddress::from_address(&address).map(|base| base.stake_cred());
+        let key_matches = address
+            .payment_cred()
+            .into_iter()
+            .chain(stake_cred)
+            .any(|cred| cred.to_keyhash() == Some(key_hash.clone()));
+        Ok(key_matches && key.public_key.verify(&data, &self.signature))
+    }
+}
+
+fn protected_header_bytes(address: &[u8]) -> Vec<u8> {
+    let mut serializer = Serializer::new_vec();
+    serializer.write_map(cbor_event::Len::Len(2)).unwrap();
+    serializer.write_unsigned_integer(1).unwrap();
+    serializer
+        .write_negative_integer(ALGORITHM_EDDSA as i64)
+        .unwrap();
+    serializer.write_text("address").unwrap();
+    serializer.write_bytes(address).unwrap();
+    serializer.finalize()
+}
+
+// Sig_structure = ["Signature1", protected, external_aad, payload]
+fn sig_structure(protected_header: &[u8], external_aad: &[u8], payload: &[u8]) -> Vec<u8> {
+    let mut serializer = Serializer::new_vec();
+    serializer.write_array(cbor_event::Len::Len(4)).unwrap();
+    serializer.write_text("Signature1").unwrap();
+    serializer.write_bytes(protected_header).unwrap();
+    serializer.write_bytes(external_aad).unwrap();
+    serializer.write_bytes(payload).unwrap();
+    serializer.finalize()
+}
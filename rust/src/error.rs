@@ -62,36 +62,158 @@ pub enum DeserializeFailure {
     IoError(String),
     ExpectedType(String, cbor_event::Type),
     CustomError(String),
+    InvalidForEra {
+        era: BlockEra,
+        valid_from: BlockEra,
+        valid_until: BlockEra,
+    },
+}
+
+/// One step of the decode chain of a DeserializeError, ordered from the outermost value to the failing one
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DeserializeLocation {
+    /// A type being decoded, e.g. `TransactionBody`
+    Type(String),
+    /// A field of the enclosing type, e.g. `outputs`
+    Field(String),
+    /// An item of the enclosing array
+    Index(usize),
+}
+
+impl DeserializeLocation {
+    // annotations name types in UpperCamelCase and fields in snake_case
+    fn from_annotation(location: String) -> Self {
+        match location.chars().next() {
+            Some(c) if c.is_uppercase() => DeserializeLocation::Type(location),
+            _ => DeserializeLocation::Field(location),
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct DeserializeError {
-    location: Option<String>,
+    chain: Vec<DeserializeLocation>,
     failure: DeserializeFailure,
+    offset: Option<u64>,
+    cbor_type: Option<cbor_event::Type>,
 }
 
 impl DeserializeError {
     pub fn new<T: Into<String>>(location: T, failure: DeserializeFailure) -> Self {
         Self {
-            location: Some(location.into()),
+            chain: vec![DeserializeLocation::from_annotation(location.into())],
             failure,
+            offset: None,
+            cbor_type: None,
+        }
+    }
+
+    pub fn annotate<T: Into<String>>(mut self, location: T) -> Self {
+        self.chain
+            .insert(0, DeserializeLocation::from_annotation(location.into()));
+        self
+    }
+
+    /// Marks the error as coming from the item at {index} of the array being decoded
+    pub fn annotate_index(mut self, index: usize) -> Self {
+        self.chain.insert(0, DeserializeLocation::Index(index));
+        self
+    }
+
+    /// Records where the reader stopped, unless an inner deserializer already located the failure.
+    /// Only for failures whose item start is unknown: a deserializer can consume an item before rejecting it,
+    /// so the reader may already be at the next item.
+    pub(crate) fn locate<R: BufRead + Seek>(mut self, raw: &mut Deserializer<R>) -> Self {
+        if self.offset.is_none() {
+            self.offset = raw.as_mut_ref().stream_position().ok();
+            self.cbor_type = raw.cbor_type().ok();
+        }
+        self
+    }
+
+    /// Records the item starting at {start} as the position of the failure,
+    /// unless an inner deserializer already located it. The reader is left where it stopped.
+    pub(crate) fn locate_item<R: BufRead + Seek>(
+        mut self,
+        raw: &mut Deserializer<R>,
+        start: u64,
+    ) -> Self {
+        if self.offset.is_none() {
+            // these unwraps are fine since we only seek within what was read already
+            let stopped = raw.as_mut_ref().stream_position().unwrap();
+            raw.as_mut_ref().seek(std::io::SeekFrom::Start(start)).unwrap();
+            self.offset = Some(start);
+            self.cbor_type = raw.cbor_type().ok();
+            raw.as_mut_ref().seek(std::io::SeekFrom::Start(stopped)).unwrap();
+        }
+        self
+    }
+
+    pub fn failure(&self) -> &DeserializeFailure {
+        &self.failure
+    }
+
+    /// Byte offset of the failure from the start of the decoded bytes
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    /// CBOR major type found at the offset, None when the bytes ended there
+    pub fn cbor_type(&self) -> Option<cbor_event::Type> {
+        self.cbor_type
+    }
+
+    /// Types, fields and array items that were being decoded when the failure happened
+    pub fn decode_chain(&self) -> &[DeserializeLocation] {
+        &self.chain
+    }
+
+    /// Fields and array items leading to the failure, e.g. `body.outputs[3].amount.multiasset`
+    pub fn path(&self) -> String {
+        Self::join(
+            self.chain
+                .iter()
+                .filter(|location| !matches!(location, DeserializeLocation::Type(_))),
+        )
+    }
+
+    fn location(&self) -> Option<String> {
+        match self.chain.is_empty() {
+            true => None,
+            false => Some(Self::join(self.chain.iter())),
         }
     }
 
-    pub fn annotate<T: Into<String>>(self, location: T) -> Self {
-        match self.location {
-            Some(loc) => Self::new(format!("{}.{}", location.into(), loc), self.failure),
-            None => Self::new(location, self.failure),
+    fn join<'a>(chain: impl Iterator<Item = &'a DeserializeLocation>) -> String {
+        let mut joined = String::new();
+        for location in chain {
+            match location {
+                DeserializeLocation::Index(index) => joined.push_str(&format!("[{}]", index)),
+                DeserializeLocation::Type(name) | DeserializeLocation::Field(name) => {
+                    if !joined.is_empty() {
+                        joined.push('.');
+                    }
+                    joined.push_str(name);
+                }
+            }
         }
+        joined
     }
 }
 
 impl std::fmt::Display for DeserializeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &self.location {
-            Some(loc) => write!(f, "Deserialization failed in {} because: ", loc),
-            None => write!(f, "Deserialization: "),
+        match self.location() {
+            Some(loc) => write!(f, "Deserialization failed in {}", loc),
+            None => write!(f, "Deserialization failed"),
         }?;
+        if let Some(offset) = self.offset {
+            write!(f, " at byte {}", offset)?;
+            if let Some(cbor_type) = &self.cbor_type {
+                write!(f, " (found CBOR {:?})", cbor_type)?;
+            }
+        }
+        write!(f, " because: ")?;
         match &self.failure {
             DeserializeFailure::BadAddressType(header) => {
                 write!(f, "Encountered unknown address header {:#08b}", header)
@@ -145,6 +267,15 @@ impl std::fmt::Display for DeserializeError {
             DeserializeFailure::ExpectedType(expected, found) => {
                 write!(f, "Expected type {}, found {:?}", expected, found)
             }
+            DeserializeFailure::InvalidForEra {
+                era,
+                valid_from,
+                valid_until,
+            } => write!(
+                f,
+                "Not valid in the {:?} era, only from {:?} to {:?}",
+                era, valid_from, valid_until
+            ),
         }
     }
 }
@@ -161,8 +292,10 @@ impl From<DeserializeError> for JsError {
 impl From<DeserializeFailure> for DeserializeError {
     fn from(failure: DeserializeFailure) -> DeserializeError {
         DeserializeError {
-            location: None,
+            chain: Vec::new(),
             failure,
+            offset: None,
+            cbor_type: None,
         }
     }
 }
@@ -170,8 +303,10 @@ impl From<DeserializeFailure> for DeserializeError {
 impl From<cbor_event::Error> for DeserializeError {
     fn from(err: cbor_event::Error) -> DeserializeError {
         DeserializeError {
-            location: None,
+            chain: Vec::new(),
             failure: DeserializeFailure::CBOR(err),
+            offset: None,
+            cbor_type: None,
         }
     }
 }
@@ -179,8 +314,10 @@ impl From<cbor_event::Error> for DeserializeError {
 impl From<chain_crypto::SignatureError> for DeserializeError {
     fn from(err: chain_crypto::SignatureError) -> DeserializeError {
         DeserializeError {
-            location: None,
+            chain: Vec::new(),
             failure: DeserializeFailure::SignatureError(err),
+            offset: None,
+            cbor_type: None,
         }
     }
 }
@@ -188,8 +325,10 @@ impl From<chain_crypto::SignatureError> for DeserializeError {
 impl From<chain_crypto::PublicKeyError> for DeserializeError {
     fn from(err: chain_crypto::PublicKeyError) -> DeserializeError {
         DeserializeError {
-            location: None,
+            chain: Vec::new(),
             failure: DeserializeFailure::PublicKeyError(err),
+            offset: None,
+            cbor_type: None,
         }
     }
 }
@@ -0,0 +1,7 @@
+mod transaction_explanation;
+pub use transaction_explanation::*;
+
+mod text;
+
+mod transaction_view;
+pub(crate) use transaction_view::*;
@@ -0,0 +1,217 @@
+use super::transaction_explanation::*;
+use std::fmt::Write;
+
+pub(super) fn render_text(explanation: &TransactionExplanation) -> String {
+    let mut text = String::new();
+    // writing to a String can't fail
+    let _ = write_explanation(&mut text, explanation);
+    text
+}
+
+fn write_explanation(text: &mut String, explanation: &TransactionExplanation) -> std::fmt::Result {
+    writeln!(text, "Transaction {}", explanation.hash)?;
+    writeln!(text, "Fee: {} lovelace", explanation.fee)?;
+    match (&explanation.invalid_before, &explanation.invalid_hereafter) {
+        (Some(start), Some(end)) => writeln!(text, "Valid from slot {} until slot {}", start, end)?,
+        (Some(start), None) => writeln!(text, "Valid from slot {}", start)?,
+        (None, Some(end)) => writeln!(text, "Valid until slot {}", end)?,
+        (None, None) => {}
+    }
+    if !explanation.is_valid {
+        writeln!(
+            text,
+            "Scripts are expected to fail, only the collateral is spent"
+        )?;
+    }
+
+    write_inputs(text, "Inputs", &explanation.inputs)?;
+    write_inputs(text, "Reference inputs", &explanation.reference_inputs)?;
+    if !explanation.outputs.is_empty() {
+        writeln!(text, "Outputs:")?;
+        for (i, output) in explanation.outputs.iter().enumerate() {
+            writeln!(text, "  #{} {}", i, output.address)?;
+            write_output_details(text, output)?;
+        }
+    }
+    write_inputs(text, "Collateral inputs", &explanation.collateral_inputs)?;
+    if let Some(output) = &explanation.collateral_return {
+        writeln!(text, "Collateral return: {}", output.address)?;
+        write_output_details(text, output)?;
+    }
+    if let Some(total_collateral) = &explanation.total_collateral {
+        writeln!(text, "Total collateral: {} lovelace", total_collateral)?;
+    }
+
+    if !explanation.mint.is_empty() {
+        writeln!(text, "Mint:")?;
+        for policy in &explanation.mint {
+            writeln!(text, "  policy {}", policy.policy_id)?;
+            for token in &policy.minted {
+                writeln!(text, "    minted {} {}", token.quantity, token.asset_name)?;
+            }
+            for token in &policy.burned {
+                writeln!(text, "    burned {} {}", token.quantity, token.asset_name)?;
+            }
+        }
+    }
+    if !explanation.certificates.is_empty() {
+        writeln!(text, "Certificates:")?;
+        for (i, cert) in explanation.certificates.iter().enumerate() {
+            match &cert.deposit {
+                Some(deposit) => {
+                    writeln!(text, "  #{} {}, {}", i, cert.kind, deposit_to_text(deposit))?
+                }
+                None => writeln!(text, "  #{} {}", i, cert.kind)?,
+            }
+        }
+    }
+    if !explanation.withdrawals.is_empty() {
+        writeln!(text, "Withdrawals:")?;
+        for withdrawal in &explanation.withdrawals {
+            writeln!(
+                text,
+                "  {}: {} lovelace",
+                withdrawal.reward_address, withdrawal.amount
+            )?;
+        }
+    }
+    if !explanation.votes.is_empty() {
+        writeln!(text, "Votes:")?;
+        for vote in &explanation.votes {
+            writeln!(
+                text,
+                "  {} {} votes {} on {}",
+                vote.voter_kind, vote.voter, vote.vote, vote.action_id
+            )?;
+            if let Some(url) = &vote.anchor_url {
+                writeln!(text, "    anchor: {}", url)?;
+            }
+        }
+    }
+    if !explanation.proposals.is_empty() {
+        writeln!(text, "Proposals:")?;
+        for (i, proposal) in explanation.proposals.iter().enumerate() {
+            writeln!(
+                text,
+                "  #{} {}, deposit {} lovelace returned to {}",
+                i, proposal.action, proposal.deposit, proposal.reward_address
+            )?;
+            writeln!(text, "    anchor: {}", proposal.anchor_url)?;
+        }
+    }
+    if !explanation.required_signers.is_empty() {
+        writeln!(text, "Required signers:")?;
+        for signer in &explanation.required_signers {
+            writeln!(text, "  {}", signer)?;
+        }
+    }
+    if let Some(donation) = &explanation.donation {
+        writeln!(text, "Treasury donation: {} lovelace", donation)?;
+    }
+    if let Some(treasury) = &explanation.current_treasury_value {
+        writeln!(text, "Current treasury value: {} lovelace", treasury)?;
+    }
+
+    if !explanation.balances_complete {
+        writeln!(
+            text,
+            "Some spent inputs are not resolved, the net changes below are incomplete"
+        )?;
+    }
+    write_balances(
+        text,
+        "Net change per address",
+        &explanation.address_balances,
+    )?;
+    write_balances(
+        text,
+        "Net change per stake credential",
+        &explanation.stake_credential_balances,
+    )
+}
+
+fn write_inputs(text: &mut String, title: &str, inputs: &[InputExplanation]) -> std::fmt::Result {
+    if inputs.is_empty() {
+        return Ok(());
+    }
+    writeln!(text, "{}:", title)?;
+    for input in inputs {
+        match &input.output {
+            Some(output) => {
+                writeln!(text, "  {} from {}", input.input, output.address)?;
+                write_output_details(text, output)?;
+            }
+            None => writeln!(text, "  {} (unresolved)", input.input)?,
+        }
+    }
+    Ok(())
+}
+
+fn write_output_details(text: &mut String, output: &OutputExplanation) -> std::fmt::Result {
+    writeln!(text, "    value: {}", value_to_text(&output.value, false))?;
+    match &output.datum {
+        Some(DatumExplanation::Hash(hash)) => writeln!(text, "    datum hash: {}", hash)?,
+        Some(DatumExplanation::Inline(datum)) => writeln!(text, "    inline datum: {}", datum)?,
+        None => {}
+    }
+    if let Some(script_ref) = &output.script_ref {
+        writeln!(
+            text,
+            "    script ref: {} {}",
+            script_ref.language, script_ref.hash
+        )?;
+    }
+    Ok(())
+}
+
+fn write_balances(text: &mut String, title: &str, balances: &[BalanceChange]) -> std::fmt::Result {
+    if balances.is_empty() {
+        return Ok(());
+    }
+    writeln!(text, "{}:", title)?;
+    for balance in balances {
+        writeln!(
+            text,
+            "  {}: {}",
+            balance.address,
+            value_to_text(&balance.change, true)
+        )?;
+    }
+    Ok(())
+}
+
+fn value_to_text(value: &ValueExplanation, signed: bool) -> String {
+    let quantity = |quantity: i128| {
+        if signed {
+            format!("{:+}", quantity)
+        } else {
+            quantity.to_string()
+        }
+    };
+    let mut parts = vec![format!("{} lovelace", quantity(value.coin))];
+    parts.extend(value.assets.iter().map(|asset| {
+        format!(
+            "{} {}.{}",
+            quantity(asset.quantity),
+            asset.policy_id,
+            asset.asset_name
+        )
+    }));
+    parts.join(", ")
+}
+
+fn deposit_to_text(deposit: &DepositChange) -> String {
+    match deposit {
+        DepositChange::Deposit(coin) => format!("deposit {} lovelace", coin),
+        DepositChange::Refund(coin) => format!("refund {} lovelace", coin),
+        DepositChange::ProtocolKeyDeposit => {
+            "key deposit set by the protocol parameters".to_string()
+        }
+        DepositChange::ProtocolKeyDepositRefund => {
+            "refund of the key deposit set by the protocol parameters".to_string()
+        }
+        DepositChange::ProtocolPoolDeposit => {
+            "pool deposit set by the protocol parameters for a new pool".to_string()
+        }
+    }
+}
@@ -0,0 +1,542 @@
+use super::text::render_text;
+use crate::*;
+use hashlink::LinkedHashMap;
+use std::collections::BTreeMap;
+
+/// Human-readable report of a transaction produced by Transaction::explain.
+/// It lists the inputs with their resolved outputs, the outputs with decoded datums and script refs,
+/// minted and burned assets, certificates with their deposits, votes, proposals and withdrawals,
+/// and ends with the net value change per address and per stake credential.
+#[wasm_bindgen]
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct TransactionExplanation {
+    pub(crate) hash: String,
+    pub(crate) is_valid: bool,
+    pub(crate) fee: Coin,
+    pub(crate) invalid_before: Option<SlotBigNum>,
+    pub(crate) invalid_hereafter: Option<SlotBigNum>,
+    pub(crate) inputs: Vec<InputExplanation>,
+    pub(crate) reference_inputs: Vec<InputExplanation>,
+    pub(crate) outputs: Vec<OutputExplanation>,
+    pub(crate) collateral_inputs: Vec<InputExplanation>,
+    pub(crate) collateral_return: Option<OutputExplanation>,
+    pub(crate) total_collateral: Option<Coin>,
+    pub(crate) mint: Vec<MintExplanation>,
+    pub(crate) certificates: Vec<CertificateExplanation>,
+    pub(crate) withdrawals: Vec<WithdrawalExplanation>,
+    pub(crate) votes: Vec<VoteExplanation>,
+    pub(crate) proposals: Vec<ProposalExplanation>,
+    pub(crate) required_signers: Vec<String>,
+    pub(crate) donation: Option<Coin>,
+    pub(crate) current_treasury_value: Option<Coin>,
+    pub(crate) balances_complete: bool,
+    pub(crate) address_balances: Vec<BalanceChange>,
+    pub(crate) stake_credential_balances: Vec<BalanceChange>,
+}
+
+#[wasm_bindgen]
+impl TransactionExplanation {
+    pub fn to_json(&self) -> Result<String, JsError> {
+        serde_json::to_string_pretty(&self)
+            .map_err(|e| JsError::from_str(&format!("to_json: {}", e)))
+    }
+
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    pub fn to_js_value(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(&self)
+            .map_err(|e| JsError::from_str(&format!("to_js_value: {}", e)))
+    }
+
+    /// Renders the explanation as plain text, one line per item
+    pub fn to_text(&self) -> String {
+        render_text(self)
+    }
+
+    /// False when some spent inputs are not in the resolved UTxOs,
+    /// the net balances don't include the values of these inputs
+    pub fn balances_complete(&self) -> bool {
+        self.balances_complete
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct InputExplanation {
+    pub(crate) input: String,
+    pub(crate) output: Option<OutputExplanation>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct OutputExplanation {
+    pub(crate) address: String,
+    pub(crate) value: ValueExplanation,
+    pub(crate) datum: Option<DatumExplanation>,
+    pub(crate) script_ref: Option<ScriptRefExplanation>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum DatumExplanation {
+    Hash(String),
+    Inline(serde_json::Value),
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct ScriptRefExplanation {
+    pub(crate) language: String,
+    pub(crate) hash: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct ValueExplanation {
+    #[serde(serialize_with = "serialize_quantity")]
+    pub(crate) coin: i128,
+    pub(crate) assets: Vec<AssetAmount>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct AssetAmount {
+    pub(crate) policy_id: String,
+    pub(crate) asset_name: String,
+    #[serde(serialize_with = "serialize_quantity")]
+    pub(crate) quantity: i128,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct MintExplanation {
+    pub(crate) policy_id: String,
+    pub(crate) minted: Vec<TokenAmount>,
+    pub(crate) burned: Vec<TokenAmount>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct TokenAmount {
+    pub(crate) asset_name: String,
+    #[serde(serialize_with = "serialize_quantity")]
+    pub(crate) quantity: i128,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct CertificateExplanation {
+    pub(crate) kind: String,
+    pub(crate) deposit: Option<DepositChange>,
+    pub(crate) details: serde_json::Value,
+}
+
+// deposit paid or refunded by a certificate, legacy certificates don't state the amount
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum DepositChange {
+    Deposit(Coin),
+    Refund(Coin),
+    ProtocolKeyDeposit,
+    ProtocolKeyDepositRefund,
+    ProtocolPoolDeposit,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct WithdrawalExplanation {
+    pub(crate) reward_address: String,
+    pub(crate) amount: Coin,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct VoteExplanation {
+    pub(crate) voter_kind: String,
+    pub(crate) voter: String,
+    pub(crate) action_id: String,
+    pub(crate) vote: String,
+    pub(crate) anchor_url: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct ProposalExplanation {
+    pub(crate) action: String,
+    pub(crate) deposit: Coin,
+    pub(crate) reward_address: String,
+    pub(crate) anchor_url: String,
+    pub(crate) details: serde_json::Value,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct BalanceChange {
+    pub(crate) address: String,
+    pub(crate) change: ValueExplanation,
+}
+
+fn serialize_quantity<S: serde::Serializer>(
+    quantity: &i128,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&quantity.to_string())
+}
+
+impl TransactionExplanation {
+    pub(crate) fn new(
+        tx_hash: &TransactionHash,
+        body: &TransactionBody,
+        is_valid: bool,
+        resolved_utxos: &TransactionUnspentOutputs,
+        network: &NetworkInfo,
+    ) -> Result<TransactionExplanation, JsError> {
+        let utxos: BTreeMap<&TransactionInput, &TransactionOutput> = resolved_utxos
+            .0
+            .iter()
+            .map(|utxo| (&utxo.input, &utxo.output))
+            .collect();
+        let explain_inputs =
+            |inputs: Option<&TransactionInputs>| -> Result<Vec<InputExplanation>, JsError> {
+                inputs
+                    .into_iter()
+                    .flat_map(|inputs| inputs.into_iter())
+                    .map(|input| {
+                        Ok(InputExplanation {
+                            input: input.to_string(),
+                            output: utxos
+                                .get(input)
+                                .map(|output| explain_output(output))
+                                .transpose()?,
+                        })
+                    })
+                    .collect()
+            };
+
+        let balances = Balances::new(body, is_valid, &utxos, network)?;
+        Ok(TransactionExplanation {
+            hash: tx_hash.to_hex(),
+            is_valid,
+            fee: body.fee,
+            invalid_before: body.validity_start_interval,
+            invalid_hereafter: body.ttl,
+            inputs: explain_inputs(Some(&body.inputs))?,
+            reference_inputs: explain_inputs(body.reference_inputs.as_ref())?,
+            outputs: body
+                .outputs
+                .0
+                .iter()
+                .map(explain_output)
+                .collect::<Result<_, _>>()?,
+            collateral_inputs: explain_inputs(body.collateral.as_ref())?,
+            collateral_return: body
+                .collateral_return
+                .as_ref()
+                .map(explain_output)
+                .transpose()?,
+            total_collateral: body.total_collateral,
+            mint: body.mint.as_ref().map(explain_mint).unwrap_or_default(),
+            certificates: match &body.certs {
+                Some(certs) => certs
+                    .certs
+                    .iter()
+                    .map(|cert| explain_certificate(cert))
+                    .collect::<Result<_, _>>()?,
+                None => Vec::new(),
+            },
+            withdrawals: match &body.withdrawals {
+                Some(withdrawals) => withdrawals
+                    .0
+                    .iter()
+                    .map(|(address, amount)| {
+                        Ok(WithdrawalExplanation {
+                            reward_address: address.to_address().to_bech32(None)?,
+                            amount: *amount,
+                        })
+                    })
+                    .collect::<Result<_, JsError>>()?,
+                None => Vec::new(),
+            },
+            votes: body
+                .voting_procedures
+                .as_ref()
+                .map(explain_votes)
+                .unwrap_or_default(),
+            proposals: match &body.voting_proposals {
+                Some(proposals) => (0..proposals.len())
+                    .map(|i| explain_proposal(&proposals.get(i)))
+                    .collect::<Result<_, _>>()?,
+                None => Vec::new(),
+            },
+            required_signers: body
+                .required_signers
+                .iter()
+                .flat_map(|signers| signers.into_iter())
+                .map(|signer| signer.to_hex())
+                .collect(),
+            donation: body.donation,
+            current_treasury_value: body.current_treasury_value,
+            balances_complete: balances.complete,
+            address_balances: balances.addresses,
+            stake_credential_balances: balances.stake_credentials,
+        })
+    }
+}
+
+// Byron addresses are shown in their usual base58 form
+fn address_to_string(address: &Address) -> Result<String, JsError> {
+    match &address.0 {
+        AddrType::Byron(byron) => Ok(byron.to_base58()),
+        _ => address.to_bech32(None),
+    }
+}
+
+fn stake_address_to_string(
+    network: &NetworkInfo,
+    credential: &Credential,
+) -> Result<String, JsError> {
+    RewardAddress::new(network.network_id(), credential)
+        .to_address()
+        .to_bech32(None)
+}
+
+fn explain_output(output: &TransactionOutput) -> Result<OutputExplanation, JsError> {
+    let datum = match &output.plutus_data {
+        Some(DataOption::DataHash(hash)) => Some(DatumExplanation::Hash(hash.to_hex())),
+        Some(DataOption::Data(data)) => Some(DatumExplanation::Inline(
+            decode_plutus_datum_to_json_value(data, PlutusDatumSchema::DetailedSchema)?,
+        )),
+        None => None,
+    };
+    let script_ref = output
+        .script_ref
+        .as_ref()
+        .map(|script_ref| match &script_ref.0 {
+            ScriptRefEnum::NativeScript(script) => ScriptRefExplanation {
+                language: "NativeScript".to_string(),
+                hash: script.hash().to_hex(),
+            },
+            ScriptRefEnum::PlutusScript(script) => ScriptRefExplanation {
+                language: format!("{:?}", script.language_version().kind()),
+                hash: script.hash().to_hex(),
+            },
+        });
+    let mut value = SignedValue::default();
+    value.add(&output.amount, 1);
+    Ok(OutputExplanation {
+        address: address_to_string(&output.address)?,
+        value: value.explain(),
+        datum,
+        script_ref,
+    })
+}
+
+fn explain_mint(mint: &Mint) -> Vec<MintExplanation> {
+    mint.0
+        .iter()
+        .map(|(policy_id, assets)| {
+            let tokens = |sign: i128| -> Vec<TokenAmount> {
+                assets
+                    .0
+                    .iter()
+                    .filter(|(_, amount)| amount.0.signum() == sign)
+                    .map(|(name, amount)| TokenAmount {
+                        asset_name: name.to_string(),
+                        quantity: amount.0.abs(),
+                    })
+                    .collect()
+            };
+            MintExplanation {
+                policy_id: policy_id.to_hex(),
+                minted: tokens(1),
+                burned: tokens(-1),
+            }
+        })
+        .collect()
+}
+
+fn explain_certificate(cert: &Certificate) -> Result<CertificateExplanation, JsError> {
+    let deposit = match &cert.0 {
+        CertificateEnum::StakeRegistration(cert) => Some(
+            cert.coin
+                .map_or(DepositChange::ProtocolKeyDeposit, DepositChange::Deposit),
+        ),
+        CertificateEnum::StakeDeregistration(cert) => Some(cert.coin.map_or(
+            DepositChange::ProtocolKeyDepositRefund,
+            DepositChange::Refund,
+        )),
+        CertificateEnum::PoolRegistration(_) => Some(DepositChange::ProtocolPoolDeposit),
+        CertificateEnum::DRepRegistration(cert) => Some(DepositChange::Deposit(cert.coin)),
+        CertificateEnum::DRepDeregistration(cert) => Some(DepositChange::Refund(cert.coin)),
+        CertificateEnum::StakeRegistrationAndDelegation(cert) => {
+            Some(DepositChange::Deposit(cert.coin))
+        }
+        CertificateEnum::StakeVoteRegistrationAndDelegation(cert) => {
+            Some(DepositChange::Deposit(cert.coin))
+        }
+        CertificateEnum::VoteRegistrationAndDelegation(cert) => {
+            Some(DepositChange::Deposit(cert.coin))
+        }
+        _ => None,
+    };
+    Ok(CertificateExplanation {
+        kind: format!("{:?}", cert.kind()),
+        deposit,
+        details: serde_json::to_value(cert).map_err(|e| JsError::from_str(&e.to_string()))?,
+    })
+}
+
+fn explain_votes(voting_procedures: &VotingProcedures) -> Vec<VoteExplanation> {
+    let mut votes = Vec::new();
+    for (voter, procedures) in &voting_procedures.0 {
+        let voter_id = match &voter.0 {
+            VoterEnum::ConstitutionalCommitteeHotCred(cred) => hex::encode(cred.to_raw_bytes()),
+            VoterEnum::DRep(cred) => hex::encode(cred.to_raw_bytes()),
+            VoterEnum::StakingPool(key_hash) => key_hash.to_hex(),
+        };
+        for (action_id, procedure) in procedures {
+            votes.push(VoteExplanation {
+                voter_kind: format!("{:?}", voter.kind()),
+                voter: voter_id.clone(),
+                action_id: format!("{}#{}", action_id.transaction_id, action_id.index),
+                vote: format!("{:?}", procedure.vote),
+                anchor_url: procedure
+                    .anchor
+                    .as_ref()
+                    .map(|anchor| anchor.anchor_url.0.clone()),
+            });
+        }
+    }
+    votes
+}
+
+fn explain_proposal(proposal: &VotingProposal) -> Result<ProposalExplanation, JsError> {
+    Ok(ProposalExplanation {
+        action: format!("{:?}", proposal.governance_action.kind()),
+        deposit: proposal.deposit,
+        reward_address: proposal.reward_account.to_address().to_bech32(None)?,
+        anchor_url: proposal.anchor.anchor_url.0.clone(),
+        details: serde_json::to_value(&proposal.governance_action)
+            .map_err(|e| JsError::from_str(&e.to_string()))?,
+    })
+}
+
+// value that can go below zero, used for the net changes
+#[derive(Clone, Debug, Default)]
+struct SignedValue {
+    coin: i128,
+    assets: BTreeMap<(PolicyID, AssetName), i128>,
+}
+
+impl SignedValue {
+    fn add(&mut self, value: &Value, sign: i128) {
+        self.coin += sign * u64::from(value.coin) as i128;
+        if let Some(multiasset) = &value.multiasset {
+            for (policy_id, assets) in &multiasset.0 {
+                for (name, amount) in &assets.0 {
+                    *self
+                        .assets
+                        .entry((policy_id.clone(), name.clone()))
+                        .or_default() += sign * u64::from(amount) as i128;
+                }
+            }
+        }
+    }
+
+    fn add_value(&mut self, other: &SignedValue) {
+        self.coin += other.coin;
+        for (asset, amount) in &other.assets {
+            *self.assets.entry(asset.clone()).or_default() += amount;
+        }
+    }
+
+    fn explain(&self) -> ValueExplanation {
+        ValueExplanation {
+            coin: self.coin,
+            assets: self
+                .assets
+                .iter()
+                .filter(|(_, amount)| **amount != 0)
+                .map(|((policy_id, name), amount)| AssetAmount {
+                    policy_id: policy_id.to_hex(),
+                    asset_name: name.to_string(),
+                    quantity: *amount,
+                })
+                .collect(),
+        }
+    }
+}
+
+// net value changes, outputs minus spent inputs, collateral when the scripts are expected to fail
+struct Balances {
+    complete: bool,
+    addresses: Vec<BalanceChange>,
+    stake_credentials: Vec<BalanceChange>,
+}
+
+impl Balances {
+    fn new(
+        body: &TransactionBody,
+        is_valid: bool,
+        utxos: &BTreeMap<&TransactionInput, &TransactionOutput>,
+        network: &NetworkInfo,
+    ) -> Result<Balances, JsError> {
+        let (spent, produced): (Vec<&TransactionInput>, Vec<&TransactionOutput>) = if is_valid {
+            (
+                body.inputs.into_iter().collect(),
+                body.outputs.0.iter().collect(),
+            )
+        } else {
+            (
+                body.collateral
+                    .iter()
+                    .flat_map(|inputs| inputs.into_iter())
+                    .collect(),
+                body.collateral_return.iter().collect(),
+            )
+        };
+        let complete = spent.iter().all(|input| utxos.contains_key(input));
+        let changes = spent
+            .iter()
+            .filter_map(|input| utxos.get(input).map(|output| (*output, -1)))
+            .chain(produced.into_iter().map(|output| (output, 1)));
+
+        let mut addresses: LinkedHashMap<String, (Option<Credential>, SignedValue)> =
+            LinkedHashMap::new();
+        for (output, sign) in changes {
+            let stake_credential = match &output.address.0 {
+                AddrType::Base(base) => Some(base.stake_cred()),
+                _ => None,
+            };
+            addresses
+                .entry(address_to_string(&output.address)?)
+                .or_insert_with(|| (stake_credential, SignedValue::default()))
+                .1
+                .add(&output.amount, sign);
+        }
+
+        let mut stake_credentials: LinkedHashMap<String, SignedValue> = LinkedHashMap::new();
+        for (stake_credential, change) in addresses.values() {
+            if let Some(stake_credential) = stake_credential {
+                stake_credentials
+                    .entry(stake_address_to_string(network, stake_credential)?)
+                    .or_insert_with(SignedValue::default)
+                    .add_value(change);
+            }
+        }
+        // withdrawn rewards leave the reward account of the credential
+        if let Some(withdrawals) = body.withdrawals.as_ref().filter(|_| is_valid) {
+            for (address, amount) in &withdrawals.0 {
+                stake_credentials
+                    .entry(stake_address_to_string(network, &address.payment_cred())?)
+                    .or_insert_with(SignedValue::default)
+                    .coin -= u64::from(amount) as i128;
+            }
+        }
+
+        Ok(Balances {
+            complete,
+            addresses: addresses
+                .into_iter()
+                .map(|(address, (_, change))| BalanceChange {
+                    address,
+                    change: change.explain(),
+                })
+                .collect(),
+            stake_credentials: stake_credentials
+                .into_iter()
+                .map(|(address, change)| BalanceChange {
+                    address,
+                    change: change.explain(),
+                })
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,898 @@
+use crate::*;
+use serde_json::{json, Map, Value as JsonValue};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+// JSON rendering of `cardano-cli transaction view --output-json`.
+// Keys, labels and nested ledger objects follow cardano-cli so both outputs can be diffed.
+// Values that cardano-cli prints with Haskell's `show` are reproduced for keys and signatures,
+// auxiliary scripts and protocol parameter updates use the JSON of this library instead.
+
+pub(crate) fn render_transaction_view(tx: &Transaction, era: BlockEra) -> Result<String, JsError> {
+    let view = transaction_view(tx, era)?;
+    // cardano-cli pretty prints with four spaces and sorted keys, serde_json maps are sorted
+    let mut bytes = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut serializer = serde_json::Serializer::with_formatter(&mut bytes, formatter);
+    serde::Serialize::serialize(&view, &mut serializer)
+        .map_err(|e| JsError::from_str(&format!("transaction view: {}", e)))?;
+    String::from_utf8(bytes).map_err(|e| JsError::from_str(&format!("transaction view: {}", e)))
+}
+
+fn transaction_view(tx: &Transaction, era: BlockEra) -> Result<JsonValue, JsError> {
+    let body = &tx.body;
+    let mut view = Map::new();
+    view.insert(
+        "auxiliary scripts".to_string(),
+        auxiliary_scripts_view(tx.auxiliary_data.as_ref()),
+    );
+    view.insert(
+        "certificates".to_string(),
+        match &body.certs {
+            Some(certs) => JsonValue::Array(
+                certs
+                    .certs
+                    .iter()
+                    .map(|cert| certificate_view(cert, era))
+                    .collect::<Result<_, _>>()?,
+            ),
+            None => JsonValue::Null,
+        },
+    );
+    view.insert(
+        "collateral inputs".to_string(),
+        inputs_view(body.collateral.as_ref()),
+    );
+    view.insert("era".to_string(), json!(format!("{:?}", era)));
+    view.insert("fee".to_string(), json!(lovelace_text(&body.fee)));
+    view.insert("inputs".to_string(), inputs_view(Some(&body.inputs)));
+    view.insert(
+        "metadata".to_string(),
+        match tx
+            .auxiliary_data
+            .as_ref()
+            .and_then(|data| data.metadata.as_ref())
+        {
+            Some(metadata) => JsonValue::Object(
+                metadata
+                    .0
+                    .iter()
+                    .map(|(label, metadatum)| {
+                        (u64::from(label).to_string(), metadatum_view(metadatum))
+                    })
+                    .collect(),
+            ),
+            None => JsonValue::Null,
+        },
+    );
+    view.insert(
+        "mint".to_string(),
+        body.mint.as_ref().map_or(JsonValue::Null, mint_view),
+    );
+    view.insert(
+        "outputs".to_string(),
+        JsonValue::Array(
+            body.outputs
+                .0
+                .iter()
+                .map(|output| output_view(output, era))
+                .collect::<Result<_, _>>()?,
+        ),
+    );
+    view.insert(
+        "reference inputs".to_string(),
+        inputs_view(body.reference_inputs.as_ref()),
+    );
+    view.insert(
+        "required signers (payment key hashes needed for scripts)".to_string(),
+        match &body.required_signers {
+            Some(signers) => signers
+                .into_iter()
+                .map(|signer| json!(signer.to_hex()))
+                .collect(),
+            None => JsonValue::Null,
+        },
+    );
+    view.insert(
+        "return collateral".to_string(),
+        match &body.collateral_return {
+            Some(output) => output_view(output, era)?,
+            None => JsonValue::Null,
+        },
+    );
+    view.insert(
+        "total collateral".to_string(),
+        body.total_collateral
+            .as_ref()
+            .map_or(JsonValue::Null, coin_json),
+    );
+    view.insert(
+        "update proposal".to_string(),
+        body.update
+            .as_ref()
+            .map_or(Ok(JsonValue::Null), update_view)?,
+    );
+    view.insert("validity range".to_string(), validity_range_view(body, era));
+    view.insert(
+        "withdrawals".to_string(),
+        match &body.withdrawals {
+            Some(withdrawals) => JsonValue::Array(
+                withdrawals
+                    .0
+                    .iter()
+                    .map(|(address, amount)| {
+                        let mut withdrawal = Map::new();
+                        withdrawal.insert(
+                            "address".to_string(),
+                            json!(address.to_address().to_bech32(None)?),
+                        );
+                        withdrawal.insert("amount".to_string(), json!(lovelace_text(amount)));
+                        let (key, hash) = credential_field("stake", &address.payment);
+                        withdrawal.insert(key, json!(hash));
+                        Ok(JsonValue::Object(withdrawal))
+                    })
+                    .collect::<Result<_, JsError>>()?,
+            ),
+            None => JsonValue::Null,
+        },
+    );
+    view.insert("witnesses".to_string(), witnesses_view(&tx.witness_set));
+    if era >= BlockEra::Conway {
+        if let Some(proposals) = &body.voting_proposals {
+            view.insert(
+                "governance actions".to_string(),
+                JsonValue::Array(
+                    (0..proposals.len())
+                        .map(|i| proposal_view(&proposals.get(i)))
+                        .collect(),
+                ),
+            );
+        }
+        if let Some(voting_procedures) = &body.voting_procedures {
+            view.insert(
+                "voters".to_string(),
+                voting_procedures_view(voting_procedures),
+            );
+        }
+        view.insert(
+            "currentTreasuryValue".to_string(),
+            body.current_treasury_value
+                .as_ref()
+                .map_or(JsonValue::Null, coin_json),
+        );
+        view.insert(
+            "treasuryDonation".to_string(),
+            body.donation.as_ref().map_or(JsonValue::Null, coin_json),
+        );
+    }
+    Ok(JsonValue::Object(view))
+}
+
+fn coin_json(coin: &Coin) -> JsonValue {
+    json!(u64::from(coin))
+}
+
+fn lovelace_text(coin: &Coin) -> String {
+    format!("{} Lovelace", coin)
+}
+
+fn int_json(value: i128) -> JsonValue {
+    if let Ok(value) = i64::try_from(value) {
+        json!(value)
+    } else if let Ok(value) = u64::try_from(value) {
+        json!(value)
+    } else {
+        json!(value.to_string())
+    }
+}
+
+fn inputs_view(inputs: Option<&TransactionInputs>) -> JsonValue {
+    match inputs {
+        Some(inputs) => inputs
+            .into_iter()
+            .map(|input| json!(input.to_string()))
+            .collect(),
+        None => JsonValue::Null,
+    }
+}
+
+fn network_name(network_id: u8) -> &'static str {
+    if network_id == NetworkInfo::mainnet().network_id() {
+        "Mainnet"
+    } else {
+        "Testnet"
+    }
+}
+
+// "payment credential key hash", "stake credential script hash", ...
+fn credential_field(role: &str, credential: &Credential) -> (String, String) {
+    match &credential.0 {
+        CredType::Key(hash) => (format!("{} credential key hash", role), hash.to_hex()),
+        CredType::Script(hash) => (format!("{} credential script hash", role), hash.to_hex()),
+    }
+}
+
+// ledger JSON of a credential
+fn credential_json(credential: &Credential) -> JsonValue {
+    match &credential.0 {
+        CredType::Key(hash) => json!({ "keyHash": hash.to_hex() }),
+        CredType::Script(hash) => json!({ "scriptHash": hash.to_hex() }),
+    }
+}
+
+// ledger JSON key of a credential
+fn credential_key(credential: &Credential) -> String {
+    match &credential.0 {
+        CredType::Key(hash) => format!("keyHash-{}", hash.to_hex()),
+        CredType::Script(hash) => format!("scriptHash-{}", hash.to_hex()),
+    }
+}
+
+fn reward_account_json(address: &RewardAddress) -> JsonValue {
+    json!({
+        "network": network_name(address.network),
+        "credential": credential_json(&address.payment),
+    })
+}
+
+fn anchor_json(anchor: Option<&Anchor>) -> JsonValue {
+    match anchor {
+        Some(anchor) => json!({
+            "url": anchor.anchor_url.0,
+            "dataHash": anchor.anchor_data_hash.to_hex(),
+        }),
+        None => JsonValue::Null,
+    }
+}
+
+fn gov_action_id_json(action_id: Option<&GovernanceActionId>) -> JsonValue {
+    match action_id {
+        Some(action_id) => json!({
+            "txId": action_id.transaction_id.to_hex(),
+            "govActionIx": action_id.index,
+        }),
+        None => JsonValue::Null,
+    }
+}
+
+fn drep_json(drep: &DRep) -> JsonValue {
+    match &drep.0 {
+        DRepEnum::KeyHash(hash) => json!({ "keyHash": hash.to_hex() }),
+        DRepEnum::ScriptHash(hash) => json!({ "scriptHash": hash.to_hex() }),
+        DRepEnum::AlwaysAbstain => json!("drep-alwaysAbstain"),
+        DRepEnum::AlwaysNoConfidence => json!("drep-alwaysNoConfidence"),
+    }
+}
+
+fn unit_interval_json(interval: &UnitInterval) -> JsonValue {
+    json!(u64::from(interval.numerator()) as f64 / u64::from(interval.denominator()) as f64)
+}
+
+// "asset 746f6b656e (token)", the name is shown when it is printable UTF-8
+fn asset_name_key(name: &AssetName) -> String {
+    if name.0.is_empty() {
+        return "default asset".to_string();
+    }
+    match std::str::from_utf8(&name.0) {
+        Ok(text) if text.chars().all(|c| !c.is_control()) => {
+            format!("asset {} ({})", hex::encode(&name.0), text)
+        }
+        _ => format!("asset {}", hex::encode(&name.0)),
+    }
+}
+
+fn policy_key(policy_id: &PolicyID) -> String {
+    format!("policy {}", policy_id.to_hex())
+}
+
+fn value_view(value: &Value) -> JsonValue {
+    let mut view = Map::new();
+    view.insert("lovelace".to_string(), coin_json(&value.coin));
+    if let Some(multiasset) = &value.multiasset {
+        for (policy_id, assets) in &multiasset.0 {
+            view.insert(
+                policy_key(policy_id),
+                JsonValue::Object(
+                    assets
+                        .0
+                        .iter()
+                        .map(|(name, amount)| (asset_name_key(name), coin_json(amount)))
+                        .collect(),
+                ),
+            );
+        }
+    }
+    JsonValue::Object(view)
+}
+
+// a mint can repeat a policy, cardano-cli shows the quantities of each policy together
+fn mint_view(mint: &Mint) -> JsonValue {
+    let mut policies: BTreeMap<String, BTreeMap<String, i128>> = BTreeMap::new();
+    for (policy_id, assets) in &mint.0 {
+        let policy = policies.entry(policy_key(policy_id)).or_default();
+        for (name, amount) in &assets.0 {
+            *policy.entry(asset_name_key(name)).or_default() += amount.0;
+        }
+    }
+    JsonValue::Object(
+        policies
+            .into_iter()
+            .map(|(policy, assets)| {
+                let assets = assets
+                    .into_iter()
+                    .map(|(name, amount)| (name, int_json(amount)))
+                    .collect();
+                (policy, JsonValue::Object(assets))
+            })
+            .collect(),
+    )
+}
+
+fn output_view(output: &TransactionOutput, era: BlockEra) -> Result<JsonValue, JsError> {
+    let mut view = Map::new();
+    view.insert("amount".to_string(), value_view(&output.amount));
+    let (network_id, payment, stake_reference) = match &output.address.0 {
+        AddrType::Byron(byron) => {
+            view.insert("address era".to_string(), json!("Byron"));
+            view.insert("address".to_string(), json!(byron.to_base58()));
+            return Ok(JsonValue::Object(view));
+        }
+        AddrType::Base(base) => {
+            let (key, hash) = credential_field("stake", &base.stake);
+            (base.network, &base.payment, json!({ key: hash }))
+        }
+        AddrType::Ptr(pointer) => (
+            pointer.network,
+            &pointer.payment,
+            json!(format!(
+                "StakeAddressPointer {{unStakeAddressPointer = Ptr (SlotNo {}) (TxIx {}) (CertIx {})}}",
+                pointer.stake.slot, pointer.stake.tx_index, pointer.stake.cert_index
+            )),
+        ),
+        AddrType::Enterprise(enterprise) => {
+            (enterprise.network, &enterprise.payment, JsonValue::Null)
+        }
+        AddrType::Reward(_) | AddrType::Malformed(_) => {
+            return Err(JsError::from_str(&format!(
+                "{} is not a payment address",
+                output.address.to_hex()
+            )))
+        }
+    };
+    view.insert("address era".to_string(), json!("Shelley"));
+    view.insert(
+        "address".to_string(),
+        json!(output.address.to_bech32(None)?),
+    );
+    view.insert("network".to_string(), json!(network_name(network_id)));
+    let (key, hash) = credential_field("payment", payment);
+    view.insert(key, json!(hash));
+    view.insert("stake reference".to_string(), stake_reference);
+    if era >= BlockEra::Alonzo {
+        view.insert(
+            "datum".to_string(),
+            match &output.plutus_data {
+                Some(DataOption::DataHash(hash)) => json!(hash.to_hex()),
+                Some(DataOption::Data(data)) => {
+                    decode_plutus_datum_to_json_value(data, PlutusDatumSchema::DetailedSchema)?
+                }
+                None => JsonValue::Null,
+            },
+        );
+        view.insert(
+            "reference script".to_string(),
+            match &output.script_ref {
+                Some(script_ref) => json!({ "referenceScript": script_view(&script_ref.0)? }),
+                None => JsonValue::Null,
+            },
+        );
+    }
+    Ok(JsonValue::Object(view))
+}
+
+// {"script": <text envelope>, "scriptLanguage": "..."}
+fn script_view(script: &ScriptRefEnum) -> Result<JsonValue, JsError> {
+    let (language, mut envelope) = match script {
+        ScriptRefEnum::NativeScript(script) => (
+            "SimpleScriptLanguage".to_string(),
+            TextEnvelope::new_native_script(script),
+        ),
+        ScriptRefEnum::PlutusScript(script) => (
+            format!(
+                "PlutusScriptLanguage {:?}",
+                script.language_version().kind()
+            )
+            .replace("PlutusV", "PlutusScriptV"),
+            TextEnvelope::new_plutus_script(script),
+        ),
+    };
+    envelope.set_description("");
+    Ok(json!({
+        "script": serde_json::to_value(&envelope).map_err(|e| JsError::from_str(&e.to_string()))?,
+        "scriptLanguage": language,
+    }))
+}
+
+fn auxiliary_scripts_view(auxiliary_data: Option<&AuxiliaryData>) -> JsonValue {
+    let auxiliary_data = match auxiliary_data {
+        Some(auxiliary_data) => auxiliary_data,
+        None => return JsonValue::Null,
+    };
+    let scripts: Vec<ScriptRefEnum> = auxiliary_data
+        .native_scripts
+        .iter()
+        .flat_map(|scripts| scripts.into_iter())
+        .map(|script| ScriptRefEnum::NativeScript(script.clone()))
+        .chain(
+            auxiliary_data
+                .plutus_scripts
+                .iter()
+                .flat_map(|scripts| scripts.into_iter())
+                .map(|script| ScriptRefEnum::PlutusScript(script.clone())),
+        )
+        .collect();
+    if scripts.is_empty() {
+        return JsonValue::Null;
+    }
+    scripts
+        .iter()
+        .filter_map(|script| script_view(script).ok())
+        .collect()
+}
+
+// metadata as cardano-cli shows it, maps are lists of key and value pairs
+fn metadatum_view(metadatum: &TransactionMetadatum) -> JsonValue {
+    match &metadatum.0 {
+        TransactionMetadatumEnum::Int(int) => int_json(int.0),
+        TransactionMetadatumEnum::Bytes(bytes) => json!(haskell_show_bytes(bytes)),
+        TransactionMetadatumEnum::Text(text) => json!(text),
+        TransactionMetadatumEnum::MetadataList(list) => list.0.iter().map(metadatum_view).collect(),
+        TransactionMetadatumEnum::MetadataMap(map) => map
+            .0
+            .iter()
+            .map(|(key, value)| json!([metadatum_view(key), metadatum_view(value)]))
+            .collect(),
+    }
+}
+
+const ASCII_CONTROL_NAMES: [&str; 32] = [
+    "NUL", "SOH", "STX", "ETX", "EOT", "ENQ", "ACK", "a", "b", "t", "n", "v", "f", "r", "SO", "SI",
+    "DLE", "DC1", "DC2", "DC3", "DC4", "NAK", "SYN", "ETB", "CAN", "EM", "SUB", "ESC", "FS", "GS",
+    "RS", "US",
+];
+
+// Haskell's `show` of a ByteString, which cardano-cli uses for metadata bytes
+fn haskell_show_bytes(bytes: &[u8]) -> String {
+    let mut shown = String::from("\"");
+    for (i, &byte) in bytes.iter().enumerate() {
+        let next = bytes.get(i + 1).copied();
+        match byte {
+            b'"' => shown.push_str("\\\""),
+            b'\\' => shown.push_str("\\\\"),
+            0x20..=0x7e => shown.push(byte as char),
+            0x00..=0x1f => {
+                shown.push('\\');
+                shown.push_str(ASCII_CONTROL_NAMES[byte as usize]);
+                // "\SO" followed by "H" would read as "\SOH"
+                if byte == 0x0e && next == Some(b'H') {
+                    shown.push_str("\\&");
+                }
+            }
+            0x7f => shown.push_str("\\DEL"),
+            _ => {
+                shown.push_str(&format!("\\{}", byte));
+                if next.is_some_and(|next| next.is_ascii_digit()) {
+                    shown.push_str("\\&");
+                }
+            }
+        }
+    }
+    shown.push('"');
+    shown
+}
+
+fn validity_range_view(body: &TransactionBody, era: BlockEra) -> JsonValue {
+    let slot = |slot: Option<&SlotBigNum>| slot.map_or(JsonValue::Null, coin_json);
+    // the validity start only exists since Allegra
+    if era == BlockEra::Shelley {
+        json!({ "time to live": slot(body.ttl.as_ref()) })
+    } else {
+        json!({
+            "lower bound": slot(body.validity_start_interval.as_ref()),
+            "upper bound": slot(body.ttl.as_ref()),
+        })
+    }
+}
+
+fn update_view(update: &Update) -> Result<JsonValue, JsError> {
+    let updates = update
+        .proposed_protocol_parameter_updates
+        .0
+        .iter()
+        .map(|(genesis_hash, params)| {
+            Ok(json!({
+                "genesis key hash": genesis_hash.to_hex(),
+                "update": serde_json::to_value(params).map_err(|e| JsError::from_str(&e.to_string()))?,
+            }))
+        })
+        .collect::<Result<Vec<_>, JsError>>()?;
+    Ok(json!({ "epoch": update.epoch, "updates": updates }))
+}
+
+fn pool_params_json(params: &PoolParams) -> JsonValue {
+    let relays: Vec<JsonValue> = params
+        .relays
+        .0
+        .iter()
+        .map(|relay| match &relay.0 {
+            RelayEnum::SingleHostAddr(relay) => json!({
+                "single host address": {
+                    "IPv4": relay.ipv4.as_ref().map(|ip| std::net::Ipv4Addr::from(ip.0).to_string()),
+                    "IPv6": relay.ipv6.as_ref().map(|ip| std::net::Ipv6Addr::from(ip.0).to_string()),
+                    "port": relay.port,
+                }
+            }),
+            RelayEnum::SingleHostName(relay) => json!({
+                "single host name": { "dnsName": relay.dns_name.0, "port": relay.port }
+            }),
+            RelayEnum::MultiHostName(relay) => json!({
+                "multi host name": { "dnsName": relay.dns_name.0 }
+            }),
+        })
+        .collect();
+    json!({
+        "publicKey": params.operator.to_hex(),
+        "vrf": params.vrf_keyhash.to_hex(),
+        "pledge": coin_json(&params.pledge),
+        "cost": coin_json(&params.cost),
+        "margin": unit_interval_json(&params.margin),
+        "rewardAccount": reward_account_json(&params.reward_account),
+        "owners": params.pool_owners.into_iter().map(|owner| owner.to_hex()).collect::<Vec<_>>(),
+        "relays": relays,
+        "metadata": params.pool_metadata.as_ref().map(|metadata| json!({
+            "url": metadata.url.0,
+            "hash": metadata.pool_metadata_hash.to_hex(),
+        })),
+    })
+}
+
+// the ledger names of the certificates changed with the Conway certificates
+fn certificate_view(cert: &Certificate, era: BlockEra) -> Result<JsonValue, JsError> {
+    let conway = era >= BlockEra::Conway;
+    let name = |before_conway: &'static str, since_conway: &'static str| {
+        if conway {
+            since_conway
+        } else {
+            before_conway
+        }
+    };
+    let delegatee = |pool: Option<&Ed25519KeyHash>, drep: Option<&DRep>| {
+        let mut delegatee = Map::new();
+        if let Some(pool) = pool {
+            delegatee.insert("stakePool".to_string(), json!(pool.to_hex()));
+        }
+        if let Some(drep) = drep {
+            delegatee.insert("dRep".to_string(), drep_json(drep));
+        }
+        JsonValue::Object(delegatee)
+    };
+    let (key, details) = match &cert.0 {
+        CertificateEnum::StakeRegistration(cert) => {
+            let mut details =
+                json!({ "stake credential": credential_json(&cert.stake_credential) });
+            if let Some(coin) = &cert.coin {
+                details["deposit"] = coin_json(coin);
+            }
+            (
+                name("stake address registration", "Stake address registration"),
+                details,
+            )
+        }
+        CertificateEnum::StakeDeregistration(cert) => {
+            let mut details =
+                json!({ "stake credential": credential_json(&cert.stake_credential) });
+            if let Some(coin) = &cert.coin {
+                details["refund"] = coin_json(coin);
+            }
+            (
+                name(
+                    "stake address deregistration",
+                    "Stake address deregistration",
+                ),
+                details,
+            )
+        }
+        CertificateEnum::StakeDelegation(cert) => (
+            name("stake address delegation", "Stake address delegation"),
+            if conway {
+                json!({
+                    "stake credential": credential_json(&cert.stake_credential),
+                    "delegatee": delegatee(Some(&cert.pool_keyhash), None),
+                })
+            } else {
+                json!({
+                    "stake credential": credential_json(&cert.stake_credential),
+                    "pool": cert.pool_keyhash.to_hex(),
+                })
+            },
+        ),
+        CertificateEnum::PoolRegistration(cert) => (
+            name("stake pool registration", "Pool registration"),
+            pool_params_json(&cert.pool_params),
+        ),
+        CertificateEnum::PoolRetirement(cert) => (
+            name("stake pool retirement", "Pool retirement"),
+            json!({ "pool": cert.pool_keyhash.to_hex(), "epoch": cert.epoch }),
+        ),
+        CertificateEnum::GenesisKeyDelegation(cert) => (
+            "genesis key delegation",
+            json!({
+                "genesis key hash": cert.genesishash.to_hex(),
+                "delegate key hash": cert.genesis_delegate_hash.to_hex(),
+                "VRF key hash": cert.vrf_keyhash.to_hex(),
+            }),
+        ),
+        CertificateEnum::MoveInstantaneousRewardsCert(cert) => {
+            let mir = &cert.move_instantaneous_reward;
+            let mut details = Map::new();
+            details.insert(
+                "pot".to_string(),
+                json!(match mir.pot {
+                    MIRPot::Reserves => "reserves",
+                    MIRPot::Treasury => "treasury",
+                }),
+            );
+            match &mir.variant {
+                MIREnum::ToOtherPot(coin) => {
+                    let target = match mir.pot {
+                        MIRPot::Reserves => "send to treasury",
+                        MIRPot::Treasury => "send to reserves",
+                    };
+                    details.insert(target.to_string(), coin_json(coin));
+                }
+                MIREnum::ToStakeCredentials(rewards) => {
+                    let targets = rewards
+                        .rewards
+                        .iter()
+                        .map(|(credential, amount)| {
+                            let (key, hash) = credential_field("stake", credential);
+                            json!({ key: hash, "amount": format!("{} Lovelace", amount.0) })
+                        })
+                        .collect();
+                    details.insert("target stake addresses".to_string(), targets);
+                }
+            }
+            ("MIR", JsonValue::Object(details))
+        }
+        CertificateEnum::CommitteeHotAuth(cert) => (
+            "Constitutional committee member hot key registration",
+            json!({
+                "cold credential": credential_json(&cert.committee_cold_credential),
+                "hot credential": credential_json(&cert.committee_hot_credential),
+            }),
+        ),
+        CertificateEnum::CommitteeColdResign(cert) => (
+            "Constitutional committee cold key resignation",
+            json!({
+                "cold credential": credential_json(&cert.committee_cold_credential),
+                "anchor": anchor_json(cert.anchor.as_ref()),
+            }),
+        ),
+        CertificateEnum::DRepRegistration(cert) => (
+            "Drep registration certificate",
+            json!({
+                "drep credential": credential_json(&cert.voting_credential),
+                "deposit": coin_json(&cert.coin),
+                "anchor": anchor_json(cert.anchor.as_ref()),
+            }),
+        ),
+        CertificateEnum::DRepDeregistration(cert) => (
+            "Drep unregistration certificate",
+            json!({
+                "drep credential": credential_json(&cert.voting_credential),
+                "refund": coin_json(&cert.coin),
+            }),
+        ),
+        CertificateEnum::DRepUpdate(cert) => (
+            "Drep update certificate",
+            json!({
+                "drep credential": credential_json(&cert.voting_credential),
+                "anchor": anchor_json(cert.anchor.as_ref()),
+            }),
+        ),
+        CertificateEnum::StakeAndVoteDelegation(cert) => (
+            "Stake address delegation",
+            json!({
+                "stake credential": credential_json(&cert.stake_credential),
+                "delegatee": delegatee(Some(&cert.pool_keyhash), Some(&cert.drep)),
+            }),
+        ),
+        CertificateEnum::VoteDelegation(cert) => (
+            "Stake address delegation",
+            json!({
+                "stake credential": credential_json(&cert.stake_credential),
+                "delegatee": delegatee(None, Some(&cert.drep)),
+            }),
+        ),
+        CertificateEnum::StakeRegistrationAndDelegation(cert) => (
+            "Stake address registration and delegation",
+            json!({
+                "stake credential": credential_json(&cert.stake_credential),
+                "delegatee": delegatee(Some(&cert.pool_keyhash), None),
+                "deposit": coin_json(&cert.coin),
+            }),
+        ),
+        CertificateEnum::VoteRegistrationAndDelegation(cert) => (
+            "Stake address registration and delegation",
+            json!({
+                "stake credential": credential_json(&cert.stake_credential),
+                "delegatee": delegatee(None, Some(&cert.drep)),
+                "deposit": coin_json(&cert.coin),
+            }),
+        ),
+        CertificateEnum::StakeVoteRegistrationAndDelegation(cert) => (
+            "Stake address registration and delegation",
+            json!({
+                "stake credential": credential_json(&cert.stake_credential),
+                "delegatee": delegatee(Some(&cert.pool_keyhash), Some(&cert.drep)),
+                "deposit": coin_json(&cert.coin),
+            }),
+        ),
+    };
+    Ok(json!({ key: details }))
+}
+
+// ledger JSON of a governance action: {"tag": "...", "contents": ...}
+fn governance_action_json(action: &GovernanceAction) -> JsonValue {
+    let (tag, contents) = match &action.0 {
+        GovernanceActionEnum::ParameterChangeAction(action) => (
+            "ParameterChange",
+            json!([
+                gov_action_id_json(action.gov_action_id.as_ref()),
+                serde_json::to_value(&action.protocol_param_updates).unwrap_or(JsonValue::Null),
+                action.policy_hash.as_ref().map(|hash| hash.to_hex()),
+            ]),
+        ),
+        GovernanceActionEnum::HardForkInitiationAction(action) => (
+            "HardForkInitiation",
+            json!([
+                gov_action_id_json(action.gov_action_id.as_ref()),
+                {
+                    "major": action.protocol_version.major,
+                    "minor": action.protocol_version.minor,
+                },
+            ]),
+        ),
+        GovernanceActionEnum::TreasuryWithdrawalsAction(action) => (
+            "TreasuryWithdrawals",
+            json!([
+                action
+                    .withdrawals
+                    .0
+                    .iter()
+                    .map(|(address, amount)| json!([
+                        reward_account_json(address),
+                        coin_json(amount)
+                    ]))
+                    .collect::<Vec<_>>(),
+                action.policy_hash.as_ref().map(|hash| hash.to_hex()),
+            ]),
+        ),
+        GovernanceActionEnum::NoConfidenceAction(action) => (
+            "NoConfidence",
+            gov_action_id_json(action.gov_action_id.as_ref()),
+        ),
+        GovernanceActionEnum::UpdateCommitteeAction(action) => (
+            "UpdateCommittee",
+            json!([
+                gov_action_id_json(action.gov_action_id.as_ref()),
+                action
+                    .members_to_remove
+                    .credentials
+                    .iter()
+                    .map(|credential| credential_json(credential))
+                    .collect::<Vec<_>>(),
+                action
+                    .committee
+                    .members
+                    .iter()
+                    .map(|(credential, epoch)| (credential_key(credential), json!(epoch)))
+                    .collect::<Map<_, _>>(),
+                unit_interval_json(&action.committee.quorum_threshold),
+            ]),
+        ),
+        GovernanceActionEnum::NewConstitutionAction(action) => (
+            "NewConstitution",
+            json!([
+                gov_action_id_json(action.gov_action_id.as_ref()),
+                {
+                    "anchor": anchor_json(Some(&action.constitution.anchor)),
+                    "script": action.constitution.script_hash.as_ref().map(|hash| hash.to_hex()),
+                },
+            ]),
+        ),
+        GovernanceActionEnum::InfoAction(_) => return json!({ "tag": "InfoAction" }),
+    };
+    json!({ "tag": tag, "contents": contents })
+}
+
+fn proposal_view(proposal: &VotingProposal) -> JsonValue {
+    json!({
+        "deposit": coin_json(&proposal.deposit),
+        "return address": reward_account_json(&proposal.reward_account),
+        "governance action": governance_action_json(&proposal.governance_action),
+        "anchor": anchor_json(Some(&proposal.anchor)),
+    })
+}
+
+// {"drep-keyHash-...": {"<tx id>#<index>": {"decision": "VoteYes", "anchor": null}}}
+fn voting_procedures_view(voting_procedures: &VotingProcedures) -> JsonValue {
+    voting_procedures
+        .0
+        .iter()
+        .map(|(voter, procedures)| {
+            let voter = match &voter.0 {
+                VoterEnum::ConstitutionalCommitteeHotCred(cred) => {
+                    format!("committee-{}", credential_key(cred))
+                }
+                VoterEnum::DRep(cred) => format!("drep-{}", credential_key(cred)),
+                VoterEnum::StakingPool(key_hash) => {
+                    format!("stakepool-keyHash-{}", key_hash.to_hex())
+                }
+            };
+            let procedures = procedures
+                .iter()
+                .map(|(action_id, procedure)| {
+                    let decision = match procedure.vote {
+                        VoteKind::No => "VoteNo",
+                        VoteKind::Yes => "VoteYes",
+                        VoteKind::Abstain => "Abstain",
+                    };
+                    (
+                        format!("{}#{}", action_id.transaction_id, action_id.index),
+                        json!({
+                            "decision": decision,
+                            "anchor": anchor_json(procedure.anchor.as_ref()),
+                        }),
+                    )
+                })
+                .collect::<Map<_, _>>();
+            (voter, JsonValue::Object(procedures))
+        })
+        .collect::<Map<_, _>>()
+        .into()
+}
+
+// keys and signatures are shown the way Haskell shows them
+fn witnesses_view(witness_set: &TransactionWitnessSet) -> JsonValue {
+    let show_key = |key: &PublicKey| format!("VKey (VerKeyEd25519DSIGN \"{}\")", key.to_hex());
+    let show_signature = |signature: &Ed25519Signature| {
+        format!("SignedDSIGN (SigEd25519DSIGN \"{}\")", signature.to_hex())
+    };
+    let vkeys = witness_set
+        .vkeys
+        .iter()
+        .flat_map(|witnesses| witnesses.into_iter())
+        .map(|witness| {
+            json!({
+                "key": show_key(&witness.vkey.0),
+                "signature": show_signature(&witness.signature),
+            })
+        });
+    let bootstraps = witness_set
+        .bootstraps
+        .iter()
+        .flat_map(|witnesses| witnesses.into_iter())
+        .map(|witness| {
+            json!({
+                "bootstrap witness": format!(
+                    "BootstrapWitness {{bwKey = {}, bwSig = {}, bwChainCode = ChainCode \"{}\", bwAttributes = \"{}\"}}",
+                    show_key(&witness.vkey.0),
+                    show_signature(&witness.signature),
+                    hex::encode(&witness.chain_code),
+                    hex::encode(&witness.attributes),
+                )
+            })
+        });
+    vkeys.chain(bootstraps).collect()
+}
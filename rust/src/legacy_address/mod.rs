@@ -3,4 +3,4 @@ mod base58;
 mod cbor;
 mod crc32;
 
-pub use address::{Addr, AddressMatchXPub, ByronAddressType, ExtendedAddr, ParseExtendedAddrError};
+pub use address::{Addr, AddressMatchXPub, Attributes, ByronAddressType, ExtendedAddr, ParseExtendedAddrError};
@@ -41,20 +41,32 @@ use cbor_event::{
     se::{Serialize, Serializer},
 };
 
+mod address_discovery;
+pub use address_discovery::*;
+mod bip39;
+pub use bip39::*;
 mod builders;
 pub use builders::*;
 pub mod chain_core;
 pub mod chain_crypto;
+mod cip8;
+pub use cip8::*;
+mod cip1852;
+pub use cip1852::*;
 mod crypto;
 pub(crate) use crypto::*;
 mod emip3;
 pub use emip3::*;
 mod error;
 pub use error::*;
+mod explain;
+pub use explain::*;
 mod fees;
 pub use fees::*;
 pub mod impl_mockchain;
 pub mod legacy_address;
+mod text_envelope;
+pub use text_envelope::*;
 pub mod traits;
 mod protocol_types;
 pub use protocol_types::*;
@@ -62,6 +74,10 @@ pub mod typed_bytes;
 #[macro_use]
 mod utils;
 pub use utils::*;
+mod validation;
+pub use validation::*;
+mod vrf;
+pub(crate) use vrf::*;
 mod serialization;
 mod rational;
 
@@ -128,9 +144,14 @@ pub struct Transaction {
     witness_set: TransactionWitnessSet,
     is_valid: bool,
     auxiliary_data: Option<AuxiliaryData>,
+
+    #[serde(skip)]
+    original_encoding: OriginalEncoding,
 }
 
 impl_to_from!(Transaction);
+to_canonical_bytes!(Transaction);
+to_annotated_diagnostic!(Transaction, Transaction);
 
 #[wasm_bindgen]
 impl Transaction {
@@ -164,8 +185,57 @@ impl Transaction {
             witness_set: witness_set.clone(),
             is_valid: true,
             auxiliary_data: auxiliary_data.clone(),
+            original_encoding: OriginalEncoding::default(),
         }
     }
+
+    /// Checks the signatures of all vkey and bootstrap witnesses against the hash of the body.
+    /// Returns false if at least one signature is invalid.
+    /// The hash is taken from the re-serialized body,
+    /// use FixedTransaction to verify a deserialized transaction against its original body bytes.
+    pub fn verify_witnesses(&self) -> bool {
+        let tx_hash = TransactionHash::from(blake2b256(&self.body.to_bytes()));
+        has_valid_witness_signatures(&tx_hash, &self.witness_set)
+    }
+
+    /// Reports which of the key hashes required by the transaction are signed, missing or signed with an invalid signature.
+    /// The required key hashes come from inputs, collateral inputs, certificates, withdrawals, voters,
+    /// native scripts and required signers of the body.
+    /// {resolved_utxos} must contain the outputs of all inputs and collateral inputs,
+    /// outputs of the reference inputs are used to find referenced native scripts.
+    /// Signatures are checked against the hash of the re-serialized body.
+    pub fn required_signers_report(
+        &self,
+        resolved_utxos: &TransactionUnspentOutputs,
+    ) -> Result<RequiredSignersReport, JsError> {
+        let tx_hash = TransactionHash::from(blake2b256(&self.body.to_bytes()));
+        RequiredSignersReport::new(&tx_hash, &self.body, &self.witness_set, resolved_utxos)
+    }
+
+    /// Explains what the transaction does: the spent and produced values, minted assets,
+    /// certificates, votes, proposals and withdrawals, and the net value change
+    /// per address and per stake credential.
+    /// {resolved_utxos} should contain the outputs of the inputs, unresolved inputs are listed without a value.
+    /// Stake credentials are shown as reward addresses of {network}.
+    pub fn explain(
+        &self,
+        resolved_utxos: &TransactionUnspentOutputs,
+        network: &NetworkInfo,
+    ) -> Result<TransactionExplanation, JsError> {
+        let tx_hash = TransactionHash::from(blake2b256(&self.body.to_bytes()));
+        TransactionExplanation::new(&tx_hash, &self.body, self.is_valid, resolved_utxos, network)
+    }
+
+    /// Renders the transaction as JSON the way `cardano-cli transaction view --output-json` does,
+    /// see MultiEraTransaction::to_cli_view_json().
+    /// The era is the first one in which every field and encoding of the transaction is valid,
+    /// it fails if the transaction has fields of eras that exclude each other.
+    /// A Transaction always has the is_valid flag, so transactions of the eras before Alonzo
+    /// are labelled Alonzo or later, and built transactions write tagged sets so they are labelled Conway.
+    /// Use MultiEraTransaction::to_cli_view_json() on the original bytes to get the right era.
+    pub fn to_cli_view_json(&self) -> Result<String, JsError> {
+        render_transaction_view(self, MultiEraTransaction::detect_era(self)?)
+    }
 }
 
 // index of a tx within a block
@@ -237,9 +307,12 @@ pub struct TransactionOutput {
 
     #[serde(skip)]
     serialization_format: Option<CborContainerType>,
+    #[serde(skip)]
+    original_encoding: OriginalEncoding,
 }
 
 impl_to_from!(TransactionOutput);
+to_canonical_bytes!(TransactionOutput);
 
 #[wasm_bindgen]
 impl TransactionOutput {
@@ -306,12 +379,23 @@ impl TransactionOutput {
             plutus_data: None,
             script_ref: None,
             serialization_format: None,
+            original_encoding: OriginalEncoding::default(),
         }
     }
 
     pub fn serialization_format(&self) -> Option<CborContainerType> {
         self.serialization_format.clone()
     }
+
+    /// Copy of the output in the post-Alonzo map format,
+    /// legacy Shelley to Alonzo outputs keep their address, value and datum hash
+    pub fn to_current_format(&self) -> TransactionOutput {
+        Self {
+            serialization_format: Some(CborContainerType::Map),
+            original_encoding: OriginalEncoding::default(),
+            ..self.clone()
+        }
+    }
 }
 
 impl PartialEq for TransactionOutput {
@@ -1365,14 +1449,22 @@ impl Assets {
 
 #[wasm_bindgen]
 #[derive(Clone, Debug, Eq, Ord, PartialEq, serde::Serialize, serde::Deserialize, JsonSchema)]
-pub struct MultiAsset(pub(crate) std::collections::BTreeMap<PolicyID, Assets>);
+#[serde(transparent)]
+pub struct MultiAsset(
+    pub(crate) std::collections::BTreeMap<PolicyID, Assets>,
+    #[serde(skip)] pub(crate) OriginalEncoding,
+);
 
 impl_to_from!(MultiAsset);
+to_canonical_bytes!(MultiAsset);
 
 #[wasm_bindgen]
 impl MultiAsset {
     pub fn new() -> Self {
-        Self(std::collections::BTreeMap::new())
+        Self(
+            std::collections::BTreeMap::new(),
+            OriginalEncoding::default(),
+        )
     }
 
     /// the number of unique policy IDs in the multiasset
@@ -1593,9 +1685,14 @@ impl MintAssets {
 #[derive(
     Clone, Debug, Eq, Ord, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize, JsonSchema,
 )]
-pub struct Mint(Vec<(PolicyID, MintAssets)>);
+#[serde(transparent)]
+pub struct Mint(
+    Vec<(PolicyID, MintAssets)>,
+    #[serde(skip)] OriginalEncoding,
+);
 
 impl_to_from!(Mint);
+to_canonical_bytes!(Mint);
 
 impl NoneOrEmpty for Mint {
     fn is_none_or_empty(&self) -> bool {
@@ -1606,7 +1703,7 @@ impl NoneOrEmpty for Mint {
 #[wasm_bindgen]
 impl Mint {
     pub fn new() -> Self {
-        Self(Vec::new())
+        Self(Vec::new(), OriginalEncoding::default())
     }
 
     pub fn new_from_entry(key: &PolicyID, value: &MintAssets) -> Self {
@@ -263,6 +263,13 @@ impl ByronAddress {
     }
 }
 
+impl ByronAddress {
+    // the address root, the ledger uses it as the key hash a bootstrap witness has to provide
+    pub(crate) fn key_hash(&self) -> Ed25519KeyHash {
+        Ed25519KeyHash::from(self.0.addr)
+    }
+}
+
 #[wasm_bindgen]
 #[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Address(pub(crate) AddrType);
@@ -13,6 +13,7 @@ pub struct Block {
 }
 
 impl_to_from!(Block);
+to_annotated_diagnostic!(Block, Block);
 
 #[wasm_bindgen]
 impl Block {
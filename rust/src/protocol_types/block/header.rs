@@ -8,6 +8,7 @@ pub struct Header {
 }
 
 impl_to_from!(Header);
+to_annotated_diagnostic!(Header, Header);
 
 #[wasm_bindgen]
 impl Header {
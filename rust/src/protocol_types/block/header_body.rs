@@ -115,6 +115,56 @@ impl HeaderBody {
         self.protocol_version.clone()
     }
 
+    /// Checks the VRF proofs of the header against the vrf_vkey.
+    /// {epoch_nonce} is the nonce of the epoch of the slot of the header.
+    /// It doesn't check that the pool of the issuer registered this VRF key.
+    pub fn verify_vrf(&self, epoch_nonce: &Nonce) -> bool {
+        let slot = u64::from(&self.slot);
+        match &self.leader_cert {
+            HeaderLeaderCertEnum::NonceAndLeader(nonce, leader) => {
+                nonce.verify(&self.vrf_vkey, &tpraos_nonce_seed(slot, epoch_nonce))
+                    && leader.verify(&self.vrf_vkey, &tpraos_leader_seed(slot, epoch_nonce))
+            }
+            HeaderLeaderCertEnum::VrfResult(cert) => {
+                cert.verify(&self.vrf_vkey, &praos_vrf_input(slot, epoch_nonce))
+            }
+        }
+    }
+
+    /// The value compared with the stake of the pool to decide if it leads the slot,
+    /// the 64 bytes output of the leader VRF before Babbage and a 32 bytes hash of the VRF output after.
+    /// The proofs are not checked, see verify_vrf.
+    pub fn vrf_leader_value(&self) -> Vec<u8> {
+        match &self.leader_cert {
+            HeaderLeaderCertEnum::NonceAndLeader(_, leader) => leader.output.clone(),
+            HeaderLeaderCertEnum::VrfResult(cert) => praos_leader_value(&cert.output),
+        }
+    }
+
+    /// The contribution of the header to the evolving nonce. The proofs are not checked, see verify_vrf.
+    pub fn vrf_nonce_value(&self) -> Nonce {
+        match &self.leader_cert {
+            HeaderLeaderCertEnum::NonceAndLeader(nonce, _) => tpraos_nonce_value(&nonce.output),
+            HeaderLeaderCertEnum::VrfResult(cert) => praos_nonce_value(&cert.output),
+        }
+    }
+
+    /// Checks that vrf_leader_value() elects a pool with {relative_stake} of the active stake
+    /// with the {active_slot_coefficient} of the genesis, 1/20 on mainnet.
+    /// The threshold is computed with f64, the node's fixed point computation may decide values
+    /// within about 1e-15 of the threshold differently. The proofs are not checked, see verify_vrf.
+    pub fn is_leader(
+        &self,
+        relative_stake: &UnitInterval,
+        active_slot_coefficient: &UnitInterval,
+    ) -> bool {
+        is_leader_value(
+            &self.vrf_leader_value(),
+            relative_stake,
+            active_slot_coefficient,
+        )
+    }
+
     /// !!! DEPRECATED !!!
     /// This constructor uses outdated slot number format.
     /// Use `.new_headerbody` instead
@@ -0,0 +1,127 @@
+use crate::*;
+use std::collections::BTreeMap;
+
+/// Borrowed view of a block that only records where every transaction is in the original bytes.
+/// Only the header is decoded when the view is read,
+/// a transaction is decoded on demand from its original bytes.
+/// Use it to pick a few transactions out of a block, FixedBlock decodes the whole block.
+/// Warning: This is experimental and may be removed or changed in the future.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LazyBlock<'a> {
+    pub(crate) header: Header,
+    pub(crate) raw_header: &'a [u8],
+    pub(crate) transaction_bodies: Vec<&'a [u8]>,
+    pub(crate) transaction_witness_sets: Vec<&'a [u8]>,
+    pub(crate) auxiliary_data_set: BTreeMap<TransactionIndex, &'a [u8]>,
+    pub(crate) invalid_transactions: TransactionIndexes,
+    pub(crate) block_hash: BlockHash,
+}
+
+impl<'a> LazyBlock<'a> {
+    pub fn header(&self) -> Header {
+        self.header.clone()
+    }
+
+    pub fn raw_header(&self) -> &'a [u8] {
+        self.raw_header
+    }
+
+    pub fn block_hash(&self) -> BlockHash {
+        self.block_hash.clone()
+    }
+
+    pub fn invalid_transactions(&self) -> TransactionIndexes {
+        self.invalid_transactions.clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.transaction_bodies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.transaction_bodies.is_empty()
+    }
+
+    /// Returns the transaction at {index} without decoding it, None if the block has no such transaction
+    pub fn transaction(&self, index: usize) -> Option<LazyTransaction<'a>> {
+        let tx_index = index as TransactionIndex;
+        Some(LazyTransaction {
+            index: tx_index,
+            body: self.transaction_bodies.get(index).copied()?,
+            witness_set: self.transaction_witness_sets.get(index).copied()?,
+            auxiliary_data: self.auxiliary_data_set.get(&tx_index).copied(),
+            is_valid: !self.invalid_transactions.contains(&tx_index),
+        })
+    }
+
+    /// Iterates over the transactions of the block in order without decoding them
+    pub fn transactions(&self) -> impl Iterator<Item = LazyTransaction<'a>> + '_ {
+        (0..self.len()).filter_map(move |index| self.transaction(index))
+    }
+}
+
+/// Transaction of a LazyBlock, it keeps the original bytes of its parts and decodes them on demand.
+/// Warning: This is experimental and may be removed or changed in the future.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LazyTransaction<'a> {
+    pub(crate) index: TransactionIndex,
+    pub(crate) body: &'a [u8],
+    pub(crate) witness_set: &'a [u8],
+    pub(crate) auxiliary_data: Option<&'a [u8]>,
+    pub(crate) is_valid: bool,
+}
+
+impl<'a> LazyTransaction<'a> {
+    /// Index of the transaction within the block
+    pub fn index(&self) -> TransactionIndex {
+        self.index
+    }
+
+    /// Hash of the original body bytes, the body is not decoded
+    pub fn tx_hash(&self) -> TransactionHash {
+        TransactionHash::from(blake2b256(self.body))
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+
+    pub fn raw_body(&self) -> &'a [u8] {
+        self.body
+    }
+
+    pub fn raw_witness_set(&self) -> &'a [u8] {
+        self.witness_set
+    }
+
+    pub fn raw_auxiliary_data(&self) -> Option<&'a [u8]> {
+        self.auxiliary_data
+    }
+
+    pub fn body(&self) -> Result<FixedTransactionBody, DeserializeError> {
+        from_bytes(&self.body.to_vec())
+    }
+
+    pub fn witness_set(&self) -> Result<TransactionWitnessSet, DeserializeError> {
+        from_bytes(&self.witness_set.to_vec())
+    }
+
+    pub fn auxiliary_data(&self) -> Result<Option<AuxiliaryData>, DeserializeError> {
+        self.auxiliary_data
+            .map(|bytes| from_bytes(&bytes.to_vec()))
+            .transpose()
+    }
+
+    /// Decodes the whole transaction, the original bytes of the body, witness set and auxiliary data are kept
+    pub fn to_fixed_transaction(&self) -> Result<FixedTransaction, JsError> {
+        match self.auxiliary_data {
+            Some(auxiliary_data) => FixedTransaction::new_with_auxiliary(
+                self.body,
+                self.witness_set,
+                auxiliary_data,
+                self.is_valid,
+            ),
+            None => FixedTransaction::new(self.body, self.witness_set, self.is_valid),
+        }
+    }
+}
@@ -26,4 +26,6 @@ mod fixed_transaction_bodies;
 pub use fixed_transaction_bodies::*;
 
 mod versioned_block;
-pub use versioned_block::*;
\ No newline at end of file
+pub use versioned_block::*;
+mod lazy_block;
+pub use lazy_block::*;
@@ -1,7 +1,10 @@
 use crate::*;
 
 #[wasm_bindgen]
-#[derive(Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[derive(
+    Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize, JsonSchema,
+)]
+/// Ordered by the start of the era, Unknown comes last
 pub enum BlockEra {
     Byron,
     Shelley,
@@ -13,11 +16,44 @@ pub enum BlockEra {
     Unknown
 }
 
+// the era code of the VersionedBlock tells which variant the JSON of the block is
+#[derive(Clone, Eq, Debug, PartialEq, serde::Serialize, JsonSchema)]
+#[serde(untagged)]
+pub(crate) enum VersionedBlockEnum {
+    Block(Box<Block>),
+    // era codes 0 and 1
+    Byron(ByronBlock),
+}
+
 #[wasm_bindgen]
-#[derive(Clone, Eq, Debug, PartialEq, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[derive(Clone, Eq, Debug, PartialEq, serde::Serialize, JsonSchema)]
 pub struct VersionedBlock {
     pub(crate) era_code: u32,
-    pub(crate) block: Block,
+    pub(crate) block: VersionedBlockEnum,
+}
+
+impl<'de> serde::de::Deserialize<'de> for VersionedBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        struct VersionedBlockJson {
+            era_code: u32,
+            block: serde_json::Value,
+        }
+
+        let json = VersionedBlockJson::deserialize(deserializer)?;
+        let block = match json.era_code {
+            0 | 1 => serde_json::from_value(json.block).map(VersionedBlockEnum::Byron),
+            _ => serde_json::from_value(json.block).map(VersionedBlockEnum::Block),
+        }
+        .map_err(serde::de::Error::custom)?;
+        Ok(Self {
+            era_code: json.era_code,
+            block,
+        })
+    }
 }
 
 impl_to_from!(VersionedBlock);
@@ -26,13 +62,42 @@ impl_to_from!(VersionedBlock);
 impl VersionedBlock {
     pub fn new(block: Block, era_code: u32) -> VersionedBlock {
         VersionedBlock {
-            block,
+            block: VersionedBlockEnum::Block(Box::new(block)),
             era_code,
         }
     }
 
+    pub fn new_byron(block: &ByronBlock) -> VersionedBlock {
+        VersionedBlock {
+            era_code: block.era_code(),
+            block: VersionedBlockEnum::Byron(block.clone()),
+        }
+    }
+
+    /// Panics for Byron blocks, check era() first or use shelley_block()
     pub fn block(&self) -> Block {
-        self.block.clone()
+        match &self.block {
+            VersionedBlockEnum::Block(block) => block.as_ref().clone(),
+            VersionedBlockEnum::Byron(_) => {
+                panic!("Byron blocks have no Shelley format, use byron_block()")
+            }
+        }
+    }
+
+    /// None for Byron blocks, see byron_block()
+    pub fn shelley_block(&self) -> Option<Block> {
+        match &self.block {
+            VersionedBlockEnum::Block(block) => Some(block.as_ref().clone()),
+            VersionedBlockEnum::Byron(_) => None,
+        }
+    }
+
+    /// None for blocks of the eras after Byron, see shelley_block()
+    pub fn byron_block(&self) -> Option<ByronBlock> {
+        match &self.block {
+            VersionedBlockEnum::Byron(block) => Some(block.clone()),
+            VersionedBlockEnum::Block(_) => None,
+        }
     }
 
     pub fn era(&self) -> BlockEra {
@@ -0,0 +1,224 @@
+use crate::*;
+
+/// Number of slots in a Byron epoch on every network (10k, k = 2160)
+pub(crate) const BYRON_SLOTS_PER_EPOCH: u64 = 21600;
+
+#[wasm_bindgen]
+#[derive(Clone, Eq, Debug, PartialEq)]
+/// Read-only view of a Byron main block. With the hash used by the node and original bytes.
+/// The update, delegation and shared seed payloads are only kept as part of the original bytes.
+pub struct ByronMainBlock {
+    pub(crate) protocol_magic: u32,
+    pub(crate) prev_block: BlockHash,
+    pub(crate) epoch: u64,
+    pub(crate) slot: u64,
+    pub(crate) issuer_public_key: Vec<u8>,
+    pub(crate) difficulty: u64,
+    pub(crate) transactions: ByronTransactions,
+    pub(crate) block_hash: BlockHash,
+    pub(crate) original_bytes: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl ByronMainBlock {
+    pub fn protocol_magic(&self) -> u32 {
+        self.protocol_magic
+    }
+
+    pub fn prev_block(&self) -> BlockHash {
+        self.prev_block.clone()
+    }
+
+    pub fn epoch(&self) -> BigNum {
+        BigNum::from(self.epoch)
+    }
+
+    /// Slot number within the epoch
+    pub fn slot(&self) -> BigNum {
+        BigNum::from(self.slot)
+    }
+
+    pub fn absolute_slot(&self) -> BigNum {
+        BigNum::from(self.epoch * BYRON_SLOTS_PER_EPOCH + self.slot)
+    }
+
+    /// Extended public key of the block issuer
+    pub fn issuer_public_key(&self) -> Vec<u8> {
+        self.issuer_public_key.clone()
+    }
+
+    /// Chain length up to and including this block, epoch boundary blocks don't count
+    pub fn difficulty(&self) -> BigNum {
+        BigNum::from(self.difficulty)
+    }
+
+    pub fn transactions(&self) -> ByronTransactions {
+        self.transactions.clone()
+    }
+
+    /// blake2b-256 of the header tagged with its block kind: `[1, header]`
+    pub fn block_hash(&self) -> BlockHash {
+        self.block_hash.clone()
+    }
+
+    pub fn original_bytes(&self) -> Vec<u8> {
+        self.original_bytes.clone()
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Eq, Debug, PartialEq)]
+/// Read-only view of a Byron epoch boundary block. With the hash used by the node and original bytes.
+pub struct ByronEpochBoundaryBlock {
+    pub(crate) protocol_magic: u32,
+    pub(crate) prev_block: BlockHash,
+    pub(crate) epoch: u64,
+    pub(crate) difficulty: u64,
+    pub(crate) block_hash: BlockHash,
+    pub(crate) original_bytes: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl ByronEpochBoundaryBlock {
+    pub fn protocol_magic(&self) -> u32 {
+        self.protocol_magic
+    }
+
+    /// Hash of the last block of the previous epoch, or of the genesis data for the first epoch
+    pub fn prev_block(&self) -> BlockHash {
+        self.prev_block.clone()
+    }
+
+    pub fn epoch(&self) -> BigNum {
+        BigNum::from(self.epoch)
+    }
+
+    pub fn difficulty(&self) -> BigNum {
+        BigNum::from(self.difficulty)
+    }
+
+    /// blake2b-256 of the header tagged with its block kind: `[0, header]`
+    pub fn block_hash(&self) -> BlockHash {
+        self.block_hash.clone()
+    }
+
+    pub fn original_bytes(&self) -> Vec<u8> {
+        self.original_bytes.clone()
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum ByronBlockKind {
+    EpochBoundary,
+    Main,
+}
+
+#[derive(Clone, Eq, Debug, PartialEq)]
+pub(crate) enum ByronBlockEnum {
+    EpochBoundary(ByronEpochBoundaryBlock),
+    Main(ByronMainBlock),
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Eq, Debug, PartialEq)]
+/// A Byron block as stored by the node: `[0, epoch boundary block]` or `[1, main block]`
+pub struct ByronBlock(pub(crate) ByronBlockEnum);
+
+to_from_bytes!(ByronBlock);
+
+#[wasm_bindgen]
+impl ByronBlock {
+    pub fn kind(&self) -> ByronBlockKind {
+        match &self.0 {
+            ByronBlockEnum::EpochBoundary(_) => ByronBlockKind::EpochBoundary,
+            ByronBlockEnum::Main(_) => ByronBlockKind::Main,
+        }
+    }
+
+    pub fn as_epoch_boundary_block(&self) -> Option<ByronEpochBoundaryBlock> {
+        match &self.0 {
+            ByronBlockEnum::EpochBoundary(block) => Some(block.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn as_main_block(&self) -> Option<ByronMainBlock> {
+        match &self.0 {
+            ByronBlockEnum::Main(block) => Some(block.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn block_hash(&self) -> BlockHash {
+        match &self.0 {
+            ByronBlockEnum::EpochBoundary(block) => block.block_hash(),
+            ByronBlockEnum::Main(block) => block.block_hash(),
+        }
+    }
+
+    pub fn prev_block(&self) -> BlockHash {
+        match &self.0 {
+            ByronBlockEnum::EpochBoundary(block) => block.prev_block(),
+            ByronBlockEnum::Main(block) => block.prev_block(),
+        }
+    }
+
+    pub fn epoch(&self) -> BigNum {
+        match &self.0 {
+            ByronBlockEnum::EpochBoundary(block) => block.epoch(),
+            ByronBlockEnum::Main(block) => block.epoch(),
+        }
+    }
+
+    /// Empty for epoch boundary blocks
+    pub fn transactions(&self) -> ByronTransactions {
+        match &self.0 {
+            ByronBlockEnum::EpochBoundary(_) => ByronTransactions(Vec::new()),
+            ByronBlockEnum::Main(block) => block.transactions(),
+        }
+    }
+
+    pub(crate) fn era_code(&self) -> u32 {
+        match &self.0 {
+            ByronBlockEnum::EpochBoundary(_) => 0,
+            ByronBlockEnum::Main(_) => 1,
+        }
+    }
+}
+
+impl serde::Serialize for ByronBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_hex())
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for ByronBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let hex_str = <String as serde::de::Deserialize>::deserialize(deserializer)?;
+        ByronBlock::from_hex(&hex_str).map_err(|_e| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(&hex_str),
+                &"hex bytes of a Byron block",
+            )
+        })
+    }
+}
+
+impl JsonSchema for ByronBlock {
+    fn schema_name() -> String {
+        String::from("ByronBlock")
+    }
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+    fn is_referenceable() -> bool {
+        String::is_referenceable()
+    }
+}
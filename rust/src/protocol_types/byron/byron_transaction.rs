@@ -0,0 +1,211 @@
+use crate::*;
+
+#[wasm_bindgen]
+#[derive(Clone, Eq, Debug, PartialEq)]
+pub struct ByronTransactionOutput {
+    pub(crate) address: ByronAddress,
+    pub(crate) amount: Coin,
+}
+
+to_from_bytes!(ByronTransactionOutput);
+
+#[wasm_bindgen]
+impl ByronTransactionOutput {
+    pub fn address(&self) -> ByronAddress {
+        self.address.clone()
+    }
+
+    pub fn amount(&self) -> Coin {
+        self.amount
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Eq, Debug, PartialEq)]
+pub struct ByronTransactionOutputs(pub(crate) Vec<ByronTransactionOutput>);
+
+#[wasm_bindgen]
+impl ByronTransactionOutputs {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> ByronTransactionOutput {
+        self.0[index].clone()
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Eq, Debug, PartialEq)]
+/// Read-only view of a Byron transaction body (`Tx`). With the hash used by the node and original bytes.
+pub struct ByronTransactionBody {
+    pub(crate) inputs: Vec<TransactionInput>,
+    pub(crate) outputs: ByronTransactionOutputs,
+    pub(crate) tx_hash: TransactionHash,
+    pub(crate) original_bytes: Vec<u8>,
+}
+
+to_from_bytes!(ByronTransactionBody);
+
+#[wasm_bindgen]
+impl ByronTransactionBody {
+    pub fn inputs(&self) -> TransactionInputs {
+        let mut inputs = TransactionInputs::new();
+        for input in &self.inputs {
+            inputs.add(input);
+        }
+        inputs
+    }
+
+    pub fn outputs(&self) -> ByronTransactionOutputs {
+        self.outputs.clone()
+    }
+
+    /// blake2b-256 of the original bytes of the body, the id other transactions use to spend its outputs
+    pub fn tx_hash(&self) -> TransactionHash {
+        self.tx_hash.clone()
+    }
+
+    pub fn original_bytes(&self) -> Vec<u8> {
+        self.original_bytes.clone()
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum ByronTxWitnessKind {
+    PublicKey,
+    Script,
+    Redeem,
+    Unknown,
+}
+
+#[derive(Clone, Eq, Debug, PartialEq)]
+pub(crate) enum ByronTxWitnessEnum {
+    PublicKey {
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    },
+    // the encoded validator and redeemer scripts
+    Script(Vec<u8>),
+    Redeem {
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    },
+    Unknown(u64),
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Eq, Debug, PartialEq)]
+/// Read-only view of a Byron input witness (`TxInWitness`) with its original bytes
+pub struct ByronTxWitness {
+    pub(crate) witness: ByronTxWitnessEnum,
+    pub(crate) original_bytes: Vec<u8>,
+}
+
+to_from_bytes!(ByronTxWitness);
+
+#[wasm_bindgen]
+impl ByronTxWitness {
+    pub fn kind(&self) -> ByronTxWitnessKind {
+        match &self.witness {
+            ByronTxWitnessEnum::PublicKey { .. } => ByronTxWitnessKind::PublicKey,
+            ByronTxWitnessEnum::Script(_) => ByronTxWitnessKind::Script,
+            ByronTxWitnessEnum::Redeem { .. } => ByronTxWitnessKind::Redeem,
+            ByronTxWitnessEnum::Unknown(_) => ByronTxWitnessKind::Unknown,
+        }
+    }
+
+    /// Extended public key of a public key witness or the public key of a redeem witness
+    pub fn public_key(&self) -> Option<Vec<u8>> {
+        match &self.witness {
+            ByronTxWitnessEnum::PublicKey { public_key, .. }
+            | ByronTxWitnessEnum::Redeem { public_key, .. } => Some(public_key.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn signature(&self) -> Option<Vec<u8>> {
+        match &self.witness {
+            ByronTxWitnessEnum::PublicKey { signature, .. }
+            | ByronTxWitnessEnum::Redeem { signature, .. } => Some(signature.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn original_bytes(&self) -> Vec<u8> {
+        self.original_bytes.clone()
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Eq, Debug, PartialEq)]
+pub struct ByronTxWitnesses(pub(crate) Vec<ByronTxWitness>);
+
+#[wasm_bindgen]
+impl ByronTxWitnesses {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> ByronTxWitness {
+        self.0[index].clone()
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Eq, Debug, PartialEq)]
+/// Read-only view of a Byron transaction (`TxAux`): the body and the witnesses of its inputs
+pub struct ByronTransaction {
+    pub(crate) body: ByronTransactionBody,
+    pub(crate) witnesses: ByronTxWitnesses,
+    pub(crate) original_bytes: Vec<u8>,
+}
+
+to_from_bytes!(ByronTransaction);
+
+#[wasm_bindgen]
+impl ByronTransaction {
+    pub fn body(&self) -> ByronTransactionBody {
+        self.body.clone()
+    }
+
+    pub fn witnesses(&self) -> ByronTxWitnesses {
+        self.witnesses.clone()
+    }
+
+    pub fn tx_hash(&self) -> TransactionHash {
+        self.body.tx_hash.clone()
+    }
+
+    pub fn original_bytes(&self) -> Vec<u8> {
+        self.original_bytes.clone()
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Eq, Debug, PartialEq)]
+pub struct ByronTransactions(pub(crate) Vec<ByronTransaction>);
+
+#[wasm_bindgen]
+impl ByronTransactions {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> ByronTransaction {
+        self.0[index].clone()
+    }
+}
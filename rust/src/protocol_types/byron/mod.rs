@@ -0,0 +1,5 @@
+mod byron_block;
+pub use byron_block::*;
+
+mod byron_transaction;
+pub use byron_transaction::*;
@@ -70,6 +70,7 @@ pub enum CertificateEnum {
 pub struct Certificate(pub(crate) CertificateEnum);
 
 impl_to_from!(Certificate);
+to_annotated_diagnostic!(Certificate, Certificate);
 
 #[wasm_bindgen]
 impl Certificate {
@@ -416,3 +417,26 @@ impl Certificate {
         }
     }
 }
+
+impl Certificate {
+    /// Returns the script which has to witness the certificate if it has a script credential
+    pub(crate) fn required_script_hash(&self) -> Option<ScriptHash> {
+        let cred = match &self.0 {
+            CertificateEnum::StakeRegistration(x) if x.coin.is_some() => &x.stake_credential,
+            CertificateEnum::StakeDeregistration(x) => &x.stake_credential,
+            CertificateEnum::StakeDelegation(x) => &x.stake_credential,
+            CertificateEnum::VoteDelegation(x) => &x.stake_credential,
+            CertificateEnum::StakeAndVoteDelegation(x) => &x.stake_credential,
+            CertificateEnum::StakeRegistrationAndDelegation(x) => &x.stake_credential,
+            CertificateEnum::StakeVoteRegistrationAndDelegation(x) => &x.stake_credential,
+            CertificateEnum::VoteRegistrationAndDelegation(x) => &x.stake_credential,
+            CertificateEnum::CommitteeHotAuth(x) => &x.committee_cold_credential,
+            CertificateEnum::CommitteeColdResign(x) => &x.committee_cold_credential,
+            CertificateEnum::DRepRegistration(x) => &x.voting_credential,
+            CertificateEnum::DRepDeregistration(x) => &x.voting_credential,
+            CertificateEnum::DRepUpdate(x) => &x.voting_credential,
+            _ => return None,
+        };
+        cred.to_scripthash()
+    }
+}
@@ -13,9 +13,11 @@ pub struct Certificates {
     pub(crate) certs: Vec<Rc<Certificate>>,
     pub(crate) dedup: HashSet<Rc<Certificate>>,
     pub(crate) cbor_set_type: CborSetType,
+    pub(crate) original_encoding: OriginalEncoding,
 }
 
 impl_to_from!(Certificates);
+to_canonical_bytes!(Certificates);
 
 impl NoneOrEmpty for Certificates {
     fn is_none_or_empty(&self) -> bool {
@@ -30,6 +32,7 @@ impl Certificates {
             certs: Vec::new(),
             dedup: HashSet::new(),
             cbor_set_type: CborSetType::Tagged,
+            original_encoding: OriginalEncoding::default(),
         }
     }
 
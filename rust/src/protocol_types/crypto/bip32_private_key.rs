@@ -4,7 +4,7 @@ use rand_os::OsRng;
 use crate::chain_crypto::bech32::Bech32;
 
 #[wasm_bindgen]
-pub struct Bip32PrivateKey(chain_crypto::SecretKey<chain_crypto::Ed25519Bip32>);
+pub struct Bip32PrivateKey(pub(crate) chain_crypto::SecretKey<chain_crypto::Ed25519Bip32>);
 
 #[wasm_bindgen]
 impl Bip32PrivateKey {
@@ -100,6 +100,22 @@ impl Bip32PrivateKey {
         Bip32PrivateKey(crate::chain_crypto::derive::from_bip39_entropy(&entropy, &password))
     }
 
+    /// Restores the root key from an English BIP39 mnemonic, see from_bip39_entropy.
+    /// Wallets like Daedalus and Yoroi use an empty {passphrase}.
+    /// Fails if a word is unknown, the number of words is wrong or the checksum doesn't match.
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<Bip32PrivateKey, JsError> {
+        Self::from_mnemonic_with_wordlist(phrase, passphrase, &Bip39Wordlist::english())
+    }
+
+    pub fn from_mnemonic_with_wordlist(
+        phrase: &str,
+        passphrase: &str,
+        wordlist: &Bip39Wordlist,
+    ) -> Result<Bip32PrivateKey, JsError> {
+        let entropy = bip39_mnemonic_to_entropy(phrase, wordlist)?;
+        Ok(Self::from_bip39_entropy(&entropy, passphrase.as_bytes()))
+    }
+
     pub fn chaincode(&self) -> Vec<u8> {
         const ED25519_PRIVATE_KEY_LENGTH: usize = 64;
         const XPRV_SIZE: usize = 96;
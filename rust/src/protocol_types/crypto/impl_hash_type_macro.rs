@@ -79,11 +79,11 @@ macro_rules! impl_hash_type {
         }
 
         impl Deserialize for $name {
-            fn deserialize<R: std::io::BufRead>(
+            fn deserialize<R: std::io::BufRead + std::io::Seek>(
                 raw: &mut Deserializer<R>,
             ) -> Result<Self, DeserializeError> {
                 use std::convert::TryInto;
-                (|| -> Result<Self, DeserializeError> {
+                $crate::serialization::deserialize_item(raw, |raw| {
                     let bytes = raw.bytes()?;
                     if bytes.len() != $byte_count {
                         return Err(DeserializeFailure::CBOR(cbor_event::Error::WrongLen(
@@ -94,7 +94,7 @@ macro_rules! impl_hash_type {
                         .into());
                     }
                     Ok($name(bytes[..$byte_count].try_into().unwrap()))
-                })()
+                })
                 .map_err(|e| e.annotate(stringify!($name)))
             }
         }
@@ -53,10 +53,12 @@ macro_rules! impl_signature {
         }
 
         impl Deserialize for $name {
-            fn deserialize<R: std::io::BufRead>(
+            fn deserialize<R: std::io::BufRead + std::io::Seek>(
                 raw: &mut Deserializer<R>,
             ) -> Result<Self, DeserializeError> {
-                Ok(Self(chain_crypto::Signature::from_binary(raw.bytes()?.as_ref())?))
+                $crate::serialization::deserialize_item(raw, |raw| {
+                    Ok(Self(chain_crypto::Signature::from_binary(raw.bytes()?.as_ref())?))
+                })
             }
         }
 
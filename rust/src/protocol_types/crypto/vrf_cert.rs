@@ -25,6 +25,15 @@ impl VRFCert {
         self.proof.clone()
     }
 
+    /// Checks the ECVRF-ED25519-SHA512-Elligator2 proof (draft 03) of {vrf_vkey} for {input}
+    /// and that the output is the one of the proof
+    pub fn verify(&self, vrf_vkey: &VRFVKey, input: &[u8]) -> bool {
+        match verify_vrf_proof(&vrf_vkey.0, &self.proof, input) {
+            Some(output) => output == self.output,
+            None => false,
+        }
+    }
+
     pub fn new(output: Vec<u8>, proof: Vec<u8>) -> Result<VRFCert, JsError> {
         if proof.len() != Self::PROOF_LEN {
             return Err(JsError::from_str(&format!(
@@ -17,6 +17,8 @@ pub struct FixedTransaction {
 }
 
 to_from_bytes!(FixedTransaction);
+to_canonical_bytes!(FixedTransaction);
+to_annotated_diagnostic!(FixedTransaction, Transaction);
 
 #[wasm_bindgen]
 impl FixedTransaction {
@@ -231,6 +233,33 @@ impl FixedTransaction {
         Ok(())
     }
 
+    /// Checks the signatures of all vkey and bootstrap witnesses against the hash of the original body bytes.
+    /// Returns false if at least one signature is invalid.
+    pub fn verify_witnesses(&self) -> bool {
+        has_valid_witness_signatures(&self.tx_hash, self.witness_set.tx_witnesses_set_ref())
+    }
+
+    /// Reports which of the key hashes required by the transaction are signed, missing or signed with an invalid signature.
+    /// The required key hashes come from inputs, collateral inputs, certificates, withdrawals, voters,
+    /// native scripts and required signers of the body.
+    /// {resolved_utxos} must contain the outputs of all inputs and collateral inputs,
+    /// outputs of the reference inputs are used to find referenced native scripts.
+    pub fn required_signers_report(
+        &self,
+        resolved_utxos: &TransactionUnspentOutputs,
+    ) -> Result<RequiredSignersReport, JsError> {
+        RequiredSignersReport::new(
+            &self.tx_hash,
+            &self.body,
+            self.witness_set.tx_witnesses_set_ref(),
+            resolved_utxos,
+        )
+    }
+
+    pub(crate) fn body_ref(&self) -> &TransactionBody {
+        &self.body
+    }
+
     pub(crate) fn body_bytes_ref(&self) -> &Vec<u8> {
         &self.body_bytes
     }
@@ -242,4 +271,8 @@ impl FixedTransaction {
     pub(crate) fn auxiliary_bytes_ref(&self) -> Option<&Vec<u8>> {
         self.auxiliary_bytes.as_ref()
     }
+
+    pub(crate) fn auxiliary_data_ref(&self) -> Option<&AuxiliaryData> {
+        self.auxiliary_data.as_ref()
+    }
 }
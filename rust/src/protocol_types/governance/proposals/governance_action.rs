@@ -61,6 +61,7 @@ pub enum GovernanceActionKind {
 pub struct GovernanceAction(pub(crate) GovernanceActionEnum);
 
 impl_to_from!(GovernanceAction);
+to_annotated_diagnostic!(GovernanceAction, GovernanceAction);
 
 #[wasm_bindgen]
 impl GovernanceAction {
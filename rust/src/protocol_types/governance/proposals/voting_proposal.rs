@@ -21,6 +21,7 @@ pub struct VotingProposal {
 }
 
 impl_to_from!(VotingProposal);
+to_annotated_diagnostic!(VotingProposal, VotingProposal);
 
 #[wasm_bindgen]
 impl VotingProposal {
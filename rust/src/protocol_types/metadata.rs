@@ -360,6 +360,8 @@ pub struct AuxiliaryData {
     pub(crate) native_scripts: Option<NativeScripts>,
     pub(crate) plutus_scripts: Option<PlutusScripts>,
     pub(crate) prefer_alonzo_format: bool,
+    #[serde(skip)]
+    pub(crate) original_encoding: OriginalEncoding,
 }
 
 impl std::cmp::PartialEq<Self> for AuxiliaryData {
@@ -373,6 +375,8 @@ impl std::cmp::PartialEq<Self> for AuxiliaryData {
 impl std::cmp::Eq for AuxiliaryData {}
 
 impl_to_from!(AuxiliaryData);
+to_canonical_bytes!(AuxiliaryData);
+to_annotated_diagnostic!(AuxiliaryData, AuxiliaryData);
 
 #[wasm_bindgen]
 impl AuxiliaryData {
@@ -382,6 +386,7 @@ impl AuxiliaryData {
             native_scripts: None,
             plutus_scripts: None,
             prefer_alonzo_format: false,
+            original_encoding: OriginalEncoding::default(),
         }
     }
 
@@ -416,6 +421,17 @@ impl AuxiliaryData {
     pub fn set_prefer_alonzo_format(&mut self, prefer: bool) {
         self.prefer_alonzo_format = prefer
     }
+
+    /// Copy of the auxiliary data in the Alonzo tagged map format.
+    /// The Shelley metadata map and the Allegra [metadata, native_scripts] array
+    /// become the metadata and native_scripts entries of the map.
+    pub fn to_current_format(&self) -> AuxiliaryData {
+        Self {
+            prefer_alonzo_format: true,
+            original_encoding: OriginalEncoding::default(),
+            ..self.clone()
+        }
+    }
 }
 
 // encodes arbitrary bytes into chunks of 64 bytes (the limit for bytes) as a list to be valid Metadata
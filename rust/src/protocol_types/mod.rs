@@ -2,6 +2,9 @@
 mod fixed_tx;
 pub use fixed_tx::*;
 
+mod multi_era_tx;
+pub use multi_era_tx::*;
+
 mod certificates;
 pub use certificates::*;
 
@@ -20,6 +23,12 @@ pub use transaction_body::*;
 mod protocol_param_update;
 pub use protocol_param_update::*;
 
+mod protocol_parameters;
+pub use protocol_parameters::*;
+
+mod slot_config;
+pub use slot_config::*;
+
 mod address;
 pub use address::*;
 
@@ -50,6 +59,12 @@ pub use native_script::*;
 mod native_scripts;
 pub use native_scripts::*;
 
+mod native_script_signer_sets;
+pub use native_script_signer_sets::*;
+
+mod validity_interval;
+pub use validity_interval::*;
+
 mod numeric;
 pub use numeric::*;
 
@@ -58,3 +73,6 @@ pub use script_ref::*;
 
 mod block;
 pub use block::*;
+
+mod byron;
+pub use byron::*;
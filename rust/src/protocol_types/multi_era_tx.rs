@@ -0,0 +1,386 @@
+use crate::*;
+
+const FIRST_SHELLEY_BASED_ERA: BlockEra = BlockEra::Shelley;
+const LATEST_ERA: BlockEra = BlockEra::Conway;
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A transaction of one of the Shelley based eras together with the era it was decoded for.
+/// Decoding fails if the transaction uses a field or an encoding that is not valid in that era.
+pub struct MultiEraTransaction {
+    pub(crate) era: BlockEra,
+    pub(crate) transaction: Transaction,
+}
+
+to_bytes!(MultiEraTransaction);
+to_hex!(MultiEraTransaction);
+
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+#[wasm_bindgen]
+impl MultiEraTransaction {
+    /// Decodes a transaction of the {era_hint} era.
+    /// Without a hint the era is the first one in which every field and encoding of the transaction is valid.
+    pub fn from_bytes(
+        bytes: Vec<u8>,
+        era_hint: Option<BlockEra>,
+    ) -> Result<MultiEraTransaction, JsError> {
+        Ok(Self::from_bytes_impl(bytes, era_hint)?)
+    }
+}
+
+#[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+impl MultiEraTransaction {
+    /// Decodes a transaction of the {era_hint} era.
+    /// Without a hint the era is the first one in which every field and encoding of the transaction is valid.
+    pub fn from_bytes(
+        bytes: Vec<u8>,
+        era_hint: Option<BlockEra>,
+    ) -> Result<MultiEraTransaction, DeserializeError> {
+        Self::from_bytes_impl(bytes, era_hint)
+    }
+}
+
+#[wasm_bindgen]
+impl MultiEraTransaction {
+    pub fn era(&self) -> BlockEra {
+        self.era
+    }
+
+    /// The transaction as decoded, it is written back with its original bytes
+    pub fn transaction(&self) -> Transaction {
+        self.transaction.clone()
+    }
+
+    /// Converts the transaction to the encodings of the current era:
+    /// outputs use the post-Alonzo map format and the auxiliary data the Alonzo tagged map format.
+    /// The body of the converted transaction has a different hash than the decoded one
+    /// and its auxiliary_data_hash still refers to the original auxiliary data,
+    /// so it can be inspected but not submitted.
+    pub fn to_current_format(&self) -> Transaction {
+        let mut transaction = self.transaction.clone();
+        let body = &mut transaction.body;
+        body.outputs = TransactionOutputs(
+            body.outputs
+                .0
+                .iter()
+                .map(TransactionOutput::to_current_format)
+                .collect(),
+        );
+        body.collateral_return = body
+            .collateral_return
+            .as_ref()
+            .map(TransactionOutput::to_current_format);
+        body.original_encoding = OriginalEncoding::default();
+        transaction.auxiliary_data = transaction
+            .auxiliary_data
+            .as_ref()
+            .map(AuxiliaryData::to_current_format);
+        transaction.original_encoding = OriginalEncoding::default();
+        transaction
+    }
+
+    /// Renders the transaction as JSON the way `cardano-cli transaction view --output-json` does:
+    /// bech32 addresses, values grouped by policy and asset, decoded certificates, governance actions and datums.
+    pub fn to_cli_view_json(&self) -> Result<String, JsError> {
+        render_transaction_view(&self.transaction, self.era)
+    }
+}
+
+impl MultiEraTransaction {
+    fn from_bytes_impl(
+        bytes: Vec<u8>,
+        era_hint: Option<BlockEra>,
+    ) -> Result<MultiEraTransaction, DeserializeError> {
+        let mut raw = Deserializer::from(std::io::Cursor::new(bytes));
+        // the transaction array has no is_valid flag before Alonzo
+        let has_is_valid = raw.array()? != cbor_event::Len::Len(3);
+        raw.as_mut_ref()
+            .seek(std::io::SeekFrom::Start(0))
+            .map_err(|e| DeserializeFailure::IoError(e.to_string()))?;
+        let transaction = Transaction::deserialize(&mut raw)
+            .map_err(|e| e.locate(&mut raw).annotate("MultiEraTransaction"))?;
+        let rules = era_rules(&transaction, has_is_valid);
+        let era = match era_hint {
+            Some(era) if era < FIRST_SHELLEY_BASED_ERA || era > LATEST_ERA => {
+                return Err(DeserializeError::new(
+                    "MultiEraTransaction",
+                    DeserializeFailure::CustomError(format!(
+                        "{:?} is not a Shelley based era, Byron transactions are decoded with ByronTransaction",
+                        era
+                    )),
+                ))
+            }
+            Some(era) => era,
+            None => first_valid_era(&rules)?,
+        };
+        match rules.iter().find(|rule| !rule.is_valid_in(era)) {
+            Some(rule) => Err(rule.to_error(era)),
+            None => Ok(MultiEraTransaction { era, transaction }),
+        }
+    }
+
+    // transactions built in memory are always written with the is_valid flag
+    pub(crate) fn detect_era(transaction: &Transaction) -> Result<BlockEra, DeserializeError> {
+        first_valid_era(&era_rules(transaction, true))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum EraRuleStep {
+    Field(&'static str),
+    Index(usize),
+}
+
+// A field or an encoding of the transaction that only exists in the eras from valid_from to valid_until
+struct EraRule {
+    outer: Vec<EraRuleStep>,
+    location: &'static str,
+    valid_from: BlockEra,
+    valid_until: BlockEra,
+}
+
+impl EraRule {
+    fn is_valid_in(&self, era: BlockEra) -> bool {
+        self.valid_from <= era && era <= self.valid_until
+    }
+
+    fn to_error(&self, era: BlockEra) -> DeserializeError {
+        let mut error = DeserializeError::new(
+            self.location,
+            DeserializeFailure::InvalidForEra {
+                era,
+                valid_from: self.valid_from,
+                valid_until: self.valid_until,
+            },
+        );
+        for &step in self.outer.iter().rev() {
+            error = match step {
+                EraRuleStep::Field(field) => error.annotate(field),
+                EraRuleStep::Index(index) => error.annotate_index(index),
+            };
+        }
+        error.annotate("MultiEraTransaction")
+    }
+}
+
+struct EraRules(Vec<EraRule>);
+
+impl EraRules {
+    fn add(
+        &mut self,
+        outer: Vec<EraRuleStep>,
+        location: &'static str,
+        valid_from: BlockEra,
+        valid_until: BlockEra,
+    ) {
+        self.0.push(EraRule {
+            outer,
+            location,
+            valid_from,
+            valid_until,
+        });
+    }
+
+    fn add_since(&mut self, outer: Vec<EraRuleStep>, location: &'static str, valid_from: BlockEra) {
+        self.add(outer, location, valid_from, LATEST_ERA);
+    }
+
+    fn add_until(&mut self, outer: Vec<EraRuleStep>, location: &'static str, valid_until: BlockEra) {
+        self.add(outer, location, FIRST_SHELLEY_BASED_ERA, valid_until);
+    }
+
+    fn add_output(&mut self, mut outer: Vec<EraRuleStep>, output: &TransactionOutput) {
+        // legacy outputs are arrays, the map format came with Babbage
+        if output.serialization_format == Some(CborContainerType::Map) {
+            self.add_since(outer.clone(), "TransactionOutput", BlockEra::Babbage);
+        }
+        outer.push(EraRuleStep::Field("TransactionOutput"));
+        if output.amount.multiasset.is_some() {
+            self.add_since(outer.clone(), "amount", BlockEra::Mary);
+        }
+        match &output.plutus_data {
+            Some(DataOption::DataHash(_)) => self.add_since(outer.clone(), "datum_hash", BlockEra::Alonzo),
+            Some(DataOption::Data(_)) => self.add_since(outer.clone(), "plutus_data", BlockEra::Babbage),
+            None => (),
+        }
+        if output.script_ref.is_some() {
+            self.add_since(outer, "script_ref", BlockEra::Babbage);
+        }
+    }
+
+    fn add_native_scripts(&mut self, outer: &'static str, scripts: &NativeScripts) {
+        if scripts.scripts.iter().any(has_timelock) {
+            self.add_since(vec![EraRuleStep::Field(outer)], "native_scripts", BlockEra::Allegra);
+        }
+    }
+
+    fn add_plutus_scripts(&mut self, outer: &'static str, scripts: &PlutusScripts) {
+        let versions = [
+            (Language::new_plutus_v1(), "plutus_scripts_v1", BlockEra::Alonzo),
+            (Language::new_plutus_v2(), "plutus_scripts_v2", BlockEra::Babbage),
+            (Language::new_plutus_v3(), "plutus_scripts_v3", BlockEra::Conway),
+        ];
+        for (language, location, valid_from) in versions.iter() {
+            if scripts.has_version(language) {
+                self.add_since(vec![EraRuleStep::Field(outer)], location, *valid_from);
+            }
+        }
+    }
+}
+
+// the rules of a transaction can leave no valid era, e.g. an update together with a donation
+fn first_valid_era(rules: &[EraRule]) -> Result<BlockEra, DeserializeError> {
+    let era = rules
+        .iter()
+        .map(|rule| rule.valid_from)
+        .max()
+        .unwrap_or(FIRST_SHELLEY_BASED_ERA);
+    match rules.iter().find(|rule| !rule.is_valid_in(era)) {
+        Some(rule) => Err(rule.to_error(era)),
+        None => Ok(era),
+    }
+}
+
+// timelock scripts were added in Allegra, Shelley multisig scripts can't have them
+fn has_timelock(script: &NativeScript) -> bool {
+    match &script.0 {
+        NativeScriptEnum::TimelockStart(_) | NativeScriptEnum::TimelockExpiry(_) => true,
+        NativeScriptEnum::ScriptAll(script) => script.native_scripts.scripts.iter().any(has_timelock),
+        NativeScriptEnum::ScriptAny(script) => script.native_scripts.scripts.iter().any(has_timelock),
+        NativeScriptEnum::ScriptNOfK(script) => script.native_scripts.scripts.iter().any(has_timelock),
+        NativeScriptEnum::ScriptPubkey(_) => false,
+    }
+}
+
+fn era_rules(transaction: &Transaction, has_is_valid: bool) -> Vec<EraRule> {
+    use EraRuleStep::{Field, Index};
+    let mut rules = EraRules(Vec::new());
+
+    if has_is_valid {
+        rules.add_since(vec![], "is_valid", BlockEra::Alonzo);
+    } else {
+        rules.add_until(vec![], "Transaction", BlockEra::Mary);
+    }
+    // sets tagged with 258 are only accepted since Conway
+    if has_set_tag(transaction) {
+        rules.add_since(vec![], "Transaction", BlockEra::Conway);
+    }
+
+    let body = &transaction.body;
+    // the ttl is mandatory in Shelley
+    if body.ttl.is_none() {
+        rules.add_since(vec![Field("body")], "ttl", BlockEra::Allegra);
+    }
+    let optional_fields: [(bool, &'static str, BlockEra, BlockEra); 14] = [
+        (body.update.is_some(), "update", BlockEra::Shelley, BlockEra::Babbage),
+        (body.validity_start_interval.is_some(), "validity_start_interval", BlockEra::Allegra, LATEST_ERA),
+        (body.mint.is_some(), "mint", BlockEra::Mary, LATEST_ERA),
+        (body.script_data_hash.is_some(), "script_data_hash", BlockEra::Alonzo, LATEST_ERA),
+        (body.collateral.is_some(), "collateral", BlockEra::Alonzo, LATEST_ERA),
+        (body.required_signers.is_some(), "required_signers", BlockEra::Alonzo, LATEST_ERA),
+        (body.network_id.is_some(), "network_id", BlockEra::Alonzo, LATEST_ERA),
+        (body.collateral_return.is_some(), "collateral_return", BlockEra::Babbage, LATEST_ERA),
+        (body.total_collateral.is_some(), "total_collateral", BlockEra::Babbage, LATEST_ERA),
+        (body.reference_inputs.is_some(), "reference_inputs", BlockEra::Babbage, LATEST_ERA),
+        (body.voting_procedures.is_some(), "voting_procedures", BlockEra::Conway, LATEST_ERA),
+        (body.voting_proposals.is_some(), "voting_proposals", BlockEra::Conway, LATEST_ERA),
+        (body.current_treasury_value.is_some(), "current_treasury_value", BlockEra::Conway, LATEST_ERA),
+        (body.donation.is_some(), "donation", BlockEra::Conway, LATEST_ERA),
+    ];
+    for (is_set, name, valid_from, valid_until) in optional_fields.iter() {
+        if *is_set {
+            rules.add(vec![Field("body")], name, *valid_from, *valid_until);
+        }
+    }
+
+    for (index, output) in body.outputs.0.iter().enumerate() {
+        rules.add_output(vec![Field("body"), Field("outputs"), Index(index)], output);
+    }
+    if let Some(output) = &body.collateral_return {
+        rules.add_output(vec![Field("body"), Field("collateral_return")], output);
+    }
+
+    if let Some(certs) = &body.certs {
+        for (index, cert) in certs.certs.iter().enumerate() {
+            let outer = vec![Field("body"), Field("certs"), Index(index)];
+            match &cert.as_ref().0 {
+                CertificateEnum::GenesisKeyDelegation(_)
+                | CertificateEnum::MoveInstantaneousRewardsCert(_) => {
+                    rules.add_until(outer, "Certificate", BlockEra::Babbage)
+                }
+                CertificateEnum::StakeRegistration(cert) if cert.coin.is_some() => {
+                    rules.add_since(outer, "Certificate", BlockEra::Conway)
+                }
+                CertificateEnum::StakeDeregistration(cert) if cert.coin.is_some() => {
+                    rules.add_since(outer, "Certificate", BlockEra::Conway)
+                }
+                CertificateEnum::StakeRegistration(_)
+                | CertificateEnum::StakeDeregistration(_)
+                | CertificateEnum::StakeDelegation(_)
+                | CertificateEnum::PoolRegistration(_)
+                | CertificateEnum::PoolRetirement(_) => (),
+                _ => rules.add_since(outer, "Certificate", BlockEra::Conway),
+            }
+        }
+    }
+
+    let witness_set = &transaction.witness_set;
+    if let Some(scripts) = &witness_set.native_scripts {
+        rules.add_native_scripts("witness_set", scripts);
+    }
+    if let Some(scripts) = &witness_set.plutus_scripts {
+        rules.add_plutus_scripts("witness_set", scripts);
+    }
+    if witness_set.plutus_data.is_some() {
+        rules.add_since(vec![Field("witness_set")], "plutus_data", BlockEra::Alonzo);
+    }
+    if let Some(redeemers) = &witness_set.redeemers {
+        rules.add_since(vec![Field("witness_set")], "redeemers", BlockEra::Alonzo);
+        if redeemers.serialization_format == Some(CborContainerType::Map) {
+            rules.add_since(vec![Field("witness_set")], "redeemers", BlockEra::Conway);
+        }
+    }
+
+    if let Some(auxiliary_data) = &transaction.auxiliary_data {
+        // Shelley only has the metadata map, Allegra added [metadata, native_scripts], Alonzo the tagged map
+        if auxiliary_data.prefer_alonzo_format {
+            rules.add_since(vec![], "auxiliary_data", BlockEra::Alonzo);
+        }
+        if let Some(scripts) = &auxiliary_data.native_scripts {
+            rules.add_since(vec![], "auxiliary_data", BlockEra::Allegra);
+            rules.add_native_scripts("auxiliary_data", scripts);
+        }
+        if let Some(scripts) = &auxiliary_data.plutus_scripts {
+            rules.add_plutus_scripts("auxiliary_data", scripts);
+        }
+    }
+
+    rules.0
+}
+
+// has_transaction_set_tag_internal counts absent witness sets as tagged, so only sets read with the tag count here
+fn has_set_tag(transaction: &Transaction) -> bool {
+    let body_tag = has_transaction_body_set_tag(&transaction.body);
+    if matches!(
+        body_tag,
+        Ok(TransactionSetsState::AllSetsHaveTag) | Ok(TransactionSetsState::MixedSets)
+    ) {
+        return true;
+    }
+    let witness_set = &transaction.witness_set;
+    let tagged = Some(CborSetType::Tagged);
+    let languages = [
+        Language::new_plutus_v1(),
+        Language::new_plutus_v2(),
+        Language::new_plutus_v3(),
+    ];
+    witness_set.vkeys.as_ref().map(|vkeys| vkeys.get_set_type()) == tagged
+        || witness_set.bootstraps.as_ref().map(|bs| bs.get_set_type()) == tagged
+        || witness_set.native_scripts.as_ref().and_then(|scripts| scripts.get_set_type()) == tagged
+        || witness_set.plutus_data.as_ref().and_then(|data| data.get_set_type()) == tagged
+        || witness_set.plutus_scripts.as_ref().is_some_and(|scripts| {
+            languages
+                .iter()
+                .any(|language| scripts.get_set_type(language) == tagged)
+        })
+}
@@ -27,9 +27,14 @@ pub enum NativeScriptEnum {
 #[derive(
     Clone, Debug, Eq, Ord, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize, JsonSchema,
 )]
-pub struct NativeScript(pub(crate) NativeScriptEnum);
+#[serde(transparent)]
+pub struct NativeScript(
+    pub(crate) NativeScriptEnum,
+    #[serde(skip)] pub(crate) OriginalEncoding,
+);
 
 impl_to_from!(NativeScript);
+to_canonical_bytes!(NativeScript);
 
 #[wasm_bindgen]
 impl NativeScript {
@@ -41,27 +46,45 @@ impl NativeScript {
     }
 
     pub fn new_script_pubkey(script_pubkey: &ScriptPubkey) -> Self {
-        Self(NativeScriptEnum::ScriptPubkey(script_pubkey.clone()))
+        Self(
+            NativeScriptEnum::ScriptPubkey(script_pubkey.clone()),
+            OriginalEncoding::default(),
+        )
     }
 
     pub fn new_script_all(script_all: &ScriptAll) -> Self {
-        Self(NativeScriptEnum::ScriptAll(script_all.clone()))
+        Self(
+            NativeScriptEnum::ScriptAll(script_all.clone()),
+            OriginalEncoding::default(),
+        )
     }
 
     pub fn new_script_any(script_any: &ScriptAny) -> Self {
-        Self(NativeScriptEnum::ScriptAny(script_any.clone()))
+        Self(
+            NativeScriptEnum::ScriptAny(script_any.clone()),
+            OriginalEncoding::default(),
+        )
     }
 
     pub fn new_script_n_of_k(script_n_of_k: &ScriptNOfK) -> Self {
-        Self(NativeScriptEnum::ScriptNOfK(script_n_of_k.clone()))
+        Self(
+            NativeScriptEnum::ScriptNOfK(script_n_of_k.clone()),
+            OriginalEncoding::default(),
+        )
     }
 
     pub fn new_timelock_start(timelock_start: &TimelockStart) -> Self {
-        Self(NativeScriptEnum::TimelockStart(timelock_start.clone()))
+        Self(
+            NativeScriptEnum::TimelockStart(timelock_start.clone()),
+            OriginalEncoding::default(),
+        )
     }
 
     pub fn new_timelock_expiry(timelock_expiry: &TimelockExpiry) -> Self {
-        Self(NativeScriptEnum::TimelockExpiry(timelock_expiry.clone()))
+        Self(
+            NativeScriptEnum::TimelockExpiry(timelock_expiry.clone()),
+            OriginalEncoding::default(),
+        )
     }
 
     pub fn kind(&self) -> NativeScriptKind {
@@ -123,6 +146,57 @@ impl NativeScript {
     pub fn get_required_signers(&self) -> Ed25519KeyHashes {
         Ed25519KeyHashes::from(self)
     }
+
+    /// Evaluates the script the same way the ledger does for a transaction
+    /// signed by {signers} with the validity interval {validity_interval}.
+    /// TimelockStart requires the interval to start at or after its slot,
+    /// TimelockExpiry requires the interval to end at or before its slot.
+    pub fn evaluate(&self, signers: &Ed25519KeyHashes, validity_interval: &ValidityInterval) -> bool {
+        match &self.0 {
+            NativeScriptEnum::ScriptPubkey(script_pubkey) => signers.contains(&script_pubkey.addr_keyhash),
+            NativeScriptEnum::ScriptAll(script_all) => script_all
+                .native_scripts
+                .iter()
+                .all(|script| script.evaluate(signers, validity_interval)),
+            NativeScriptEnum::ScriptAny(script_any) => script_any
+                .native_scripts
+                .iter()
+                .any(|script| script.evaluate(signers, validity_interval)),
+            NativeScriptEnum::ScriptNOfK(script_n_of_k) => {
+                let satisfied = script_n_of_k
+                    .native_scripts
+                    .iter()
+                    .filter(|script| script.evaluate(signers, validity_interval))
+                    .count();
+                satisfied >= script_n_of_k.n as usize
+            }
+            NativeScriptEnum::TimelockStart(timelock_start) => validity_interval
+                .invalid_before
+                .is_some_and(|slot| timelock_start.slot <= slot),
+            NativeScriptEnum::TimelockExpiry(timelock_expiry) => validity_interval
+                .invalid_hereafter
+                .is_some_and(|slot| slot <= timelock_expiry.slot),
+        }
+    }
+
+    /// Returns the minimal sets of key hashes that satisfy the script, the smallest sets go first.
+    /// Each set comes with the slot window the transaction validity interval has to be within.
+    /// A set is left out when a smaller set with the same or a wider window exists.
+    /// The result is empty if the script can never succeed.
+    /// Fails if a part of the script has more than 100 sets, like a 10 of 20 multisig.
+    pub fn minimal_signer_sets(&self) -> Result<NativeScriptSignerSets, JsError> {
+        NativeScriptSignerSets::from_script(self)
+    }
+}
+
+impl NativeScript {
+    // a small key set satisfying the script, used to estimate the number of witnesses
+    pub(crate) fn smallest_signer_set(&self) -> Ed25519KeyHashes {
+        match greedy_signer_set(self) {
+            Some(signers) => Ed25519KeyHashes::from_vec(signers.into_iter().collect()),
+            None => Ed25519KeyHashes::from(self),
+        }
+    }
 }
 
 #[wasm_bindgen]
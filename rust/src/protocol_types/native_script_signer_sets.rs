@@ -0,0 +1,197 @@
+use crate::*;
+use std::collections::BTreeSet;
+
+/// Key hashes that satisfy a native script together with the slot window its timelocks allow.
+/// The script succeeds when the transaction is signed by all {signers}
+/// and the validity interval of the transaction is within {validity_interval}.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct NativeScriptSignerSet {
+    pub(crate) signers: BTreeSet<Ed25519KeyHash>,
+    pub(crate) validity_interval: ValidityInterval,
+}
+
+#[wasm_bindgen]
+impl NativeScriptSignerSet {
+    pub fn signers(&self) -> Ed25519KeyHashes {
+        Ed25519KeyHashes::from_vec(self.signers.iter().cloned().collect())
+    }
+
+    pub fn validity_interval(&self) -> ValidityInterval {
+        self.validity_interval.clone()
+    }
+}
+
+impl NativeScriptSignerSet {
+    fn new(signers: BTreeSet<Ed25519KeyHash>, validity_interval: ValidityInterval) -> Self {
+        Self {
+            signers,
+            validity_interval,
+        }
+    }
+
+    fn combine(&self, other: &NativeScriptSignerSet) -> Option<NativeScriptSignerSet> {
+        let validity_interval = self.validity_interval.intersect(&other.validity_interval)?;
+        let signers = self.signers.union(&other.signers).cloned().collect();
+        Some(Self::new(signers, validity_interval))
+    }
+
+    // any transaction satisfying {other} satisfies this set as well
+    fn covers(&self, other: &NativeScriptSignerSet) -> bool {
+        self.signers.is_subset(&other.signers)
+            && other.validity_interval.is_within(&self.validity_interval)
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct NativeScriptSignerSets(pub(crate) Vec<NativeScriptSignerSet>);
+
+#[wasm_bindgen]
+impl NativeScriptSignerSets {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> NativeScriptSignerSet {
+        self.0[index].clone()
+    }
+}
+
+impl NativeScriptSignerSets {
+    pub(crate) fn from_script(script: &NativeScript) -> Result<Self, JsError> {
+        Ok(NativeScriptSignerSets(signer_sets(script)?))
+    }
+}
+
+// limit of the sets of every node of the script, a multisig of n keys out of k
+// has C(k, n) minimal sets
+const MAX_SIGNER_SETS: usize = 100;
+
+// drops the sets covered by another one, the rest is ordered from the smallest set.
+// Fails if more than MAX_SIGNER_SETS are left, cutting them would lose sets
+// that other nodes of the script still need
+fn minimize(mut sets: Vec<NativeScriptSignerSet>) -> Result<Vec<NativeScriptSignerSet>, JsError> {
+    sets.sort_by(|left, right| {
+        left.signers
+            .len()
+            .cmp(&right.signers.len())
+            .then_with(|| left.cmp(right))
+    });
+    let mut result: Vec<NativeScriptSignerSet> = Vec::new();
+    for set in sets {
+        if !result.iter().any(|kept| kept.covers(&set)) {
+            if result.len() == MAX_SIGNER_SETS {
+                return Err(JsError::from_str(&format!(
+                    "a part of the script has more than {} minimal signer sets",
+                    MAX_SIGNER_SETS
+                )));
+            }
+            result.push(set);
+        }
+    }
+    Ok(result)
+}
+
+fn combine_all(
+    left: &[NativeScriptSignerSet],
+    right: &[NativeScriptSignerSet],
+) -> Result<Vec<NativeScriptSignerSet>, JsError> {
+    let combined = left
+        .iter()
+        .flat_map(|left| right.iter().filter_map(move |right| left.combine(right)))
+        .collect();
+    minimize(combined)
+}
+
+// sets satisfying {n} of the {children}. by_count[m] holds the sets of m children out of
+// the children from the current one to the last, so every combination is computed once.
+fn n_of(
+    children: &[Vec<NativeScriptSignerSet>],
+    n: usize,
+) -> Result<Vec<NativeScriptSignerSet>, JsError> {
+    if children.len() < n {
+        return Ok(Vec::new());
+    }
+    let mut by_count: Vec<Vec<NativeScriptSignerSet>> = vec![Vec::new(); n + 1];
+    by_count[0] = vec![NativeScriptSignerSet::new(BTreeSet::new(), ValidityInterval::unbounded())];
+    for (index, child) in children.iter().enumerate().rev() {
+        let remaining = children.len() - index;
+        // from the biggest count, so by_count[count - 1] still holds the sets without this child
+        for count in (1..=n.min(remaining)).rev() {
+            let mut sets = combine_all(child, &by_count[count - 1])?;
+            sets.append(&mut by_count[count]);
+            by_count[count] = minimize(sets)?;
+        }
+    }
+    Ok(by_count.swap_remove(n))
+}
+
+fn signer_sets(script: &NativeScript) -> Result<Vec<NativeScriptSignerSet>, JsError> {
+    let children = |scripts: &NativeScripts| -> Result<Vec<Vec<NativeScriptSignerSet>>, JsError> {
+        scripts.iter().map(signer_sets).collect()
+    };
+    match &script.0 {
+        NativeScriptEnum::ScriptPubkey(script_pubkey) => Ok(vec![NativeScriptSignerSet::new(
+            BTreeSet::from([script_pubkey.addr_keyhash.clone()]),
+            ValidityInterval::unbounded(),
+        )]),
+        NativeScriptEnum::ScriptAll(script_all) => {
+            let children = children(&script_all.native_scripts)?;
+            n_of(&children, children.len())
+        }
+        NativeScriptEnum::ScriptAny(script_any) => {
+            minimize(children(&script_any.native_scripts)?.into_iter().flatten().collect())
+        }
+        NativeScriptEnum::ScriptNOfK(script_n_of_k) => {
+            n_of(&children(&script_n_of_k.native_scripts)?, script_n_of_k.n as usize)
+        }
+        NativeScriptEnum::TimelockStart(timelock_start) => Ok(vec![NativeScriptSignerSet::new(
+            BTreeSet::new(),
+            ValidityInterval::new(Some(timelock_start.slot), None),
+        )]),
+        NativeScriptEnum::TimelockExpiry(timelock_expiry) => Ok(vec![NativeScriptSignerSet::new(
+            BTreeSet::new(),
+            ValidityInterval::new(None, Some(timelock_expiry.slot)),
+        )]),
+    }
+}
+
+// A small key set satisfying the script when its timelocks are ignored, picked greedily:
+// the smallest sets of the children are taken for ScriptAny and ScriptNOfK.
+// It's linear in the size of the script, so it's used for the fee estimation.
+// None if the script can't succeed.
+pub(crate) fn greedy_signer_set(script: &NativeScript) -> Option<BTreeSet<Ed25519KeyHash>> {
+    let smallest_children = |scripts: &NativeScripts, n: usize| {
+        let mut children = scripts
+            .iter()
+            .filter_map(greedy_signer_set)
+            .collect::<Vec<BTreeSet<Ed25519KeyHash>>>();
+        if children.len() < n {
+            return None;
+        }
+        children.sort_by_key(|signers| signers.len());
+        Some(children.into_iter().take(n).flatten().collect())
+    };
+    match &script.0 {
+        NativeScriptEnum::ScriptPubkey(script_pubkey) => {
+            Some(BTreeSet::from([script_pubkey.addr_keyhash.clone()]))
+        }
+        NativeScriptEnum::ScriptAll(script_all) => smallest_children(
+            &script_all.native_scripts,
+            script_all.native_scripts.len(),
+        ),
+        NativeScriptEnum::ScriptAny(script_any) => smallest_children(&script_any.native_scripts, 1),
+        NativeScriptEnum::ScriptNOfK(script_n_of_k) => smallest_children(
+            &script_n_of_k.native_scripts,
+            script_n_of_k.n as usize,
+        ),
+        NativeScriptEnum::TimelockStart(_) | NativeScriptEnum::TimelockExpiry(_) => {
+            Some(BTreeSet::new())
+        }
+    }
+}
@@ -200,6 +200,17 @@ impl Hash for PlutusData {
 impl std::cmp::Eq for PlutusData {}
 
 to_from_bytes!(PlutusData);
+to_annotated_diagnostic!(PlutusData, Any);
+
+#[wasm_bindgen]
+impl PlutusData {
+    /// Plutus data is not re-encoded in the canonical format, datum hashes and the script data hash
+    /// are taken over its bytes: decoded data returns its original bytes, other data the bytes of to_bytes().
+    /// The same bytes are kept by to_canonical_bytes() of the values holding Plutus data.
+    pub fn to_canonical_bytes(&self) -> Result<Vec<u8>, JsError> {
+        Ok(self.to_bytes())
+    }
+}
 
 #[wasm_bindgen]
 impl PlutusData {
@@ -5,9 +5,11 @@ use crate::*;
 pub struct PlutusScript {
     pub(crate) bytes: Vec<u8>,
     pub(crate) language: LanguageKind,
+    pub(crate) original_encoding: OriginalEncoding,
 }
 
 to_from_bytes!(PlutusScript);
+to_canonical_bytes!(PlutusScript);
 
 #[wasm_bindgen]
 impl PlutusScript {
@@ -47,6 +49,7 @@ impl PlutusScript {
         Self {
             bytes,
             language: language.0.clone(),
+            original_encoding: OriginalEncoding::default(),
         }
     }
 
@@ -5,9 +5,11 @@ use crate::*;
 pub struct Redeemers {
     pub(crate) redeemers: Vec<Redeemer>,
     pub(crate) serialization_format: Option<CborContainerType>,
+    pub(crate) original_encoding: OriginalEncoding,
 }
 
 impl_to_from!(Redeemers);
+to_canonical_bytes!(Redeemers);
 
 #[wasm_bindgen]
 impl Redeemers {
@@ -15,6 +17,7 @@ impl Redeemers {
         Self {
             redeemers: Vec::new(),
             serialization_format: None,
+            original_encoding: OriginalEncoding::default(),
         }
     }
 
@@ -26,6 +29,7 @@ impl Redeemers {
         Self {
             redeemers,
             serialization_format: Some(serialization_format),
+            original_encoding: OriginalEncoding::default(),
         }
     }
 
@@ -81,6 +85,7 @@ impl From<Vec<Redeemer>> for Redeemers {
         Self {
             redeemers: values,
             serialization_format: None,
+            original_encoding: OriginalEncoding::default(),
         }
     }
 }
@@ -103,6 +108,7 @@ impl<'de> serde::de::Deserialize<'de> for Redeemers {
         Ok(Self {
             redeemers: vec,
             serialization_format: None,
+            original_encoding: OriginalEncoding::default(),
         })
     }
 }
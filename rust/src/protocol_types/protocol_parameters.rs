@@ -0,0 +1,833 @@
+use crate::*;
+use num_integer::Integer;
+use std::convert::TryFrom;
+use serde_json::{Map, Value as JsonValue};
+
+/// Full set of protocol parameters of a Conway (or earlier, starting from Alonzo) era ledger.
+/// Governance parameters are optional because they don't exist before Conway.
+#[wasm_bindgen]
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    JsonSchema,
+)]
+pub struct ProtocolParameters {
+    pub(crate) minfee_a: Coin,
+    pub(crate) minfee_b: Coin,
+    pub(crate) max_block_body_size: u32,
+    pub(crate) max_tx_size: u32,
+    pub(crate) max_block_header_size: u32,
+    pub(crate) key_deposit: Coin,
+    pub(crate) pool_deposit: Coin,
+    pub(crate) max_epoch: Epoch,
+    // desired number of stake pools
+    pub(crate) n_opt: u32,
+    pub(crate) pool_pledge_influence: UnitInterval,
+    pub(crate) expansion_rate: UnitInterval,
+    pub(crate) treasury_growth_rate: UnitInterval,
+    // decentralization constant
+    pub(crate) d: Option<UnitInterval>,
+    pub(crate) extra_entropy: Option<Nonce>,
+    pub(crate) protocol_version: ProtocolVersion,
+    pub(crate) min_pool_cost: Coin,
+    pub(crate) ada_per_utxo_byte: Coin,
+    pub(crate) cost_models: Costmdls,
+    pub(crate) execution_costs: ExUnitPrices,
+    pub(crate) max_tx_ex_units: ExUnits,
+    pub(crate) max_block_ex_units: ExUnits,
+    pub(crate) max_value_size: u32,
+    pub(crate) collateral_percentage: u32,
+    pub(crate) max_collateral_inputs: u32,
+    pub(crate) pool_voting_thresholds: Option<PoolVotingThresholds>,
+    pub(crate) drep_voting_thresholds: Option<DRepVotingThresholds>,
+    pub(crate) min_committee_size: Option<u32>,
+    pub(crate) committee_term_limit: Option<Epoch>,
+    pub(crate) governance_action_validity_period: Option<Epoch>,
+    pub(crate) governance_action_deposit: Option<Coin>,
+    pub(crate) drep_deposit: Option<Coin>,
+    pub(crate) drep_inactivity_period: Option<Epoch>,
+    pub(crate) ref_script_coins_per_byte: Option<UnitInterval>,
+}
+
+to_from_json!(ProtocolParameters);
+
+#[wasm_bindgen]
+impl ProtocolParameters {
+    /// Parses the output of `cardano-cli query protocol-parameters`
+    pub fn from_cardano_cli_json(json: &str) -> Result<ProtocolParameters, JsError> {
+        let value = parse_json(json)?;
+        let obj = JsonObject::new(&value, "")?;
+
+        let ada_per_utxo_byte = match obj.opt("utxoCostPerByte") {
+            Some(_) => obj.coin("utxoCostPerByte")?,
+            None => coins_per_word_to_byte(&obj.coin("utxoCostPerWord")?),
+        };
+        let prices = obj.object("executionUnitPrices")?;
+        let version = obj.object("protocolVersion")?;
+
+        let pool_voting_thresholds = match obj.opt_object("poolVotingThresholds")? {
+            Some(t) => Some(PoolVotingThresholds::new(
+                &t.unit_interval("motionNoConfidence")?,
+                &t.unit_interval("committeeNormal")?,
+                &t.unit_interval("committeeNoConfidence")?,
+                &t.unit_interval("hardForkInitiation")?,
+                &t.unit_interval("ppSecurityGroup")?,
+            )),
+            None => None,
+        };
+        let drep_voting_thresholds = match obj.opt_object("dRepVotingThresholds")? {
+            Some(t) => Some(DRepVotingThresholds::new(
+                &t.unit_interval("motionNoConfidence")?,
+                &t.unit_interval("committeeNormal")?,
+                &t.unit_interval("committeeNoConfidence")?,
+                &t.unit_interval("updateToConstitution")?,
+                &t.unit_interval("hardForkInitiation")?,
+                &t.unit_interval("ppNetworkGroup")?,
+                &t.unit_interval("ppEconomicGroup")?,
+                &t.unit_interval("ppTechnicalGroup")?,
+                &t.unit_interval("ppGovGroup")?,
+                &t.unit_interval("treasuryWithdrawal")?,
+            )),
+            None => None,
+        };
+
+        Ok(Self {
+            minfee_a: obj.coin("txFeePerByte")?,
+            minfee_b: obj.coin("txFeeFixed")?,
+            max_block_body_size: obj.u32("maxBlockBodySize")?,
+            max_tx_size: obj.u32("maxTxSize")?,
+            max_block_header_size: obj.u32("maxBlockHeaderSize")?,
+            key_deposit: obj.coin("stakeAddressDeposit")?,
+            pool_deposit: obj.coin("stakePoolDeposit")?,
+            max_epoch: obj.u32("poolRetireMaxEpoch")?,
+            n_opt: obj.u32("stakePoolTargetNum")?,
+            pool_pledge_influence: obj.unit_interval("poolPledgeInfluence")?,
+            expansion_rate: obj.unit_interval("monetaryExpansion")?,
+            treasury_growth_rate: obj.unit_interval("treasuryCut")?,
+            d: obj.opt_unit_interval("decentralization")?,
+            extra_entropy: obj.opt_nonce("extraPraosEntropy")?,
+            protocol_version: ProtocolVersion::new(version.u32("major")?, version.u32("minor")?),
+            min_pool_cost: obj.coin("minPoolCost")?,
+            ada_per_utxo_byte,
+            cost_models: obj.cost_models(
+                "costModels",
+                &[
+                    ("PlutusV1", Language::new_plutus_v1()),
+                    ("PlutusScriptV1", Language::new_plutus_v1()),
+                    ("PlutusV2", Language::new_plutus_v2()),
+                    ("PlutusScriptV2", Language::new_plutus_v2()),
+                    ("PlutusV3", Language::new_plutus_v3()),
+                    ("PlutusScriptV3", Language::new_plutus_v3()),
+                ],
+            )?,
+            execution_costs: ExUnitPrices::new(
+                &prices.unit_interval("priceMemory")?,
+                &prices.unit_interval("priceSteps")?,
+            ),
+            max_tx_ex_units: obj.object("maxTxExecutionUnits")?.ex_units("memory", "steps")?,
+            max_block_ex_units: obj
+                .object("maxBlockExecutionUnits")?
+                .ex_units("memory", "steps")?,
+            max_value_size: obj.u32("maxValueSize")?,
+            collateral_percentage: obj.u32("collateralPercentage")?,
+            max_collateral_inputs: obj.u32("maxCollateralInputs")?,
+            pool_voting_thresholds,
+            drep_voting_thresholds,
+            min_committee_size: obj.opt_u32("committeeMinSize")?,
+            committee_term_limit: obj.opt_u32("committeeMaxTermLength")?,
+            governance_action_validity_period: obj.opt_u32("govActionLifetime")?,
+            governance_action_deposit: obj.opt_coin("govActionDeposit")?,
+            drep_deposit: obj.opt_coin("dRepDeposit")?,
+            drep_inactivity_period: obj.opt_u32("dRepActivity")?,
+            ref_script_coins_per_byte: obj.opt_unit_interval("minFeeRefScriptCostPerByte")?,
+        })
+    }
+
+    /// Parses the result of the Ogmios (v6) `queryLedgerState/protocolParameters` query
+    pub fn from_ogmios_json(json: &str) -> Result<ProtocolParameters, JsError> {
+        let value = parse_json(json)?;
+        let obj = JsonObject::new(&value, "")?;
+
+        let prices = obj.object("scriptExecutionPrices")?;
+        let version = obj.object("version")?;
+
+        let pool_voting_thresholds = match obj.opt_object("stakePoolVotingThresholds")? {
+            Some(t) => {
+                let committee = t.object("constitutionalCommittee")?;
+                let update = t.object("protocolParametersUpdate")?;
+                Some(PoolVotingThresholds::new(
+                    &t.unit_interval("noConfidence")?,
+                    &committee.unit_interval("default")?,
+                    &committee.unit_interval("stateOfNoConfidence")?,
+                    &t.unit_interval("hardForkInitiation")?,
+                    &update.unit_interval("security")?,
+                ))
+            }
+            None => None,
+        };
+        let drep_voting_thresholds =
+            match obj.opt_object("delegateRepresentativeVotingThresholds")? {
+                Some(t) => {
+                    let committee = t.object("constitutionalCommittee")?;
+                    let update = t.object("protocolParametersUpdate")?;
+                    Some(DRepVotingThresholds::new(
+                        &t.unit_interval("noConfidence")?,
+                        &committee.unit_interval("default")?,
+                        &committee.unit_interval("stateOfNoConfidence")?,
+                        &t.unit_interval("constitution")?,
+                        &t.unit_interval("hardForkInitiation")?,
+                        &update.unit_interval("network")?,
+                        &update.unit_interval("economic")?,
+                        &update.unit_interval("technical")?,
+                        &update.unit_interval("governance")?,
+                        &t.unit_interval("treasuryWithdrawals")?,
+                    ))
+                }
+                None => None,
+            };
+        let ref_script_coins_per_byte = match obj.opt_object("minFeeReferenceScripts")? {
+            Some(ref_scripts) => Some(ref_scripts.unit_interval("base")?),
+            None => None,
+        };
+
+        Ok(Self {
+            minfee_a: obj.coin("minFeeCoefficient")?,
+            minfee_b: obj.ogmios_lovelace("minFeeConstant")?,
+            max_block_body_size: obj.ogmios_bytes("maxBlockBodySize")?,
+            max_tx_size: obj.ogmios_bytes("maxTransactionSize")?,
+            max_block_header_size: obj.ogmios_bytes("maxBlockHeaderSize")?,
+            key_deposit: obj.ogmios_lovelace("stakeCredentialDeposit")?,
+            pool_deposit: obj.ogmios_lovelace("stakePoolDeposit")?,
+            max_epoch: obj.u32("stakePoolRetirementEpochBound")?,
+            n_opt: obj.u32("desiredNumberOfStakePools")?,
+            pool_pledge_influence: obj.unit_interval("stakePoolPledgeInfluence")?,
+            expansion_rate: obj.unit_interval("monetaryExpansion")?,
+            treasury_growth_rate: obj.unit_interval("treasuryExpansion")?,
+            d: obj.opt_unit_interval("federatedBlockProductionRatio")?,
+            extra_entropy: obj.opt_nonce("extraEntropy")?,
+            protocol_version: ProtocolVersion::new(version.u32("major")?, version.u32("minor")?),
+            min_pool_cost: obj.ogmios_lovelace("minStakePoolCost")?,
+            ada_per_utxo_byte: obj.coin("minUtxoDepositCoefficient")?,
+            cost_models: obj.cost_models(
+                "plutusCostModels",
+                &[
+                    ("plutus:v1", Language::new_plutus_v1()),
+                    ("plutus:v2", Language::new_plutus_v2()),
+                    ("plutus:v3", Language::new_plutus_v3()),
+                ],
+            )?,
+            execution_costs: ExUnitPrices::new(
+                &prices.unit_interval("memory")?,
+                &prices.unit_interval("cpu")?,
+            ),
+            max_tx_ex_units: obj
+                .object("maxExecutionUnitsPerTransaction")?
+                .ex_units("memory", "cpu")?,
+            max_block_ex_units: obj
+                .object("maxExecutionUnitsPerBlock")?
+                .ex_units("memory", "cpu")?,
+            max_value_size: obj.ogmios_bytes("maxValueSize")?,
+            collateral_percentage: obj.u32("collateralPercentage")?,
+            max_collateral_inputs: obj.u32("maxCollateralInputs")?,
+            pool_voting_thresholds,
+            drep_voting_thresholds,
+            min_committee_size: obj.opt_u32("constitutionalCommitteeMinSize")?,
+            committee_term_limit: obj.opt_u32("constitutionalCommitteeMaxTermLength")?,
+            governance_action_validity_period: obj.opt_u32("governanceActionLifetime")?,
+            governance_action_deposit: match obj.opt("governanceActionDeposit") {
+                Some(_) => Some(obj.ogmios_lovelace("governanceActionDeposit")?),
+                None => None,
+            },
+            drep_deposit: match obj.opt("delegateRepresentativeDeposit") {
+                Some(_) => Some(obj.ogmios_lovelace("delegateRepresentativeDeposit")?),
+                None => None,
+            },
+            drep_inactivity_period: obj.opt_u32("delegateRepresentativeMaxIdleTime")?,
+            ref_script_coins_per_byte,
+        })
+    }
+
+    /// Parses the response of the Blockfrost `/epochs/latest/parameters` endpoint
+    pub fn from_blockfrost_json(json: &str) -> Result<ProtocolParameters, JsError> {
+        let value = parse_json(json)?;
+        let obj = JsonObject::new(&value, "")?;
+
+        let ada_per_utxo_byte = match obj.opt("coins_per_utxo_size") {
+            Some(_) => obj.coin("coins_per_utxo_size")?,
+            None => coins_per_word_to_byte(&obj.coin("coins_per_utxo_word")?),
+        };
+        let cost_models_key = match obj.opt("cost_models_raw") {
+            Some(_) => "cost_models_raw",
+            None => "cost_models",
+        };
+
+        let pool_voting_thresholds = match obj.opt("pvt_motion_no_confidence") {
+            Some(_) => Some(PoolVotingThresholds::new(
+                &obj.unit_interval("pvt_motion_no_confidence")?,
+                &obj.unit_interval("pvt_committee_normal")?,
+                &obj.unit_interval("pvt_committee_no_confidence")?,
+                &obj.unit_interval("pvt_hard_fork_initiation")?,
+                &match obj.opt("pvt_p_p_security_group") {
+                    Some(_) => obj.unit_interval("pvt_p_p_security_group")?,
+                    None => obj.unit_interval("pvtpp_security_group")?,
+                },
+            )),
+            None => None,
+        };
+        let drep_voting_thresholds = match obj.opt("dvt_motion_no_confidence") {
+            Some(_) => Some(DRepVotingThresholds::new(
+                &obj.unit_interval("dvt_motion_no_confidence")?,
+                &obj.unit_interval("dvt_committee_normal")?,
+                &obj.unit_interval("dvt_committee_no_confidence")?,
+                &obj.unit_interval("dvt_update_to_constitution")?,
+                &obj.unit_interval("dvt_hard_fork_initiation")?,
+                &obj.unit_interval("dvt_p_p_network_group")?,
+                &obj.unit_interval("dvt_p_p_economic_group")?,
+                &obj.unit_interval("dvt_p_p_technical_group")?,
+                &obj.unit_interval("dvt_p_p_gov_group")?,
+                &obj.unit_interval("dvt_treasury_withdrawal")?,
+            )),
+            None => None,
+        };
+
+        Ok(Self {
+            minfee_a: obj.coin("min_fee_a")?,
+            minfee_b: obj.coin("min_fee_b")?,
+            max_block_body_size: obj.u32("max_block_size")?,
+            max_tx_size: obj.u32("max_tx_size")?,
+            max_block_header_size: obj.u32("max_block_header_size")?,
+            key_deposit: obj.coin("key_deposit")?,
+            pool_deposit: obj.coin("pool_deposit")?,
+            max_epoch: obj.u32("e_max")?,
+            n_opt: obj.u32("n_opt")?,
+            pool_pledge_influence: obj.unit_interval("a0")?,
+            expansion_rate: obj.unit_interval("rho")?,
+            treasury_growth_rate: obj.unit_interval("tau")?,
+            d: obj.opt_unit_interval("decentralisation_param")?,
+            extra_entropy: obj.opt_nonce("extra_entropy")?,
+            protocol_version: ProtocolVersion::new(
+                obj.u32("protocol_major_ver")?,
+                obj.u32("protocol_minor_ver")?,
+            ),
+            min_pool_cost: obj.coin("min_pool_cost")?,
+            ada_per_utxo_byte,
+            cost_models: obj.cost_models(
+                cost_models_key,
+                &[
+                    ("PlutusV1", Language::new_plutus_v1()),
+                    ("PlutusV2", Language::new_plutus_v2()),
+                    ("PlutusV3", Language::new_plutus_v3()),
+                ],
+            )?,
+            execution_costs: ExUnitPrices::new(
+                &obj.unit_interval("price_mem")?,
+                &obj.unit_interval("price_step")?,
+            ),
+            max_tx_ex_units: obj.ex_units("max_tx_ex_mem", "max_tx_ex_steps")?,
+            max_block_ex_units: obj.ex_units("max_block_ex_mem", "max_block_ex_steps")?,
+            max_value_size: obj.u32("max_val_size")?,
+            collateral_percentage: obj.u32("collateral_percent")?,
+            max_collateral_inputs: obj.u32("max_collateral_inputs")?,
+            pool_voting_thresholds,
+            drep_voting_thresholds,
+            min_committee_size: obj.opt_u32("committee_min_size")?,
+            committee_term_limit: obj.opt_u32("committee_max_term_length")?,
+            governance_action_validity_period: obj.opt_u32("gov_action_lifetime")?,
+            governance_action_deposit: obj.opt_coin("gov_action_deposit")?,
+            drep_deposit: obj.opt_coin("drep_deposit")?,
+            drep_inactivity_period: obj.opt_u32("drep_activity")?,
+            ref_script_coins_per_byte: obj.opt_unit_interval("min_fee_ref_script_cost_per_byte")?,
+        })
+    }
+
+    /// Returns the parameters with all values present in the update applied.
+    /// Cost models are updated per language, the languages missing in the update keep their cost models.
+    pub fn apply_update(&self, update: &ProtocolParamUpdate) -> ProtocolParameters {
+        fn set<T: Clone>(target: &mut T, value: &Option<T>) {
+            if let Some(value) = value {
+                *target = value.clone();
+            }
+        }
+        fn set_opt<T: Clone>(target: &mut Option<T>, value: &Option<T>) {
+            if value.is_some() {
+                *target = value.clone();
+            }
+        }
+
+        let mut params = self.clone();
+        set(&mut params.minfee_a, &update.minfee_a);
+        set(&mut params.minfee_b, &update.minfee_b);
+        set(&mut params.max_block_body_size, &update.max_block_body_size);
+        set(&mut params.max_tx_size, &update.max_tx_size);
+        set(&mut params.max_block_header_size, &update.max_block_header_size);
+        set(&mut params.key_deposit, &update.key_deposit);
+        set(&mut params.pool_deposit, &update.pool_deposit);
+        set(&mut params.max_epoch, &update.max_epoch);
+        set(&mut params.n_opt, &update.n_opt);
+        set(&mut params.pool_pledge_influence, &update.pool_pledge_influence);
+        set(&mut params.expansion_rate, &update.expansion_rate);
+        set(&mut params.treasury_growth_rate, &update.treasury_growth_rate);
+        set_opt(&mut params.d, &update.d);
+        set_opt(&mut params.extra_entropy, &update.extra_entropy);
+        set(&mut params.protocol_version, &update.protocol_version);
+        set(&mut params.min_pool_cost, &update.min_pool_cost);
+        set(&mut params.ada_per_utxo_byte, &update.ada_per_utxo_byte);
+        if let Some(cost_models) = &update.cost_models {
+            for (language, cost_model) in cost_models.0.iter() {
+                params.cost_models.insert(language, cost_model);
+            }
+        }
+        set(&mut params.execution_costs, &update.execution_costs);
+        set(&mut params.max_tx_ex_units, &update.max_tx_ex_units);
+        set(&mut params.max_block_ex_units, &update.max_block_ex_units);
+        set(&mut params.max_value_size, &update.max_value_size);
+        set(&mut params.collateral_percentage, &update.collateral_percentage);
+        set(&mut params.max_collateral_inputs, &update.max_collateral_inputs);
+        set_opt(&mut params.pool_voting_thresholds, &update.pool_voting_thresholds);
+        set_opt(&mut params.drep_voting_thresholds, &update.drep_voting_thresholds);
+        set_opt(&mut params.min_committee_size, &update.min_committee_size);
+        set_opt(&mut params.committee_term_limit, &update.committee_term_limit);
+        set_opt(
+            &mut params.governance_action_validity_period,
+            &update.governance_action_validity_period,
+        );
+        set_opt(&mut params.governance_action_deposit, &update.governance_action_deposit);
+        set_opt(&mut params.drep_deposit, &update.drep_deposit);
+        set_opt(&mut params.drep_inactivity_period, &update.drep_inactivity_period);
+        set_opt(&mut params.ref_script_coins_per_byte, &update.ref_script_coins_per_byte);
+        params
+    }
+
+    /// Returns a config builder filled with the protocol parameters,
+    /// so the non-protocol options (like `prefer_pure_change`) can still be set before building
+    pub fn to_tx_builder_config_builder(&self) -> TransactionBuilderConfigBuilder {
+        let mut builder = TransactionBuilderConfigBuilder::new()
+            .fee_algo(&LinearFee::new(&self.minfee_a, &self.minfee_b))
+            .pool_deposit(&self.pool_deposit)
+            .key_deposit(&self.key_deposit)
+            .max_value_size(self.max_value_size)
+            .max_tx_size(self.max_tx_size)
+            .coins_per_utxo_byte(&self.ada_per_utxo_byte)
+            .ex_unit_prices(&self.execution_costs)
+            .collateral_percentage(self.collateral_percentage)
+            .max_collateral_inputs(self.max_collateral_inputs);
+        if let Some(ref_script_coins_per_byte) = &self.ref_script_coins_per_byte {
+            builder = builder.ref_script_coins_per_byte(ref_script_coins_per_byte);
+        }
+        builder
+    }
+
+    pub fn to_tx_builder_config(&self) -> Result<TransactionBuilderConfig, JsError> {
+        self.to_tx_builder_config_builder().build()
+    }
+
+    pub fn minfee_a(&self) -> Coin {
+        self.minfee_a
+    }
+
+    pub fn minfee_b(&self) -> Coin {
+        self.minfee_b
+    }
+
+    pub fn max_block_body_size(&self) -> u32 {
+        self.max_block_body_size
+    }
+
+    pub fn max_tx_size(&self) -> u32 {
+        self.max_tx_size
+    }
+
+    pub fn max_block_header_size(&self) -> u32 {
+        self.max_block_header_size
+    }
+
+    pub fn key_deposit(&self) -> Coin {
+        self.key_deposit
+    }
+
+    pub fn pool_deposit(&self) -> Coin {
+        self.pool_deposit
+    }
+
+    pub fn max_epoch(&self) -> Epoch {
+        self.max_epoch
+    }
+
+    pub fn n_opt(&self) -> u32 {
+        self.n_opt
+    }
+
+    pub fn pool_pledge_influence(&self) -> UnitInterval {
+        self.pool_pledge_influence.clone()
+    }
+
+    pub fn expansion_rate(&self) -> UnitInterval {
+        self.expansion_rate.clone()
+    }
+
+    pub fn treasury_growth_rate(&self) -> UnitInterval {
+        self.treasury_growth_rate.clone()
+    }
+
+    pub fn d(&self) -> Option<UnitInterval> {
+        self.d.clone()
+    }
+
+    pub fn extra_entropy(&self) -> Option<Nonce> {
+        self.extra_entropy.clone()
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version.clone()
+    }
+
+    pub fn min_pool_cost(&self) -> Coin {
+        self.min_pool_cost
+    }
+
+    pub fn ada_per_utxo_byte(&self) -> Coin {
+        self.ada_per_utxo_byte
+    }
+
+    pub fn cost_models(&self) -> Costmdls {
+        self.cost_models.clone()
+    }
+
+    pub fn execution_costs(&self) -> ExUnitPrices {
+        self.execution_costs.clone()
+    }
+
+    pub fn max_tx_ex_units(&self) -> ExUnits {
+        self.max_tx_ex_units.clone()
+    }
+
+    pub fn max_block_ex_units(&self) -> ExUnits {
+        self.max_block_ex_units.clone()
+    }
+
+    pub fn max_value_size(&self) -> u32 {
+        self.max_value_size
+    }
+
+    pub fn collateral_percentage(&self) -> u32 {
+        self.collateral_percentage
+    }
+
+    pub fn max_collateral_inputs(&self) -> u32 {
+        self.max_collateral_inputs
+    }
+
+    pub fn pool_voting_thresholds(&self) -> Option<PoolVotingThresholds> {
+        self.pool_voting_thresholds.clone()
+    }
+
+    pub fn drep_voting_thresholds(&self) -> Option<DRepVotingThresholds> {
+        self.drep_voting_thresholds.clone()
+    }
+
+    pub fn min_committee_size(&self) -> Option<u32> {
+        self.min_committee_size
+    }
+
+    pub fn committee_term_limit(&self) -> Option<Epoch> {
+        self.committee_term_limit
+    }
+
+    pub fn governance_action_validity_period(&self) -> Option<Epoch> {
+        self.governance_action_validity_period
+    }
+
+    pub fn governance_action_deposit(&self) -> Option<Coin> {
+        self.governance_action_deposit
+    }
+
+    pub fn drep_deposit(&self) -> Option<Coin> {
+        self.drep_deposit
+    }
+
+    pub fn drep_inactivity_period(&self) -> Option<Epoch> {
+        self.drep_inactivity_period
+    }
+
+    pub fn ref_script_coins_per_byte(&self) -> Option<UnitInterval> {
+        self.ref_script_coins_per_byte.clone()
+    }
+}
+
+fn parse_json(json: &str) -> Result<JsonValue, JsError> {
+    serde_json::from_str(json).map_err(|e| JsError::from_str(&format!("from_json: {}", e)))
+}
+
+// a utxo entry was accounted as 8 bytes per word before Babbage
+fn coins_per_word_to_byte(coins_per_word: &Coin) -> Coin {
+    coins_per_word.div_floor(&BigNum(8))
+}
+
+// JSON object with the path to it for error messages
+struct JsonObject<'a> {
+    map: &'a Map<String, JsonValue>,
+    path: String,
+}
+
+impl<'a> JsonObject<'a> {
+    fn new(value: &'a JsonValue, path: &str) -> Result<Self, JsError> {
+        match value {
+            JsonValue::Object(map) => Ok(Self {
+                map,
+                path: path.to_string(),
+            }),
+            _ => Err(JsError::from_str(&format!(
+                "Expected a JSON object at \"{}\"",
+                path
+            ))),
+        }
+    }
+
+    fn path_of(&self, key: &str) -> String {
+        if self.path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", self.path, key)
+        }
+    }
+
+    // null is treated as a missing value
+    fn opt(&self, key: &str) -> Option<&'a JsonValue> {
+        self.map.get(key).filter(|value| !value.is_null())
+    }
+
+    fn field(&self, key: &str) -> Result<&'a JsonValue, JsError> {
+        self.opt(key).ok_or_else(|| {
+            JsError::from_str(&format!("Missing field \"{}\"", self.path_of(key)))
+        })
+    }
+
+    fn object(&self, key: &str) -> Result<JsonObject<'a>, JsError> {
+        JsonObject::new(self.field(key)?, &self.path_of(key))
+    }
+
+    fn opt_object(&self, key: &str) -> Result<Option<JsonObject<'a>>, JsError> {
+        match self.opt(key) {
+            Some(value) => Ok(Some(JsonObject::new(value, &self.path_of(key))?)),
+            None => Ok(None),
+        }
+    }
+
+    fn u64(&self, key: &str) -> Result<u64, JsError> {
+        json_to_u64(self.field(key)?, &self.path_of(key))
+    }
+
+    fn u32(&self, key: &str) -> Result<u32, JsError> {
+        let value = self.u64(key)?;
+        u32::try_from(value).map_err(|_| {
+            JsError::from_str(&format!(
+                "Value {} of \"{}\" doesn't fit into u32",
+                value,
+                self.path_of(key)
+            ))
+        })
+    }
+
+    fn opt_u32(&self, key: &str) -> Result<Option<u32>, JsError> {
+        match self.opt(key) {
+            Some(_) => Ok(Some(self.u32(key)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn coin(&self, key: &str) -> Result<Coin, JsError> {
+        Ok(BigNum(self.u64(key)?))
+    }
+
+    fn opt_coin(&self, key: &str) -> Result<Option<Coin>, JsError> {
+        match self.opt(key) {
+            Some(_) => Ok(Some(self.coin(key)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn unit_interval(&self, key: &str) -> Result<UnitInterval, JsError> {
+        json_to_unit_interval(self.field(key)?, &self.path_of(key))
+    }
+
+    fn opt_unit_interval(&self, key: &str) -> Result<Option<UnitInterval>, JsError> {
+        match self.opt(key) {
+            Some(_) => Ok(Some(self.unit_interval(key)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn ex_units(&self, mem_key: &str, steps_key: &str) -> Result<ExUnits, JsError> {
+        Ok(ExUnits::new(&self.coin(mem_key)?, &self.coin(steps_key)?))
+    }
+
+    // {"ada": {"lovelace": 123}}
+    fn ogmios_lovelace(&self, key: &str) -> Result<Coin, JsError> {
+        self.object(key)?.object("ada")?.coin("lovelace")
+    }
+
+    // {"bytes": 123}
+    fn ogmios_bytes(&self, key: &str) -> Result<u32, JsError> {
+        self.object(key)?.u32("bytes")
+    }
+
+    // the neutral nonce is "neutral" for Ogmios and null for other sources
+    fn opt_nonce(&self, key: &str) -> Result<Option<Nonce>, JsError> {
+        let value = match self.opt(key) {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+        let nonce = match value.as_str() {
+            Some("neutral") => Nonce::new_identity(),
+            Some(hash) if hash.len() == Nonce::HASH_LEN * 2 => Nonce::new_from_hash(
+                hex::decode(hash).map_err(|e| JsError::from_str(&e.to_string()))?,
+            )?,
+            _ => {
+                return Err(JsError::from_str(&format!(
+                    "Unsupported nonce value of \"{}\"",
+                    self.path_of(key)
+                )))
+            }
+        };
+        Ok(Some(nonce))
+    }
+
+    // Cost models are either arrays of costs or objects with named costs.
+    // Named costs are put in the order of their names, which is the order of the costs
+    // in the Plutus V1 and V2 cost models only, so Plutus V3 costs have to be an array.
+    fn cost_models(&self, key: &str, languages: &[(&str, Language)]) -> Result<Costmdls, JsError> {
+        let mut cost_models = Costmdls::new();
+        let models = match self.opt_object(key)? {
+            Some(models) => models,
+            None => return Ok(cost_models),
+        };
+        for (name, language) in languages {
+            let path = models.path_of(name);
+            let costs: Vec<&JsonValue> = match models.opt(name) {
+                Some(JsonValue::Array(costs)) => costs.iter().collect(),
+                Some(JsonValue::Object(_)) if language.kind() == LanguageKind::PlutusV3 => {
+                    return Err(JsError::from_str(&format!(
+                        "Named costs are not supported for PlutusV3, expected an array at \"{}\"",
+                        path
+                    )))
+                }
+                Some(JsonValue::Object(costs)) => costs.values().collect(),
+                Some(_) => {
+                    return Err(JsError::from_str(&format!(
+                        "Expected an array or an object at \"{}\"",
+                        path
+                    )))
+                }
+                None => continue,
+            };
+            let costs = costs
+                .into_iter()
+                .map(|cost| json_to_i128(cost, &path))
+                .collect::<Result<Vec<i128>, JsError>>()?;
+            cost_models.insert(language, &CostModel::from(costs));
+        }
+        Ok(cost_models)
+    }
+}
+
+// Numbers can come as JSON numbers or strings, in the integer, decimal or "numerator/denominator" form
+fn json_to_rational(value: &JsonValue, path: &str) -> Result<(u64, u64), JsError> {
+    let text = match value {
+        JsonValue::Number(number) => number.to_string(),
+        JsonValue::String(text) => text.clone(),
+        _ => {
+            return Err(JsError::from_str(&format!(
+                "Expected a number at \"{}\"",
+                path
+            )))
+        }
+    };
+    parse_rational(&text).ok_or_else(|| {
+        JsError::from_str(&format!(
+            "Unsupported number {} at \"{}\"",
+            text, path
+        ))
+    })
+}
+
+fn json_to_u64(value: &JsonValue, path: &str) -> Result<u64, JsError> {
+    match json_to_rational(value, path)? {
+        (numerator, 1) => Ok(numerator),
+        _ => Err(JsError::from_str(&format!(
+            "Expected an integer at \"{}\"",
+            path
+        ))),
+    }
+}
+
+fn json_to_i128(value: &JsonValue, path: &str) -> Result<i128, JsError> {
+    let text = match value {
+        JsonValue::Number(number) => number.to_string(),
+        JsonValue::String(text) => text.clone(),
+        _ => String::new(),
+    };
+    text.parse::<i128>().map_err(|_| {
+        JsError::from_str(&format!("Expected an integer at \"{}\"", path))
+    })
+}
+
+fn json_to_unit_interval(value: &JsonValue, path: &str) -> Result<UnitInterval, JsError> {
+    let (numerator, denominator) = json_to_rational(value, path)?;
+    Ok(UnitInterval::new(&BigNum(numerator), &BigNum(denominator)))
+}
+
+// Parses "3/10", "15", "0.0577" or "7.21e-5" into a reduced fraction
+fn parse_rational(text: &str) -> Option<(u64, u64)> {
+    let text = text.trim();
+    let (numerator, denominator) = match text.split_once('/') {
+        Some((numerator, denominator)) => (
+            numerator.trim().parse::<u128>().ok()?,
+            denominator.trim().parse::<u128>().ok()?,
+        ),
+        None => parse_decimal(text)?,
+    };
+    if denominator == 0 {
+        return None;
+    }
+    let gcd = numerator.gcd(&denominator);
+    let (numerator, denominator) = if gcd > 1 {
+        (numerator / gcd, denominator / gcd)
+    } else {
+        (numerator, denominator)
+    };
+    Some((
+        u64::try_from(numerator).ok()?,
+        u64::try_from(denominator).ok()?,
+    ))
+}
+
+fn parse_decimal(text: &str) -> Option<(u128, u128)> {
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(position) => (&text[..position], text[position + 1..].parse::<i32>().ok()?),
+        None => (text, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    let digits = format!("{}{}", integer, fraction);
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let mut numerator = digits.parse::<u128>().ok()?;
+    let mut denominator: u128 = 1;
+    let exponent = exponent.checked_sub(i32::try_from(fraction.len()).ok()?)?;
+    let scale = 10u128.checked_pow(exponent.unsigned_abs())?;
+    if exponent >= 0 {
+        numerator = numerator.checked_mul(scale)?;
+    } else {
+        denominator = scale;
+    }
+    Some((numerator, denominator))
+}
@@ -0,0 +1,171 @@
+use crate::*;
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) struct SlotEra {
+    pub(crate) start_slot: u64,
+    // POSIX time in milliseconds
+    pub(crate) start_time: u64,
+    // in milliseconds
+    pub(crate) slot_length: u64,
+}
+
+impl SlotEra {
+    fn time_of(&self, slot: u64) -> Option<u64> {
+        (slot - self.start_slot)
+            .checked_mul(self.slot_length)?
+            .checked_add(self.start_time)
+    }
+}
+
+/// Era history of a network: the slot and POSIX time (in milliseconds) at which each era
+/// with a different slot length has started. The last era is open-ended.
+/// The conversions match the ledger, which translates the validity interval of a transaction
+/// into the POSIX time range of the Plutus script context using the start time of each slot.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SlotConfig {
+    eras: Vec<SlotEra>,
+}
+
+#[wasm_bindgen]
+impl SlotConfig {
+    /// Creates a single era config. {zero_time} is the POSIX time in milliseconds
+    /// at which the slot {zero_slot} started and {slot_length} is in milliseconds
+    pub fn new(
+        zero_time: &BigNum,
+        zero_slot: &SlotBigNum,
+        slot_length: u32,
+    ) -> Result<SlotConfig, JsError> {
+        if slot_length == 0 {
+            return Err(JsError::from_str("Slot length must be greater than zero"));
+        }
+        Ok(Self {
+            eras: vec![SlotEra {
+                start_slot: zero_slot.into(),
+                start_time: zero_time.into(),
+                slot_length: slot_length.into(),
+            }],
+        })
+    }
+
+    /// Adds an era that starts at {start_slot} with a new slot length in milliseconds.
+    /// The start time of the era is calculated from the previous era.
+    pub fn add_era(&mut self, start_slot: &SlotBigNum, slot_length: u32) -> Result<(), JsError> {
+        if slot_length == 0 {
+            return Err(JsError::from_str("Slot length must be greater than zero"));
+        }
+        let start_slot: u64 = start_slot.into();
+        let last = self.last_era();
+        if start_slot <= last.start_slot {
+            return Err(JsError::from_str(&format!(
+                "Era must start after slot {}",
+                last.start_slot
+            )));
+        }
+        let start_time = last
+            .time_of(start_slot)
+            .ok_or_else(|| JsError::from_str("Era start time overflow"))?;
+        self.eras.push(SlotEra {
+            start_slot,
+            start_time,
+            slot_length: slot_length.into(),
+        });
+        Ok(())
+    }
+
+    /// Byron (20 seconds slots) from 2017-09-23T21:44:51Z, Shelley and later (1 second slots) from slot 4492800
+    pub fn mainnet() -> SlotConfig {
+        Self::with_byron_era(1506203091000, 4492800)
+    }
+
+    /// Byron (20 seconds slots) from 2022-06-01T00:00:00Z, Shelley and later (1 second slots) from slot 86400
+    pub fn testnet_preprod() -> SlotConfig {
+        Self::with_byron_era(1654041600000, 86400)
+    }
+
+    /// 1 second slots from 2022-10-25T00:00:00Z, the network has no Byron era
+    pub fn testnet_preview() -> SlotConfig {
+        Self {
+            eras: vec![SlotEra {
+                start_slot: 0,
+                start_time: 1666656000000,
+                slot_length: 1000,
+            }],
+        }
+    }
+
+    /// Returns the POSIX time in milliseconds at which the slot starts
+    pub fn slot_to_posix_time(&self, slot: &SlotBigNum) -> Result<BigNum, JsError> {
+        let slot: u64 = slot.into();
+        let era = self.era_of_slot(slot)?;
+        era.time_of(slot)
+            .map(BigNum)
+            .ok_or_else(|| JsError::from_str("POSIX time overflow"))
+    }
+
+    /// Returns the slot which contains the POSIX time in milliseconds
+    pub fn posix_time_to_slot(&self, posix_time: &BigNum) -> Result<SlotBigNum, JsError> {
+        let (slot, _) = self.slot_of_time(posix_time.into())?;
+        Ok(BigNum(slot))
+    }
+
+    /// Returns the first slot which starts not earlier than the POSIX time in milliseconds
+    pub fn posix_time_to_slot_ceil(&self, posix_time: &BigNum) -> Result<SlotBigNum, JsError> {
+        let (slot, exact) = self.slot_of_time(posix_time.into())?;
+        if exact {
+            Ok(BigNum(slot))
+        } else {
+            BigNum(slot).checked_add(&BigNum::one())
+        }
+    }
+}
+
+impl SlotConfig {
+    fn with_byron_era(system_start: u64, shelley_start_slot: u64) -> Self {
+        let mut config = Self {
+            eras: vec![SlotEra {
+                start_slot: 0,
+                start_time: system_start,
+                slot_length: 20000,
+            }],
+        };
+        config.eras.push(SlotEra {
+            start_slot: shelley_start_slot,
+            start_time: system_start + shelley_start_slot * 20000,
+            slot_length: 1000,
+        });
+        config
+    }
+
+    fn last_era(&self) -> &SlotEra {
+        // a config always has at least one era
+        self.eras.last().unwrap()
+    }
+
+    fn era_of_slot(&self, slot: u64) -> Result<&SlotEra, JsError> {
+        self.eras
+            .iter()
+            .rev()
+            .find(|era| era.start_slot <= slot)
+            .ok_or_else(|| {
+                JsError::from_str(&format!("Slot {} is before the first known era", slot))
+            })
+    }
+
+    // the slot containing the time and whether the slot starts exactly at that time
+    fn slot_of_time(&self, posix_time: u64) -> Result<(u64, bool), JsError> {
+        let era = self
+            .eras
+            .iter()
+            .rev()
+            .find(|era| era.start_time <= posix_time)
+            .ok_or_else(|| {
+                JsError::from_str(&format!(
+                    "POSIX time {} is before the first known era",
+                    posix_time
+                ))
+            })?;
+        let slot = era.start_slot + (posix_time - era.start_time) / era.slot_length;
+        Ok((slot, era.time_of(slot) == Some(posix_time)))
+    }
+}
@@ -24,9 +24,13 @@ pub struct TransactionBody {
     pub(crate) voting_proposals: Option<VotingProposals>,
     pub(crate) donation: Option<Coin>,
     pub(crate) current_treasury_value: Option<Coin>,
+    #[serde(skip)]
+    pub(crate) original_encoding: OriginalEncoding,
 }
 
 impl_to_from!(TransactionBody);
+to_canonical_bytes!(TransactionBody);
+to_annotated_diagnostic!(TransactionBody, TransactionBody);
 
 #[wasm_bindgen]
 impl TransactionBody {
@@ -285,6 +289,7 @@ impl TransactionBody {
             voting_proposals: None,
             donation: None,
             current_treasury_value: None,
+            original_encoding: OriginalEncoding::default(),
         }
     }
 }
\ No newline at end of file
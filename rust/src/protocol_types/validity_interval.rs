@@ -0,0 +1,66 @@
+use crate::*;
+
+/// Slot interval in which a transaction is valid, it corresponds to the validity start interval and ttl of the body.
+/// The interval includes {invalid_before} and excludes {invalid_hereafter}, a missing bound leaves that side open.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ValidityInterval {
+    pub(crate) invalid_before: Option<SlotBigNum>,
+    pub(crate) invalid_hereafter: Option<SlotBigNum>,
+}
+
+#[wasm_bindgen]
+impl ValidityInterval {
+    pub fn new(invalid_before: Option<SlotBigNum>, invalid_hereafter: Option<SlotBigNum>) -> Self {
+        Self {
+            invalid_before,
+            invalid_hereafter,
+        }
+    }
+
+    pub fn from_transaction_body(body: &TransactionBody) -> Self {
+        Self::new(body.validity_start_interval, body.ttl)
+    }
+
+    pub fn invalid_before(&self) -> Option<SlotBigNum> {
+        self.invalid_before
+    }
+
+    pub fn invalid_hereafter(&self) -> Option<SlotBigNum> {
+        self.invalid_hereafter
+    }
+
+    /// Returns true if every slot of this interval is inside {other}
+    pub fn is_within(&self, other: &ValidityInterval) -> bool {
+        let after_start = match (other.invalid_before, self.invalid_before) {
+            (None, _) => true,
+            (Some(start), Some(slot)) => slot >= start,
+            (Some(_), None) => false,
+        };
+        let before_end = match (other.invalid_hereafter, self.invalid_hereafter) {
+            (None, _) => true,
+            (Some(end), Some(slot)) => slot <= end,
+            (Some(_), None) => false,
+        };
+        after_start && before_end
+    }
+}
+
+impl ValidityInterval {
+    pub(crate) fn unbounded() -> Self {
+        Self::new(None, None)
+    }
+
+    // the intersection of two intervals, None if they have no slot in common
+    pub(crate) fn intersect(&self, other: &ValidityInterval) -> Option<ValidityInterval> {
+        let invalid_before = self.invalid_before.max(other.invalid_before);
+        let invalid_hereafter = match (self.invalid_hereafter, other.invalid_hereafter) {
+            (Some(left), Some(right)) => Some(left.min(right)),
+            (left, right) => left.or(right),
+        };
+        match (invalid_before, invalid_hereafter) {
+            (Some(start), Some(end)) if start >= end => None,
+            _ => Some(Self::new(invalid_before, invalid_hereafter)),
+        }
+    }
+}
@@ -1,4 +1,6 @@
+use crate::legacy_address::{Attributes, ExtendedAddr};
 use crate::*;
+use ed25519_bip32::XPub;
 
 #[wasm_bindgen]
 #[derive(Clone, Debug, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize, JsonSchema)]
@@ -42,4 +44,16 @@ impl BootstrapWitness {
             attributes: attributes,
         }
     }
-}
\ No newline at end of file
+}
+
+impl BootstrapWitness {
+    // the root of the Byron address the witness was made for, None for malformed witness keys
+    pub(crate) fn key_hash(&self) -> Option<Ed25519KeyHash> {
+        let mut xpub = self.vkey.public_key().as_bytes();
+        xpub.extend(&self.chain_code);
+        let xpub = XPub::from_slice(&xpub).ok()?;
+        let mut raw = Deserializer::from(std::io::Cursor::new(&self.attributes));
+        let attributes: Attributes = cbor_event::de::Deserialize::deserialize(&mut raw).ok()?;
+        Some(Ed25519KeyHash::from(ExtendedAddr::new(&xpub, attributes).addr))
+    }
+}
@@ -37,9 +37,13 @@ pub struct TransactionWitnessSet {
     pub(crate) plutus_scripts: Option<PlutusScripts>,
     pub(crate) plutus_data: Option<PlutusList>,
     pub(crate) redeemers: Option<Redeemers>,
+    #[serde(skip)]
+    pub(crate) original_encoding: OriginalEncoding,
 }
 
 impl_to_from!(TransactionWitnessSet);
+to_canonical_bytes!(TransactionWitnessSet);
+to_annotated_diagnostic!(TransactionWitnessSet, TransactionWitnessSet);
 
 #[wasm_bindgen]
 impl TransactionWitnessSet {
@@ -107,6 +111,7 @@ impl TransactionWitnessSet {
             plutus_scripts: None,
             plutus_data: None,
             redeemers: None,
+            original_encoding: OriginalEncoding::default(),
         }
     }
 
@@ -134,6 +139,7 @@ impl TransactionWitnessSet {
                 .empty_to_none()
                 .flatten(),
             redeemers,
+            original_encoding: OriginalEncoding::default(),
         }
     }
 }
\ No newline at end of file
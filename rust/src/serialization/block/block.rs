@@ -54,7 +54,8 @@ impl Deserialize for Block {
                         if is_break_tag(raw, "Block.invalid_transactions")? {
                             break;
                         }
-                        arr.push(TransactionIndex::deserialize(raw)?);
+                        let index = arr.len();
+                        arr.push(TransactionIndex::deserialize(raw).map_err(|e| e.annotate_index(index))?);
                     }
                 }
                 Ok(arr)
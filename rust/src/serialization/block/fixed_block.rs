@@ -60,7 +60,8 @@ fn deserialize_block<R: BufRead + Seek>(
                 if is_break_tag(raw, "Block.invalid_transactions")? {
                     break;
                 }
-                arr.push(TransactionIndex::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(TransactionIndex::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
         }
         Ok(arr)
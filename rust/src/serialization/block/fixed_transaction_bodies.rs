@@ -13,7 +13,8 @@ impl Deserialize for FixedTransactionBodies {
                 if is_break_tag(raw, "FixedTransactionBodies")? {
                     break;
                 }
-                arr.push(FixedTransactionBody::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(FixedTransactionBody::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
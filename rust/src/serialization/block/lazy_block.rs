@@ -0,0 +1,136 @@
+use crate::serialization::utils::{is_break_tag, skip_cbor_item};
+use crate::*;
+use std::collections::BTreeMap;
+use std::io::Cursor;
+
+impl<'a> LazyBlock<'a> {
+    /// Reads the layout of the block from {bytes} by skipping over the transactions,
+    /// only the header is decoded as some encodings of the header can't be skipped without decoding it.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<LazyBlock<'a>, DeserializeError> {
+        let mut raw = Deserializer::from(Cursor::new(bytes));
+        read_lazy_block(&mut raw, bytes).map_err(|e| e.annotate("LazyBlock"))
+    }
+}
+
+fn position(raw: &mut Deserializer<Cursor<&[u8]>>) -> usize {
+    raw.as_mut_ref().stream_position().unwrap() as usize
+}
+
+// skips one item and returns its bytes
+fn read_item<'a>(
+    raw: &mut Deserializer<Cursor<&'a [u8]>>,
+    bytes: &'a [u8],
+) -> Result<&'a [u8], DeserializeError> {
+    let start = position(raw);
+    skip_cbor_item(raw)?;
+    let end = position(raw);
+    bytes.get(start..end).ok_or_else(|| {
+        DeserializeFailure::CBOR(cbor_event::Error::NotEnough(bytes.len(), end)).into()
+    })
+}
+
+fn read_items<'a>(
+    raw: &mut Deserializer<Cursor<&'a [u8]>>,
+    bytes: &'a [u8],
+    location: &str,
+) -> Result<Vec<&'a [u8]>, DeserializeError> {
+    let mut items = Vec::new();
+    let len = raw.array()?;
+    while match len {
+        Len::Len(n) => items.len() < n as usize,
+        Len::Indefinite => true,
+    } {
+        if is_break_tag(raw, location)? {
+            break;
+        }
+        items.push(read_item(raw, bytes)?);
+    }
+    Ok(items)
+}
+
+fn read_lazy_block<'a>(
+    raw: &mut Deserializer<Cursor<&'a [u8]>>,
+    bytes: &'a [u8],
+) -> Result<LazyBlock<'a>, DeserializeError> {
+    let start = position(raw);
+    let len = raw.array()?;
+    let mut read_len = CBORReadLen::new(len);
+    read_len.read_elems(4)?;
+    let header_start = position(raw);
+    let header = Header::deserialize(raw).map_err(|e| e.annotate("header"))?;
+    let raw_header = &bytes[header_start..position(raw)];
+    let transaction_bodies = read_items(raw, bytes, "transaction_bodies")
+        .map_err(|e| e.annotate("transaction_bodies"))?;
+    let transaction_witness_sets = read_items(raw, bytes, "transaction_witness_sets")
+        .map_err(|e| e.annotate("transaction_witness_sets"))?;
+    if transaction_bodies.len() != transaction_witness_sets.len() {
+        return Err(DeserializeFailure::CustomError(format!(
+            "{} transaction bodies but {} witness sets",
+            transaction_bodies.len(),
+            transaction_witness_sets.len()
+        ))
+        .into());
+    }
+
+    let auxiliary_data_set = (|| -> Result<_, DeserializeError> {
+        let mut table = BTreeMap::new();
+        let len = raw.map()?;
+        while match len {
+            Len::Len(n) => table.len() < n as usize,
+            Len::Indefinite => true,
+        } {
+            if is_break_tag(raw, "auxiliary_data_set")? {
+                break;
+            }
+            let key = TransactionIndex::deserialize(raw)?;
+            if table.insert(key, read_item(raw, bytes)?).is_some() {
+                return Err(DeserializeFailure::DuplicateKey(Key::Uint(key as u64)).into());
+            }
+        }
+        Ok(table)
+    })()
+    .map_err(|e| e.annotate("auxiliary_data_set"))?;
+
+    let invalid_present = match len {
+        Len::Indefinite => raw.cbor_type()? == CBORType::Array,
+        Len::Len(4) => false,
+        _ => true,
+    };
+    let invalid_transactions = (|| -> Result<_, DeserializeError> {
+        let mut arr = Vec::new();
+        if invalid_present {
+            read_len.read_elems(1)?;
+            let len = raw.array()?;
+            while match len {
+                Len::Len(n) => arr.len() < n as usize,
+                Len::Indefinite => true,
+            } {
+                if is_break_tag(raw, "invalid_transactions")? {
+                    break;
+                }
+                let index = arr.len();
+                arr.push(TransactionIndex::deserialize(raw).map_err(|e| e.annotate_index(index))?);
+            }
+        }
+        Ok(arr)
+    })()
+    .map_err(|e| e.annotate("invalid_transactions"))?;
+    match len {
+        Len::Len(_) => (),
+        Len::Indefinite => match raw.special()? {
+            CBORSpecial::Break => (),
+            _ => return Err(DeserializeFailure::EndingBreakMissing.into()),
+        },
+    }
+
+    let end = position(raw);
+    Ok(LazyBlock {
+        header,
+        raw_header,
+        transaction_bodies,
+        transaction_witness_sets,
+        auxiliary_data_set,
+        invalid_transactions,
+        block_hash: BlockHash(blake2b256(&bytes[start..end])),
+    })
+}
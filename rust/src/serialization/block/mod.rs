@@ -8,3 +8,4 @@ mod fixed_transaction_body;
 mod fixed_transaction_bodies;
 mod versioned_block;
 mod fixed_versioned_block;
+mod lazy_block;
@@ -26,7 +26,8 @@ impl Deserialize for TransactionBodies {
                 if is_break_tag(raw, "TransactionBodies")? {
                     break;
                 }
-                arr.push(TransactionBody::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(TransactionBody::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
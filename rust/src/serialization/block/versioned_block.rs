@@ -6,9 +6,15 @@ impl Serialize for VersionedBlock {
         &self,
         serializer: &'se mut Serializer<W>,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
-        serializer.write_array(Len::Len(2))?;
-        self.era_code.serialize(serializer)?;
-        self.block.serialize(serializer)
+        match &self.block {
+            // a Byron block already starts with its era code
+            VersionedBlockEnum::Byron(block) => block.serialize(serializer),
+            VersionedBlockEnum::Block(block) => {
+                serializer.write_array(Len::Len(2))?;
+                self.era_code.serialize(serializer)?;
+                block.serialize(serializer)
+            }
+        }
     }
 }
 
@@ -17,7 +23,10 @@ impl Deserialize for VersionedBlock {
         let len = raw.array()?;
         check_len(len, 2, "VersionedBlock")?;
         let era_code = u32::deserialize(raw)?;
-        let block = Block::deserialize(raw)?;
+        let block = match era_code {
+            0 | 1 => VersionedBlockEnum::Byron(ByronBlock::deserialize_by_era_code(raw, era_code as u64)?),
+            _ => VersionedBlockEnum::Block(Box::new(Block::deserialize(raw)?)),
+        };
         check_len_indefinite(raw, len)?;
         Ok(VersionedBlock { era_code, block })
     }
@@ -0,0 +1,236 @@
+use super::deserialize_byron_array;
+use crate::serialization::utils::{
+    check_len, check_len_indefinite, deserilized_with_orig_bytes, skip_cbor_item,
+};
+use crate::*;
+
+// The node hashes the header together with the kind of its block: [0, ebbhead] or [1, blockhead]
+fn byron_block_hash(era_code: u8, header_bytes: &[u8]) -> BlockHash {
+    let mut tagged_header = vec![0x82, era_code];
+    tagged_header.extend_from_slice(header_bytes);
+    BlockHash(blake2b256(&tagged_header))
+}
+
+// difficulty = [u64]
+fn deserialize_difficulty<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<u64, DeserializeError> {
+    let len = raw.array()?;
+    check_len(len, 1, "[chain_difficulty]")?;
+    let difficulty = u64::deserialize(raw)?;
+    check_len_indefinite(raw, len)?;
+    Ok(difficulty)
+}
+
+struct MainBlockHeader {
+    protocol_magic: u32,
+    prev_block: BlockHash,
+    epoch: u64,
+    slot: u64,
+    issuer_public_key: Vec<u8>,
+    difficulty: u64,
+}
+
+fn deserialize_main_block_header<R: BufRead + Seek>(
+    raw: &mut Deserializer<R>,
+) -> Result<MainBlockHeader, DeserializeError> {
+    let len = raw.array()?;
+    check_len(len, 5, "[protocol_magic, prev_block, body_proof, consensus_data, extra_data]")?;
+    let protocol_magic = u32::deserialize(raw).map_err(|e| e.annotate("protocol_magic"))?;
+    let prev_block = BlockHash::deserialize(raw).map_err(|e| e.annotate("prev_block"))?;
+    skip_cbor_item(raw).map_err(|e| e.annotate("body_proof"))?;
+    let (epoch, slot, issuer_public_key, difficulty) = (|| -> Result<_, DeserializeError> {
+        let len = raw.array()?;
+        check_len(len, 4, "[slot_id, pubkey, difficulty, block_signature]")?;
+        let slot_id_len = raw.array()?;
+        check_len(slot_id_len, 2, "[epoch, slot]")?;
+        let epoch = u64::deserialize(raw).map_err(|e| e.annotate("epoch"))?;
+        let slot = u64::deserialize(raw).map_err(|e| e.annotate("slot"))?;
+        check_len_indefinite(raw, slot_id_len)?;
+        let issuer_public_key = raw
+            .bytes()
+            .map_err(|e| DeserializeError::from(e).annotate("issuer_public_key"))?;
+        let difficulty = deserialize_difficulty(raw).map_err(|e| e.annotate("difficulty"))?;
+        skip_cbor_item(raw).map_err(|e| e.annotate("block_signature"))?;
+        check_len_indefinite(raw, len)?;
+        Ok((epoch, slot, issuer_public_key, difficulty))
+    })()
+    .map_err(|e| e.annotate("consensus_data"))?;
+    skip_cbor_item(raw).map_err(|e| e.annotate("extra_data"))?;
+    check_len_indefinite(raw, len)?;
+    Ok(MainBlockHeader {
+        protocol_magic,
+        prev_block,
+        epoch,
+        slot,
+        issuer_public_key,
+        difficulty,
+    })
+}
+
+// blockbody = [tx_payload, ssc_payload, dlg_payload, upd_payload], only the transactions are decoded
+fn deserialize_main_block_body<R: BufRead + Seek>(
+    raw: &mut Deserializer<R>,
+) -> Result<Vec<ByronTransaction>, DeserializeError> {
+    let len = raw.array()?;
+    check_len(len, 4, "[tx_payload, ssc_payload, dlg_payload, upd_payload]")?;
+    let transactions = deserialize_byron_array(raw, "ByronMainBlock.tx_payload", ByronTransaction::deserialize)
+        .map_err(|e| e.annotate("tx_payload"))?;
+    skip_cbor_item(raw).map_err(|e| e.annotate("ssc_payload"))?;
+    skip_cbor_item(raw).map_err(|e| e.annotate("dlg_payload"))?;
+    skip_cbor_item(raw).map_err(|e| e.annotate("upd_payload"))?;
+    check_len_indefinite(raw, len)?;
+    Ok(transactions)
+}
+
+impl cbor_event::se::Serialize for ByronMainBlock {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serializer.write_raw_bytes(&self.original_bytes)
+    }
+}
+
+impl Deserialize for ByronMainBlock {
+    fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        let ((header, header_bytes, transactions), original_bytes) =
+            deserilized_with_orig_bytes(raw, |raw| -> Result<_, DeserializeError> {
+                let len = raw.array()?;
+                check_len(len, 3, "[header, body, extra]")?;
+                let (header, header_bytes) = deserilized_with_orig_bytes(raw, deserialize_main_block_header)
+                    .map_err(|e| e.annotate("header"))?;
+                let transactions = deserialize_main_block_body(raw).map_err(|e| e.annotate("body"))?;
+                skip_cbor_item(raw).map_err(|e| e.annotate("extra"))?;
+                check_len_indefinite(raw, len)?;
+                Ok((header, header_bytes, transactions))
+            })
+            .map_err(|e| e.annotate("ByronMainBlock"))?;
+        Ok(ByronMainBlock {
+            protocol_magic: header.protocol_magic,
+            prev_block: header.prev_block,
+            epoch: header.epoch,
+            slot: header.slot,
+            issuer_public_key: header.issuer_public_key,
+            difficulty: header.difficulty,
+            transactions: ByronTransactions(transactions),
+            block_hash: byron_block_hash(1, &header_bytes),
+            original_bytes,
+        })
+    }
+}
+
+struct EpochBoundaryBlockHeader {
+    protocol_magic: u32,
+    prev_block: BlockHash,
+    epoch: u64,
+    difficulty: u64,
+}
+
+fn deserialize_epoch_boundary_block_header<R: BufRead + Seek>(
+    raw: &mut Deserializer<R>,
+) -> Result<EpochBoundaryBlockHeader, DeserializeError> {
+    let len = raw.array()?;
+    check_len(len, 5, "[protocol_magic, prev_block, body_proof, consensus_data, extra_data]")?;
+    let protocol_magic = u32::deserialize(raw).map_err(|e| e.annotate("protocol_magic"))?;
+    let prev_block = BlockHash::deserialize(raw).map_err(|e| e.annotate("prev_block"))?;
+    skip_cbor_item(raw).map_err(|e| e.annotate("body_proof"))?;
+    let (epoch, difficulty) = (|| -> Result<_, DeserializeError> {
+        let len = raw.array()?;
+        check_len(len, 2, "[epoch, difficulty]")?;
+        let epoch = u64::deserialize(raw).map_err(|e| e.annotate("epoch"))?;
+        let difficulty = deserialize_difficulty(raw).map_err(|e| e.annotate("difficulty"))?;
+        check_len_indefinite(raw, len)?;
+        Ok((epoch, difficulty))
+    })()
+    .map_err(|e| e.annotate("consensus_data"))?;
+    skip_cbor_item(raw).map_err(|e| e.annotate("extra_data"))?;
+    check_len_indefinite(raw, len)?;
+    Ok(EpochBoundaryBlockHeader {
+        protocol_magic,
+        prev_block,
+        epoch,
+        difficulty,
+    })
+}
+
+impl cbor_event::se::Serialize for ByronEpochBoundaryBlock {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serializer.write_raw_bytes(&self.original_bytes)
+    }
+}
+
+impl Deserialize for ByronEpochBoundaryBlock {
+    fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        let ((header, header_bytes), original_bytes) =
+            deserilized_with_orig_bytes(raw, |raw| -> Result<_, DeserializeError> {
+                let len = raw.array()?;
+                check_len(len, 3, "[header, body, extra]")?;
+                let header = deserilized_with_orig_bytes(raw, deserialize_epoch_boundary_block_header)
+                    .map_err(|e| e.annotate("header"))?;
+                // the body only lists the stakeholders of the new epoch
+                skip_cbor_item(raw).map_err(|e| e.annotate("body"))?;
+                skip_cbor_item(raw).map_err(|e| e.annotate("extra"))?;
+                check_len_indefinite(raw, len)?;
+                Ok(header)
+            })
+            .map_err(|e| e.annotate("ByronEpochBoundaryBlock"))?;
+        Ok(ByronEpochBoundaryBlock {
+            protocol_magic: header.protocol_magic,
+            prev_block: header.prev_block,
+            epoch: header.epoch,
+            difficulty: header.difficulty,
+            block_hash: byron_block_hash(0, &header_bytes),
+            original_bytes,
+        })
+    }
+}
+
+impl cbor_event::se::Serialize for ByronBlock {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serializer.write_array(cbor_event::Len::Len(2))?;
+        serializer.write_unsigned_integer(self.era_code() as u64)?;
+        match &self.0 {
+            ByronBlockEnum::EpochBoundary(block) => block.serialize(serializer),
+            ByronBlockEnum::Main(block) => block.serialize(serializer),
+        }
+    }
+}
+
+impl Deserialize for ByronBlock {
+    fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        (|| -> Result<_, DeserializeError> {
+            let len = raw.array()?;
+            check_len(len, 2, "[block_kind, block]")?;
+            let era_code = raw.unsigned_integer()?;
+            let block = ByronBlock::deserialize_by_era_code(raw, era_code)?;
+            check_len_indefinite(raw, len)?;
+            Ok(block)
+        })()
+        .map_err(|e| e.annotate("ByronBlock"))
+    }
+}
+
+impl ByronBlock {
+    // the era code of the node's block storage tells the two Byron block kinds apart
+    pub(crate) fn deserialize_by_era_code<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+        era_code: u64,
+    ) -> Result<Self, DeserializeError> {
+        match era_code {
+            0 => Ok(ByronBlock(ByronBlockEnum::EpochBoundary(
+                ByronEpochBoundaryBlock::deserialize(raw)?,
+            ))),
+            1 => Ok(ByronBlock(ByronBlockEnum::Main(ByronMainBlock::deserialize(raw)?))),
+            _ => Err(DeserializeFailure::FixedValuesMismatch {
+                found: Key::Uint(era_code),
+                expected: vec![Key::Uint(0), Key::Uint(1)],
+            }
+            .into()),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use super::{deserialize_byron_array, read_encoded_cbor};
+use crate::legacy_address::ExtendedAddr;
+use crate::serialization::utils::{
+    check_len, check_len_indefinite, deserilized_with_orig_bytes, skip_cbor_item,
+};
+use crate::*;
+
+impl cbor_event::se::Serialize for ByronTransactionOutput {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serializer.write_array(cbor_event::Len::Len(2))?;
+        self.address.0.serialize(serializer)?;
+        self.amount.serialize(serializer)?;
+        Ok(serializer)
+    }
+}
+
+impl Deserialize for ByronTransactionOutput {
+    fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        (|| -> Result<_, DeserializeError> {
+            let len = raw.array()?;
+            check_len(len, 2, "[address, amount]")?;
+            let address = ExtendedAddr::deserialize(raw).map_err(|e| e.annotate("address"))?;
+            let amount = Coin::deserialize(raw).map_err(|e| e.annotate("amount"))?;
+            check_len_indefinite(raw, len)?;
+            Ok(ByronTransactionOutput {
+                address: ByronAddress(address),
+                amount,
+            })
+        })()
+        .map_err(|e| e.annotate("ByronTransactionOutput"))
+    }
+}
+
+// txin = [0, #6.24(bytes .cbor ([txid, u32]))]
+// other input kinds are reserved by the CDDL but were never used on chain
+fn deserialize_byron_input<R: BufRead + Seek>(
+    raw: &mut Deserializer<R>,
+) -> Result<TransactionInput, DeserializeError> {
+    let len = raw.array()?;
+    check_len(len, 2, "[input_kind, input]")?;
+    let kind = raw.unsigned_integer()?;
+    if kind != 0 {
+        return Err(DeserializeFailure::FixedValueMismatch {
+            found: Key::Uint(kind),
+            expected: Key::Uint(0),
+        }
+        .into());
+    }
+    let mut input_raw = Deserializer::from(std::io::Cursor::new(read_encoded_cbor(raw)?));
+    let input_len = input_raw.array()?;
+    check_len(input_len, 2, "[txid, index]")?;
+    let transaction_id =
+        TransactionHash::deserialize(&mut input_raw).map_err(|e| e.annotate("txid"))?;
+    let index = u32::deserialize(&mut input_raw).map_err(|e| e.annotate("index"))?;
+    check_len_indefinite(&mut input_raw, input_len)?;
+    check_len_indefinite(raw, len)?;
+    Ok(TransactionInput::new(&transaction_id, index))
+}
+
+impl cbor_event::se::Serialize for ByronTransactionBody {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serializer.write_raw_bytes(&self.original_bytes)
+    }
+}
+
+impl Deserialize for ByronTransactionBody {
+    fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        let ((inputs, outputs), original_bytes) =
+            deserilized_with_orig_bytes(raw, |raw| -> Result<_, DeserializeError> {
+                let len = raw.array()?;
+                check_len(len, 3, "[inputs, outputs, attributes]")?;
+                let inputs = deserialize_byron_array(raw, "ByronTransactionBody.inputs", deserialize_byron_input)
+                    .map_err(|e| e.annotate("inputs"))?;
+                let outputs = deserialize_byron_array(raw, "ByronTransactionBody.outputs", ByronTransactionOutput::deserialize)
+                    .map_err(|e| e.annotate("outputs"))?;
+                skip_cbor_item(raw).map_err(|e| e.annotate("attributes"))?;
+                check_len_indefinite(raw, len)?;
+                Ok((inputs, outputs))
+            })
+            .map_err(|e| e.annotate("ByronTransactionBody"))?;
+        let tx_hash = TransactionHash(blake2b256(original_bytes.as_ref()));
+        Ok(ByronTransactionBody {
+            inputs,
+            outputs: ByronTransactionOutputs(outputs),
+            tx_hash,
+            original_bytes,
+        })
+    }
+}
+
+// reads the [pubkey, signature] pair embedded in public key and redeem witnesses
+fn deserialize_key_and_signature(bytes: Vec<u8>) -> Result<(Vec<u8>, Vec<u8>), DeserializeError> {
+    let mut raw = Deserializer::from(std::io::Cursor::new(bytes));
+    let len = raw.array()?;
+    check_len(len, 2, "[pubkey, signature]")?;
+    let public_key = raw.bytes().map_err(|e| DeserializeError::from(e).annotate("pubkey"))?;
+    let signature = raw.bytes().map_err(|e| DeserializeError::from(e).annotate("signature"))?;
+    check_len_indefinite(&mut raw, len)?;
+    Ok((public_key, signature))
+}
+
+impl cbor_event::se::Serialize for ByronTxWitness {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serializer.write_raw_bytes(&self.original_bytes)
+    }
+}
+
+impl Deserialize for ByronTxWitness {
+    fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        let (witness, original_bytes) =
+            deserilized_with_orig_bytes(raw, |raw| -> Result<_, DeserializeError> {
+                let len = raw.array()?;
+                check_len(len, 2, "[witness_kind, witness]")?;
+                let kind = raw.unsigned_integer()?;
+                let witness = match kind {
+                    0 => {
+                        let (public_key, signature) =
+                            deserialize_key_and_signature(read_encoded_cbor(raw)?)?;
+                        ByronTxWitnessEnum::PublicKey {
+                            public_key,
+                            signature,
+                        }
+                    }
+                    1 => ByronTxWitnessEnum::Script(read_encoded_cbor(raw)?),
+                    2 => {
+                        let (public_key, signature) =
+                            deserialize_key_and_signature(read_encoded_cbor(raw)?)?;
+                        ByronTxWitnessEnum::Redeem {
+                            public_key,
+                            signature,
+                        }
+                    }
+                    _ => {
+                        skip_cbor_item(raw)?;
+                        ByronTxWitnessEnum::Unknown(kind)
+                    }
+                };
+                check_len_indefinite(raw, len)?;
+                Ok(witness)
+            })
+            .map_err(|e| e.annotate("ByronTxWitness"))?;
+        Ok(ByronTxWitness {
+            witness,
+            original_bytes,
+        })
+    }
+}
+
+impl cbor_event::se::Serialize for ByronTransaction {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serializer.write_raw_bytes(&self.original_bytes)
+    }
+}
+
+impl Deserialize for ByronTransaction {
+    fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        let ((body, witnesses), original_bytes) =
+            deserilized_with_orig_bytes(raw, |raw| -> Result<_, DeserializeError> {
+                let len = raw.array()?;
+                check_len(len, 2, "[tx, [* twit]]")?;
+                let body = ByronTransactionBody::deserialize(raw).map_err(|e| e.annotate("body"))?;
+                let witnesses = deserialize_byron_array(raw, "ByronTransaction.witnesses", ByronTxWitness::deserialize)
+                    .map_err(|e| e.annotate("witnesses"))?;
+                check_len_indefinite(raw, len)?;
+                Ok((body, witnesses))
+            })
+            .map_err(|e| e.annotate("ByronTransaction"))?;
+        Ok(ByronTransaction {
+            body,
+            witnesses: ByronTxWitnesses(witnesses),
+            original_bytes,
+        })
+    }
+}
@@ -0,0 +1,39 @@
+mod byron_block;
+mod byron_transaction;
+
+use crate::serialization::utils::is_break_tag;
+use crate::*;
+
+// Byron arrays are mostly written with indefinite lengths
+fn deserialize_byron_array<R: BufRead + Seek, T>(
+    raw: &mut Deserializer<R>,
+    location: &str,
+    deserialize_item: fn(&mut Deserializer<R>) -> Result<T, DeserializeError>,
+) -> Result<Vec<T>, DeserializeError> {
+    let mut arr = Vec::new();
+    let len = raw.array()?;
+    while match len {
+        cbor_event::Len::Len(n) => arr.len() < n as usize,
+        cbor_event::Len::Indefinite => true,
+    } {
+        if is_break_tag(raw, location)? {
+            break;
+        }
+        let index = arr.len();
+        arr.push(deserialize_item(raw).map_err(|e| e.annotate_index(index))?);
+    }
+    Ok(arr)
+}
+
+// #6.24(bytes .cbor a), returns the embedded CBOR
+fn read_encoded_cbor<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Vec<u8>, DeserializeError> {
+    let tag = raw.tag()?;
+    if tag != 24 {
+        return Err(DeserializeFailure::TagMismatch {
+            found: tag,
+            expected: 24,
+        }
+        .into());
+    }
+    Ok(raw.bytes()?)
+}
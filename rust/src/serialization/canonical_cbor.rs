@@ -0,0 +1,188 @@
+use crate::serialization::utils::{is_break_tag, read_nint, write_nint};
+use crate::*;
+use std::io::Cursor;
+
+/// Re-encodes {bytes} in the canonical CBOR format of RFC 7049 section 3.9:
+/// definite lengths, minimal integer and length widths and map keys sorted by length, then bytewise.
+/// Tags are kept and floats are written as they are decoded.
+pub(crate) fn to_canonical_cbor(bytes: &[u8]) -> Result<Vec<u8>, DeserializeError> {
+    let mut raw = Deserializer::from(Cursor::new(bytes));
+    let canonical = canonical_item(&mut raw)?;
+    let read = raw.as_mut_ref().position() as usize;
+    if read != bytes.len() {
+        return Err(cbor_event::Error::TrailingData.into());
+    }
+    Ok(canonical)
+}
+
+/// Returns true if {bytes} are one CBOR item in the canonical CBOR format of RFC 7049 section 3.9
+pub(crate) fn is_canonical_cbor(bytes: &[u8]) -> bool {
+    matches!(to_canonical_cbor(bytes), Ok(canonical) if canonical == bytes)
+}
+
+/// Values written by to_canonical_bytes()
+pub(crate) trait CanonicalEncoding: Serialize {
+    /// Sorts the map keys of the value on top of its canonical encoding,
+    /// values holding Plutus data override it to keep the bytes of the data.
+    fn canonical_bytes(&self) -> Result<Vec<u8>, DeserializeError> {
+        to_canonical_cbor(&own_encoding_bytes(self)?)
+    }
+}
+
+// the value written with this library's own encoding (definite lengths, minimal integer widths),
+// map keys are left in the order they are written
+fn own_encoding_bytes<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, DeserializeError> {
+    with_canonical_encoding(|| {
+        let mut serializer = Serializer::new_vec();
+        value.serialize(&mut serializer)?;
+        Ok(serializer.finalize())
+    })
+}
+
+impl CanonicalEncoding for AuxiliaryData {}
+
+impl CanonicalEncoding for Certificates {}
+
+impl CanonicalEncoding for Mint {}
+
+impl CanonicalEncoding for MultiAsset {}
+
+impl CanonicalEncoding for NativeScript {}
+
+impl CanonicalEncoding for PlutusScript {}
+
+impl CanonicalEncoding for TransactionBody {}
+
+// inline datums are written as byte strings, sorting the output keeps them as they are
+impl CanonicalEncoding for TransactionOutput {}
+
+impl CanonicalEncoding for Value {}
+
+impl CanonicalEncoding for TransactionWitnessSet {
+    fn canonical_bytes(&self) -> Result<Vec<u8>, DeserializeError> {
+        own_encoding_bytes(self)
+    }
+}
+
+impl CanonicalEncoding for Redeemers {
+    fn canonical_bytes(&self) -> Result<Vec<u8>, DeserializeError> {
+        own_encoding_bytes(self)
+    }
+}
+
+// only the body and the auxiliary data are sorted, CIP-21 doesn't cover the witness set
+fn canonical_transaction(
+    body: &TransactionBody,
+    witness_set: &[u8],
+    is_valid: bool,
+    auxiliary_data: Option<&AuxiliaryData>,
+) -> Result<Vec<u8>, DeserializeError> {
+    let mut serializer = Serializer::new_vec();
+    serializer.write_array(Len::Len(4))?;
+    serializer.write_raw_bytes(&body.canonical_bytes()?)?;
+    serializer.write_raw_bytes(witness_set)?;
+    serializer.write_special(CBORSpecial::Bool(is_valid))?;
+    match auxiliary_data {
+        Some(auxiliary_data) => serializer.write_raw_bytes(&auxiliary_data.canonical_bytes()?)?,
+        None => serializer.write_special(CBORSpecial::Null)?,
+    };
+    Ok(serializer.finalize())
+}
+
+impl CanonicalEncoding for Transaction {
+    fn canonical_bytes(&self) -> Result<Vec<u8>, DeserializeError> {
+        canonical_transaction(
+            &self.body,
+            &self.witness_set.canonical_bytes()?,
+            self.is_valid,
+            self.auxiliary_data.as_ref(),
+        )
+    }
+}
+
+impl CanonicalEncoding for FixedTransaction {
+    fn canonical_bytes(&self) -> Result<Vec<u8>, DeserializeError> {
+        canonical_transaction(
+            self.body_ref(),
+            &own_encoding_bytes(self.witnesses_set_ref())?,
+            self.is_valid(),
+            self.auxiliary_data_ref(),
+        )
+    }
+}
+
+fn canonical_item<R: BufRead + Seek>(
+    raw: &mut Deserializer<R>,
+) -> Result<Vec<u8>, DeserializeError> {
+    let mut serializer = Serializer::new_vec();
+    match raw.cbor_type()? {
+        CBORType::UnsignedInteger => {
+            serializer.write_unsigned_integer(raw.unsigned_integer()?)?;
+        }
+        CBORType::NegativeInteger => {
+            write_nint(&mut serializer, read_nint(raw)?)?;
+        }
+        CBORType::Bytes => {
+            serializer.write_bytes(raw.bytes()?)?;
+        }
+        CBORType::Text => {
+            serializer.write_text(raw.text()?)?;
+        }
+        CBORType::Array => {
+            let items = canonical_entries(raw, "array", 1)?;
+            serializer.write_array(Len::Len(items.len() as u64))?;
+            for item in items {
+                serializer.write_raw_bytes(&item[0])?;
+            }
+        }
+        CBORType::Map => {
+            let mut entries = canonical_entries(raw, "map", 2)?;
+            // keys are sorted by the length of their encoding first, then bytewise
+            entries.sort_by(|a, b| a[0].len().cmp(&b[0].len()).then_with(|| a[0].cmp(&b[0])));
+            serializer.write_map(Len::Len(entries.len() as u64))?;
+            for entry in entries {
+                serializer.write_raw_bytes(&entry[0])?;
+                serializer.write_raw_bytes(&entry[1])?;
+            }
+        }
+        CBORType::Tag => {
+            serializer.write_tag(raw.tag()?)?;
+            serializer.write_raw_bytes(&canonical_item(raw)?)?;
+        }
+        CBORType::Special => match raw.special()? {
+            CBORSpecial::Break => {
+                return Err(DeserializeFailure::BreakInDefiniteLen.into());
+            }
+            special => {
+                serializer.write_special(special)?;
+            }
+        },
+    }
+    Ok(serializer.finalize())
+}
+
+// reads the entries of an array or map, a map entry is a key followed by its value
+fn canonical_entries<R: BufRead + Seek>(
+    raw: &mut Deserializer<R>,
+    location: &str,
+    items_per_entry: usize,
+) -> Result<Vec<Vec<Vec<u8>>>, DeserializeError> {
+    let len = match items_per_entry {
+        1 => raw.array()?,
+        _ => raw.map()?,
+    };
+    let mut entries = Vec::new();
+    while match len {
+        Len::Len(n) => (entries.len() as u64) < n,
+        Len::Indefinite => true,
+    } {
+        if len == Len::Indefinite && is_break_tag(raw, location)? {
+            break;
+        }
+        let entry = (0..items_per_entry)
+            .map(|_| canonical_item(raw))
+            .collect::<Result<Vec<_>, _>>()?;
+        entries.push(entry);
+    }
+    Ok(entries)
+}
@@ -5,6 +5,23 @@ impl Serialize for Certificates {
     fn serialize<'se, W: Write>(
         &self,
         serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serialize_preserving_encoding(self, serializer)
+    }
+}
+
+impl PreservesEncoding for Certificates {
+    fn original_encoding(&self) -> &OriginalEncoding {
+        &self.original_encoding
+    }
+
+    fn original_encoding_mut(&mut self) -> &mut OriginalEncoding {
+        &mut self.original_encoding
+    }
+
+    fn serialize_own_encoding<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
         serializer.write_tag(258)?;
         serializer.write_array(Len::Len(self.len() as u64))?;
@@ -17,6 +34,19 @@ impl Serialize for Certificates {
 
 impl Deserialize for Certificates {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        let mut certs = deserialize_preserving_encoding(raw, Self::deserialize_own_encoding)?;
+        // certificates decoded without the set tag are always written with it
+        if certs.get_set_type() == CborSetType::Untagged {
+            certs.original_encoding = OriginalEncoding::default();
+        }
+        Ok(certs)
+    }
+}
+
+impl Certificates {
+    fn deserialize_own_encoding<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+    ) -> Result<Self, DeserializeError> {
         let has_set_tag= skip_set_tag(raw)?;
         let mut arr = Vec::new();
         (|| -> Result<_, DeserializeError> {
@@ -28,7 +58,8 @@ impl Deserialize for Certificates {
                 if is_break_tag(raw, "Certificates")? {
                     break;
                 }
-                arr.push(Certificate::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(Certificate::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
@@ -28,7 +28,8 @@ impl Deserialize for Relays {
                 if is_break_tag(raw, "Relays")? {
                     break;
                 }
-                arr.push(Relay::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(Relay::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
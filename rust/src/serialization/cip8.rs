@@ -0,0 +1,220 @@
+use crate::serialization::utils::{
+    check_len, check_len_indefinite, is_break_tag, read_nint, skip_cbor_item, skip_tag,
+};
+use crate::*;
+
+const COSE_SIGN1_TAG: u64 = 18;
+
+// COSE_Key labels
+const LABEL_KEY_TYPE: i128 = 1;
+const LABEL_KEY_ALGORITHM: i128 = 3;
+const LABEL_CURVE: i128 = -1;
+const LABEL_X: i128 = -2;
+// header labels
+const LABEL_ALGORITHM: i128 = 1;
+const LABEL_ADDRESS: &str = "address";
+const LABEL_HASHED: &str = "hashed";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Label {
+    Int(i128),
+    Text(String),
+}
+
+impl Label {
+    fn key(&self) -> Key {
+        match self {
+            Label::Int(label) if *label >= 0 => Key::Uint(*label as u64),
+            Label::Int(label) => Key::Str(label.to_string()),
+            Label::Text(label) => Key::Str(label.clone()),
+        }
+    }
+}
+
+impl cbor_event::se::Serialize for CoseKey {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serializer.write_map(cbor_event::Len::Len(4))?;
+        serializer.write_unsigned_integer(LABEL_KEY_TYPE as u64)?;
+        serializer.write_unsigned_integer(KEY_TYPE_OKP as u64)?;
+        serializer.write_unsigned_integer(LABEL_KEY_ALGORITHM as u64)?;
+        serializer.write_negative_integer(ALGORITHM_EDDSA as i64)?;
+        serializer.write_negative_integer(LABEL_CURVE as i64)?;
+        serializer.write_unsigned_integer(CURVE_ED25519 as u64)?;
+        serializer.write_negative_integer(LABEL_X as i64)?;
+        serializer.write_bytes(self.public_key.as_bytes())
+    }
+}
+
+impl Deserialize for CoseKey {
+    fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        (|| -> Result<_, DeserializeError> {
+            let mut key_type = None;
+            let mut curve = None;
+            let mut x = None;
+            deserialize_labeled_map(raw, |label, raw| {
+                match label {
+                    Label::Int(LABEL_KEY_TYPE) => key_type = Some(read_int(raw)?),
+                    Label::Int(LABEL_KEY_ALGORITHM) => {
+                        check_int(read_int(raw)?, ALGORITHM_EDDSA, "algorithm")?
+                    }
+                    Label::Int(LABEL_CURVE) => curve = Some(read_int(raw)?),
+                    Label::Int(LABEL_X) => x = Some(raw.bytes()?),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            })?;
+            let key_type = key_type.ok_or(DeserializeFailure::MandatoryFieldMissing(
+                Label::Int(LABEL_KEY_TYPE).key(),
+            ))?;
+            check_int(key_type, KEY_TYPE_OKP, "key type")?;
+            let curve = curve.ok_or(DeserializeFailure::MandatoryFieldMissing(
+                Label::Int(LABEL_CURVE).key(),
+            ))?;
+            check_int(curve, CURVE_ED25519, "curve")?;
+            let x = x.ok_or(DeserializeFailure::MandatoryFieldMissing(
+                Label::Int(LABEL_X).key(),
+            ))?;
+            let public_key = crate::chain_crypto::PublicKey::from_binary(&x)
+                .map_err(DeserializeFailure::PublicKeyError)?;
+            Ok(CoseKey {
+                public_key: PublicKey(public_key),
+            })
+        })()
+        .map_err(|e| e.annotate("CoseKey"))
+    }
+}
+
+impl cbor_event::se::Serialize for CoseSign1 {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serializer.write_array(cbor_event::Len::Len(4))?;
+        serializer.write_bytes(&self.protected_header)?;
+        serializer.write_map(cbor_event::Len::Len(1))?;
+        serializer.write_text(LABEL_HASHED)?;
+        serializer.write_special(CBORSpecial::Bool(self.hashed))?;
+        match &self.payload {
+            Some(payload) => serializer.write_bytes(payload)?,
+            None => serializer.write_special(CBORSpecial::Null)?,
+        };
+        self.signature.serialize(serializer)
+    }
+}
+
+impl Deserialize for CoseSign1 {
+    fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        (|| -> Result<_, DeserializeError> {
+            if raw.cbor_type()? == CBORType::Tag {
+                skip_tag(raw, COSE_SIGN1_TAG)?;
+            }
+            let len = raw.array()?;
+            check_len(len, 4, "[protected, unprotected, payload, signature]")?;
+
+            let protected_header = raw.bytes()?;
+            let mut algorithm = None;
+            let mut address = None;
+            // an empty protected header is encoded as an empty byte string
+            if !protected_header.is_empty() {
+                let mut protected =
+                    Deserializer::from(std::io::Cursor::new(protected_header.clone()));
+                deserialize_labeled_map(&mut protected, |label, raw| {
+                    match label {
+                        Label::Int(LABEL_ALGORITHM) => algorithm = Some(read_int(raw)?),
+                        Label::Text(text) if text == LABEL_ADDRESS => address = Some(raw.bytes()?),
+                        _ => return Ok(false),
+                    }
+                    Ok(true)
+                })
+                .map_err(|e| e.annotate("protected"))?;
+            }
+
+            let mut hashed = false;
+            deserialize_labeled_map(raw, |label, raw| {
+                match label {
+                    Label::Text(text) if text == LABEL_HASHED => {
+                        hashed = match raw.special()? {
+                            CBORSpecial::Bool(hashed) => hashed,
+                            _ => return Err(DeserializeFailure::ExpectedBool.into()),
+                        }
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            })
+            .map_err(|e| e.annotate("unprotected"))?;
+
+            let payload = match raw.cbor_type()? {
+                CBORType::Special => match raw.special()? {
+                    CBORSpecial::Null => None,
+                    _ => return Err(DeserializeFailure::ExpectedNull.into()),
+                },
+                _ => Some(raw.bytes()?),
+            };
+            let signature =
+                Ed25519Signature::deserialize(raw).map_err(|e| e.annotate("signature"))?;
+            check_len_indefinite(raw, len)?;
+
+            Ok(CoseSign1 {
+                protected_header,
+                algorithm,
+                address,
+                hashed,
+                payload,
+                signature,
+            })
+        })()
+        .map_err(|e| e.annotate("CoseSign1"))
+    }
+}
+
+// reads a map with integer and text labels, {read_value} returns false for the values it
+// doesn't read, they are skipped
+fn deserialize_labeled_map<R: BufRead + Seek>(
+    raw: &mut Deserializer<R>,
+    mut read_value: impl FnMut(&Label, &mut Deserializer<R>) -> Result<bool, DeserializeError>,
+) -> Result<(), DeserializeError> {
+    let len = raw.map()?;
+    let mut labels = Vec::new();
+    while match len {
+        cbor_event::Len::Len(n) => labels.len() < n as usize,
+        cbor_event::Len::Indefinite => !is_break_tag(raw, "deserialize_labeled_map")?,
+    } {
+        let label = match raw.cbor_type()? {
+            CBORType::UnsignedInteger => Label::Int(raw.unsigned_integer()? as i128),
+            CBORType::NegativeInteger => Label::Int(read_nint(raw)?),
+            CBORType::Text => Label::Text(raw.text()?),
+            other_type => return Err(DeserializeFailure::UnexpectedKeyType(other_type).into()),
+        };
+        if labels.contains(&label) {
+            return Err(DeserializeFailure::DuplicateKey(label.key()).into());
+        }
+        if !read_value(&label, raw).map_err(|e| e.annotate(label.key().to_string()))? {
+            skip_cbor_item(raw)?;
+        }
+        labels.push(label);
+    }
+    Ok(())
+}
+
+fn read_int<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<i128, DeserializeError> {
+    match raw.cbor_type()? {
+        CBORType::UnsignedInteger => Ok(raw.unsigned_integer()? as i128),
+        CBORType::NegativeInteger => read_nint(raw),
+        other_type => Err(DeserializeFailure::ExpectedType("int".to_string(), other_type).into()),
+    }
+}
+
+fn check_int(found: i128, expected: i128, name: &str) -> Result<(), DeserializeError> {
+    if found != expected {
+        return Err(DeserializeFailure::CustomError(format!(
+            "unsupported {} {}, only {} is supported",
+            name, found, expected
+        ))
+        .into());
+    }
+    Ok(())
+}
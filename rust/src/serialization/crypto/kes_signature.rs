@@ -2,6 +2,7 @@ use cbor_event::de::Deserializer;
 use cbor_event::se::Serializer;
 use crate::{DeserializeError, DeserializeFailure, KESSignature};
 use crate::protocol_types::Deserialize;
+use crate::serialization::utils::deserialize_item;
 
 impl cbor_event::se::Serialize for KESSignature {
     fn serialize<'se, W: std::io::Write>(
@@ -13,10 +14,10 @@ impl cbor_event::se::Serialize for KESSignature {
 }
 
 impl Deserialize for KESSignature {
-    fn deserialize<R: std::io::BufRead>(
+    fn deserialize<R: std::io::BufRead + std::io::Seek>(
         raw: &mut Deserializer<R>,
     ) -> Result<Self, DeserializeError> {
-        (|| -> Result<Self, DeserializeError> {
+        deserialize_item(raw, |raw| {
             let bytes = raw.bytes()?;
             if bytes.len() != Self::BYTE_COUNT {
                 return Err(DeserializeFailure::CBOR(cbor_event::Error::WrongLen(
@@ -27,7 +28,7 @@ impl Deserialize for KESSignature {
                     .into());
             }
             Ok(KESSignature(bytes))
-        })()
+        })
             .map_err(|e| e.annotate("KESSignature"))
     }
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 use std::convert::TryInto;
 use crate::protocol_types::{CBORSpecial, Deserialize};
+use crate::serialization::utils::deserialize_item;
 use crate::{DeserializeError, DeserializeFailure, Nonce};
 use cbor_event::de::Deserializer;
 use cbor_event::se::Serializer;
@@ -24,14 +25,14 @@ impl cbor_event::se::Serialize for Nonce {
 }
 
 impl Deserialize for Nonce {
-    fn deserialize<R: std::io::BufRead>(
+    fn deserialize<R: std::io::BufRead + std::io::Seek>(
         raw: &mut Deserializer<R>,
     ) -> Result<Self, DeserializeError> {
         (|| -> Result<Self, DeserializeError> {
             let len = raw.array()?;
             let hash = match raw.unsigned_integer()? {
                 0 => None,
-                1 => {
+                1 => Some(deserialize_item(raw, |raw| {
                     let bytes = raw.bytes()?;
                     if bytes.len() != Self::HASH_LEN {
                         return Err(DeserializeFailure::CBOR(cbor_event::Error::WrongLen(
@@ -41,8 +42,8 @@ impl Deserialize for Nonce {
                         ))
                         .into());
                     }
-                    Some(bytes[..Self::HASH_LEN].try_into().unwrap())
-                }
+                    Ok(bytes[..Self::HASH_LEN].try_into().unwrap())
+                })?),
                 _ => return Err(DeserializeFailure::NoVariantMatched.into()),
             };
             match len {
@@ -30,7 +30,8 @@ impl Deserialize for Vkeys {
                 if is_break_tag(raw, "Vkeys")? {
                     break;
                 }
-                arr.push(Vkey::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(Vkey::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
@@ -1,3 +1,4 @@
+use crate::serialization::utils::deserialize_item;
 use crate::*;
 
 impl cbor_event::se::Serialize for VRFCert {
@@ -18,16 +19,19 @@ impl Deserialize for VRFCert {
             let len = raw.array()?;
             let output = (|| -> Result<_, DeserializeError> { Ok(raw.bytes()?) })()
                 .map_err(|e| e.annotate("output"))?;
-            let proof = (|| -> Result<_, DeserializeError> { Ok(raw.bytes()?) })()
-                .map_err(|e| e.annotate("proof"))?;
-            if proof.len() != Self::PROOF_LEN {
-                return Err(DeserializeFailure::CBOR(cbor_event::Error::WrongLen(
-                    Self::PROOF_LEN as u64,
-                    cbor_event::Len::Len(proof.len() as u64),
-                    "proof length",
-                ))
-                .into());
-            }
+            let proof = deserialize_item(raw, |raw| {
+                let proof = raw.bytes()?;
+                if proof.len() != Self::PROOF_LEN {
+                    return Err(DeserializeFailure::CBOR(cbor_event::Error::WrongLen(
+                        Self::PROOF_LEN as u64,
+                        cbor_event::Len::Len(proof.len() as u64),
+                        "proof length",
+                    ))
+                    .into());
+                }
+                Ok(proof)
+            })
+            .map_err(|e| e.annotate("proof"))?;
             match len {
                 cbor_event::Len::Len(_) =>
                 /* TODO: check finite len somewhere */
@@ -0,0 +1,561 @@
+use crate::serialization::map_names::*;
+use crate::*;
+use num_traits::FromPrimitive;
+
+/// Shape of the CBOR item being written, it tells which CDDL names annotate map keys and array items
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum DiagnosticSchema {
+    Any,
+    Transaction,
+    Block,
+    Header,
+    HeaderBody,
+    TransactionBodies,
+    TransactionBody,
+    TransactionWitnessSets,
+    TransactionWitnessSet,
+    AuxiliaryDataSet,
+    AuxiliaryData,
+    AlonzoAuxiliaryData,
+    Certificates,
+    Certificate,
+    CertificateKind,
+    VotingProposals,
+    VotingProposal,
+    GovernanceAction,
+    GovernanceActionKind,
+}
+
+impl DiagnosticSchema {
+    // name and schema of the array item at {index}, {len} is None for indefinite-length arrays
+    fn array_item(&self, index: usize, len: Option<u64>) -> (Option<&'static str>, Self) {
+        use DiagnosticSchema::*;
+        match self {
+            Transaction => {
+                // transactions before Alonzo have no is_valid flag
+                let names: &[&'static str] = match len {
+                    Some(3) => &["transaction_body", "transaction_witness_set", "auxiliary_data"],
+                    _ => &[
+                        "transaction_body",
+                        "transaction_witness_set",
+                        "is_valid",
+                        "auxiliary_data",
+                    ],
+                };
+                let name = names.get(index).copied();
+                let schema = match name {
+                    Some("transaction_body") => TransactionBody,
+                    Some("transaction_witness_set") => TransactionWitnessSet,
+                    Some("auxiliary_data") => AuxiliaryData,
+                    _ => Any,
+                };
+                (name, schema)
+            }
+            Block => match index {
+                0 => (Some("header"), Header),
+                1 => (Some("transaction_bodies"), TransactionBodies),
+                2 => (Some("transaction_witness_sets"), TransactionWitnessSets),
+                3 => (Some("auxiliary_data_set"), AuxiliaryDataSet),
+                4 => (Some("invalid_transactions"), Any),
+                _ => (None, Any),
+            },
+            Header => match index {
+                0 => (Some("header_body"), HeaderBody),
+                1 => (Some("body_signature"), Any),
+                _ => (None, Any),
+            },
+            // Babbage replaced the two VRF results with one and nested the operational certificate
+            // and the protocol version in arrays of their own
+            HeaderBody if len == Some(10) => {
+                const NAMES: [&str; 10] = [
+                    "block_number",
+                    "slot",
+                    "prev_hash",
+                    "issuer_vkey",
+                    "vrf_vkey",
+                    "vrf_result",
+                    "block_body_size",
+                    "block_body_hash",
+                    "operational_cert",
+                    "protocol_version",
+                ];
+                (NAMES.get(index).copied(), Any)
+            }
+            // before Babbage the operational certificate and the protocol version are inlined
+            HeaderBody if len == Some(15) => {
+                const NAMES: [&str; 15] = [
+                    "block_number",
+                    "slot",
+                    "prev_hash",
+                    "issuer_vkey",
+                    "vrf_vkey",
+                    "nonce_vrf",
+                    "leader_vrf",
+                    "block_body_size",
+                    "block_body_hash",
+                    "hot_vkey",
+                    "sequence_number",
+                    "kes_period",
+                    "sigma",
+                    "protocol_major",
+                    "protocol_minor",
+                ];
+                (NAMES.get(index).copied(), Any)
+            }
+            TransactionBodies => (None, TransactionBody),
+            TransactionWitnessSets => (None, TransactionWitnessSet),
+            AuxiliaryData => match index {
+                0 => (Some("transaction_metadata"), Any),
+                1 => (Some("auxiliary_scripts"), Any),
+                _ => (None, Any),
+            },
+            Certificates => (None, Certificate),
+            Certificate if index == 0 => (None, CertificateKind),
+            VotingProposals => (None, VotingProposal),
+            VotingProposal => match index {
+                0 => (Some("deposit"), Any),
+                1 => (Some("reward_account"), Any),
+                2 => (Some("gov_action"), GovernanceAction),
+                3 => (Some("anchor"), Any),
+                _ => (None, Any),
+            },
+            GovernanceAction if index == 0 => (None, GovernanceActionKind),
+            _ => (None, Any),
+        }
+    }
+
+    // name of the map key {key} and schema of its value, {key} is None for keys that are not unsigned integers
+    fn map_entry(&self, key: Option<u64>) -> (Option<&'static str>, Self) {
+        use DiagnosticSchema::*;
+        let key = match key {
+            Some(key) => key,
+            None => return (None, Any),
+        };
+        match self {
+            TransactionBody => match TxBodyNames::from_u64(key) {
+                Some(TxBodyNames::Certs) => (Some(TxBodyNames::Certs.cddl_name()), Certificates),
+                Some(TxBodyNames::ProposalProcedures) => (
+                    Some(TxBodyNames::ProposalProcedures.cddl_name()),
+                    VotingProposals,
+                ),
+                Some(name) => (Some(name.cddl_name()), Any),
+                None => (None, Any),
+            },
+            TransactionWitnessSet => (
+                WitnessSetNames::from_u64(key).map(|name| name.cddl_name()),
+                Any,
+            ),
+            AuxiliaryDataSet => (None, AuxiliaryData),
+            AlonzoAuxiliaryData => {
+                const NAMES: [&str; 5] = [
+                    "metadata",
+                    "native_scripts",
+                    "plutus_v1_scripts",
+                    "plutus_v2_scripts",
+                    "plutus_v3_scripts",
+                ];
+                (NAMES.get(key as usize).copied(), Any)
+            }
+            _ => (None, Any),
+        }
+    }
+
+    // schema of the item inside the tag {tag}, sets are transparent
+    fn tagged_item(&self, tag: u64) -> Self {
+        match (self, tag) {
+            (DiagnosticSchema::AuxiliaryData, 259) => DiagnosticSchema::AlonzoAuxiliaryData,
+            _ => *self,
+        }
+    }
+
+    // CDDL name of an unsigned integer that selects a variant
+    fn variant_name(&self, value: u64) -> Option<&'static str> {
+        match self {
+            DiagnosticSchema::CertificateKind => {
+                CertificateIndexNames::from_u64(value).map(|name| name.cddl_name())
+            }
+            DiagnosticSchema::GovernanceActionKind => {
+                VotingProposalIndexNames::from_u64(value).map(|name| name.cddl_name())
+            }
+            _ => None,
+        }
+    }
+}
+
+fn tag_name(tag: u64) -> Option<String> {
+    match tag {
+        2 => Some("positive bignum".to_string()),
+        3 => Some("negative bignum".to_string()),
+        24 => Some("encoded cbor data item".to_string()),
+        30 => Some("rational number".to_string()),
+        102 => Some("constr".to_string()),
+        121..=127 => Some(format!("constr {}", tag - 121)),
+        1280..=1400 => Some(format!("constr {}", tag - 1280 + 7)),
+        258 => Some("set".to_string()),
+        259 => Some("auxiliary data".to_string()),
+        _ => None,
+    }
+}
+
+/// Writes {bytes} in the diagnostic notation of RFC 8949 section 8, one array item or map entry per line.
+/// Map keys and array items are annotated with their CDDL names from {schema} as comments, so are
+/// the variants of certificates and governance actions and the well-known tags.
+/// Indefinite lengths are marked with `_` and integer or length arguments that are longer than needed
+/// get the `_0`-`_3` encoding indicators, so two encodings of the same value give different output.
+pub(crate) fn to_annotated_diagnostic(
+    bytes: &[u8],
+    schema: DiagnosticSchema,
+) -> Result<String, DeserializeError> {
+    let mut writer = DiagnosticWriter {
+        bytes,
+        pos: 0,
+        out: String::new(),
+    };
+    writer.write_item(schema, 0)?;
+    if writer.pos != bytes.len() {
+        return Err(cbor_event::Error::TrailingData.into());
+    }
+    Ok(writer.out)
+}
+
+struct ItemHeader {
+    major: u8,
+    info: u8,
+    // None for indefinite lengths
+    argument: Option<u64>,
+    // bytes of the argument after the initial byte
+    argument_size: usize,
+}
+
+impl ItemHeader {
+    // encoding indicator of RFC 8949 section 8.1, only written when the argument is longer than needed
+    fn encoding_indicator(&self) -> &'static str {
+        let argument = match self.argument {
+            Some(argument) => argument,
+            None => return "",
+        };
+        let needed = match argument {
+            0..=23 => 0,
+            24..=0xff => 1,
+            0x100..=0xffff => 2,
+            0x1_0000..=0xffff_ffff => 4,
+            _ => 8,
+        };
+        if self.argument_size == needed {
+            return "";
+        }
+        match self.argument_size {
+            1 => "_0",
+            2 => "_1",
+            4 => "_2",
+            8 => "_3",
+            _ => "",
+        }
+    }
+}
+
+struct DiagnosticWriter<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    out: String,
+}
+
+impl<'a> DiagnosticWriter<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DeserializeError> {
+        let available = self.bytes.len() - self.pos;
+        if len > available {
+            return Err(cbor_event::Error::NotEnough(available, len).into());
+        }
+        let taken = &self.bytes[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(taken)
+    }
+
+    fn read_header(&mut self) -> Result<ItemHeader, DeserializeError> {
+        let initial = self.take(1)?[0];
+        let major = initial >> 5;
+        let info = initial & 0x1f;
+        let (argument, argument_size) = match info {
+            0..=23 => (Some(info as u64), 0),
+            24..=27 => {
+                let size = 1 << (info - 24);
+                let argument = self
+                    .take(size)?
+                    .iter()
+                    .fold(0u64, |acc, byte| (acc << 8) | *byte as u64);
+                (Some(argument), size)
+            }
+            31 if (2..=5).contains(&major) || major == 7 => (None, 0),
+            _ => {
+                return Err(DeserializeFailure::CustomError(format!(
+                    "invalid additional information {} for major type {}",
+                    info, major
+                ))
+                .into())
+            }
+        };
+        Ok(ItemHeader {
+            major,
+            info,
+            argument,
+            argument_size,
+        })
+    }
+
+    // unsigned integer at the current position without consuming it
+    fn peek_unsigned(&mut self) -> Option<u64> {
+        let start = self.pos;
+        let header = self.read_header();
+        self.pos = start;
+        match header {
+            Ok(ItemHeader {
+                major: 0,
+                argument: Some(value),
+                ..
+            }) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn is_break(&self) -> bool {
+        self.bytes.get(self.pos) == Some(&0xff)
+    }
+
+    fn newline(&mut self, depth: usize) {
+        self.out.push('\n');
+        for _ in 0..depth {
+            self.out.push_str("  ");
+        }
+    }
+
+    fn comment(&mut self, text: &str) {
+        self.out.push_str(" / ");
+        self.out.push_str(text);
+        self.out.push_str(" /");
+    }
+
+    fn write_item(&mut self, schema: DiagnosticSchema, depth: usize) -> Result<(), DeserializeError> {
+        let header = self.read_header()?;
+        let indicator = header.encoding_indicator();
+        match (header.major, header.argument) {
+            (0, Some(value)) => {
+                self.out.push_str(&format!("{}{}", value, indicator));
+                if let Some(name) = schema.variant_name(value) {
+                    self.comment(name);
+                }
+            }
+            (1, Some(value)) => {
+                self.out
+                    .push_str(&format!("{}{}", -(value as i128) - 1, indicator));
+            }
+            (2, Some(len)) => {
+                let bytes = self.take(len as usize)?;
+                self.out
+                    .push_str(&format!("h'{}'{}", hex::encode(bytes), indicator));
+            }
+            (3, Some(len)) => {
+                let text = self.take(len as usize)?;
+                let text = std::str::from_utf8(text)
+                    .map_err(|e| DeserializeFailure::CustomError(e.to_string()))?;
+                self.out
+                    .push_str(&format!("{}{}", escape_text(text), indicator));
+            }
+            (2, None) | (3, None) => self.write_chunks(header.major)?,
+            (4, len) => self.write_array(schema, len, indicator, depth)?,
+            (5, len) => self.write_map(schema, len, indicator, depth)?,
+            (6, Some(tag)) => {
+                self.out.push_str(&format!("{}{}(", tag, indicator));
+                if let Some(name) = tag_name(tag) {
+                    self.out.push_str(&format!("/ {} / ", name));
+                }
+                self.write_item(schema.tagged_item(tag), depth)?;
+                self.out.push(')');
+            }
+            (7, _) => self.write_simple(&header)?,
+            _ => unreachable!("indefinite lengths are only read for major types 2 to 5 and 7"),
+        }
+        Ok(())
+    }
+
+    // chunks of an indefinite-length byte or text string
+    fn write_chunks(&mut self, major: u8) -> Result<(), DeserializeError> {
+        self.out.push_str("(_ ");
+        let mut first = true;
+        while !self.is_break() {
+            if !first {
+                self.out.push_str(", ");
+            }
+            first = false;
+            let chunk = self.read_header()?;
+            if chunk.major != major || chunk.argument.is_none() {
+                return Err(cbor_event::Error::InvalidIndefiniteString.into());
+            }
+            // a chunk is a definite-length string of the same major type
+            self.pos -= 1 + chunk.argument_size;
+            self.write_item(DiagnosticSchema::Any, 0)?;
+        }
+        self.take(1)?;
+        self.out.push(')');
+        Ok(())
+    }
+
+    fn write_array(
+        &mut self,
+        schema: DiagnosticSchema,
+        len: Option<u64>,
+        indicator: &str,
+        depth: usize,
+    ) -> Result<(), DeserializeError> {
+        self.out.push('[');
+        match len {
+            None => self.out.push('_'),
+            Some(_) => self.out.push_str(indicator),
+        }
+        let mut index = 0;
+        while !self.at_end(len, index)? {
+            if index > 0 {
+                self.out.push(',');
+            }
+            self.newline(depth + 1);
+            let (name, item_schema) = schema.array_item(index as usize, len);
+            if let Some(name) = name {
+                self.out.push_str(&format!("/ {} / ", name));
+            }
+            self.write_item(item_schema, depth + 1)?;
+            index += 1;
+        }
+        if index > 0 {
+            self.newline(depth);
+        } else if len.is_none() {
+            self.out.push(' ');
+        }
+        self.out.push(']');
+        Ok(())
+    }
+
+    fn write_map(
+        &mut self,
+        schema: DiagnosticSchema,
+        len: Option<u64>,
+        indicator: &str,
+        depth: usize,
+    ) -> Result<(), DeserializeError> {
+        self.out.push('{');
+        match len {
+            None => self.out.push('_'),
+            Some(_) => self.out.push_str(indicator),
+        }
+        let mut index = 0;
+        while !self.at_end(len, index)? {
+            if index > 0 {
+                self.out.push(',');
+            }
+            self.newline(depth + 1);
+            let (name, value_schema) = schema.map_entry(self.peek_unsigned());
+            self.write_item(DiagnosticSchema::Any, depth + 1)?;
+            if let Some(name) = name {
+                self.comment(name);
+            }
+            self.out.push_str(": ");
+            self.write_item(value_schema, depth + 1)?;
+            index += 1;
+        }
+        if index > 0 {
+            self.newline(depth);
+        } else if len.is_none() {
+            self.out.push(' ');
+        }
+        self.out.push('}');
+        Ok(())
+    }
+
+    // consumes the break of an indefinite-length array or map
+    fn at_end(&mut self, len: Option<u64>, index: u64) -> Result<bool, DeserializeError> {
+        match len {
+            Some(len) => {
+                if index < len && self.is_break() {
+                    return Err(DeserializeFailure::BreakInDefiniteLen.into());
+                }
+                Ok(index >= len)
+            }
+            None => {
+                if self.pos >= self.bytes.len() {
+                    return Err(DeserializeFailure::EndingBreakMissing.into());
+                }
+                if self.is_break() {
+                    self.pos += 1;
+                    return Ok(true);
+                }
+                Ok(false)
+            }
+        }
+    }
+
+    fn write_simple(&mut self, header: &ItemHeader) -> Result<(), DeserializeError> {
+        let argument = header.argument.unwrap_or_default();
+        let text = match header.info {
+            20 => "false".to_string(),
+            21 => "true".to_string(),
+            22 => "null".to_string(),
+            23 => "undefined".to_string(),
+            24 => format!("simple({})", argument),
+            25 => format!("{}_1", float_text(half_to_f64(argument as u16))),
+            26 => format!("{}_2", float_text(f32::from_bits(argument as u32) as f64)),
+            27 => format!("{}_3", float_text(f64::from_bits(argument))),
+            // a lone break, breaks ending indefinite lengths are consumed by their container
+            31 => return Err(DeserializeFailure::BreakInDefiniteLen.into()),
+            info => format!("simple({})", info),
+        };
+        self.out.push_str(&text);
+        Ok(())
+    }
+}
+
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn float_text(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value == f64::INFINITY {
+        "Infinity".to_string()
+    } else if value == f64::NEG_INFINITY {
+        "-Infinity".to_string()
+    } else if value.fract() == 0.0 && value.abs() < 1e16 {
+        format!("{:.1}", value)
+    } else {
+        format!("{}", value)
+    }
+}
+
+// IEEE 754 half-precision, RFC 8949 appendix D
+fn half_to_f64(half: u16) -> f64 {
+    let exponent = ((half >> 10) & 0x1f) as i32;
+    let mantissa = (half & 0x3ff) as f64;
+    let value = match exponent {
+        0 => mantissa * 2f64.powi(-24),
+        31 if mantissa == 0.0 => f64::INFINITY,
+        31 => f64::NAN,
+        _ => (mantissa + 1024.0) * 2f64.powi(exponent - 25),
+    };
+    if half & 0x8000 != 0 {
+        -value
+    } else {
+        value
+    }
+}
@@ -7,12 +7,20 @@ impl cbor_event::se::Serialize for FixedTransaction {
         serializer: &'se mut Serializer<W>,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
         serializer.write_array(cbor_event::Len::Len(4))?;
-        serializer.write_raw_bytes(&self.body_bytes_ref())?;
+        // the original bytes are only dropped when the caller asks for the canonical encoding
+        if is_canonical_encoding() {
+            self.body_ref().serialize(serializer)?;
+        } else {
+            serializer.write_raw_bytes(self.body_bytes_ref())?;
+        }
         self.witnesses_set_ref().serialize(serializer)?;
         serializer.write_special(CBORSpecial::Bool(self.is_valid()))?;
-        match &self.auxiliary_bytes_ref() {
-            Some(auxiliary_bytes) => serializer.write_raw_bytes(auxiliary_bytes)?,
-            None => serializer.write_special(CBORSpecial::Null)?,
+        match (&self.auxiliary_bytes_ref(), self.auxiliary_data_ref()) {
+            (_, Some(auxiliary_data)) if is_canonical_encoding() => {
+                auxiliary_data.serialize(serializer)?
+            }
+            (Some(auxiliary_bytes), _) => serializer.write_raw_bytes(auxiliary_bytes)?,
+            _ => serializer.write_special(CBORSpecial::Null)?,
         };
         Ok(serializer)
     }
@@ -75,6 +75,23 @@ impl cbor_event::se::Serialize for Transaction {
     fn serialize<'se, W: Write>(
         &self,
         serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serialize_preserving_encoding(self, serializer)
+    }
+}
+
+impl PreservesEncoding for Transaction {
+    fn original_encoding(&self) -> &OriginalEncoding {
+        &self.original_encoding
+    }
+
+    fn original_encoding_mut(&mut self) -> &mut OriginalEncoding {
+        &mut self.original_encoding
+    }
+
+    fn serialize_own_encoding<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
         serializer.write_array(cbor_event::Len::Len(4))?;
         self.body.serialize(serializer)?;
@@ -90,6 +107,14 @@ impl cbor_event::se::Serialize for Transaction {
 
 impl Deserialize for Transaction {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        deserialize_preserving_encoding(raw, Self::deserialize_own_encoding)
+    }
+}
+
+impl Transaction {
+    fn deserialize_own_encoding<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+    ) -> Result<Self, DeserializeError> {
         (|| -> Result<_, DeserializeError> {
             let len = raw.array()?;
             let ret = Self::deserialize_as_embedded_group(raw, len);
@@ -172,6 +197,7 @@ impl DeserializeEmbeddedGroup for Transaction {
             witness_set,
             is_valid,
             auxiliary_data,
+            original_encoding: OriginalEncoding::default(),
         })
     }
 }
@@ -201,7 +227,8 @@ impl Deserialize for TransactionOutputs {
                 if is_break_tag(raw, "TransactionOutputs")? {
                     break;
                 }
-                arr.push(TransactionOutput::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(TransactionOutput::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
@@ -215,7 +242,27 @@ impl cbor_event::se::Serialize for TransactionOutput {
         &self,
         serializer: &'se mut Serializer<W>,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
-        if self.has_plutus_data() || self.has_script_ref() {
+        serialize_preserving_encoding(self, serializer)
+    }
+}
+
+impl PreservesEncoding for TransactionOutput {
+    fn original_encoding(&self) -> &OriginalEncoding {
+        &self.original_encoding
+    }
+
+    fn original_encoding_mut(&mut self) -> &mut OriginalEncoding {
+        &mut self.original_encoding
+    }
+
+    fn serialize_own_encoding<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        if self.has_plutus_data()
+            || self.has_script_ref()
+            || self.serialization_format == Some(CborContainerType::Map)
+        {
             //post alonzo output
             let map_len = 2 + opt64(&self.plutus_data) + opt64(&self.script_ref);
             serializer.write_map(cbor_event::Len::Len(map_len))?;
@@ -249,6 +296,14 @@ impl cbor_event::se::Serialize for TransactionOutput {
 // is when it's done via TransactionOutputs
 impl Deserialize for TransactionOutput {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        deserialize_preserving_encoding(raw, Self::deserialize_own_encoding)
+    }
+}
+
+impl TransactionOutput {
+    fn deserialize_own_encoding<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+    ) -> Result<Self, DeserializeError> {
         (|| -> Result<_, DeserializeError> {
             match raw.cbor_type()? {
                 CBORType::Array => {
@@ -324,6 +379,7 @@ impl DeserializeEmbeddedGroup for TransactionOutput {
             plutus_data: data_hash,
             script_ref: None,
             serialization_format: Some(CborContainerType::Array),
+            original_encoding: OriginalEncoding::default(),
         })
     }
 }
@@ -417,6 +473,7 @@ fn deserialize_as_postalonzo_output<R: BufRead + Seek>(
             plutus_data: data,
             script_ref,
             serialization_format: Some(CborContainerType::Map),
+            original_encoding: OriginalEncoding::default(),
         })
     })()
     .map_err(|e| e.annotate("TransactionOutput"))
@@ -1022,7 +1079,8 @@ impl Deserialize for RewardAddresses {
                 if is_break_tag(raw, "RewardAddresses")? {
                     break;
                 }
-                arr.push(RewardAddress::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(RewardAddress::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
@@ -1155,7 +1213,8 @@ impl Deserialize for GenesisHashes {
                 if is_break_tag(raw, "GenesisHashes")? {
                     break;
                 }
-                arr.push(GenesisHash::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(GenesisHash::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
@@ -1189,7 +1248,8 @@ impl Deserialize for ScriptHashes {
                 if is_break_tag(raw, "ScriptHashes")? {
                     break;
                 }
-                arr.push(ScriptHash::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(ScriptHash::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
@@ -1382,7 +1442,8 @@ impl Deserialize for AssetNames {
                 if is_break_tag(raw, "AssetNames")? {
                     break;
                 }
-                arr.push(AssetName::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(AssetName::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
@@ -1437,6 +1498,23 @@ impl cbor_event::se::Serialize for MultiAsset {
     fn serialize<'se, W: Write>(
         &self,
         serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serialize_preserving_encoding(self, serializer)
+    }
+}
+
+impl PreservesEncoding for MultiAsset {
+    fn original_encoding(&self) -> &OriginalEncoding {
+        &self.1
+    }
+
+    fn original_encoding_mut(&mut self) -> &mut OriginalEncoding {
+        &mut self.1
+    }
+
+    fn serialize_own_encoding<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
         serializer.write_map(cbor_event::Len::Len(self.0.len() as u64))?;
         for (key, value) in &self.0 {
@@ -1449,6 +1527,14 @@ impl cbor_event::se::Serialize for MultiAsset {
 
 impl Deserialize for MultiAsset {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        deserialize_preserving_encoding(raw, Self::deserialize_own_encoding)
+    }
+}
+
+impl MultiAsset {
+    fn deserialize_own_encoding<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+    ) -> Result<Self, DeserializeError> {
         let mut table = std::collections::BTreeMap::new();
         (|| -> Result<_, DeserializeError> {
             let len = raw.map()?;
@@ -1471,7 +1557,7 @@ impl Deserialize for MultiAsset {
             Ok(())
         })()
         .map_err(|e| e.annotate("MultiAsset"))?;
-        Ok(Self(table))
+        Ok(Self(table, OriginalEncoding::default()))
     }
 }
 
@@ -1521,6 +1607,23 @@ impl cbor_event::se::Serialize for Mint {
     fn serialize<'se, W: Write>(
         &self,
         serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serialize_preserving_encoding(self, serializer)
+    }
+}
+
+impl PreservesEncoding for Mint {
+    fn original_encoding(&self) -> &OriginalEncoding {
+        &self.1
+    }
+
+    fn original_encoding_mut(&mut self) -> &mut OriginalEncoding {
+        &mut self.1
+    }
+
+    fn serialize_own_encoding<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
         serializer.write_map(cbor_event::Len::Len(self.0.len() as u64))?;
         for (key, value) in &self.0 {
@@ -1533,6 +1636,14 @@ impl cbor_event::se::Serialize for Mint {
 
 impl Deserialize for Mint {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        deserialize_preserving_encoding(raw, Self::deserialize_own_encoding)
+    }
+}
+
+impl Mint {
+    fn deserialize_own_encoding<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+    ) -> Result<Self, DeserializeError> {
         let mut mints = Vec::new();
         (|| -> Result<_, DeserializeError> {
             let len = raw.map()?;
@@ -1550,7 +1661,7 @@ impl Deserialize for Mint {
             Ok(())
         })()
         .map_err(|e| e.annotate("Mint"))?;
-        Ok(Self(mints))
+        Ok(Self(mints, OriginalEncoding::default()))
     }
 }
 
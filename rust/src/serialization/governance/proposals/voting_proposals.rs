@@ -29,7 +29,8 @@ impl Deserialize for VotingProposals {
                 if is_break_tag(raw, "VotingProposals")? {
                     break;
                 }
-                arr.push(VotingProposal::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(VotingProposal::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
@@ -20,3 +20,30 @@ pub(crate) enum CertificateIndexNames {
     DRepDeregistration = 17,
     DRepUpdate = 18,
 }
+
+impl CertificateIndexNames {
+    /// Name of the field in the CDDL of the ledger
+    pub(crate) fn cddl_name(&self) -> &'static str {
+        match self {
+            CertificateIndexNames::StakeRegistrationLegacy => "stake_registration",
+            CertificateIndexNames::StakeDeregistrationLegacy => "stake_deregistration",
+            CertificateIndexNames::StakeDelegation => "stake_delegation",
+            CertificateIndexNames::PoolRegistration => "pool_registration",
+            CertificateIndexNames::PoolRetirement => "pool_retirement",
+            CertificateIndexNames::GenesisKeyDelegation => "genesis_key_delegation",
+            CertificateIndexNames::MoveInstantaneousRewardsCert => "move_instantaneous_rewards_cert",
+            CertificateIndexNames::StakeRegistrationConway => "reg_cert",
+            CertificateIndexNames::StakeDeregistrationConway => "unreg_cert",
+            CertificateIndexNames::VoteDelegation => "vote_deleg_cert",
+            CertificateIndexNames::StakeAndVoteDelegation => "stake_vote_deleg_cert",
+            CertificateIndexNames::StakeRegistrationAndDelegation => "stake_reg_deleg_cert",
+            CertificateIndexNames::VoteRegistrationAndDelegation => "vote_reg_deleg_cert",
+            CertificateIndexNames::StakeVoteRegistrationAndDelegation => "stake_vote_reg_deleg_cert",
+            CertificateIndexNames::CommitteeHotAuth => "auth_committee_hot_cert",
+            CertificateIndexNames::CommitteeColdResign => "resign_committee_cold_cert",
+            CertificateIndexNames::DRepRegistration => "reg_drep_cert",
+            CertificateIndexNames::DRepDeregistration => "unreg_drep_cert",
+            CertificateIndexNames::DRepUpdate => "update_drep_cert",
+        }
+    }
+}
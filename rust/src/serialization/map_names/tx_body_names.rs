@@ -17,4 +17,37 @@ pub(crate) enum TxBodyNames {
     CollateralReturn = 16,
     TotalCollateral = 17,
     ReferenceInputs = 18,
+    VotingProcedures = 19,
+    ProposalProcedures = 20,
+    CurrentTreasuryValue = 21,
+    Donation = 22,
+}
+
+impl TxBodyNames {
+    /// Name of the field in the CDDL of the ledger
+    pub(crate) fn cddl_name(&self) -> &'static str {
+        match self {
+            TxBodyNames::Inputs => "inputs",
+            TxBodyNames::Outputs => "outputs",
+            TxBodyNames::Fee => "fee",
+            TxBodyNames::Ttl => "ttl",
+            TxBodyNames::Certs => "certificates",
+            TxBodyNames::Withdrawals => "withdrawals",
+            TxBodyNames::Update => "update",
+            TxBodyNames::AuxiliaryDataHash => "auxiliary_data_hash",
+            TxBodyNames::ValidityStartInterval => "validity_interval_start",
+            TxBodyNames::Mint => "mint",
+            TxBodyNames::ScriptDataHash => "script_data_hash",
+            TxBodyNames::Collateral => "collateral_inputs",
+            TxBodyNames::RequiredSigners => "required_signers",
+            TxBodyNames::NetworkId => "network_id",
+            TxBodyNames::CollateralReturn => "collateral_return",
+            TxBodyNames::TotalCollateral => "total_collateral",
+            TxBodyNames::ReferenceInputs => "reference_inputs",
+            TxBodyNames::VotingProcedures => "voting_procedures",
+            TxBodyNames::ProposalProcedures => "proposal_procedures",
+            TxBodyNames::CurrentTreasuryValue => "current_treasury_value",
+            TxBodyNames::Donation => "donation",
+        }
+    }
 }
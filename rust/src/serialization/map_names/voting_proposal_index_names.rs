@@ -8,3 +8,18 @@ pub(crate) enum VotingProposalIndexNames {
     NewConstitutionAction = 5,
     InfoAction = 6,
 }
+
+impl VotingProposalIndexNames {
+    /// Name of the field in the CDDL of the ledger
+    pub(crate) fn cddl_name(&self) -> &'static str {
+        match self {
+            VotingProposalIndexNames::ParameterChangeAction => "parameter_change_action",
+            VotingProposalIndexNames::HardForkInitiationAction => "hard_fork_initiation_action",
+            VotingProposalIndexNames::TreasuryWithdrawalsAction => "treasury_withdrawals_action",
+            VotingProposalIndexNames::NoConfidenceAction => "no_confidence",
+            VotingProposalIndexNames::UpdateCommitteeAction => "update_committee",
+            VotingProposalIndexNames::NewConstitutionAction => "new_constitution",
+            VotingProposalIndexNames::InfoAction => "info_action",
+        }
+    }
+}
@@ -71,7 +71,8 @@ impl Deserialize for MetadataList {
                 if is_break_tag(raw, "MetadataList")? {
                     break;
                 }
-                arr.push(TransactionMetadatum::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(TransactionMetadatum::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
@@ -161,7 +162,8 @@ impl Deserialize for TransactionMetadatumLabels {
                 if is_break_tag(raw, "TransactionMetadatumLabels")? {
                     break;
                 }
-                arr.push(TransactionMetadatumLabel::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(TransactionMetadatumLabel::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
@@ -216,6 +218,23 @@ impl cbor_event::se::Serialize for AuxiliaryData {
     fn serialize<'se, W: Write>(
         &self,
         serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serialize_preserving_encoding(self, serializer)
+    }
+}
+
+impl PreservesEncoding for AuxiliaryData {
+    fn original_encoding(&self) -> &OriginalEncoding {
+        &self.original_encoding
+    }
+
+    fn original_encoding_mut(&mut self) -> &mut OriginalEncoding {
+        &mut self.original_encoding
+    }
+
+    fn serialize_own_encoding<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
         // we still serialize using the shelley-mary era format as it is still supported
         // and it takes up less space on-chain so this should be better for scaling.
@@ -273,6 +292,14 @@ impl cbor_event::se::Serialize for AuxiliaryData {
 
 impl Deserialize for AuxiliaryData {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        deserialize_preserving_encoding(raw, Self::deserialize_own_encoding)
+    }
+}
+
+impl AuxiliaryData {
+    fn deserialize_own_encoding<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+    ) -> Result<Self, DeserializeError> {
         (|| -> Result<_, DeserializeError> {
             match raw.cbor_type()? {
                 // alonzo format
@@ -412,6 +439,7 @@ impl Deserialize for AuxiliaryData {
                         native_scripts,
                         plutus_scripts,
                         prefer_alonzo_format: true,
+                        original_encoding: OriginalEncoding::default(),
                     })
                 }
                 // shelley mary format (still valid for alonzo)
@@ -439,6 +467,7 @@ impl Deserialize for AuxiliaryData {
                         native_scripts: Some(native_scripts),
                         plutus_scripts: None,
                         prefer_alonzo_format: false,
+                        original_encoding: OriginalEncoding::default(),
                     })
                 }
                 // shelley pre-mary format (still valid for alonzo + mary)
@@ -450,6 +479,7 @@ impl Deserialize for AuxiliaryData {
                     native_scripts: None,
                     plutus_scripts: None,
                     prefer_alonzo_format: false,
+                    original_encoding: OriginalEncoding::default(),
                 }),
                 _ => return Err(DeserializeFailure::NoVariantMatched)?,
             }
@@ -11,7 +11,16 @@ mod certificates;
 mod governance;
 mod utils;
 mod fixed_tx;
+mod cip8;
+mod multi_era_tx;
+mod original_encoding;
+pub(crate) use original_encoding::*;
+mod canonical_cbor;
+pub(crate) use canonical_cbor::*;
+mod diagnostic;
+pub(crate) use diagnostic::*;
 use utils::*;
+pub(crate) use utils::deserialize_item;
 mod metadata;
 mod transaction_body;
 mod protocol_param_update;
@@ -27,4 +36,5 @@ mod native_scripts;
 mod numeric;
 mod script_ref;
 mod tx_input;
-mod block;
\ No newline at end of file
+mod block;
+mod byron;
\ No newline at end of file
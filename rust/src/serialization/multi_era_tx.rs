@@ -0,0 +1,11 @@
+use crate::*;
+
+// the era is not part of the encoding, the transaction is written with its original bytes
+impl cbor_event::se::Serialize for MultiEraTransaction {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        self.transaction.serialize(serializer)
+    }
+}
@@ -5,6 +5,23 @@ impl cbor_event::se::Serialize for NativeScript {
     fn serialize<'se, W: Write>(
         &self,
         serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serialize_preserving_encoding(self, serializer)
+    }
+}
+
+impl PreservesEncoding for NativeScript {
+    fn original_encoding(&self) -> &OriginalEncoding {
+        &self.1
+    }
+
+    fn original_encoding_mut(&mut self) -> &mut OriginalEncoding {
+        &mut self.1
+    }
+
+    fn serialize_own_encoding<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
         self.0.serialize(serializer)
     }
@@ -12,7 +29,18 @@ impl cbor_event::se::Serialize for NativeScript {
 
 impl Deserialize for NativeScript {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
-        Ok(Self(NativeScriptEnum::deserialize(raw)?))
+        deserialize_preserving_encoding(raw, Self::deserialize_own_encoding)
+    }
+}
+
+impl NativeScript {
+    fn deserialize_own_encoding<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+    ) -> Result<Self, DeserializeError> {
+        Ok(Self(
+            NativeScriptEnum::deserialize(raw)?,
+            OriginalEncoding::default(),
+        ))
     }
 }
 
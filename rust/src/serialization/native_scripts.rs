@@ -50,7 +50,8 @@ impl Deserialize for NativeScripts {
                 if is_break_tag(raw, "NativeScripts")? {
                     break;
                 }
-                arr.push(NativeScript::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(NativeScript::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
@@ -1,5 +1,5 @@
 use crate::*;
-use crate::serialization::utils::read_nint;
+use crate::serialization::utils::{deserialize_item, read_nint, write_nint};
 
 impl Serialize for BigInt {
     fn serialize<'se, W: Write>(
@@ -16,14 +16,15 @@ impl Serialize for BigInt {
                     serializer.write_unsigned_integer(*u64_digits.first().unwrap())
                 }
                 // nint
-                num_bigint::Sign::Minus => serializer
-                    .write_negative_integer(-(*u64_digits.first().unwrap() as i128) as i64),
+                num_bigint::Sign::Minus => {
+                    write_nint(serializer, -(*u64_digits.first().unwrap() as i128))
+                }
             },
             _ => {
                 // Small edge case: nint's minimum is -18446744073709551616 but in this bigint lib
                 // that takes 2 u64 bytes so we put that as a special case here:
                 if sign == num_bigint::Sign::Minus && u64_digits == vec![0, 1] {
-                    serializer.write_negative_integer(-18446744073709551616i128 as i64)
+                    write_nint(serializer, -18446744073709551616i128)
                 } else {
                     let (sign, bytes) = self.0.to_bytes_be();
                     match sign {
@@ -56,7 +57,7 @@ impl Serialize for BigInt {
 
 impl Deserialize for BigInt {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
-        (|| -> Result<_, DeserializeError> {
+        deserialize_item(raw, |raw| {
             match raw.cbor_type()? {
                 // bigint
                 CBORType::Tag => {
@@ -97,7 +98,7 @@ impl Deserialize for BigInt {
                 CBORType::NegativeInteger => Ok(Self(num_bigint::BigInt::from(read_nint(raw)?))),
                 _ => return Err(DeserializeFailure::NoVariantMatched.into()),
             }
-        })()
+        })
             .map_err(|e| e.annotate("BigInt"))
     }
 }
\ No newline at end of file
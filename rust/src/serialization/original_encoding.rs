@@ -0,0 +1,123 @@
+use crate::serialization::utils::deserilized_with_orig_bytes;
+use crate::*;
+use std::cell::Cell;
+
+thread_local! {
+    static CANONICAL_ENCODING: Cell<bool> = const { Cell::new(false) };
+}
+
+// restores the previous encoding mode even if the serialization panics
+struct CanonicalEncodingGuard(bool);
+
+impl Drop for CanonicalEncodingGuard {
+    fn drop(&mut self) {
+        CANONICAL_ENCODING.with(|canonical| canonical.set(self.0));
+    }
+}
+
+/// Runs {f} with the original encodings of decoded values ignored,
+/// every value is written with this library's own encoding (definite lengths, minimal integer widths).
+pub(crate) fn with_canonical_encoding<T>(f: impl FnOnce() -> T) -> T {
+    let _guard =
+        CanonicalEncodingGuard(CANONICAL_ENCODING.with(|canonical| canonical.replace(true)));
+    f()
+}
+
+pub(crate) fn is_canonical_encoding() -> bool {
+    CANONICAL_ENCODING.with(|canonical| canonical.get())
+}
+
+/// Original bytes of a decoded value, only kept when they differ from this library's own encoding of the value.
+/// The hash of the own encoding at decode time tells if the value was changed since it was decoded,
+/// a changed value is written with the own encoding.
+/// It never takes part in comparisons or hashing of the value it belongs to.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct OriginalEncoding(Option<Box<RecordedEncoding>>);
+
+#[derive(Clone, Debug)]
+struct RecordedEncoding {
+    original_bytes: Vec<u8>,
+    own_encoding_hash: [u8; 32],
+}
+
+impl PartialEq for OriginalEncoding {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for OriginalEncoding {}
+
+impl PartialOrd for OriginalEncoding {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OriginalEncoding {
+    fn cmp(&self, _other: &Self) -> std::cmp::Ordering {
+        std::cmp::Ordering::Equal
+    }
+}
+
+impl std::hash::Hash for OriginalEncoding {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
+// Types that keep the original encoding they were decoded from.
+// Their Serialize impl goes through serialize_preserving_encoding
+// and their Deserialize impl through deserialize_preserving_encoding.
+// Only Transaction, TransactionBody, TransactionWitnessSet, AuxiliaryData, TransactionOutput, Value,
+// MultiAsset, Mint, Certificates, Redeemers, NativeScript and PlutusScript implement it, PlutusData
+// keeps its original bytes on its own. Every other type, like a standalone Certificate,
+// GovernanceAction, TransactionInputs, Withdrawals or the block header, is written with this
+// library's own encoding. Its bytes are only kept as part of one of these types that wasn't changed.
+pub(crate) trait PreservesEncoding: Sized {
+    fn original_encoding(&self) -> &OriginalEncoding;
+
+    fn original_encoding_mut(&mut self) -> &mut OriginalEncoding;
+
+    fn serialize_own_encoding<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>>;
+}
+
+fn own_encoding<T: PreservesEncoding>(value: &T) -> cbor_event::Result<Vec<u8>> {
+    let mut serializer = Serializer::new_vec();
+    value.serialize_own_encoding(&mut serializer)?;
+    Ok(serializer.finalize())
+}
+
+pub(crate) fn serialize_preserving_encoding<'se, T: PreservesEncoding, W: Write>(
+    value: &T,
+    serializer: &'se mut Serializer<W>,
+) -> cbor_event::Result<&'se mut Serializer<W>> {
+    match &value.original_encoding().0 {
+        Some(recorded) if !is_canonical_encoding() => {
+            let own_bytes = own_encoding(value)?;
+            if blake2b256(&own_bytes) == recorded.own_encoding_hash {
+                serializer.write_raw_bytes(&recorded.original_bytes)
+            } else {
+                serializer.write_raw_bytes(&own_bytes)
+            }
+        }
+        _ => value.serialize_own_encoding(serializer),
+    }
+}
+
+pub(crate) fn deserialize_preserving_encoding<T: PreservesEncoding, R: BufRead + Seek>(
+    raw: &mut Deserializer<R>,
+    deserializer: fn(&mut Deserializer<R>) -> Result<T, DeserializeError>,
+) -> Result<T, DeserializeError> {
+    let (mut value, original_bytes) =
+        deserilized_with_orig_bytes(raw, deserializer).map_err(|e| e.locate(raw))?;
+    let own_bytes = own_encoding(&value)?;
+    if own_bytes != original_bytes {
+        *value.original_encoding_mut() = OriginalEncoding(Some(Box::new(RecordedEncoding {
+            original_bytes,
+            own_encoding_hash: blake2b256(&own_bytes),
+        })));
+    }
+    Ok(value)
+}
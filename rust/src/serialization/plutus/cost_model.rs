@@ -26,7 +26,8 @@ impl Deserialize for CostModel {
                 if is_break_tag(raw, "CostModel")? {
                     break;
                 }
-                arr.push(Int::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(Int::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
@@ -26,7 +26,8 @@ impl Deserialize for Languages {
                 if is_break_tag(raw, "Languages")? {
                     break;
                 }
-                arr.push(Language::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(Language::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
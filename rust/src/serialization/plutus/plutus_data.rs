@@ -188,9 +188,11 @@ impl cbor_event::se::Serialize for PlutusData {
         &self,
         serializer: &'se mut Serializer<W>,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        // datum hashes and the script data hash are taken over the original bytes,
+        // so they are kept even when the canonical encoding is asked for
         match &self.original_bytes {
             Some(bytes) => serializer.write_raw_bytes(bytes),
-            None => self.datum.serialize(serializer),
+            _ => self.datum.serialize(serializer),
         }
     }
 }
@@ -282,7 +284,8 @@ impl Deserialize for PlutusList {
                 if is_break_tag(raw, "PlutusList")? {
                     break;
                 }
-                arr.push(PlutusData::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(PlutusData::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(len)
         })()
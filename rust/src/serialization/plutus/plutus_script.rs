@@ -4,6 +4,23 @@ impl cbor_event::se::Serialize for PlutusScript {
     fn serialize<'se, W: Write>(
         &self,
         serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serialize_preserving_encoding(self, serializer)
+    }
+}
+
+impl PreservesEncoding for PlutusScript {
+    fn original_encoding(&self) -> &OriginalEncoding {
+        &self.original_encoding
+    }
+
+    fn original_encoding_mut(&mut self) -> &mut OriginalEncoding {
+        &mut self.original_encoding
+    }
+
+    fn serialize_own_encoding<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
         serializer.write_bytes(&self.bytes)
     }
@@ -11,7 +28,7 @@ impl cbor_event::se::Serialize for PlutusScript {
 
 impl Deserialize for PlutusScript {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
-        Ok(Self::new(raw.bytes()?))
+        deserialize_preserving_encoding(raw, |raw| Ok(Self::new(raw.bytes()?)))
     }
 }
 
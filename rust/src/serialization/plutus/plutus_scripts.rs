@@ -61,7 +61,8 @@ impl Deserialize for PlutusScripts {
                 if is_break_tag(raw, "PlutusScripts")? {
                     break;
                 }
-                arr.push(PlutusScript::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(PlutusScript::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
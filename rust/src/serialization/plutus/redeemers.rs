@@ -5,6 +5,23 @@ impl Serialize for Redeemers {
     fn serialize<'se, W: Write>(
         &self,
         serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serialize_preserving_encoding(self, serializer)
+    }
+}
+
+impl PreservesEncoding for Redeemers {
+    fn original_encoding(&self) -> &OriginalEncoding {
+        &self.original_encoding
+    }
+
+    fn original_encoding_mut(&mut self) -> &mut OriginalEncoding {
+        &mut self.original_encoding
+    }
+
+    fn serialize_own_encoding<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
         match self.get_container_type() {
             CborContainerType::Map => {
@@ -26,6 +43,14 @@ impl Serialize for Redeemers {
 
 impl Deserialize for Redeemers {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        deserialize_preserving_encoding(raw, Self::deserialize_own_encoding)
+    }
+}
+
+impl Redeemers {
+    fn deserialize_own_encoding<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+    ) -> Result<Self, DeserializeError> {
         (|| -> Result<Self, DeserializeError> {
             let cbor_type = raw.cbor_type()?;
             match cbor_type {
@@ -53,6 +78,7 @@ impl Redeemers {
         Ok(Self {
             redeemers: arr,
             serialization_format: Some(CborContainerType::Map),
+            original_encoding: OriginalEncoding::default(),
         })
     }
 
@@ -71,6 +97,7 @@ impl Redeemers {
         Ok(Self {
             redeemers: arr,
             serialization_format: Some(CborContainerType::Array),
+            original_encoding: OriginalEncoding::default(),
         })
     }
 }
\ No newline at end of file
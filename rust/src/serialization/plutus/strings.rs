@@ -26,7 +26,8 @@ impl Deserialize for Strings {
                 if is_break_tag(raw, "Strings")? {
                     break;
                 }
-                arr.push(String::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(String::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
@@ -26,7 +26,7 @@ macro_rules! from_bytes {
     ($name:ident) => {
         from_bytes!($name, bytes, {
             let mut raw = Deserializer::from(std::io::Cursor::new(bytes));
-            Self::deserialize(&mut raw)
+            Self::deserialize(&mut raw).map_err(|e| e.locate(&mut raw))
         });
     };
 }
@@ -49,6 +49,46 @@ macro_rules! to_bytes {
     };
 }
 
+/// Adds to_canonical_bytes() for a type which keeps the original encoding it was decoded from
+#[macro_export]
+macro_rules! to_canonical_bytes {
+    ($name:ident) => {
+        #[wasm_bindgen]
+        impl $name {
+            /// Unlike to_bytes() the original encoding of decoded values is not reproduced,
+            /// the value is written in the canonical CBOR format of RFC 7049 section 3.9 required by CIP-21:
+            /// definite lengths, minimal integer widths and map keys sorted by length, then bytewise.
+            /// Map keys are only sorted in transaction bodies and auxiliary data,
+            /// Plutus data keeps its original bytes so datum hashes and the script data hash don't change.
+            pub fn to_canonical_bytes(&self) -> Result<Vec<u8>, JsError> {
+                $crate::serialization::CanonicalEncoding::canonical_bytes(self)
+                    .map_err(|e| JsError::from_str(&e.to_string()))
+            }
+        }
+    };
+}
+
+/// Adds to_annotated_diagnostic() for a type whose CBOR has the shape of {schema}
+#[macro_export]
+macro_rules! to_annotated_diagnostic {
+    ($name:ident, $schema:ident) => {
+        #[wasm_bindgen]
+        impl $name {
+            /// Writes the CBOR of to_bytes() in the diagnostic notation of RFC 8949, one item per line.
+            /// Map keys and array items are annotated with their CDDL names in comments,
+            /// indefinite lengths are marked with `_` and integers or lengths encoded longer than needed
+            /// get the `_0`-`_3` encoding indicators.
+            pub fn to_annotated_diagnostic(&self) -> Result<String, JsError> {
+                $crate::serialization::to_annotated_diagnostic(
+                    &self.to_bytes(),
+                    $crate::serialization::DiagnosticSchema::$schema,
+                )
+                .map_err(|e| JsError::from_str(&e.to_string()))
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! from_hex {
     // Custom from_bytes() code
@@ -75,7 +115,7 @@ macro_rules! from_hex {
     ($name:ident) => {
         from_hex!($name, hex_str, {
             let mut raw = Deserializer::from(std::io::Cursor::new(hex::decode(hex_str).unwrap()));
-            Self::deserialize(&mut raw)
+            Self::deserialize(&mut raw).map_err(|e| e.locate(&mut raw))
         });
     };
 }
@@ -1,3 +1,4 @@
+use crate::serialization::utils::deserialize_item;
 use crate::*;
 // we use the cbor_event::Serialize trait directly
 
@@ -19,7 +20,7 @@ pub trait Deserialize {
 // auto-implement for all cbor_event Deserialize implementors
 impl<T: cbor_event::de::Deserialize> Deserialize for T {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<T, DeserializeError> {
-        T::deserialize(raw).map_err(|e| DeserializeError::from(e))
+        deserialize_item(raw, |raw| T::deserialize(raw).map_err(DeserializeError::from))
     }
 }
 
@@ -48,14 +49,17 @@ impl<T: Deserialize> DeserializeNullable for T {
     where
         Self: Sized,
     {
-        if raw.cbor_type()? == CBORType::Special {
-            if raw.special()? != CBORSpecial::Null {
-                return Err(DeserializeFailure::ExpectedNull.into());
+        let value = (|| -> Result<_, DeserializeError> {
+            if raw.cbor_type()? == CBORType::Special {
+                if raw.special()? != CBORSpecial::Null {
+                    return Err(DeserializeFailure::ExpectedNull.into());
+                }
+                Ok(None)
+            } else {
+                Ok(Some(T::deserialize(raw)?))
             }
-            Ok(None)
-        } else {
-            Ok(Some(T::deserialize(raw)?))
-        }
+        })();
+        value.map_err(|e| e.locate(raw))
     }
 }
 
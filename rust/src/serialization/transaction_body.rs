@@ -4,6 +4,23 @@ impl cbor_event::se::Serialize for TransactionBody {
     fn serialize<'se, W: Write>(
         &self,
         serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serialize_preserving_encoding(self, serializer)
+    }
+}
+
+impl PreservesEncoding for TransactionBody {
+    fn original_encoding(&self) -> &OriginalEncoding {
+        &self.original_encoding
+    }
+
+    fn original_encoding_mut(&mut self) -> &mut OriginalEncoding {
+        &mut self.original_encoding
+    }
+
+    fn serialize_own_encoding<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
         serializer.write_map(cbor_event::Len::Len(
             3 + opt64(&self.ttl)
@@ -125,6 +142,14 @@ impl cbor_event::se::Serialize for TransactionBody {
 
 impl Deserialize for TransactionBody {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        deserialize_preserving_encoding(raw, Self::deserialize_own_encoding)
+    }
+}
+
+impl TransactionBody {
+    fn deserialize_own_encoding<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+    ) -> Result<Self, DeserializeError> {
         (|| -> Result<_, DeserializeError> {
             let len = raw.map()?;
             let mut read_len = CBORReadLen::new(len);
@@ -471,6 +496,7 @@ impl Deserialize for TransactionBody {
                 voting_proposals,
                 donation,
                 current_treasury_value,
+                original_encoding: OriginalEncoding::default(),
             })
         })()
             .map_err(|e| e.annotate("TransactionBody"))
@@ -28,7 +28,8 @@ impl Deserialize for TransactionInputs {
                 if is_break_tag(raw, "TransactionInputs")? {
                     break;
                 }
-                arr.push(TransactionInput::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(TransactionInput::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
@@ -1,3 +1,4 @@
+use std::convert::TryFrom;
 use std::io::SeekFrom;
 use crate::*;
 
@@ -21,6 +22,15 @@ pub(crate) fn read_nint<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<
     }
 }
 
+/// Writes a CBOR nint, `write_negative_integer` of `cbor_event` takes an i64
+/// and can't write values below i64::MIN
+pub(crate) fn write_nint<W: Write>(
+    serializer: &mut Serializer<W>,
+    value: i128,
+) -> cbor_event::Result<&mut Serializer<W>> {
+    serializer.write_negative_integer_sz(value, cbor_event::Sz::canonical((-value - 1) as u64))
+}
+
 pub(super) fn deserialize_and_check_index<R: BufRead + Seek>(
     raw: &mut Deserializer<R>,
     desired_index: Option<u64>,
@@ -164,4 +174,105 @@ pub(crate) fn deserilized_with_orig_bytes<R: BufRead + Seek, T>(
     let original_bytes = raw.as_mut_ref().fill_buf().unwrap()[..bytes_read].to_vec();
     raw.as_mut_ref().seek(SeekFrom::Start(after)).unwrap();
     Ok((value, original_bytes))
-}
\ No newline at end of file
+}
+
+// decodes one item with {deserializer}, a failure is located at the start of the item
+// even if the item was read before it was rejected
+pub(crate) fn deserialize_item<R: BufRead + Seek, T>(
+    raw: &mut Deserializer<R>,
+    deserializer: impl FnOnce(&mut Deserializer<R>) -> Result<T, DeserializeError>,
+) -> Result<T, DeserializeError> {
+    // this unwrap is fine since we're seeking the current position
+    let start = raw.as_mut_ref().stream_position().unwrap();
+    deserializer(raw).map_err(|e| e.locate_item(raw, start))
+}
+
+// skips one CBOR item without decoding it, nested items included
+pub(crate) fn skip_cbor_item<R: BufRead + Seek>(
+    raw: &mut Deserializer<R>,
+) -> Result<(), DeserializeError> {
+    match raw.cbor_type()? {
+        CBORType::UnsignedInteger => {
+            raw.unsigned_integer()?;
+        }
+        CBORType::NegativeInteger => {
+            read_nint(raw)?;
+        }
+        CBORType::Bytes | CBORType::Text => match raw.cbor_len()? {
+            (cbor_event::Len::Len(len), len_sz) => {
+                // the length comes from the input, it's checked against the bytes left before skipping
+                let left = bytes_left(raw)?;
+                let item_len = usize::try_from(len)
+                    .ok()
+                    .and_then(|len| len.checked_add(1 + len_sz));
+                match item_len {
+                    Some(item_len) if item_len <= left => raw.advance(item_len)?,
+                    _ => {
+                        return Err(cbor_event::Error::NotEnough(
+                            left,
+                            item_len.unwrap_or(usize::MAX),
+                        )
+                        .into())
+                    }
+                }
+            }
+            (cbor_event::Len::Indefinite, _) => {
+                raw.advance(1)?;
+                skip_cbor_items(raw, cbor_event::Len::Indefinite)?;
+            }
+        },
+        CBORType::Array => {
+            let len = raw.array()?;
+            skip_cbor_items(raw, len)?;
+        }
+        CBORType::Map => {
+            let len = match raw.map()? {
+                cbor_event::Len::Len(n) => {
+                    cbor_event::Len::Len(n.checked_mul(2).ok_or_else(|| {
+                        DeserializeFailure::CustomError(format!("Map length {} is too big", n))
+                    })?)
+                }
+                cbor_event::Len::Indefinite => cbor_event::Len::Indefinite,
+            };
+            skip_cbor_items(raw, len)?;
+        }
+        CBORType::Tag => {
+            raw.tag()?;
+            skip_cbor_item(raw)?;
+        }
+        CBORType::Special => {
+            if raw.special()? == CBORSpecial::Break {
+                return Err(DeserializeFailure::BreakInDefiniteLen.into());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn bytes_left<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<usize, DeserializeError> {
+    let reader = raw.as_mut_ref();
+    let io_error = |e: std::io::Error| DeserializeFailure::IoError(e.to_string());
+    let position = reader.stream_position().map_err(io_error)?;
+    let end = reader.seek(SeekFrom::End(0)).map_err(io_error)?;
+    reader.seek(SeekFrom::Start(position)).map_err(io_error)?;
+    Ok(usize::try_from(end.saturating_sub(position)).unwrap_or(usize::MAX))
+}
+
+fn skip_cbor_items<R: BufRead + Seek>(
+    raw: &mut Deserializer<R>,
+    len: cbor_event::Len,
+) -> Result<(), DeserializeError> {
+    match len {
+        cbor_event::Len::Len(n) => {
+            for _ in 0..n {
+                skip_cbor_item(raw)?;
+            }
+        }
+        cbor_event::Len::Indefinite => {
+            while !is_break_tag(raw, "skip_cbor_items")? {
+                skip_cbor_item(raw)?;
+            }
+        }
+    }
+    Ok(())
+}
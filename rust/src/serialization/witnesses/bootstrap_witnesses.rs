@@ -39,7 +39,8 @@ impl Deserialize for BootstrapWitnesses {
                     assert_eq!(raw.special()?, cbor_event::Special::Break);
                     break;
                 }
-                arr.push(BootstrapWitness::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(BootstrapWitness::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
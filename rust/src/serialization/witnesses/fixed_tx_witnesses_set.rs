@@ -3,10 +3,12 @@ use cbor_event::de::Deserializer;
 use cbor_event::se::Serializer;
 use crate::protocol_types::{Deserialize, FixedTxWitnessesSet};
 use crate::{DeserializeError};
+use crate::serialization::is_canonical_encoding;
 
 impl cbor_event::se::Serialize for FixedTxWitnessesSet {
     fn serialize<'a, W: Write + Sized>(&self, serializer: &'a mut Serializer<W>) -> cbor_event::Result<&'a mut Serializer<W>> {
-        super::transaction_witnesses_set::serialize(self.tx_witnesses_set_ref(), Some(self.raw_parts_ref()), serializer)
+        let raw_parts = Some(self.raw_parts_ref()).filter(|_| !is_canonical_encoding());
+        super::transaction_witnesses_set::serialize(self.tx_witnesses_set_ref(), raw_parts, serializer)
     }
 }
 
@@ -5,11 +5,32 @@ use cbor_event::Serialize;
 use crate::{BootstrapWitnesses, CBORReadLen, DeserializeError, DeserializeFailure, Key, Language, NativeScripts, PlutusList, PlutusScripts, Redeemers, TransactionWitnessSet, Vkeywitnesses};
 use crate::protocol_types::{CBORSpecial, CBORType, Deserialize, opt64, TransactionWitnessSetRaw};
 use crate::serialization::utils::{deserilized_with_orig_bytes, merge_option_plutus_list};
+use crate::serialization::{
+    deserialize_preserving_encoding, serialize_preserving_encoding, OriginalEncoding,
+    PreservesEncoding,
+};
 use crate::traits::NoneOrEmpty;
 use crate::utils::opt64_non_empty;
 
 impl cbor_event::se::Serialize for TransactionWitnessSet {
     fn serialize<'a, W: Write + Sized>(&self, serializer: &'a mut Serializer<W>) -> cbor_event::Result<&'a mut Serializer<W>> {
+        serialize_preserving_encoding(self, serializer)
+    }
+}
+
+impl PreservesEncoding for TransactionWitnessSet {
+    fn original_encoding(&self) -> &OriginalEncoding {
+        &self.original_encoding
+    }
+
+    fn original_encoding_mut(&mut self) -> &mut OriginalEncoding {
+        &mut self.original_encoding
+    }
+
+    fn serialize_own_encoding<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
         serialize(self, None, serializer)
     }
 }
@@ -19,8 +40,7 @@ impl Deserialize for TransactionWitnessSet {
     where
         Self: Sized
     {
-        let (witness_set, _) = deserialize(raw, false)?;
-        Ok(witness_set)
+        deserialize_preserving_encoding(raw, |raw| Ok(deserialize(raw, false)?.0))
     }
 }
 
@@ -192,6 +212,7 @@ pub(super) fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>, with_raw
             plutus_scripts,
             plutus_data,
             redeemers,
+            original_encoding: OriginalEncoding::default(),
         }, raw_part))
     })()
         .map_err(|e| e.annotate("TransactionWitnessSet"))
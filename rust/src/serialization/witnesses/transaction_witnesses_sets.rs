@@ -30,7 +30,8 @@ impl Deserialize for TransactionWitnessSets {
                 if is_break_tag(raw, "TransactionWitnessSets")? {
                     break;
                 }
-                arr.push(TransactionWitnessSet::deserialize(raw)?);
+                let index = arr.len();
+                arr.push(TransactionWitnessSet::deserialize(raw).map_err(|e| e.annotate_index(index))?);
             }
             Ok(())
         })()
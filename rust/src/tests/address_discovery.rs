@@ -0,0 +1,126 @@
+use crate::tests::fakes::fake_root_key;
+use crate::*;
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+
+struct InMemoryOracle {
+    used: BTreeSet<Address>,
+    batch_sizes: RefCell<Vec<usize>>,
+}
+
+impl InMemoryOracle {
+    fn new(used: Vec<BaseAddress>) -> Self {
+        Self {
+            used: used.iter().map(|address| address.to_address()).collect(),
+            batch_sizes: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl AddressUsageOracle for InMemoryOracle {
+    fn used_addresses(&self, addresses: &[Address]) -> Result<Vec<bool>, JsError> {
+        self.batch_sizes.borrow_mut().push(addresses.len());
+        Ok(addresses
+            .iter()
+            .map(|address| self.used.contains(address))
+            .collect())
+    }
+}
+
+fn account() -> Cip1852Account {
+    Cip1852Account::from_root_key(&fake_root_key(), 0).unwrap()
+}
+
+#[test]
+fn fresh_wallet_has_no_used_addresses() {
+    let oracle = InMemoryOracle::new(Vec::new());
+    let discovered = discover_addresses(&account(), 1, BIP44_GAP_LIMIT, &oracle).unwrap();
+    assert!(discovered.is_empty());
+    assert_eq!(discovered.next_external_index(), 0);
+    assert_eq!(discovered.next_internal_index(), 0);
+    // one batch of a full gap per chain
+    assert_eq!(*oracle.batch_sizes.borrow(), vec![20, 20]);
+}
+
+#[test]
+fn used_addresses_are_found_within_the_gap_limit() {
+    let account = account();
+    let oracle = InMemoryOracle::new(vec![
+        account.base_address(1, 0).unwrap(),
+        account.base_address(1, 4).unwrap(),
+        account.base_address(1, 8).unwrap(),
+        account.change_address(1, 2).unwrap(),
+    ]);
+    let discovered = discover_addresses(&account, 1, 5, &oracle).unwrap();
+    let found: Vec<(Cip1852Role, u32)> = discovered
+        .addresses()
+        .iter()
+        .map(|address| (address.role(), address.index()))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (Cip1852Role::External, 0),
+            (Cip1852Role::External, 4),
+            (Cip1852Role::External, 8),
+            (Cip1852Role::Internal, 2),
+        ]
+    );
+    assert_eq!(discovered.next_external_index(), 9);
+    assert_eq!(discovered.next_internal_index(), 3);
+    assert_eq!(
+        discovered.addresses()[3].address(),
+        account.change_address(1, 2).unwrap()
+    );
+    assert_eq!(
+        discovered.addresses()[2].derivation_path(),
+        "m/1852'/1815'/0'/0/8"
+    );
+    // only the addresses needed to complete the gap are asked for after a used one
+    assert_eq!(*oracle.batch_sizes.borrow(), vec![5, 5, 4, 5, 3]);
+}
+
+#[test]
+fn addresses_beyond_the_gap_are_not_found() {
+    let account = account();
+    let oracle = InMemoryOracle::new(vec![
+        account.base_address(1, 1).unwrap(),
+        account.base_address(1, 7).unwrap(),
+    ]);
+    let discovered = discover_addresses(&account, 1, 5, &oracle).unwrap();
+    assert_eq!(discovered.len(), 1);
+    assert_eq!(discovered.next_external_index(), 2);
+}
+
+#[test]
+fn public_accounts_can_be_scanned() {
+    let account = account();
+    let public = Cip1852Account::from_account_public_key(&account.account_public_key(), 7).unwrap();
+    let oracle = InMemoryOracle::new(vec![account.change_address(0, 0).unwrap()]);
+    let discovered = discover_addresses(&public, 0, BIP44_GAP_LIMIT, &oracle).unwrap();
+    assert_eq!(discovered.len(), 1);
+    assert_eq!(discovered.addresses()[0].role(), Cip1852Role::Internal);
+    // the path has the account index the account was built with
+    assert_eq!(discovered.addresses()[0].account(), 7);
+    assert_eq!(
+        discovered.addresses()[0].derivation_path(),
+        "m/1852'/1815'/7'/1/0"
+    );
+
+    // addresses of another network are not the same addresses
+    let discovered = discover_addresses(&public, 1, BIP44_GAP_LIMIT, &oracle).unwrap();
+    assert!(discovered.is_empty());
+}
+
+#[test]
+fn invalid_gap_limit_and_oracle_answers_are_rejected() {
+    struct WrongOracle;
+    impl AddressUsageOracle for WrongOracle {
+        fn used_addresses(&self, _addresses: &[Address]) -> Result<Vec<bool>, JsError> {
+            Ok(vec![false])
+        }
+    }
+    let oracle = InMemoryOracle::new(Vec::new());
+    assert!(discover_addresses(&account(), 1, 0, &oracle).is_err());
+    assert!(discover_addresses(&account(), 1, 5, &WrongOracle).is_err());
+}
@@ -0,0 +1,133 @@
+use crate::tests::fakes::fake_root_key;
+use crate::*;
+
+const VECTORS: [(&str, &str); 5] = [
+    (
+        "00000000000000000000000000000000",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+    ),
+    (
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        "legal winner thank year wave sausage worth useful legal winner thank yellow",
+    ),
+    (
+        "80808080808080808080808080808080",
+        "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+    ),
+    (
+        "ffffffffffffffffffffffffffffffff",
+        "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+         abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+    ),
+];
+
+#[test]
+fn mnemonic_and_entropy_match_test_vectors() {
+    let wordlist = Bip39Wordlist::english();
+    for (entropy, phrase) in VECTORS.iter() {
+        let entropy = hex::decode(entropy).unwrap();
+        assert_eq!(
+            bip39_entropy_to_mnemonic(&entropy, &wordlist).unwrap(),
+            *phrase
+        );
+        assert_eq!(
+            bip39_mnemonic_to_entropy(phrase, &wordlist).unwrap(),
+            entropy
+        );
+        assert!(validate_bip39_mnemonic(phrase, &wordlist).is_empty());
+    }
+    assert!(bip39_entropy_to_mnemonic(&[0; 17], &wordlist).is_err());
+    assert!(bip39_entropy_to_mnemonic(&[0; 36], &wordlist).is_err());
+}
+
+#[test]
+fn root_key_from_mnemonic() {
+    let phrase = "art forum devote street sure rather head chuckle guard poverty release quote oak craft enemy";
+    let key = Bip32PrivateKey::from_mnemonic(phrase, "").unwrap();
+    assert_eq!(key.as_bytes(), fake_root_key().as_bytes());
+
+    // words are matched case insensitively and any whitespace separates them
+    let key =
+        Bip32PrivateKey::from_mnemonic(&format!("  {}\n", phrase.to_uppercase()), "").unwrap();
+    assert_eq!(key.as_bytes(), fake_root_key().as_bytes());
+
+    let with_passphrase = Bip32PrivateKey::from_mnemonic(phrase, "secret").unwrap();
+    assert_ne!(with_passphrase.as_bytes(), fake_root_key().as_bytes());
+    assert!(Bip32PrivateKey::from_mnemonic(&phrase.replace("enemy", "energy"), "").is_err());
+}
+
+#[test]
+fn unknown_words_are_reported_with_suggestions() {
+    let wordlist = Bip39Wordlist::english();
+    let errors = validate_bip39_mnemonic(
+        "legal winner thank year wave sausage worth useful legal wlak thank acount",
+        &wordlist,
+    );
+    assert_eq!(errors.len(), 2);
+    let error = errors.get(0);
+    assert_eq!(error.kind(), Bip39ErrorKind::UnknownWord);
+    assert_eq!(error.position(), Some(9));
+    assert_eq!(error.word(), Some("wlak".to_string()));
+    assert_eq!(error.suggestions().get(0), "walk");
+    let error = errors.get(1);
+    assert_eq!(error.position(), Some(11));
+    assert_eq!(error.suggestions().0[..2], ["account", "amount"]);
+
+    let errors = validate_bip39_mnemonic("abandon abandon about", &wordlist);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors.get(0).kind(), Bip39ErrorKind::WordCount);
+    assert_eq!(errors.get(0).position(), None);
+}
+
+#[test]
+fn checksum_is_validated() {
+    let wordlist = Bip39Wordlist::english();
+    let phrase = "legal winner thank year wave sausage worth useful legal winner thank year";
+    let errors = validate_bip39_mnemonic(phrase, &wordlist);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors.get(0).kind(), Bip39ErrorKind::Checksum);
+    assert_eq!(errors.get(0).position(), Some(11));
+    assert!(bip39_mnemonic_to_entropy(phrase, &wordlist).is_err());
+}
+
+#[test]
+fn generated_mnemonics_are_valid() {
+    let wordlist = Bip39Wordlist::english();
+    for word_count in [12, 15, 24].iter() {
+        let phrase = generate_bip39_mnemonic(*word_count, &wordlist).unwrap();
+        assert_eq!(phrase.split(' ').count(), *word_count);
+        assert!(validate_bip39_mnemonic(&phrase, &wordlist).is_empty());
+    }
+    assert!(generate_bip39_mnemonic(13, &wordlist).is_err());
+}
+
+#[test]
+fn wordlist_from_text() {
+    let english = Bip39Wordlist::english();
+    let words: Vec<String> = (0..2048).map(|i| english.get(i).unwrap()).collect();
+    assert_eq!(english.get(2048), None);
+    assert_eq!(
+        Bip39Wordlist::from_text(&words.join("\n")).unwrap(),
+        english
+    );
+
+    // the checksum and the word indices don't depend on the words
+    let reversed: Vec<String> = words.iter().rev().cloned().collect();
+    let reversed = Bip39Wordlist::from_text(&reversed.join("\n")).unwrap();
+    assert_eq!(reversed.index_of("zoo"), Some(0));
+    let phrase = bip39_entropy_to_mnemonic(&[0; 16], &reversed).unwrap();
+    assert!(phrase.starts_with("zoo zoo"));
+    assert_eq!(
+        bip39_mnemonic_to_entropy(&phrase, &reversed).unwrap(),
+        vec![0; 16]
+    );
+
+    assert!(Bip39Wordlist::from_text(&words[..2047].join("\n")).is_err());
+    let mut duplicated = words.clone();
+    duplicated[1] = duplicated[0].clone();
+    assert!(Bip39Wordlist::from_text(&duplicated.join("\n")).is_err());
+}
@@ -5,4 +5,5 @@ mod voting_proposal_builder;
 mod certificates_builder;
 mod mint_builder;
 mod tx_inputs_builder;
-mod tx_builder_constans;
\ No newline at end of file
+mod tx_builder_constans;
+mod script_evaluator;
\ No newline at end of file
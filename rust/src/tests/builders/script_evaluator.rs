@@ -0,0 +1,232 @@
+use crate::tests::fakes::{
+    fake_base_address, fake_bytes_32, fake_plutus_script_and_hash,
+    fake_reallistic_tx_builder, fake_tx_input, fake_tx_input2,
+};
+use crate::*;
+use std::cell::Cell;
+
+fn fake_utxo(input: &TransactionInput, address: &Address, coin: u64) -> TransactionUnspentOutput {
+    TransactionUnspentOutput::new(
+        input,
+        &TransactionOutput::new(address, &Value::new(&BigNum(coin))),
+    )
+}
+
+fn fake_zero_cost_redeemer(tag: &RedeemerTag) -> Redeemer {
+    Redeemer::new(
+        tag,
+        &BigNum::zero(),
+        &PlutusData::new_bytes(fake_bytes_32(2)),
+        &ExUnits::new(&BigNum::zero(), &BigNum::zero()),
+    )
+}
+
+fn fake_plutus_tx_builder() -> (TransactionBuilder, TransactionUnspentOutputs) {
+    let mut tx_builder = fake_reallistic_tx_builder();
+    let (script, script_hash) = fake_plutus_script_and_hash(0);
+    let script_address = EnterpriseAddress::new(
+        NetworkInfo::testnet_preprod().network_id(),
+        &Credential::from_scripthash(&script_hash),
+    )
+    .to_address();
+
+    let script_utxo = fake_utxo(&fake_tx_input(1), &script_address, 20_000_000);
+    let collateral_utxo = fake_utxo(&fake_tx_input(2), &fake_base_address(1), 10_000_000);
+
+    let mut inputs = TxInputsBuilder::new();
+    inputs
+        .add_plutus_script_utxo(
+            &script_utxo,
+            &PlutusWitness::new(
+                &script,
+                &PlutusData::new_bytes(fake_bytes_32(1)),
+                &fake_zero_cost_redeemer(&RedeemerTag::new_spend()),
+            ),
+        )
+        .unwrap();
+    tx_builder.set_inputs(&inputs);
+
+    let mut collateral = TxInputsBuilder::new();
+    collateral.add_regular_utxo(&collateral_utxo).unwrap();
+    tx_builder.set_collateral(&collateral);
+
+    tx_builder
+        .add_output(&TransactionOutput::new(
+            &fake_base_address(2),
+            &Value::new(&BigNum(5_000_000)),
+        ))
+        .unwrap();
+
+    let mut utxos = TransactionUnspentOutputs::new();
+    utxos.add(&script_utxo);
+    utxos.add(&collateral_utxo);
+    (tx_builder, utxos)
+}
+
+struct GrowingScriptEvaluator {
+    calls: Cell<u64>,
+}
+
+impl ScriptEvaluator for GrowingScriptEvaluator {
+    fn evaluate(
+        &self,
+        tx: &Transaction,
+        _utxos: &TransactionUnspentOutputs,
+    ) -> Result<Redeemers, JsError> {
+        self.calls.set(self.calls.get() + 1);
+        let mut result = Redeemers::new();
+        for redeemer in tx.witness_set.redeemers.as_ref().unwrap().redeemers.iter() {
+            result.add(&Redeemer::new(
+                &redeemer.tag(),
+                &redeemer.index(),
+                &redeemer.data(),
+                &ExUnits::new(&BigNum(self.calls.get()), &BigNum(self.calls.get())),
+            ));
+        }
+        Ok(result)
+    }
+}
+
+#[test]
+fn build_tx_with_evaluator_fills_ex_units() {
+    let (mut tx_builder, utxos) = fake_plutus_tx_builder();
+    let ex_units = ExUnits::new(&BigNum(1_000_000), &BigNum(500_000_000));
+    let evaluator = StaticScriptEvaluator::new(&ex_units);
+    let cost_models = TxBuilderConstants::plutus_vasil_cost_models();
+
+    let tx = tx_builder
+        .build_tx_with_evaluator(
+            &evaluator,
+            &utxos,
+            &cost_models,
+            &ChangeConfig::new(&fake_base_address(3)),
+        )
+        .unwrap();
+
+    let redeemers = tx.witness_set().redeemers().unwrap();
+    assert_eq!(redeemers.len(), 1);
+    assert_eq!(redeemers.get(0).ex_units(), ex_units);
+
+    let mut used_langs = Languages::new();
+    used_langs.add(Language::new_plutus_v1());
+    let expected_hash = hash_script_data(
+        &redeemers,
+        &cost_models.retain_language_versions(&used_langs),
+        tx.witness_set().plutus_data(),
+    );
+    assert_eq!(tx.body().script_data_hash(), Some(expected_hash));
+
+    let script_fee = min_script_fee(&tx, &tx_builder.config.ex_unit_prices.clone().unwrap())
+        .unwrap();
+    assert!(tx.body().fee() > script_fee);
+    assert_eq!(tx.body().outputs().len(), 2);
+    assert_eq!(tx_builder.build_tx().unwrap(), tx);
+}
+
+#[test]
+fn build_tx_with_evaluator_uses_specific_ex_units() {
+    let (mut tx_builder, utxos) = fake_plutus_tx_builder();
+    let (mint_script, mint_policy) = fake_plutus_script_and_hash(5);
+    let mut mint_builder = MintBuilder::new();
+    mint_builder
+        .add_asset(
+            &MintWitness::new_plutus_script(
+                &PlutusScriptSource::new(&mint_script),
+                &fake_zero_cost_redeemer(&RedeemerTag::new_mint()),
+            ),
+            &AssetName::new(vec![1]).unwrap(),
+            &Int::new_i32(1),
+        )
+        .unwrap();
+    tx_builder.set_mint_builder(&mint_builder);
+
+    let default_ex_units = ExUnits::new(&BigNum(1_000), &BigNum(2_000));
+    let mint_ex_units = ExUnits::new(&BigNum(3_000), &BigNum(4_000));
+    let mut evaluator = StaticScriptEvaluator::new(&default_ex_units);
+    evaluator.set_ex_units(&RedeemerTag::new_mint(), &BigNum::zero(), &mint_ex_units);
+
+    let tx = tx_builder
+        .build_tx_with_evaluator(
+            &evaluator,
+            &utxos,
+            &TxBuilderConstants::plutus_vasil_cost_models(),
+            &ChangeConfig::new(&fake_base_address(3)),
+        )
+        .unwrap();
+
+    let redeemers = tx.witness_set().redeemers().unwrap();
+    assert_eq!(redeemers.len(), 2);
+    for redeemer in redeemers.redeemers.iter() {
+        match redeemer.tag().kind() {
+            RedeemerTagKind::Spend => assert_eq!(redeemer.ex_units(), default_ex_units),
+            RedeemerTagKind::Mint => assert_eq!(redeemer.ex_units(), mint_ex_units),
+            _ => panic!("Unexpected redeemer tag"),
+        }
+    }
+    assert!(tx.body().mint().unwrap().get(&mint_policy).is_some());
+}
+
+#[test]
+fn build_tx_with_evaluator_fails_on_unresolved_input() {
+    let (mut tx_builder, _) = fake_plutus_tx_builder();
+    let evaluator = StaticScriptEvaluator::new(&ExUnits::new(&BigNum(1), &BigNum(1)));
+
+    let mut utxos = TransactionUnspentOutputs::new();
+    utxos.add(&fake_utxo(&fake_tx_input2(3, 1), &fake_base_address(1), 1_000_000));
+
+    let res = tx_builder.build_tx_with_evaluator(
+        &evaluator,
+        &utxos,
+        &TxBuilderConstants::plutus_vasil_cost_models(),
+        &ChangeConfig::new(&fake_base_address(3)),
+    );
+    assert!(res.is_err());
+    assert!(tx_builder.get_fee_if_set().is_none());
+}
+
+#[test]
+fn build_tx_with_evaluator_fails_if_ex_units_do_not_converge() {
+    let (mut tx_builder, utxos) = fake_plutus_tx_builder();
+    let evaluator = GrowingScriptEvaluator {
+        calls: Cell::new(0),
+    };
+
+    let res = tx_builder.build_tx_with_evaluator(
+        &evaluator,
+        &utxos,
+        &TxBuilderConstants::plutus_vasil_cost_models(),
+        &ChangeConfig::new(&fake_base_address(3)),
+    );
+    assert!(res.is_err());
+    assert_eq!(evaluator.calls.get(), MAX_SCRIPT_EVALUATION_ROUNDS as u64);
+}
+
+#[test]
+fn build_tx_with_evaluator_without_scripts() {
+    let mut tx_builder = fake_reallistic_tx_builder();
+    let utxo = fake_utxo(&fake_tx_input(1), &fake_base_address(1), 10_000_000);
+    tx_builder.inputs.add_regular_utxo(&utxo).unwrap();
+    tx_builder
+        .add_output(&TransactionOutput::new(
+            &fake_base_address(2),
+            &Value::new(&BigNum(5_000_000)),
+        ))
+        .unwrap();
+    let evaluator = GrowingScriptEvaluator {
+        calls: Cell::new(0),
+    };
+    let mut utxos = TransactionUnspentOutputs::new();
+    utxos.add(&utxo);
+
+    let tx = tx_builder
+        .build_tx_with_evaluator(
+            &evaluator,
+            &utxos,
+            &TxBuilderConstants::plutus_vasil_cost_models(),
+            &ChangeConfig::new(&fake_base_address(3)),
+        )
+        .unwrap();
+    assert_eq!(evaluator.calls.get(), 0);
+    assert!(tx.witness_set().redeemers().is_none());
+    assert!(tx.body().script_data_hash().is_none());
+}
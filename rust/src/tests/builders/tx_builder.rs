@@ -2529,6 +2529,250 @@ fn tx_builder_cip2_random_improve_adds_enough_for_fees() {
     assert!(add_change_res.is_ok(), "{:?}", add_change_res.err());
 }
 
+fn make_output_to_bech32(value: &Value) -> TransactionOutput {
+    TransactionOutput::new(
+        &Address::from_bech32("addr1vyy6nhfyks7wdu3dudslys37v252w2nwhv0fw2nfawemmnqs6l44z")
+            .unwrap(),
+        value,
+    )
+}
+
+#[test]
+fn tx_builder_branch_and_bound_avoids_change() {
+    // we have a = 0 so we know adding inputs/outputs doesn't change the fee so we can analyze more
+    let mut tx_builder = fake_tx_builder_with_fee(&fake_linear_fee(0, 0));
+    tx_builder
+        .add_output(&make_output_to_bech32(&Value::new(&BigNum(1200))))
+        .unwrap();
+    let mut available_inputs = TransactionUnspentOutputs::new();
+    available_inputs.add(&make_input(0u8, Value::new(&BigNum(150))));
+    available_inputs.add(&make_input(1u8, Value::new(&BigNum(1000))));
+    available_inputs.add(&make_input(2u8, Value::new(&BigNum(800))));
+    available_inputs.add(&make_input(3u8, Value::new(&BigNum(400))));
+    available_inputs.add(&make_input(4u8, Value::new(&BigNum(250))));
+    available_inputs.add(&make_input(5u8, Value::new(&BigNum(200))));
+    tx_builder
+        .add_inputs_from(&available_inputs, CoinSelectionStrategyCIP2::BranchAndBound)
+        .unwrap();
+    let change_added = tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    assert!(!change_added);
+    let tx = tx_builder.build().unwrap();
+    // largest first would take 1000 + 800 and produce a change
+    assert_eq!(1, tx.outputs().len());
+    assert_eq!(2, tx.inputs().len());
+    assert_eq!(1u8, tx.inputs().get(0).transaction_id().0[0]);
+    assert_eq!(5u8, tx.inputs().get(1).transaction_id().0[0]);
+    assert_eq!(tx.fee(), BigNum::zero());
+}
+
+#[test]
+fn tx_builder_branch_and_bound_burns_small_excess() {
+    let mut tx_builder = fake_tx_builder_with_fee(&fake_linear_fee(0, 0));
+    tx_builder
+        .add_output(&make_output_to_bech32(&Value::new(&BigNum(1200))))
+        .unwrap();
+    let mut available_inputs = TransactionUnspentOutputs::new();
+    available_inputs.add(&make_input(0u8, Value::new(&BigNum(2000))));
+    available_inputs.add(&make_input(1u8, Value::new(&BigNum(1000))));
+    available_inputs.add(&make_input(2u8, Value::new(&BigNum(210))));
+    tx_builder
+        .add_inputs_from(&available_inputs, CoinSelectionStrategyCIP2::BranchAndBound)
+        .unwrap();
+    let change_added = tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    assert!(!change_added);
+    let tx = tx_builder.build().unwrap();
+    assert_eq!(1, tx.outputs().len());
+    assert_eq!(2, tx.inputs().len());
+    assert_eq!(tx.fee(), BigNum(10));
+}
+
+#[test]
+fn tx_builder_branch_and_bound_falls_back_to_largest_first() {
+    // we have a = 1 to test increasing fees when more inputs are added
+    let mut tx_builder = fake_tx_builder_with_fee(&fake_linear_fee(1, 0));
+    tx_builder
+        .add_output(&make_output_to_bech32(&Value::new(&BigNum(9000))))
+        .unwrap();
+    let mut available_inputs = TransactionUnspentOutputs::new();
+    available_inputs.add(&make_input(0u8, Value::new(&BigNum(1200))));
+    available_inputs.add(&make_input(1u8, Value::new(&BigNum(50000))));
+    available_inputs.add(&make_input(2u8, Value::new(&BigNum(64000))));
+    tx_builder
+        .add_inputs_from(&available_inputs, CoinSelectionStrategyCIP2::BranchAndBound)
+        .unwrap();
+    let change_added = tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    assert!(change_added);
+    let tx = tx_builder.build().unwrap();
+    assert_eq!(2, tx.outputs().len());
+    assert_eq!(1, tx.inputs().len());
+    assert_eq!(2u8, tx.inputs().get(0).transaction_id().0[0]);
+}
+
+#[test]
+fn tx_builder_branch_and_bound_skips_inputs_with_assets() {
+    let mut tx_builder = fake_tx_builder_with_fee(&fake_linear_fee(0, 0));
+    tx_builder
+        .add_output(&make_output_to_bech32(&Value::new(&BigNum(1200))))
+        .unwrap();
+    let mut available_inputs = TransactionUnspentOutputs::new();
+    // exact match, but the asset would need a change output
+    let mut input0 = make_input(0u8, Value::new(&BigNum(1200)));
+    let mut ma0 = MultiAsset::new();
+    ma0.set_asset(&fake_policy_id(1), &AssetName::new(vec![1u8]).unwrap(), &BigNum(1));
+    input0.output.amount.set_multiasset(&ma0);
+    available_inputs.add(&input0);
+    available_inputs.add(&make_input(1u8, Value::new(&BigNum(1000))));
+    available_inputs.add(&make_input(2u8, Value::new(&BigNum(205))));
+    tx_builder
+        .add_inputs_from(&available_inputs, CoinSelectionStrategyCIP2::BranchAndBound)
+        .unwrap();
+    let change_added = tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    assert!(!change_added);
+    let tx = tx_builder.build().unwrap();
+    assert_eq!(1, tx.outputs().len());
+    assert_eq!(2, tx.inputs().len());
+    assert_eq!(1u8, tx.inputs().get(0).transaction_id().0[0]);
+    assert_eq!(2u8, tx.inputs().get(1).transaction_id().0[0]);
+    assert_eq!(tx.fee(), BigNum(5));
+}
+
+#[test]
+fn tx_builder_branch_and_bound_rejects_multiasset() {
+    let mut tx_builder = fake_tx_builder_with_fee(&fake_linear_fee(0, 0));
+    let mut output_value = Value::new(&BigNum(1200));
+    let mut output_ma = MultiAsset::new();
+    output_ma.set_asset(&fake_policy_id(1), &AssetName::new(vec![1u8]).unwrap(), &BigNum(1));
+    output_value.set_multiasset(&output_ma);
+    tx_builder
+        .add_output(&make_output_to_bech32(&output_value))
+        .unwrap();
+    let mut available_inputs = TransactionUnspentOutputs::new();
+    available_inputs.add(&make_input(0u8, Value::new(&BigNum(2000))));
+    let res =
+        tx_builder.add_inputs_from(&available_inputs, CoinSelectionStrategyCIP2::BranchAndBound);
+    assert!(res.is_err());
+}
+
+#[test]
+fn tx_builder_branch_and_bound_multiasset_minimises_policies() {
+    let mut tx_builder = fake_tx_builder_with_fee(&fake_linear_fee(0, 0));
+    let asset_name = AssetName::new(vec![1u8; 8]).unwrap();
+    let pid1 = fake_policy_id(1);
+    let pid2 = fake_policy_id(2);
+    let pid3 = fake_policy_id(3);
+
+    let mut output_value = Value::new(&BigNum(415));
+    let mut output_ma = MultiAsset::new();
+    output_ma.set_asset(&pid1, &asset_name, &BigNum(5));
+    output_value.set_multiasset(&output_ma);
+    tx_builder
+        .add_output(&make_output_to_bech32(&output_value))
+        .unwrap();
+
+    let mut available_inputs = TransactionUnspentOutputs::new();
+    // has the most of the needed asset, but brings two more policies into the change
+    let mut input0 = make_input(0u8, Value::new(&BigNum(200)));
+    let mut ma0 = MultiAsset::new();
+    ma0.set_asset(&pid1, &asset_name, &BigNum(50));
+    ma0.set_asset(&pid2, &asset_name, &BigNum(1));
+    ma0.set_asset(&pid3, &asset_name, &BigNum(1));
+    input0.output.amount.set_multiasset(&ma0);
+    available_inputs.add(&input0);
+
+    // taken to satisfy pid1, no new policies
+    let mut input1 = make_input(1u8, Value::new(&BigNum(100)));
+    let mut ma1 = MultiAsset::new();
+    ma1.set_asset(&pid1, &asset_name, &BigNum(5));
+    input1.output.amount.set_multiasset(&ma1);
+    available_inputs.add(&input1);
+
+    // brings a new policy, should not be taken for ADA
+    let mut input2 = make_input(2u8, Value::new(&BigNum(315)));
+    let mut ma2 = MultiAsset::new();
+    ma2.set_asset(&pid2, &asset_name, &BigNum(1));
+    input2.output.amount.set_multiasset(&ma2);
+    available_inputs.add(&input2);
+
+    // taken for ADA
+    available_inputs.add(&make_input(3u8, Value::new(&BigNum(300))));
+    available_inputs.add(&make_input(4u8, Value::new(&BigNum(20))));
+    // too big to avoid the change
+    available_inputs.add(&make_input(5u8, Value::new(&BigNum(5000))));
+
+    tx_builder
+        .add_inputs_from(
+            &available_inputs,
+            CoinSelectionStrategyCIP2::BranchAndBoundMultiAsset,
+        )
+        .unwrap();
+    let change_added = tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    assert!(!change_added);
+    let tx = tx_builder.build().unwrap();
+    assert_eq!(1, tx.outputs().len());
+    assert_eq!(3, tx.inputs().len());
+    assert_eq!(1u8, tx.inputs().get(0).transaction_id().0[0]);
+    assert_eq!(3u8, tx.inputs().get(1).transaction_id().0[0]);
+    assert_eq!(4u8, tx.inputs().get(2).transaction_id().0[0]);
+    assert_eq!(tx.fee(), BigNum(5));
+}
+
+#[test]
+fn tx_builder_branch_and_bound_multiasset_with_change() {
+    let mut tx_builder = fake_tx_builder_with_fee(&fake_linear_fee(44, 155381));
+    let asset_name = AssetName::new(vec![1u8; 8]).unwrap();
+    let pid1 = fake_policy_id(1);
+    let pid2 = fake_policy_id(2);
+
+    let mut output_value = Value::new(&BigNum(2_000_000));
+    let mut output_ma = MultiAsset::new();
+    output_ma.set_asset(&pid1, &asset_name, &BigNum(5));
+    output_value.set_multiasset(&output_ma);
+    tx_builder
+        .add_output(&make_output_to_bech32(&output_value))
+        .unwrap();
+
+    let mut available_inputs = TransactionUnspentOutputs::new();
+    let mut input0 = make_input(0u8, Value::new(&BigNum(1_500_000)));
+    let mut ma0 = MultiAsset::new();
+    ma0.set_asset(&pid1, &asset_name, &BigNum(3));
+    ma0.set_asset(&pid2, &asset_name, &BigNum(1));
+    input0.output.amount.set_multiasset(&ma0);
+    available_inputs.add(&input0);
+
+    let mut input1 = make_input(1u8, Value::new(&BigNum(1_500_000)));
+    let mut ma1 = MultiAsset::new();
+    ma1.set_asset(&pid1, &asset_name, &BigNum(3));
+    input1.output.amount.set_multiasset(&ma1);
+    available_inputs.add(&input1);
+
+    let mut input2 = make_input(2u8, Value::new(&BigNum(1_500_000)));
+    let mut ma2 = MultiAsset::new();
+    ma2.set_asset(&pid1, &asset_name, &BigNum(4));
+    input2.output.amount.set_multiasset(&ma2);
+    available_inputs.add(&input2);
+
+    available_inputs.add(&make_input(3u8, Value::new(&BigNum(10_000_000))));
+
+    tx_builder
+        .add_inputs_from_and_change(
+            &available_inputs,
+            CoinSelectionStrategyCIP2::BranchAndBoundMultiAsset,
+            &ChangeConfig::new(&fake_change_address()),
+        )
+        .unwrap();
+    let tx = tx_builder.build_tx().unwrap();
+    assert_eq!(2, tx.body().outputs().len());
+    let change = tx.body().outputs().get(1).amount();
+    // pid2 is not dragged into the change
+    assert_eq!(1, change.multiasset().unwrap().len());
+    assert_eq!(BigNum(2), change.multiasset().unwrap().get_asset(&pid1, &asset_name));
+    assert!(tx
+        .body()
+        .inputs()
+        .into_iter()
+        .all(|input| input.transaction_id().0[0] != 0u8));
+}
+
 #[test]
 fn build_tx_pay_to_multisig() {
     let mut tx_builder = fake_tx_builder_with_fee(&fake_linear_fee(10, 2));
@@ -3639,6 +3883,32 @@ fn fee_estimation_fails_on_missing_mint_scripts() {
     // assert!(est5.err().unwrap().to_string().contains("witness scripts are not provided"));
 }
 
+#[test]
+fn fee_estimation_uses_smallest_native_script_signer_set() {
+    let pubkeys: Vec<NativeScript> = (1..4)
+        .map(|index| NativeScript::new_script_pubkey(&ScriptPubkey::new(&fake_key_hash(index))))
+        .collect();
+    let scripts = NativeScripts::from(pubkeys.iter().collect::<Vec<&NativeScript>>());
+    let any_script = NativeScript::new_script_any(&ScriptAny::new(&scripts));
+    let all_script = NativeScript::new_script_all(&ScriptAll::new(&scripts));
+    let name = AssetName::new(vec![0u8, 1, 2, 3]).unwrap();
+    let amount = Int::new_i32(1234);
+
+    let mut any_builder = fake_reallistic_tx_builder();
+    any_builder.add_mint_asset(&any_script, &name, &amount).unwrap();
+    let mut all_builder = fake_reallistic_tx_builder();
+    all_builder.add_mint_asset(&all_script, &name, &amount).unwrap();
+
+    // both scripts have the same size, ScriptAny needs one vkey witness and ScriptAll three
+    let vkey_witness_size = fake_vkey_witness(1).to_bytes().len() as u64;
+    let fee_difference = all_builder
+        .min_fee()
+        .unwrap()
+        .checked_sub(&any_builder.min_fee().unwrap())
+        .unwrap();
+    assert_eq!(fee_difference, BigNum(2 * vkey_witness_size * 44));
+}
+
 #[test]
 fn total_input_output_with_mint_and_burn() {
     let mut tx_builder = fake_tx_builder_with_fee(&fake_linear_fee(0, 1));
@@ -4971,6 +5241,367 @@ fn test_auto_calc_collateral_return_fails_on_no_collateral() {
     assert!(tx_builder.collateral_return.is_none());
 }
 
+fn fake_tx_builder_with_collateral_params(allow_assets: bool) -> TransactionBuilder {
+    TransactionBuilder::new(
+        &fake_realistic_tx_builder_config_builder()
+            .collateral_percentage(150)
+            .max_collateral_inputs(2)
+            .allow_collateral_with_assets(allow_assets)
+            .build()
+            .unwrap(),
+    )
+}
+
+fn fake_collateral_tx_builder(allow_assets: bool) -> TransactionBuilder {
+    let mut tx_builder = fake_tx_builder_with_collateral_params(allow_assets);
+    tx_builder.add_regular_input(
+        &fake_base_address(0),
+        &fake_tx_input(0),
+        &Value::new(&BigNum(10_000_000)),
+    ).unwrap();
+    tx_builder
+        .add_output(&TransactionOutput::new(
+            &fake_base_address(1),
+            &Value::new(&BigNum(3_000_000)),
+        ))
+        .unwrap();
+    tx_builder
+}
+
+fn fake_collateral_utxo(input_hash_byte: u8, address: &Address, value: &Value) -> TransactionUnspentOutput {
+    TransactionUnspentOutput::new(
+        &fake_tx_input(input_hash_byte),
+        &TransactionOutput::new(address, value),
+    )
+}
+
+fn fake_collateral_asset_value(coin: u64) -> Value {
+    let mut assets = Assets::new();
+    assets.insert(&AssetName::new(vec![1]).unwrap(), &BigNum(100));
+    let mut multiasset = MultiAsset::new();
+    multiasset.insert(&fake_policy_id(1), &assets);
+    Value::new_with_assets(&BigNum(coin), &multiasset)
+}
+
+fn assert_collateral_covers_fee(tx: &Transaction) {
+    let total_collateral = tx.body().total_collateral().unwrap();
+    let fee_share = tx.body().fee().checked_mul(&BigNum(150)).unwrap();
+    assert!(total_collateral.checked_mul(&BigNum(100)).unwrap() >= fee_share);
+    assert!(total_collateral.checked_sub(&BigNum::one()).unwrap().checked_mul(&BigNum(100)).unwrap() < fee_share);
+}
+
+#[test]
+fn add_collateral_from_selects_largest_pure_ada_utxo() {
+    let mut tx_builder = fake_collateral_tx_builder(false);
+    let mut utxos = TransactionUnspentOutputs::new();
+    utxos.add(&fake_collateral_utxo(1, &fake_base_address(2), &Value::new(&BigNum(2_000_000))));
+    utxos.add(&fake_collateral_utxo(2, &fake_base_address(2), &Value::new(&BigNum(5_000_000))));
+    utxos.add(&fake_collateral_utxo(3, &fake_base_address(2), &fake_collateral_asset_value(50_000_000)));
+    let script_address = EnterpriseAddress::new(
+        NetworkInfo::testnet_preprod().network_id(),
+        &Credential::from_scripthash(&fake_script_hash(1)),
+    )
+    .to_address();
+    utxos.add(&fake_collateral_utxo(4, &script_address, &Value::new(&BigNum(90_000_000))));
+
+    tx_builder.add_collateral_from(&utxos, &fake_change_address()).unwrap();
+    tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    let tx = tx_builder.build_tx().unwrap();
+
+    let collateral = tx.body().collateral().unwrap();
+    assert_eq!(collateral.len(), 1);
+    assert_eq!(collateral.get(0), fake_tx_input(2));
+    assert_collateral_covers_fee(&tx);
+
+    let collateral_return = tx.body().collateral_return().unwrap();
+    assert_eq!(collateral_return.address(), fake_change_address());
+    assert_eq!(
+        collateral_return.amount().coin().checked_add(&tx.body().total_collateral().unwrap()).unwrap(),
+        BigNum(5_000_000)
+    );
+}
+
+#[test]
+fn add_collateral_from_recalculates_return_after_fee_change() {
+    let mut tx_builder = fake_collateral_tx_builder(false);
+    let mut utxos = TransactionUnspentOutputs::new();
+    utxos.add(&fake_collateral_utxo(1, &fake_base_address(2), &Value::new(&BigNum(5_000_000))));
+    tx_builder.add_collateral_from(&utxos, &fake_change_address()).unwrap();
+    let total_before_change = tx_builder.total_collateral.unwrap();
+
+    tx_builder
+        .add_output(&TransactionOutput::new(
+            &fake_base_address(3),
+            &Value::new(&BigNum(2_000_000)),
+        ))
+        .unwrap();
+    tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    let tx = tx_builder.build_tx().unwrap();
+
+    assert_ne!(tx.body().total_collateral().unwrap(), total_before_change);
+    assert_collateral_covers_fee(&tx);
+    assert_eq!(
+        tx.body().collateral_return().unwrap().amount().coin()
+            .checked_add(&tx.body().total_collateral().unwrap()).unwrap(),
+        BigNum(5_000_000)
+    );
+}
+
+#[test]
+fn add_collateral_from_recalculates_return_after_failed_change() {
+    let mut tx_builder = fake_collateral_tx_builder(false);
+    let mut utxos = TransactionUnspentOutputs::new();
+    utxos.add(&fake_collateral_utxo(1, &fake_base_address(2), &Value::new(&BigNum(5_000_000))));
+    tx_builder.add_collateral_from(&utxos, &fake_change_address()).unwrap();
+    tx_builder
+        .add_output(&TransactionOutput::new(
+            &fake_base_address(3),
+            &Value::new(&BigNum(8_000_000)),
+        ))
+        .unwrap();
+    assert!(tx_builder.add_change_if_needed(&fake_change_address()).is_err());
+
+    // retried with more inputs, the collateral return is still recalculated
+    tx_builder.add_regular_input(
+        &fake_base_address(0),
+        &fake_tx_input(5),
+        &Value::new(&BigNum(10_000_000)),
+    ).unwrap();
+    tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    let tx = tx_builder.build_tx().unwrap();
+
+    assert_collateral_covers_fee(&tx);
+    assert_eq!(
+        tx.body().collateral_return().unwrap().amount().coin()
+            .checked_add(&tx.body().total_collateral().unwrap()).unwrap(),
+        BigNum(5_000_000)
+    );
+}
+
+#[test]
+fn add_collateral_from_respects_max_collateral_inputs() {
+    let mut tx_builder = fake_collateral_tx_builder(false);
+    let mut utxos = TransactionUnspentOutputs::new();
+    for i in 1..=4 {
+        utxos.add(&fake_collateral_utxo(i, &fake_base_address(2), &Value::new(&BigNum(500_000))));
+    }
+    assert!(tx_builder.add_collateral_from(&utxos, &fake_change_address()).is_err());
+    assert_eq!(tx_builder.collateral.len(), 0);
+    assert!(tx_builder.total_collateral.is_none());
+    assert!(tx_builder.collateral_return.is_none());
+
+    let mut utxos = TransactionUnspentOutputs::new();
+    utxos.add(&fake_collateral_utxo(1, &fake_base_address(2), &Value::new(&BigNum(900_000))));
+    utxos.add(&fake_collateral_utxo(2, &fake_base_address(2), &Value::new(&BigNum(1_200_000))));
+    tx_builder.add_collateral_from(&utxos, &fake_change_address()).unwrap();
+    tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    let tx = tx_builder.build_tx().unwrap();
+    assert_eq!(tx.body().collateral().unwrap().len(), 2);
+    assert_collateral_covers_fee(&tx);
+}
+
+#[test]
+fn add_collateral_from_uses_assets_only_if_allowed() {
+    let mut utxos = TransactionUnspentOutputs::new();
+    utxos.add(&fake_collateral_utxo(1, &fake_base_address(2), &fake_collateral_asset_value(5_000_000)));
+
+    let mut tx_builder = fake_collateral_tx_builder(false);
+    assert!(tx_builder.add_collateral_from(&utxos, &fake_change_address()).is_err());
+
+    let mut tx_builder = fake_collateral_tx_builder(true);
+    tx_builder.add_collateral_from(&utxos, &fake_change_address()).unwrap();
+    tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    let tx = tx_builder.build_tx().unwrap();
+    assert_collateral_covers_fee(&tx);
+    let collateral_return = tx.body().collateral_return().unwrap();
+    assert_eq!(collateral_return.amount().multiasset(), fake_collateral_asset_value(5_000_000).multiasset());
+}
+
+#[test]
+fn add_collateral_from_uses_exact_and_final_fee() {
+    let mut utxos = TransactionUnspentOutputs::new();
+    utxos.add(&fake_collateral_utxo(1, &fake_base_address(2), &Value::new(&BigNum(5_000_000))));
+
+    let mut tx_builder = fake_collateral_tx_builder(false);
+    tx_builder.set_fee(&BigNum(300_000));
+    tx_builder.add_collateral_from(&utxos, &fake_change_address()).unwrap();
+    assert_eq!(tx_builder.total_collateral.unwrap(), BigNum(450_000));
+
+    let mut tx_builder = fake_collateral_tx_builder(false);
+    tx_builder.set_min_fee(&BigNum(400_001));
+    tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    tx_builder.add_collateral_from(&utxos, &fake_change_address()).unwrap();
+    assert_eq!(tx_builder.total_collateral.unwrap(), BigNum(600_002));
+    let tx = tx_builder.build_tx().unwrap();
+    assert_eq!(tx.body().fee(), BigNum(400_001));
+
+    // the collateral input makes the already calculated fee too small
+    let mut tx_builder = fake_collateral_tx_builder(false);
+    tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    assert!(tx_builder.add_collateral_from(&utxos, &fake_change_address()).is_err());
+    assert!(tx_builder.total_collateral.is_none());
+}
+
+#[test]
+fn manual_collateral_return_is_kept_after_add_collateral_from() {
+    let mut utxos = TransactionUnspentOutputs::new();
+    utxos.add(&fake_collateral_utxo(1, &fake_base_address(2), &Value::new(&BigNum(5_000_000))));
+    let manual_return =
+        TransactionOutput::new(&fake_base_address(4), &Value::new(&BigNum(4_000_000)));
+
+    let mut tx_builder = fake_collateral_tx_builder(false);
+    tx_builder.add_collateral_from(&utxos, &fake_change_address()).unwrap();
+    tx_builder.set_collateral_return(&manual_return);
+    tx_builder.set_total_collateral(&BigNum(1_000_000));
+    tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    let tx = tx_builder.build_tx().unwrap();
+    assert_eq!(tx.body().collateral_return().unwrap(), manual_return);
+    assert_eq!(tx.body().total_collateral().unwrap(), BigNum(1_000_000));
+
+    let mut tx_builder = fake_collateral_tx_builder(false);
+    tx_builder.add_collateral_from(&utxos, &fake_change_address()).unwrap();
+    tx_builder
+        .set_total_collateral_and_return(&BigNum(1_000_000), &fake_base_address(4))
+        .unwrap();
+    tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    let tx = tx_builder.build_tx().unwrap();
+    assert_eq!(tx.body().collateral_return().unwrap(), manual_return);
+    assert_eq!(tx.body().total_collateral().unwrap(), BigNum(1_000_000));
+}
+
+#[test]
+fn add_collateral_from_fails_without_collateral_params() {
+    let mut tx_builder = fake_reallistic_tx_builder();
+    let mut utxos = TransactionUnspentOutputs::new();
+    utxos.add(&fake_collateral_utxo(1, &fake_base_address(2), &Value::new(&BigNum(5_000_000))));
+    assert!(tx_builder.add_collateral_from(&utxos, &fake_change_address()).is_err());
+}
+
+fn fake_change_split_tx_builder(max_value_size: u32, assets_count: u8) -> TransactionBuilder {
+    let mut tx_builder = TransactionBuilder::new(
+        &fake_realistic_tx_builder_config_builder()
+            .max_value_size(max_value_size)
+            .build()
+            .unwrap(),
+    );
+    let mut multiasset = MultiAsset::new();
+    for i in 0..assets_count {
+        multiasset.set_asset(
+            &fake_policy_id(i % 3),
+            &AssetName::new(vec![i; 32]).unwrap(),
+            &BigNum(1000 + i as u64),
+        );
+    }
+    tx_builder.add_regular_input(
+        &fake_base_address(0),
+        &fake_tx_input(0),
+        &Value::new_with_assets(&BigNum(50_000_000), &multiasset),
+    ).unwrap();
+    tx_builder
+        .add_output(&TransactionOutput::new(
+            &fake_base_address(1),
+            &Value::new(&BigNum(3_000_000)),
+        ))
+        .unwrap();
+    tx_builder
+}
+
+fn assert_change_split_balanced(tx_builder: &TransactionBuilder) -> Vec<TransactionOutput> {
+    let tx = tx_builder.build_tx().unwrap();
+    assert_eq!(
+        tx_builder.get_total_input().unwrap(),
+        tx_builder.get_total_output().unwrap().checked_add(&Value::new(&tx.body().fee())).unwrap()
+    );
+    assert!(tx.body().fee() >= tx_builder.min_fee().unwrap());
+    tx.body().outputs().0.into_iter().skip(1).collect()
+}
+
+fn change_assets_count(output: &TransactionOutput) -> usize {
+    output.amount().multiasset().map_or(0, |ma| ma.0.values().map(|assets| assets.len()).sum())
+}
+
+#[test]
+fn change_split_by_max_assets_per_output() {
+    let mut tx_builder = fake_change_split_tx_builder(5000, 5);
+    let change_config = ChangeConfig::new(&fake_change_address()).change_max_assets_per_output(2);
+    assert!(tx_builder.add_change_if_needed_with_config(&change_config).unwrap());
+
+    let change = assert_change_split_balanced(&tx_builder);
+    assert_eq!(change.len(), 3);
+    assert_eq!(change.iter().map(change_assets_count).collect::<Vec<_>>(), vec![2, 2, 1]);
+    assert!(change.iter().all(|output| output.address() == fake_change_address()));
+}
+
+#[test]
+fn change_split_with_pure_ada_output() {
+    let mut tx_builder = fake_change_split_tx_builder(5000, 3);
+    let change_config = ChangeConfig::new(&fake_change_address()).change_pure_ada_output(true);
+    assert!(tx_builder.add_change_if_needed_with_config(&change_config).unwrap());
+
+    let change = assert_change_split_balanced(&tx_builder);
+    assert_eq!(change.len(), 2);
+    assert_eq!(change_assets_count(&change[0]), 3);
+    assert_eq!(change_assets_count(&change[1]), 0);
+    assert!(change[0].amount().coin() < BigNum(2_000_000));
+    assert!(change[1].amount().coin() > BigNum(40_000_000));
+}
+
+#[test]
+fn change_split_to_target_outputs() {
+    let mut tx_builder = fake_change_split_tx_builder(5000, 0);
+    let change_config = ChangeConfig::new(&fake_change_address()).change_target_outputs(4);
+    assert!(tx_builder.add_change_if_needed_with_config(&change_config).unwrap());
+
+    let change = assert_change_split_balanced(&tx_builder);
+    assert_eq!(change.len(), 4);
+    let coins: Vec<BigNum> = change.iter().map(|output| output.amount().coin()).collect();
+    assert!(coins[0] >= coins[1]);
+    assert!(coins[0].checked_sub(&coins[1]).unwrap() < BigNum(4));
+    assert_eq!(coins[1], coins[2]);
+    assert_eq!(coins[2], coins[3]);
+}
+
+#[test]
+fn change_split_target_outputs_limited_by_min_ada() {
+    let mut tx_builder = fake_change_split_tx_builder(5000, 2);
+    // 47 ADA of change can't cover 100 outputs, but each added output must still satisfy min ada
+    let change_config = ChangeConfig::new(&fake_change_address()).change_target_outputs(100);
+    assert!(tx_builder.add_change_if_needed_with_config(&change_config).unwrap());
+
+    let change = assert_change_split_balanced(&tx_builder);
+    assert!(change.len() > 2 && change.len() < 100);
+    assert_eq!(change_assets_count(&change[0]), 2);
+    for output in change.iter() {
+        assert!(output.amount().coin() >= min_ada_for_output(output, &tx_builder.config.utxo_cost()).unwrap());
+    }
+}
+
+#[test]
+fn change_split_respects_max_value_size() {
+    let max_value_size = 400;
+    let mut tx_builder = fake_change_split_tx_builder(max_value_size, 20);
+    let change_config = ChangeConfig::new(&fake_change_address()).change_pure_ada_output(true);
+    assert!(tx_builder.add_change_if_needed_with_config(&change_config).unwrap());
+
+    let change = assert_change_split_balanced(&tx_builder);
+    assert!(change.len() > 2);
+    assert_eq!(change.iter().map(change_assets_count).sum::<usize>(), 20);
+    for output in change.iter() {
+        assert!(output.amount().to_bytes().len() <= max_value_size as usize);
+    }
+    assert_eq!(change_assets_count(change.last().unwrap()), 0);
+}
+
+#[test]
+fn change_split_falls_back_to_single_output() {
+    let mut tx_builder = fake_change_split_tx_builder(5000, 0);
+    let change_config = ChangeConfig::new(&fake_change_address()).change_pure_ada_output(true);
+    assert!(tx_builder.add_change_if_needed_with_config(&change_config).unwrap());
+
+    let change = assert_change_split_balanced(&tx_builder);
+    assert_eq!(change.len(), 1);
+}
+
 #[test]
 fn test_costmodel_retaining_for_v1() {
     let mut tx_builder = fake_reallistic_tx_builder();
@@ -7038,4 +7669,44 @@ fn test_withdrawals_sorting_order() {
         &withdrawals[2].0.payment_cred().to_keyhash().unwrap(),
         &key_hash2
     );
-}
\ No newline at end of file
+}
+
+#[test]
+fn set_validity_interval_posix_rounds_inside_interval() {
+    let mut tx_builder = fake_reallistic_tx_builder();
+    let slot_config = SlotConfig::testnet_preview();
+    let zero_time = 1666656000000u64;
+    tx_builder
+        .set_validity_interval_posix(
+            &BigNum(zero_time + 10_500),
+            &BigNum(zero_time + 20_500),
+            &slot_config,
+        )
+        .unwrap();
+    assert_eq!(tx_builder.validity_start_interval, Some(BigNum(11)));
+    assert_eq!(tx_builder.ttl, Some(BigNum(20)));
+
+    tx_builder
+        .set_validity_interval_posix(
+            &BigNum(zero_time + 10_000),
+            &BigNum(zero_time + 20_000),
+            &slot_config,
+        )
+        .unwrap();
+    assert_eq!(tx_builder.validity_start_interval, Some(BigNum(10)));
+    assert_eq!(tx_builder.ttl, Some(BigNum(20)));
+
+    assert!(tx_builder
+        .set_validity_interval_posix(
+            &BigNum(zero_time + 10_100),
+            &BigNum(zero_time + 10_900),
+            &slot_config,
+        )
+        .is_err());
+    assert_eq!(tx_builder.ttl, Some(BigNum(20)));
+
+    tx_builder.set_ttl_posix(&BigNum(zero_time + 30_999), &slot_config).unwrap();
+    assert_eq!(tx_builder.ttl, Some(BigNum(30)));
+    tx_builder.set_validity_start_posix(&BigNum(zero_time + 1), &slot_config).unwrap();
+    assert_eq!(tx_builder.validity_start_interval, Some(BigNum(1)));
+}
@@ -0,0 +1,441 @@
+//! Reader for the subset of CDDL (RFC 8610) used by the ledger specs in `specs/`.
+//! It understands rules, generic parameters, sockets, type and group choices,
+//! occurrences, ranges, tags and the `.size`, `.le` and `.cbor` control operators.
+
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Int(i128),
+    Text(String),
+    Bytes(Vec<u8>),
+    Tag(u64),
+    Control(String),
+    Punct(&'static str),
+}
+
+#[derive(Clone, Debug)]
+pub(super) enum Type {
+    Choice(Vec<Type>),
+    Int(i128),
+    Text(String),
+    Bytes(Vec<u8>),
+    Name(String, Vec<Type>),
+    Range(Box<Type>, Box<Type>, bool),
+    Control(Box<Type>, String, Box<Type>),
+    Array(Group),
+    Map(Group),
+    Tagged(u64, Box<Type>),
+}
+
+/// Alternatives separated by `//`, each one a sequence of entries
+#[derive(Clone, Debug)]
+pub(super) struct Group(pub Vec<Vec<Entry>>);
+
+#[derive(Clone, Debug)]
+pub(super) struct Entry {
+    pub min: usize,
+    pub max: Option<usize>,
+    pub key: Option<Key>,
+    pub kind: EntryKind,
+}
+
+#[derive(Clone, Debug)]
+pub(super) enum EntryKind {
+    Type(Type),
+    Group(Group),
+}
+
+#[derive(Clone, Debug)]
+pub(super) enum Key {
+    Label(String),
+    Type(Type),
+}
+
+#[derive(Clone, Debug)]
+pub(super) enum RuleBody {
+    Type(Type),
+    Group(Group),
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct Rule {
+    pub params: Vec<String>,
+    pub body: RuleBody,
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct Cddl {
+    rules: HashMap<String, Rule>,
+}
+
+impl Cddl {
+    pub fn parse(src: &str) -> Result<Self, String> {
+        let mut parser = Parser {
+            tokens: tokenize(src)?,
+            pos: 0,
+        };
+        let mut rules: HashMap<String, Rule> = HashMap::new();
+        while parser.peek().is_some() {
+            let (name, is_socket, rule) = parser.parse_rule()?;
+            match (is_socket, rules.get_mut(&name)) {
+                (true, Some(existing)) => match (&mut existing.body, rule.body) {
+                    (RuleBody::Type(Type::Choice(alts)), RuleBody::Type(t)) => alts.push(t),
+                    (RuleBody::Type(prev), RuleBody::Type(t)) => {
+                        *prev = Type::Choice(vec![prev.clone(), t]);
+                    }
+                    _ => return Err(format!("group socket `{}` is not supported", name)),
+                },
+                (true, None) | (false, None) => {
+                    rules.insert(name, rule);
+                }
+                (false, Some(_)) => return Err(format!("rule `{}` is defined twice", name)),
+            }
+        }
+        Ok(Self { rules })
+    }
+
+    /// Replaces the rules of the spec by the same-named rules of `src`
+    pub fn with_overrides(mut self, src: &str) -> Result<Self, String> {
+        self.rules.extend(Self::parse(src)?.rules);
+        Ok(self)
+    }
+
+    pub fn rule(&self, name: &str) -> Option<&Rule> {
+        self.rules.get(name)
+    }
+}
+
+fn tokenize(src: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = src.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).cloned();
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == ';' {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == 'h' && next == Some('\'') {
+            let start = i + 2;
+            let len = chars[start..]
+                .iter()
+                .position(|c| *c == '\'')
+                .ok_or("unterminated byte string")?;
+            let hex_str: String = chars[start..start + len]
+                .iter()
+                .filter(|c| !c.is_whitespace())
+                .collect();
+            tokens.push(Token::Bytes(
+                hex::decode(&hex_str).map_err(|e| e.to_string())?,
+            ));
+            i = start + len + 1;
+        } else if c.is_ascii_alphabetic() || c == '_' || c == '$' || c == '@' {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_ascii_alphanumeric() || matches!(chars[i], '_' | '$' | '@' | '-'))
+            {
+                i += 1;
+            }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+        } else if c.is_ascii_digit() || (c == '-' && next.is_some_and(|d| d.is_ascii_digit())) {
+            let start = i;
+            i += 1;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            let number: String = chars[start..i].iter().collect();
+            tokens.push(Token::Int(number.parse().map_err(|_| {
+                format!("invalid integer `{}`", number)
+            })?));
+        } else if c == '"' {
+            let start = i + 1;
+            let len = chars[start..]
+                .iter()
+                .position(|c| *c == '"')
+                .ok_or("unterminated text string")?;
+            tokens.push(Token::Text(chars[start..start + len].iter().collect()));
+            i = start + len + 1;
+        } else if c == '#' {
+            // only major type 6 (tags) is used by the specs: #6.<tag>
+            if next != Some('6') || chars.get(i + 2) != Some(&'.') {
+                return Err(format!("unsupported type prefix at char {}", i));
+            }
+            let start = i + 3;
+            i = start;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            let tag: String = chars[start..i].iter().collect();
+            tokens.push(Token::Tag(
+                tag.parse().map_err(|_| format!("invalid tag `{}`", tag))?,
+            ));
+        } else if c == '.' && next == Some('.') {
+            if chars.get(i + 2) == Some(&'.') {
+                tokens.push(Token::Punct("..."));
+                i += 3;
+            } else {
+                tokens.push(Token::Punct(".."));
+                i += 2;
+            }
+        } else if c == '.' && next.is_some_and(|c| c.is_ascii_alphabetic()) {
+            let start = i + 1;
+            i = start;
+            while i < chars.len() && chars[i].is_ascii_alphanumeric() {
+                i += 1;
+            }
+            tokens.push(Token::Control(chars[start..i].iter().collect()));
+        } else {
+            let punct = match (c, next) {
+                ('/', Some('/')) => "//",
+                ('/', Some('=')) => "/=",
+                ('=', Some('>')) => "=>",
+                ('=', _) => "=",
+                ('/', _) => "/",
+                ('(', _) => "(",
+                (')', _) => ")",
+                ('[', _) => "[",
+                (']', _) => "]",
+                ('{', _) => "{",
+                ('}', _) => "}",
+                ('<', _) => "<",
+                ('>', _) => ">",
+                (',', _) => ",",
+                (':', _) => ":",
+                ('?', _) => "?",
+                ('*', _) => "*",
+                ('+', _) => "+",
+                _ => return Err(format!("unexpected character `{}` at char {}", c, i)),
+            };
+            tokens.push(Token::Punct(punct));
+            i += punct.len();
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset)
+    }
+
+    fn next(&mut self) -> Result<Token, String> {
+        let token = self.peek().cloned().ok_or("unexpected end of CDDL")?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn is_punct(&self, offset: usize, punct: &str) -> bool {
+        matches!(self.peek_at(offset), Some(Token::Punct(p)) if *p == punct)
+    }
+
+    fn eat(&mut self, punct: &str) -> bool {
+        let found = self.is_punct(0, punct);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, punct: &str) -> Result<(), String> {
+        if self.eat(punct) {
+            Ok(())
+        } else {
+            Err(format!("expected `{}`, found {:?}", punct, self.peek()))
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, String> {
+        match self.next()? {
+            Token::Ident(name) => Ok(name),
+            t => Err(format!("expected a name, found {:?}", t)),
+        }
+    }
+
+    fn parse_rule(&mut self) -> Result<(String, bool, Rule), String> {
+        let name = self.ident()?;
+        let mut params = Vec::new();
+        if self.eat("<") {
+            loop {
+                params.push(self.ident()?);
+                if !self.eat(",") {
+                    break;
+                }
+            }
+            self.expect(">")?;
+        }
+        let is_socket = self.eat("/=");
+        if !is_socket {
+            self.expect("=")
+                .map_err(|e| format!("rule `{}`: {}", name, e))?;
+        }
+        let body = if self.eat("(") {
+            let group = self.parse_group()?;
+            self.expect(")")?;
+            RuleBody::Group(group)
+        } else {
+            RuleBody::Type(self.parse_type()?)
+        };
+        Ok((name, is_socket, Rule { params, body }))
+    }
+
+    fn parse_type(&mut self) -> Result<Type, String> {
+        let mut alts = vec![self.parse_type1()?];
+        while self.eat("/") {
+            alts.push(self.parse_type1()?);
+        }
+        Ok(match alts.len() {
+            1 => alts.remove(0),
+            _ => Type::Choice(alts),
+        })
+    }
+
+    fn parse_type1(&mut self) -> Result<Type, String> {
+        let base = self.parse_type2()?;
+        if self.eat("..") {
+            return Ok(Type::Range(Box::new(base), Box::new(self.parse_type2()?), true));
+        }
+        if self.eat("...") {
+            return Ok(Type::Range(Box::new(base), Box::new(self.parse_type2()?), false));
+        }
+        if let Some(Token::Control(op)) = self.peek().cloned() {
+            self.pos += 1;
+            return Ok(Type::Control(Box::new(base), op, Box::new(self.parse_type2()?)));
+        }
+        Ok(base)
+    }
+
+    fn parse_type2(&mut self) -> Result<Type, String> {
+        match self.next()? {
+            Token::Int(v) => Ok(Type::Int(v)),
+            Token::Text(s) => Ok(Type::Text(s)),
+            Token::Bytes(b) => Ok(Type::Bytes(b)),
+            Token::Ident(name) => {
+                let mut args = Vec::new();
+                if self.eat("<") {
+                    loop {
+                        args.push(self.parse_type1()?);
+                        if !self.eat(",") {
+                            break;
+                        }
+                    }
+                    self.expect(">")?;
+                }
+                Ok(Type::Name(name, args))
+            }
+            Token::Tag(tag) => {
+                self.expect("(")?;
+                let inner = self.parse_type()?;
+                self.expect(")")?;
+                Ok(Type::Tagged(tag, Box::new(inner)))
+            }
+            Token::Punct("(") => {
+                let inner = self.parse_type()?;
+                self.expect(")")?;
+                Ok(inner)
+            }
+            Token::Punct("[") => {
+                let group = self.parse_group()?;
+                self.expect("]")?;
+                Ok(Type::Array(group))
+            }
+            Token::Punct("{") => {
+                let group = self.parse_group()?;
+                self.expect("}")?;
+                Ok(Type::Map(group))
+            }
+            t => Err(format!("expected a type, found {:?}", t)),
+        }
+    }
+
+    fn parse_group(&mut self) -> Result<Group, String> {
+        let mut choices = Vec::new();
+        loop {
+            let mut entries = Vec::new();
+            while !(self.peek().is_none()
+                || self.is_punct(0, ")")
+                || self.is_punct(0, "]")
+                || self.is_punct(0, "}")
+                || self.is_punct(0, "//"))
+            {
+                entries.push(self.parse_entry()?);
+                self.eat(",");
+            }
+            choices.push(entries);
+            if !self.eat("//") {
+                break;
+            }
+        }
+        Ok(Group(choices))
+    }
+
+    fn parse_occurrence(&mut self) -> (usize, Option<usize>) {
+        if self.eat("?") {
+            return (0, Some(1));
+        }
+        if self.eat("*") {
+            return (0, None);
+        }
+        if self.eat("+") {
+            return (1, None);
+        }
+        if let (Some(Token::Int(min)), true) = (self.peek().cloned(), self.is_punct(1, "*")) {
+            self.pos += 2;
+            if let Some(Token::Int(max)) = self.peek().cloned() {
+                self.pos += 1;
+                return (min as usize, Some(max as usize));
+            }
+            return (min as usize, None);
+        }
+        (1, Some(1))
+    }
+
+    fn parse_entry(&mut self) -> Result<Entry, String> {
+        let (min, max) = self.parse_occurrence();
+        if self.eat("(") {
+            let group = self.parse_group()?;
+            self.expect(")")?;
+            return Ok(Entry {
+                min,
+                max,
+                key: None,
+                kind: EntryKind::Group(group),
+            });
+        }
+        let mut key = None;
+        if self.is_punct(1, ":") {
+            key = Some(match self.next()? {
+                Token::Ident(label) => Key::Label(label),
+                Token::Int(v) => Key::Type(Type::Int(v)),
+                Token::Text(s) => Key::Type(Type::Text(s)),
+                Token::Bytes(b) => Key::Type(Type::Bytes(b)),
+                t => return Err(format!("unsupported member key {:?}", t)),
+            });
+            self.pos += 1;
+        }
+        let mut ty = self.parse_type()?;
+        if self.eat("=>") {
+            key = Some(Key::Type(ty));
+            ty = self.parse_type()?;
+        }
+        Ok(Entry {
+            min,
+            max,
+            key,
+            kind: EntryKind::Type(ty),
+        })
+    }
+}
@@ -0,0 +1,355 @@
+//! Generates random CBOR instances of CDDL rules.
+//! Integers, lengths and tags use the shortest encoding and map keys are
+//! unique and sorted the way RFC 7049 canonical CBOR orders them.
+
+use super::cddl::{Cddl, EntryKind, Group, Key, RuleBody, Type};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+
+/// Below this depth optional and repeated items are left out and the first
+/// alternative of every choice is taken, so recursive rules terminate
+const MAX_DEPTH: usize = 8;
+const MAX_EXTRA_ITEMS: usize = 3;
+const MAX_STRING_LEN: usize = 64;
+
+type Env = HashMap<String, Type>;
+
+pub(super) struct Generator<'a> {
+    cddl: &'a Cddl,
+    rng: StdRng,
+    depth: usize,
+}
+
+impl<'a> Generator<'a> {
+    pub fn new(cddl: &'a Cddl, seed: u64) -> Self {
+        Self {
+            cddl,
+            rng: StdRng::seed_from_u64(seed),
+            depth: 0,
+        }
+    }
+
+    pub fn generate(&mut self, rule: &str) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.gen_type(&Type::Name(rule.to_string(), vec![]), &Env::new(), &mut out);
+        out
+    }
+
+    fn encode(&mut self, t: &Type, env: &Env) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.gen_type(t, env, &mut out);
+        out
+    }
+
+    fn gen_type(&mut self, t: &Type, env: &Env, out: &mut Vec<u8>) {
+        match t {
+            Type::Choice(alts) => {
+                let alt = self.pick(alts);
+                self.gen_type(alt, env, out);
+            }
+            Type::Int(v) => write_int(out, *v),
+            Type::Text(s) => write_text(out, s),
+            Type::Bytes(b) => write_bytes(out, b),
+            Type::Name(name, args) => self.gen_name(name, args, env, out),
+            Type::Range(lo, hi, inclusive) => {
+                let lo = self.int_value(lo);
+                let hi = self.int_value(hi) - if *inclusive { 0 } else { 1 };
+                let value = self.int_in(lo, hi);
+                write_int(out, value);
+            }
+            Type::Control(base, op, arg) => self.gen_control(base, op, arg, env, out),
+            Type::Array(group) => {
+                self.depth += 1;
+                let items = self.gen_group(group, env, false);
+                self.depth -= 1;
+                write_head(out, 4, items.len() as u64);
+                items.iter().for_each(|item| out.extend(item));
+            }
+            Type::Map(group) => {
+                self.depth += 1;
+                let items = self.gen_group(group, env, true);
+                self.depth -= 1;
+                let mut entries: Vec<(Vec<u8>, Vec<u8>)> = Vec::new();
+                for pair in items.chunks(2) {
+                    if entries.iter().all(|(key, _)| *key != pair[0]) {
+                        entries.push((pair[0].clone(), pair[1].clone()));
+                    }
+                }
+                entries.sort_by(|(a, _), (b, _)| a.len().cmp(&b.len()).then(a.cmp(b)));
+                write_head(out, 5, entries.len() as u64);
+                for (key, value) in entries {
+                    out.extend(key);
+                    out.extend(value);
+                }
+            }
+            Type::Tagged(tag, inner) => {
+                write_head(out, 6, *tag);
+                self.depth += 1;
+                self.gen_type(inner, env, out);
+                self.depth -= 1;
+            }
+        }
+    }
+
+    fn gen_name(&mut self, name: &str, args: &[Type], env: &Env, out: &mut Vec<u8>) {
+        if let Some(bound) = env.get(name) {
+            let bound = bound.clone();
+            return self.gen_type(&bound, &Env::new(), out);
+        }
+        match name {
+            "uint" => {
+                let value = self.uint_below(1 << 64);
+                write_int(out, value as i128);
+            }
+            "nint" => {
+                let value = self.uint_below(1 << 64);
+                write_int(out, -1 - value as i128);
+            }
+            "int" => {
+                let value = self.uint_below(1 << 64) as i128;
+                write_int(out, if self.rng.gen_bool(0.5) { value } else { -1 - value });
+            }
+            "bytes" | "bstr" => {
+                let len = self.rng.gen_range(0..=MAX_STRING_LEN);
+                let bytes = self.random_bytes(len);
+                write_bytes(out, &bytes);
+            }
+            "text" | "tstr" => {
+                let len = self.rng.gen_range(0..=MAX_STRING_LEN);
+                let text = self.random_text(len);
+                write_text(out, &text);
+            }
+            "bool" => out.push(if self.rng.gen_bool(0.5) { 0xf5 } else { 0xf4 }),
+            "true" => out.push(0xf5),
+            "false" => out.push(0xf4),
+            "null" | "nil" => out.push(0xf6),
+            _ => {
+                let cddl = self.cddl;
+                let rule = cddl
+                    .rule(name)
+                    .unwrap_or_else(|| panic!("CDDL rule `{}` is not defined", name));
+                let inner = bind_params(&rule.params, args, env);
+                match &rule.body {
+                    RuleBody::Type(t) => self.gen_type(t, &inner, out),
+                    RuleBody::Group(_) => panic!("CDDL group `{}` is used as a type", name),
+                }
+            }
+        }
+    }
+
+    fn gen_control(&mut self, base: &Type, op: &str, arg: &Type, env: &Env, out: &mut Vec<u8>) {
+        let base_name = match base {
+            Type::Name(name, _) => name.as_str(),
+            _ => panic!("unsupported base type for .{}: {:?}", op, base),
+        };
+        match (op, base_name) {
+            ("size", "uint") => {
+                let bytes = self.int_value(arg) as u32;
+                let value = self.uint_below(1u128 << (8 * bytes));
+                write_int(out, value as i128);
+            }
+            ("size", "bytes") | ("size", "bstr") => {
+                let len = self.size_value(arg);
+                let bytes = self.random_bytes(len);
+                write_bytes(out, &bytes);
+            }
+            ("size", "text") | ("size", "tstr") => {
+                let len = self.size_value(arg);
+                let text = self.random_text(len);
+                write_text(out, &text);
+            }
+            ("le", "uint") => {
+                let max = self.int_value(arg);
+                let value = self.int_in(0, max);
+                write_int(out, value);
+            }
+            ("cbor", "bytes") | ("cbor", "bstr") => {
+                self.depth += 1;
+                let inner = self.encode(arg, env);
+                self.depth -= 1;
+                write_bytes(out, &inner);
+            }
+            _ => panic!("unsupported control operator {} .{}", base_name, op),
+        }
+    }
+
+    /// Items of the chosen group alternative, or key and value items in turn for maps
+    fn gen_group(&mut self, group: &Group, env: &Env, in_map: bool) -> Vec<Vec<u8>> {
+        let entries = self.pick(&group.0);
+        let mut items = Vec::new();
+        for entry in entries {
+            let count = self.count(entry.min, entry.max);
+            for _ in 0..count {
+                let ty = match &entry.kind {
+                    EntryKind::Group(inner) => {
+                        items.extend(self.gen_group(inner, env, in_map));
+                        continue;
+                    }
+                    EntryKind::Type(ty) => ty,
+                };
+                if entry.key.is_none() {
+                    if let Some((inner, inner_env)) = self.group_rule(ty, env) {
+                        items.extend(self.gen_group(inner, &inner_env, in_map));
+                        continue;
+                    }
+                }
+                if in_map {
+                    let key = match &entry.key {
+                        Some(Key::Label(label)) => {
+                            let mut key = Vec::new();
+                            write_text(&mut key, label);
+                            key
+                        }
+                        Some(Key::Type(key)) => self.encode(key, env),
+                        None => panic!("map member without a key: {:?}", ty),
+                    };
+                    items.push(key);
+                }
+                items.push(self.encode(ty, env));
+            }
+        }
+        items
+    }
+
+    fn group_rule(&self, t: &Type, env: &Env) -> Option<(&'a Group, Env)> {
+        let cddl = self.cddl;
+        match t {
+            Type::Name(name, args) if !env.contains_key(name) => match cddl.rule(name) {
+                Some(rule) => match &rule.body {
+                    RuleBody::Group(group) => Some((group, bind_params(&rule.params, args, env))),
+                    RuleBody::Type(_) => None,
+                },
+                None => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn pick<'t, T>(&mut self, alts: &'t [T]) -> &'t T {
+        if self.depth >= MAX_DEPTH {
+            &alts[0]
+        } else {
+            &alts[self.rng.gen_range(0..alts.len())]
+        }
+    }
+
+    fn count(&mut self, min: usize, max: Option<usize>) -> usize {
+        if self.depth >= MAX_DEPTH {
+            return min;
+        }
+        let max = max.unwrap_or(min + MAX_EXTRA_ITEMS);
+        self.rng.gen_range(min..=max)
+    }
+
+    fn int_value(&self, t: &Type) -> i128 {
+        match t {
+            Type::Int(v) => *v,
+            Type::Name(name, _) => match self.cddl.rule(name).map(|rule| &rule.body) {
+                Some(RuleBody::Type(t)) => self.int_value(t),
+                _ => panic!("`{}` is not an integer constant", name),
+            },
+            _ => panic!("expected an integer constant, found {:?}", t),
+        }
+    }
+
+    fn size_value(&mut self, t: &Type) -> usize {
+        match t {
+            Type::Range(lo, hi, inclusive) => {
+                let lo = self.int_value(lo);
+                let hi = self.int_value(hi) - if *inclusive { 0 } else { 1 };
+                self.int_in(lo, hi) as usize
+            }
+            _ => self.int_value(t) as usize,
+        }
+    }
+
+    /// Mostly values close to the bounds, so every encoding width gets exercised
+    fn int_in(&mut self, lo: i128, hi: i128) -> i128 {
+        let span = (hi - lo) as u128;
+        let near = span.min(23);
+        match self.rng.gen_range(0..3) {
+            0 => lo + self.rng.gen_range(0..=near) as i128,
+            1 => hi - self.rng.gen_range(0..=near) as i128,
+            _ => lo + (self.rng.gen::<u128>() % (span + 1)) as i128,
+        }
+    }
+
+    /// A value of a random CBOR argument width that is below `limit`
+    fn uint_below(&mut self, limit: u128) -> u128 {
+        let value = match self.rng.gen_range(0..5) {
+            0 => self.rng.gen_range(0..24),
+            1 => self.rng.gen::<u8>() as u128,
+            2 => self.rng.gen::<u16>() as u128,
+            3 => self.rng.gen::<u32>() as u128,
+            _ => self.rng.gen::<u64>() as u128,
+        };
+        value % limit
+    }
+
+    fn random_bytes(&mut self, len: usize) -> Vec<u8> {
+        (0..len).map(|_| self.rng.gen()).collect()
+    }
+
+    fn random_text(&mut self, len: usize) -> String {
+        const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789-._~/:";
+        (0..len)
+            .map(|_| ALPHABET[self.rng.gen_range(0..ALPHABET.len())] as char)
+            .collect()
+    }
+}
+
+/// Generic arguments are resolved in the caller's scope before they are bound
+fn bind_params(params: &[String], args: &[Type], env: &Env) -> Env {
+    params
+        .iter()
+        .zip(args)
+        .map(|(param, arg)| {
+            let arg = match arg {
+                Type::Name(name, inner) if inner.is_empty() && env.contains_key(name) => {
+                    env[name].clone()
+                }
+                _ => arg.clone(),
+            };
+            (param.clone(), arg)
+        })
+        .collect()
+}
+
+fn write_head(out: &mut Vec<u8>, major: u8, value: u64) {
+    let major = major << 5;
+    match value {
+        0..=23 => out.push(major | value as u8),
+        24..=0xff => out.extend([major | 24, value as u8]),
+        0x100..=0xffff => {
+            out.push(major | 25);
+            out.extend((value as u16).to_be_bytes());
+        }
+        0x1_0000..=0xffff_ffff => {
+            out.push(major | 26);
+            out.extend((value as u32).to_be_bytes());
+        }
+        _ => {
+            out.push(major | 27);
+            out.extend(value.to_be_bytes());
+        }
+    }
+}
+
+fn write_int(out: &mut Vec<u8>, value: i128) {
+    if value >= 0 {
+        write_head(out, 0, value as u64);
+    } else {
+        write_head(out, 1, (-1 - value) as u64);
+    }
+}
+
+fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    write_head(out, 2, bytes.len() as u64);
+    out.extend(bytes);
+}
+
+fn write_text(out: &mut Vec<u8>, text: &str) {
+    write_head(out, 3, text.len() as u64);
+    out.extend(text.as_bytes());
+}
@@ -0,0 +1,119 @@
+use crate::tests::fakes::fake_root_key;
+use crate::tests::helpers::harden;
+use crate::*;
+
+fn account_key() -> Bip32PrivateKey {
+    fake_root_key()
+        .derive(harden(1852))
+        .derive(harden(1815))
+        .derive(harden(0))
+}
+
+fn key_hash(key: &Bip32PublicKey) -> Ed25519KeyHash {
+    key.to_raw_key().hash()
+}
+
+#[test]
+fn keys_follow_the_derivation_path() {
+    let account = Cip1852Account::from_root_key(&fake_root_key(), 0).unwrap();
+    assert!(account.has_private_keys());
+    assert_eq!(account.account(), 0);
+    assert_eq!(
+        account.account_key().unwrap().as_bytes(),
+        account_key().as_bytes()
+    );
+
+    let roles = [
+        (Cip1852Role::External, 0),
+        (Cip1852Role::Internal, 1),
+        (Cip1852Role::Stake, 2),
+        (Cip1852Role::DRep, 3),
+        (Cip1852Role::CommitteeCold, 4),
+        (Cip1852Role::CommitteeHot, 5),
+    ];
+    for (role, chain) in roles.iter() {
+        let expected = account_key().derive(*chain).derive(7);
+        assert_eq!(
+            account.private_key(*role, 7).unwrap().as_bytes(),
+            expected.as_bytes()
+        );
+        assert_eq!(
+            account.public_key(*role, 7).unwrap().as_bytes(),
+            expected.to_public().as_bytes()
+        );
+    }
+    assert_eq!(
+        account.drep_key(1).unwrap().as_bytes(),
+        account_key().derive(3).derive(1).to_public().as_bytes()
+    );
+    assert_eq!(
+        account.committee_hot_key(0).unwrap().as_bytes(),
+        account_key().derive(5).derive(0).to_public().as_bytes()
+    );
+}
+
+#[test]
+fn addresses_and_credentials() {
+    let account = Cip1852Account::from_account_key(&account_key(), 0).unwrap();
+    let payment = key_hash(&account_key().derive(0).derive(3).to_public());
+    let change = key_hash(&account_key().derive(1).derive(3).to_public());
+    let stake = key_hash(&account_key().derive(2).derive(0).to_public());
+
+    let base = account.base_address(1, 3).unwrap();
+    assert_eq!(base.network_id(), 1);
+    assert_eq!(base.payment_cred(), Credential::from_keyhash(&payment));
+    assert_eq!(base.stake_cred(), Credential::from_keyhash(&stake));
+    let change_address = account.change_address(0, 3).unwrap();
+    assert_eq!(
+        change_address.payment_cred(),
+        Credential::from_keyhash(&change)
+    );
+    assert_eq!(
+        change_address.stake_cred(),
+        Credential::from_keyhash(&stake)
+    );
+
+    let reward = account.reward_address(1, 0).unwrap();
+    assert_eq!(reward.payment_cred(), Credential::from_keyhash(&stake));
+
+    let drep = key_hash(&account_key().derive(3).derive(0).to_public());
+    assert_eq!(account.drep(0).unwrap(), DRep::new_key_hash(&drep));
+    let cold = key_hash(&account_key().derive(4).derive(0).to_public());
+    assert_eq!(
+        account.committee_cold_credential(0).unwrap(),
+        Credential::from_keyhash(&cold)
+    );
+    let hot = key_hash(&account_key().derive(5).derive(0).to_public());
+    assert_eq!(
+        account.committee_hot_credential(0).unwrap(),
+        Credential::from_keyhash(&hot)
+    );
+}
+
+#[test]
+fn public_accounts_derive_the_same_addresses() {
+    let account = Cip1852Account::from_account_key(&account_key(), 0).unwrap();
+    let public = Cip1852Account::from_account_public_key(&account_key().to_public(), 0).unwrap();
+    assert!(!public.has_private_keys());
+    assert!(public.account_key().is_none());
+    assert!(public.private_key(Cip1852Role::External, 0).is_err());
+    assert_eq!(
+        public.base_address(1, 5).unwrap().to_address(),
+        account.base_address(1, 5).unwrap().to_address()
+    );
+    assert_eq!(
+        public.stake_key(0).unwrap().as_bytes(),
+        account.stake_key(0).unwrap().as_bytes()
+    );
+}
+
+#[test]
+fn hardened_indices_are_rejected() {
+    assert!(Cip1852Account::from_root_key(&fake_root_key(), harden(0)).is_err());
+    assert!(Cip1852Account::from_account_key(&account_key(), harden(0)).is_err());
+    assert!(Cip1852Account::from_account_public_key(&account_key().to_public(), harden(0)).is_err());
+    let account = Cip1852Account::from_account_key(&account_key(), 0).unwrap();
+    assert!(account.external_key(harden(0)).is_err());
+    assert!(account.private_key(Cip1852Role::Stake, harden(0)).is_err());
+    assert!(account.base_address(1, harden(2)).is_err());
+}
@@ -0,0 +1,147 @@
+use crate::tests::fakes::fake_root_key;
+use crate::*;
+
+fn account() -> Cip1852Account {
+    Cip1852Account::from_root_key(&fake_root_key(), 0).unwrap()
+}
+
+fn payment_key() -> PrivateKey {
+    account()
+        .private_key(Cip1852Role::External, 0)
+        .unwrap()
+        .to_raw_key()
+}
+
+fn stake_key() -> PrivateKey {
+    account()
+        .private_key(Cip1852Role::Stake, 0)
+        .unwrap()
+        .to_raw_key()
+}
+
+fn address() -> Address {
+    account().base_address(1, 0).unwrap().to_address()
+}
+
+#[test]
+fn cose_key_encoding() {
+    let public_key = payment_key().to_public();
+    let key = CoseKey::new(&public_key);
+    assert_eq!(
+        key.to_hex(),
+        format!("a4010103272006215820{}", public_key.to_hex())
+    );
+    assert_eq!(CoseKey::from_bytes(key.to_bytes()).unwrap(), key);
+
+    // the algorithm is optional and other labels are ignored
+    let minimal = hex::decode(format!("a40101200602820102215820{}", public_key.to_hex())).unwrap();
+    let minimal = CoseKey::from_bytes(minimal).unwrap();
+    assert_eq!(minimal.public_key(), public_key);
+
+    // X25519 keys are not supported
+    let x25519 = hex::decode(format!("a301012004215820{}", public_key.to_hex())).unwrap();
+    assert!(CoseKey::from_bytes(x25519).is_err());
+}
+
+#[test]
+fn signed_message_layout() {
+    let message = CoseSign1::new_signed(&payment_key(), &address(), b"hello", &[], false);
+    let cbor = message.to_hex();
+    // protected header {1: -8, "address": h'...'}
+    assert!(cbor.starts_with(&format!(
+        "845846a201276761646472657373{}{}",
+        "5839",
+        address().to_hex()
+    )));
+    // unprotected header {"hashed": false} then the payload
+    assert!(cbor.contains(&format!("a166686173686564f445{}", hex::encode(b"hello"))));
+    assert!(cbor.ends_with(&format!("5840{}", message.signature().to_hex())));
+
+    let decoded = CoseSign1::from_bytes(message.to_bytes()).unwrap();
+    assert_eq!(decoded, message);
+    assert_eq!(decoded.address().unwrap(), Some(address()));
+    assert_eq!(decoded.payload(), Some(b"hello".to_vec()));
+
+    // the COSE_Sign1 tag is accepted
+    let mut tagged = vec![0xd2];
+    tagged.extend(message.to_bytes());
+    assert_eq!(CoseSign1::from_bytes(tagged).unwrap(), message);
+}
+
+#[test]
+fn signature_is_verified_against_the_address() {
+    let key = CoseKey::new(&payment_key().to_public());
+    let message = CoseSign1::new_signed(&payment_key(), &address(), b"hello", b"aad", false);
+    assert!(message.verify(&key, b"aad").unwrap());
+    assert!(!message.verify(&key, b"other aad").unwrap());
+    assert!(message.payload_matches(b"hello"));
+    assert!(payment_key()
+        .to_public()
+        .verify(&message.signed_data(b"aad").unwrap(), &message.signature()));
+
+    // a key that doesn't belong to the address
+    let other_key = account()
+        .private_key(Cip1852Role::External, 1)
+        .unwrap()
+        .to_raw_key();
+    let forged = CoseSign1::new_signed(&other_key, &address(), b"hello", b"aad", false);
+    assert!(!forged
+        .verify(&CoseKey::new(&other_key.to_public()), b"aad")
+        .unwrap());
+    assert!(!forged.verify(&key, b"aad").unwrap());
+}
+
+#[test]
+fn stake_key_signs_for_the_base_and_reward_addresses() {
+    let key = CoseKey::new(&stake_key().to_public());
+    let message = CoseSign1::new_signed(&stake_key(), &address(), b"hello", &[], false);
+    assert!(message.verify(&key, &[]).unwrap());
+
+    let reward_address = account().reward_address(1, 0).unwrap().to_address();
+    let message = CoseSign1::new_signed(&stake_key(), &reward_address, b"hello", &[], false);
+    assert!(message.verify(&key, &[]).unwrap());
+
+    let message = CoseSign1::new_signed(&payment_key(), &reward_address, b"hello", &[], false);
+    assert!(!message
+        .verify(&CoseKey::new(&payment_key().to_public()), &[])
+        .unwrap());
+}
+
+#[test]
+fn hashed_payload_carries_the_hash() {
+    let message = CoseSign1::new_signed(&payment_key(), &address(), b"hello", &[], true);
+    assert!(message.is_hashed());
+    assert_eq!(message.payload().unwrap().len(), 28);
+    assert!(message.payload_matches(b"hello"));
+    assert!(!message.payload_matches(b"hell"));
+    assert!(message.to_hex().contains("a166686173686564f5581c"));
+
+    let decoded = CoseSign1::from_bytes(message.to_bytes()).unwrap();
+    assert!(decoded.is_hashed());
+    assert!(decoded
+        .verify(&CoseKey::new(&payment_key().to_public()), &[])
+        .unwrap());
+}
+
+#[test]
+fn messages_that_cant_be_checked_are_rejected() {
+    let key = CoseKey::new(&payment_key().to_public());
+    let message = CoseSign1::new_signed(&payment_key(), &address(), b"hello", &[], false);
+
+    let mut detached = message.clone();
+    detached.payload = None;
+    let decoded = CoseSign1::from_bytes(detached.to_bytes()).unwrap();
+    assert_eq!(decoded.payload(), None);
+    assert!(decoded.verify(&key, &[]).is_err());
+
+    let mut no_address = message.clone();
+    no_address.protected_header = hex::decode("a10127").unwrap();
+    let decoded = CoseSign1::from_bytes(no_address.to_bytes()).unwrap();
+    assert_eq!(decoded.address().unwrap(), None);
+    assert!(decoded.verify(&key, &[]).is_err());
+
+    let mut no_algorithm = message;
+    no_algorithm.protected_header = Vec::new();
+    let decoded = CoseSign1::from_bytes(no_algorithm.to_bytes()).unwrap();
+    assert!(decoded.verify(&key, &[]).is_err());
+}
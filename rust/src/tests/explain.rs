@@ -0,0 +1,335 @@
+use crate::tests::fakes::*;
+use crate::tests::helpers::pre_conway_body;
+use crate::*;
+
+fn fake_utxos(utxos: &[(TransactionInput, TransactionOutput)]) -> TransactionUnspentOutputs {
+    let mut result = TransactionUnspentOutputs::new();
+    for (input, output) in utxos {
+        result.add(&TransactionUnspentOutput::new(input, output));
+    }
+    result
+}
+
+fn stake_address(address: &Address) -> String {
+    let stake_cred = BaseAddress::from_address(address).unwrap().stake_cred();
+    RewardAddress::new(NetworkInfo::testnet_preprod().network_id(), &stake_cred)
+        .to_address()
+        .to_bech32(None)
+        .unwrap()
+}
+
+#[test]
+fn explain_reports_net_balances() {
+    let sender = fake_base_address(0);
+    let receiver = fake_base_address(1);
+    let enterprise = fake_enterprise_address(0);
+    let input = fake_tx_input(1);
+    let utxos = fake_utxos(&[(
+        input.clone(),
+        TransactionOutput::new(&sender, &Value::new(&Coin::from(10_000_000u64))),
+    )]);
+
+    let policy_id = fake_script_hash(1);
+    let asset_name = AssetName::new(b"token".to_vec()).unwrap();
+    let mut assets = Assets::new();
+    assets.insert(&asset_name, &BigNum::from(5u64));
+    let mut multiasset = MultiAsset::new();
+    multiasset.insert(&policy_id, &assets);
+    let mut value = Value::new(&Coin::from(7_800_000u64));
+    value.set_multiasset(&multiasset);
+    let mut receiver_output = TransactionOutput::new(&receiver, &value);
+    receiver_output.set_plutus_data(&PlutusData::new_integer(&BigInt::from(42)));
+
+    let mut outputs = TransactionOutputs::new();
+    outputs.add(&TransactionOutput::new(
+        &enterprise,
+        &Value::new(&Coin::from(3_000_000u64)),
+    ));
+    outputs.add(&receiver_output);
+    let mut inputs = TransactionInputs::new();
+    inputs.add(&input);
+    let mut body = TransactionBody::new_tx_body(&inputs, &outputs, &Coin::from(200_000u64));
+    body.set_mint(&Mint::new_from_entry(
+        &policy_id,
+        &MintAssets::new_from_entry(&asset_name, &Int::new_i32(5)).unwrap(),
+    ));
+    let stake_cred = BaseAddress::from_address(&sender).unwrap().stake_cred();
+    let mut withdrawals = Withdrawals::new();
+    withdrawals.insert(
+        &RewardAddress::new(NetworkInfo::testnet_preprod().network_id(), &stake_cred),
+        &Coin::from(1_000_000u64),
+    );
+    body.set_withdrawals(&withdrawals);
+    let mut certs = Certificates::new();
+    certs.add(&Certificate::new_stake_registration(
+        &StakeRegistration::new(&stake_cred),
+    ));
+    body.set_certs(&certs);
+    let mut votes = VotingProcedures::new();
+    votes.insert(
+        &Voter::new_drep_credential(&Credential::from_keyhash(&fake_key_hash(1))),
+        &fake_action_id(),
+        &VotingProcedure::new(VoteKind::Yes),
+    );
+    body.set_voting_procedures(&votes);
+
+    let tx = Transaction::new(&body, &TransactionWitnessSet::new(), None);
+    let explanation = tx.explain(&utxos, &NetworkInfo::testnet_preprod()).unwrap();
+    assert!(explanation.balances_complete());
+
+    let json: serde_json::Value = serde_json::from_str(&explanation.to_json().unwrap()).unwrap();
+    assert_eq!(json["inputs"][0]["output"]["value"]["coin"], "10000000");
+    assert_eq!(json["outputs"][1]["datum"]["inline"]["int"], 42);
+    assert_eq!(
+        json["mint"][0]["minted"][0]["asset_name"],
+        hex::encode(b"token")
+    );
+    assert_eq!(json["certificates"][0]["deposit"], "protocol_key_deposit");
+    assert_eq!(json["votes"][0]["vote"], "Yes");
+    assert_eq!(json["withdrawals"][0]["amount"], "1000000");
+
+    let balances = &json["address_balances"];
+    assert_eq!(balances[0]["address"], sender.to_bech32(None).unwrap());
+    assert_eq!(balances[0]["change"]["coin"], "-10000000");
+    assert_eq!(balances[1]["address"], enterprise.to_bech32(None).unwrap());
+    assert_eq!(balances[1]["change"]["coin"], "3000000");
+    assert_eq!(balances[2]["change"]["coin"], "7800000");
+    assert_eq!(balances[2]["change"]["assets"][0]["quantity"], "5");
+
+    // sender and receiver share the stake credential, the withdrawal leaves its reward account
+    let stake_balances = &json["stake_credential_balances"];
+    assert_eq!(stake_balances.as_array().unwrap().len(), 1);
+    assert_eq!(stake_balances[0]["address"], stake_address(&sender));
+    assert_eq!(stake_balances[0]["change"]["coin"], "-3200000");
+    assert_eq!(stake_balances[0]["change"]["assets"][0]["quantity"], "5");
+
+    let text = explanation.to_text();
+    assert!(text.contains(&format!(
+        "  {} from {}",
+        input,
+        sender.to_bech32(None).unwrap()
+    )));
+    assert!(text.contains(&format!("    minted 5 {}", hex::encode(b"token"))));
+    assert!(text.contains("StakeRegistration, key deposit set by the protocol parameters"));
+    assert!(text.contains(&format!(
+        "  {}: -3200000 lovelace, +5 {}.",
+        stake_address(&sender),
+        policy_id.to_hex()
+    )));
+}
+
+#[test]
+fn explain_failing_scripts_spends_collateral() {
+    let sender = fake_base_address(0);
+    let byron = fake_byron_address();
+    let collateral = fake_tx_input(2);
+    let utxos = fake_utxos(&[(
+        collateral.clone(),
+        TransactionOutput::new(&byron, &Value::new(&Coin::from(5_000_000u64))),
+    )]);
+
+    let mut inputs = TransactionInputs::new();
+    inputs.add(&fake_tx_input(1));
+    let mut outputs = TransactionOutputs::new();
+    outputs.add(&TransactionOutput::new(
+        &sender,
+        &Value::new(&Coin::from(1_000_000u64)),
+    ));
+    let mut body = TransactionBody::new_tx_body(&inputs, &outputs, &Coin::from(200_000u64));
+    let mut collateral_inputs = TransactionInputs::new();
+    collateral_inputs.add(&collateral);
+    body.set_collateral(&collateral_inputs);
+    body.set_collateral_return(&TransactionOutput::new(
+        &byron,
+        &Value::new(&Coin::from(4_700_000u64)),
+    ));
+    body.set_total_collateral(&Coin::from(300_000u64));
+
+    let mut tx = Transaction::new(&body, &TransactionWitnessSet::new(), None);
+    let explanation = tx.explain(&utxos, &NetworkInfo::testnet_preprod()).unwrap();
+    assert!(!explanation.balances_complete());
+    assert!(explanation
+        .to_text()
+        .contains(&format!("  {} (unresolved)", fake_tx_input(1))));
+
+    tx.set_is_valid(false);
+    let explanation = tx.explain(&utxos, &NetworkInfo::testnet_preprod()).unwrap();
+    assert!(explanation.balances_complete());
+    let json: serde_json::Value = serde_json::from_str(&explanation.to_json().unwrap()).unwrap();
+    let byron_base58 = ByronAddress::from_address(&byron).unwrap().to_base58();
+    assert_eq!(
+        json["collateral_inputs"][0]["output"]["address"],
+        byron_base58
+    );
+    let balances = json["address_balances"].as_array().unwrap();
+    assert_eq!(balances.len(), 1);
+    assert_eq!(balances[0]["address"], byron_base58);
+    assert_eq!(balances[0]["change"]["coin"], "-300000");
+    assert_eq!(
+        json["stake_credential_balances"].as_array().unwrap().len(),
+        0
+    );
+}
+
+fn cli_view(json: &str) -> serde_json::Value {
+    // cardano-cli indents with four spaces
+    assert!(json.starts_with("{\n    \""));
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn cli_view_groups_assets_by_policy() {
+    let policy_id = fake_script_hash(1);
+    let asset_name = AssetName::new(b"token".to_vec()).unwrap();
+    let mut assets = Assets::new();
+    assets.insert(&asset_name, &BigNum::from(5u64));
+    let mut multiasset = MultiAsset::new();
+    multiasset.insert(&policy_id, &assets);
+    let mut value = Value::new(&Coin::from(2_000_000u64));
+    value.set_multiasset(&multiasset);
+    let mut output = TransactionOutput::new(&fake_base_address(1), &value);
+    output.set_plutus_data(&PlutusData::new_integer(&BigInt::from(42)));
+    let mut outputs = TransactionOutputs::new();
+    outputs.add(&output);
+    let mut inputs = TransactionInputs::new();
+    inputs.add(&fake_tx_input(1));
+    let mut body = TransactionBody::new_tx_body(&inputs, &outputs, &Coin::from(200_000u64));
+    body.set_validity_start_interval_bignum(&SlotBigNum::from(100u64));
+    body.set_mint(&Mint::new_from_entry(
+        &policy_id,
+        &MintAssets::new_from_entry(&AssetName::new(vec![0xff]).unwrap(), &Int::new_i32(-3))
+            .unwrap(),
+    ));
+    let tx = Transaction::new(&body, &TransactionWitnessSet::new(), None);
+
+    let view = cli_view(&tx.to_cli_view_json().unwrap());
+    let policy = format!("policy {}", policy_id.to_hex());
+    assert_eq!(view["era"], "Conway");
+    assert_eq!(view["fee"], "200000 Lovelace");
+    assert_eq!(view["inputs"][0], fake_tx_input(1).to_string());
+    assert_eq!(view["mint"][&policy]["asset ff"], -3);
+    assert_eq!(view["validity range"]["lower bound"], 100);
+    assert!(view["validity range"]["upper bound"].is_null());
+    assert!(view["treasuryDonation"].is_null());
+    assert!(view.get("governance actions").is_none());
+
+    let output = &view["outputs"][0];
+    assert_eq!(
+        output["address"],
+        fake_base_address(1).to_bech32(None).unwrap()
+    );
+    assert_eq!(output["address era"], "Shelley");
+    assert_eq!(output["network"], "Testnet");
+    assert_eq!(output["amount"]["lovelace"], 2_000_000);
+    assert_eq!(output["amount"][&policy]["asset 746f6b656e (token)"], 5);
+    assert_eq!(output["datum"], serde_json::json!({ "int": 42 }));
+    assert!(output["reference script"].is_null());
+    let stake_cred = BaseAddress::from_address(&fake_base_address(1))
+        .unwrap()
+        .stake_cred();
+    assert_eq!(
+        output["stake reference"]["stake credential key hash"],
+        stake_cred.to_keyhash().unwrap().to_hex()
+    );
+}
+
+#[test]
+fn cli_view_decodes_certificates_and_governance() {
+    let stake_cred = Credential::from_keyhash(&fake_key_hash(1));
+    let mut certs = Certificates::new();
+    certs.add(&Certificate::new_stake_registration(
+        &StakeRegistration::new_with_explicit_deposit(&stake_cred, &Coin::from(2_000_000u64)),
+    ));
+    certs.add(&Certificate::new_vote_delegation(&VoteDelegation::new(
+        &stake_cred,
+        &DRep::new_always_abstain(),
+    )));
+    let mut votes = VotingProcedures::new();
+    votes.insert(
+        &Voter::new_drep_credential(&Credential::from_keyhash(&fake_key_hash(2))),
+        &fake_action_id(),
+        &VotingProcedure::new(VoteKind::Yes),
+    );
+    let mut proposals = VotingProposals::new();
+    proposals.add(&VotingProposal::new(
+        &GovernanceAction::new_info_action(&InfoAction::new()),
+        &fake_anchor(),
+        &fake_reward_address(1),
+        &Coin::from(100_000_000_000u64),
+    ));
+    let mut inputs = TransactionInputs::new();
+    inputs.add(&fake_tx_input(1));
+    let mut body =
+        TransactionBody::new_tx_body(&inputs, &TransactionOutputs::new(), &Coin::from(200_000u64));
+    body.set_certs(&certs);
+    body.set_voting_procedures(&votes);
+    body.set_voting_proposals(&proposals);
+    let tx = Transaction::new(&body, &TransactionWitnessSet::new(), None);
+    let multi_era = MultiEraTransaction::from_bytes(tx.to_bytes(), None).unwrap();
+
+    let view = cli_view(&multi_era.to_cli_view_json().unwrap());
+    assert_eq!(
+        view["certificates"][0]["Stake address registration"],
+        serde_json::json!({
+            "stake credential": { "keyHash": fake_key_hash(1).to_hex() },
+            "deposit": 2_000_000,
+        })
+    );
+    assert_eq!(
+        view["certificates"][1]["Stake address delegation"]["delegatee"],
+        serde_json::json!({ "dRep": "drep-alwaysAbstain" })
+    );
+    let voter = format!("drep-keyHash-{}", fake_key_hash(2).to_hex());
+    let action_id = format!("{}#1", fake_tx_hash(1).to_hex());
+    assert_eq!(view["voters"][&voter][&action_id]["decision"], "VoteYes");
+    let proposal = &view["governance actions"][0];
+    assert_eq!(
+        proposal["governance action"],
+        serde_json::json!({ "tag": "InfoAction" })
+    );
+    assert_eq!(proposal["deposit"], 100_000_000_000u64);
+    assert_eq!(proposal["anchor"]["url"], "https://iohk.io");
+}
+
+#[test]
+fn cli_view_of_shelley_transaction() {
+    let mut outputs = TransactionOutputs::new();
+    outputs.add(&TransactionOutput::new(
+        &fake_enterprise_address(1),
+        &Value::new(&Coin::from(2_000_000u64)),
+    ));
+    let mut inputs = TransactionInputs::new();
+    inputs.add(&fake_tx_input(1));
+    let mut body = TransactionBody::new_tx_body(&inputs, &outputs, &Coin::from(200_000u64));
+    body.set_ttl(&SlotBigNum::from(1000u64));
+    let mut metadata = GeneralTransactionMetadata::new();
+    metadata.insert(
+        &BigNum::from(674u64),
+        &TransactionMetadatum::new_bytes(vec![0x01, b'"', 200, b'1']).unwrap(),
+    );
+    let mut auxiliary_data = AuxiliaryData::new();
+    auxiliary_data.set_metadata(&metadata);
+    // Shelley transactions have no is_valid flag
+    let mut serializer = Serializer::new_vec();
+    serializer.write_array(Len::Len(3)).unwrap();
+    serializer.write_raw_bytes(&pre_conway_body(&body).to_bytes()).unwrap();
+    serializer
+        .write_raw_bytes(&TransactionWitnessSet::new().to_bytes())
+        .unwrap();
+    serializer
+        .write_raw_bytes(&auxiliary_data.to_bytes())
+        .unwrap();
+    let tx = MultiEraTransaction::from_bytes(serializer.finalize(), None).unwrap();
+
+    let view = cli_view(&tx.to_cli_view_json().unwrap());
+    assert_eq!(view["era"], "Shelley");
+    assert_eq!(
+        view["validity range"],
+        serde_json::json!({ "time to live": 1000 })
+    );
+    assert_eq!(view["metadata"]["674"], "\"\\SOH\\\"\\200\\&1\"");
+    let output = &view["outputs"][0];
+    assert!(output["stake reference"].is_null());
+    assert!(output.get("datum").is_none());
+    assert!(view.get("currentTreasuryValue").is_none());
+}
@@ -43,6 +43,16 @@ pub(crate) fn fake_base_address_with_payment_cred(payment_cred: Credential) -> A
     addr.to_address()
 }
 
+pub(crate) fn fake_payment_key(index: u32) -> PrivateKey {
+    fake_root_key()
+        .derive(harden(1852))
+        .derive(harden(1815))
+        .derive(harden(0))
+        .derive(0)
+        .derive(index)
+        .to_raw_key()
+}
+
 pub(crate) fn fake_base_address(index: u32) -> Address {
     let spend = fake_root_key()
         .derive(harden(1852))
@@ -189,6 +199,47 @@ pub(crate) fn fake_cost_models() -> Costmdls {
     res
 }
 
+pub(crate) fn fake_protocol_parameters() -> ProtocolParameters {
+    ProtocolParameters {
+        minfee_a: BigNum(44),
+        minfee_b: BigNum(155381),
+        max_block_body_size: 90112,
+        max_tx_size: 16384,
+        max_block_header_size: 1100,
+        key_deposit: BigNum(2000000),
+        pool_deposit: BigNum(500000000),
+        max_epoch: 18,
+        n_opt: 500,
+        pool_pledge_influence: UnitInterval::new(&BigNum(3), &BigNum(10)),
+        expansion_rate: UnitInterval::new(&BigNum(3), &BigNum(1000)),
+        treasury_growth_rate: UnitInterval::new(&BigNum(1), &BigNum(5)),
+        d: None,
+        extra_entropy: None,
+        protocol_version: ProtocolVersion::new(10, 0),
+        min_pool_cost: BigNum(170000000),
+        ada_per_utxo_byte: BigNum(4310),
+        cost_models: fake_cost_models(),
+        execution_costs: ExUnitPrices::new(
+            &SubCoin::new(&BigNum(577), &BigNum(10000)),
+            &SubCoin::new(&BigNum(721), &BigNum(10000000)),
+        ),
+        max_tx_ex_units: ExUnits::new(&BigNum(14000000), &BigNum(10000000000)),
+        max_block_ex_units: ExUnits::new(&BigNum(62000000), &BigNum(20000000000)),
+        max_value_size: 5000,
+        collateral_percentage: 150,
+        max_collateral_inputs: 3,
+        pool_voting_thresholds: Some(fake_pool_voting_thresholds()),
+        drep_voting_thresholds: Some(fake_drep_voting_thresholds()),
+        min_committee_size: Some(7),
+        committee_term_limit: Some(146),
+        governance_action_validity_period: Some(6),
+        governance_action_deposit: Some(BigNum(100000000000)),
+        drep_deposit: Some(BigNum(500000000)),
+        drep_inactivity_period: Some(20),
+        ref_script_coins_per_byte: Some(UnitInterval::new(&BigNum(15), &BigNum(1))),
+    }
+}
+
 pub(crate) fn fake_anchor() -> Anchor {
     Anchor::new(
         &URL::new("https://iohk.io".to_string()).unwrap(),
@@ -240,6 +240,120 @@ fn native_scripts_get_pubkeys() {
     assert!(pks4.contains(&keyhash3));
 }
 
+#[test]
+fn native_script_evaluate() {
+    let keyhash1 = fake_key_hash(1);
+    let keyhash2 = fake_key_hash(2);
+    let keyhash3 = fake_key_hash(3);
+    let signers = |keys: &[&Ed25519KeyHash]| {
+        Ed25519KeyHashes::from_vec(keys.iter().map(|key| (*key).clone()).collect())
+    };
+    let unbounded = ValidityInterval::new(None, None);
+
+    let two_of_three = NativeScript::new_script_n_of_k(&ScriptNOfK::new(
+        2,
+        &NativeScripts::from(vec![&pkscript(&keyhash1), &pkscript(&keyhash2), &pkscript(&keyhash3)]),
+    ));
+    assert!(!two_of_three.evaluate(&signers(&[&keyhash1]), &unbounded));
+    assert!(two_of_three.evaluate(&signers(&[&keyhash1, &keyhash3]), &unbounded));
+
+    let empty_any = NativeScript::new_script_any(&ScriptAny::new(&NativeScripts::new()));
+    let empty_all = NativeScript::new_script_all(&ScriptAll::new(&NativeScripts::new()));
+    assert!(!empty_any.evaluate(&signers(&[&keyhash1]), &unbounded));
+    assert!(empty_all.evaluate(&signers(&[]), &unbounded));
+
+    let start = NativeScript::new_timelock_start(&TimelockStart::new(100));
+    let expiry = NativeScript::new_timelock_expiry(&TimelockExpiry::new(200));
+    assert!(!start.evaluate(&signers(&[]), &unbounded));
+    assert!(!start.evaluate(&signers(&[]), &ValidityInterval::new(Some(BigNum(99)), None)));
+    assert!(start.evaluate(&signers(&[]), &ValidityInterval::new(Some(BigNum(100)), None)));
+    assert!(!expiry.evaluate(&signers(&[]), &unbounded));
+    assert!(!expiry.evaluate(&signers(&[]), &ValidityInterval::new(None, Some(BigNum(201)))));
+    assert!(expiry.evaluate(&signers(&[]), &ValidityInterval::new(None, Some(BigNum(200)))));
+}
+
+#[test]
+fn native_script_minimal_signer_sets() {
+    let keyhash1 = fake_key_hash(1);
+    let keyhash2 = fake_key_hash(2);
+    let keyhash3 = fake_key_hash(3);
+
+    let two_of_three = NativeScript::new_script_n_of_k(&ScriptNOfK::new(
+        2,
+        &NativeScripts::from(vec![&pkscript(&keyhash1), &pkscript(&keyhash2), &pkscript(&keyhash3)]),
+    ));
+    let sets = two_of_three.minimal_signer_sets().unwrap();
+    assert_eq!(sets.len(), 3);
+    for i in 0..sets.len() {
+        assert_eq!(sets.get(i).signers().len(), 2);
+        assert_eq!(sets.get(i).validity_interval(), ValidityInterval::new(None, None));
+        assert!(two_of_three.evaluate(&sets.get(i).signers(), &sets.get(i).validity_interval()));
+    }
+
+    // key 1 alone after slot 100 or keys 1 and 2 at any time, key 3 is never enough
+    let script = NativeScript::new_script_all(&ScriptAll::new(&NativeScripts::from(vec![
+        &pkscript(&keyhash1),
+        &NativeScript::new_script_any(&ScriptAny::new(&NativeScripts::from(vec![
+            &NativeScript::new_timelock_start(&TimelockStart::new(100)),
+            &pkscript(&keyhash2),
+            &NativeScript::new_script_all(&ScriptAll::new(&NativeScripts::from(vec![
+                &pkscript(&keyhash2),
+                &pkscript(&keyhash3),
+            ]))),
+        ]))),
+    ])));
+    let sets = script.minimal_signer_sets().unwrap();
+    assert_eq!(sets.len(), 2);
+    assert_eq!(sets.get(0).signers(), Ed25519KeyHashes::from_vec(vec![keyhash1.clone()]));
+    assert_eq!(
+        sets.get(0).validity_interval(),
+        ValidityInterval::new(Some(BigNum(100)), None)
+    );
+    assert_eq!(sets.get(1).signers().len(), 2);
+    assert!(sets.get(1).signers().contains(&keyhash2));
+    assert_eq!(sets.get(1).validity_interval(), ValidityInterval::new(None, None));
+
+    // the timelocks leave no slot for the script
+    let never = NativeScript::new_script_all(&ScriptAll::new(&NativeScripts::from(vec![
+        &NativeScript::new_timelock_start(&TimelockStart::new(200)),
+        &NativeScript::new_timelock_expiry(&TimelockExpiry::new(100)),
+        &pkscript(&keyhash1),
+    ])));
+    assert_eq!(never.minimal_signer_sets().unwrap().len(), 0);
+}
+
+#[test]
+fn native_script_minimal_signer_sets_of_big_multisig() {
+    let keys: Vec<NativeScript> = (1..=20).map(|i| pkscript(&fake_key_hash(i))).collect();
+    let ten_of_twenty = NativeScript::new_script_n_of_k(&ScriptNOfK::new(
+        10,
+        &NativeScripts::from(keys.iter().collect::<Vec<&NativeScript>>()),
+    ));
+    // C(20, 10) sets are too many to enumerate
+    assert!(ten_of_twenty.minimal_signer_sets().is_err());
+    assert_eq!(ten_of_twenty.smallest_signer_set().len(), 10);
+
+    // only the sets with the last keys of the 2 of 20 fit the window of the timelock start,
+    // keeping the first 100 sets of the 2 of 20 would report that the script never succeeds
+    let timelocked_keys: Vec<NativeScript> = (1..=20u8)
+        .map(|i| {
+            NativeScript::new_script_all(&ScriptAll::new(&NativeScripts::from(vec![
+                &pkscript(&fake_key_hash(i)),
+                &NativeScript::new_timelock_expiry(&TimelockExpiry::new(100 + i as u32)),
+            ])))
+        })
+        .collect();
+    let two_of_twenty = NativeScript::new_script_n_of_k(&ScriptNOfK::new(
+        2,
+        &NativeScripts::from(timelocked_keys.iter().collect::<Vec<&NativeScript>>()),
+    ));
+    let late_keys = NativeScript::new_script_all(&ScriptAll::new(&NativeScripts::from(vec![
+        &two_of_twenty,
+        &NativeScript::new_timelock_start(&TimelockStart::new(119)),
+    ])));
+    assert!(late_keys.minimal_signer_sets().is_err());
+}
+
 #[test]
 fn protocol_params_update_cbor_json_roundtrip() {
     let mut orig_ppu = ProtocolParamUpdate::new();
@@ -1,3 +1,18 @@
 pub(crate) fn harden(index: u32) -> u32 {
     index | 0x80_00_00_00
 }
+
+// TransactionInputs are always written with the set tag 258, which only exists since Conway,
+// the returned body is decoded from plain array inputs and keeps them when it's written again
+pub(crate) fn pre_conway_body(body: &crate::TransactionBody) -> crate::TransactionBody {
+    let bytes = body.to_bytes();
+    let inputs = body.inputs().to_bytes();
+    let start = bytes
+        .windows(inputs.len())
+        .position(|window| window == inputs.as_slice())
+        .unwrap();
+    let mut untagged = bytes[..start].to_vec();
+    untagged.extend_from_slice(&inputs[3..]);
+    untagged.extend_from_slice(&bytes[start + inputs.len()..]);
+    crate::TransactionBody::from_bytes(untagged).unwrap()
+}
@@ -9,5 +9,15 @@ mod plutus;
 mod metadata;
 mod crypto;
 mod utils;
+mod validation;
 mod fees;
-mod emip3;
\ No newline at end of file
+mod emip3;
+mod explain;
+mod cddl_conformance;
+mod text_envelope;
+mod bip39;
+
+mod cip1852;
+mod address_discovery;
+mod cip8;
+mod vrf;
@@ -2,3 +2,5 @@ mod certificates;
 mod fixed_tx;
 mod governance;
 mod protocol_param_update;
+mod protocol_parameters;
+mod slot_config;
@@ -0,0 +1,369 @@
+use crate::*;
+
+const CARDANO_CLI_JSON: &str = r#"{
+    "collateralPercentage": 150,
+    "committeeMaxTermLength": 146,
+    "committeeMinSize": 7,
+    "costModels": {
+        "PlutusV1": [100788, 420, 1, 1, -1000],
+        "PlutusV2": [100788, 420, 1, 1, 1000, 173],
+        "PlutusV3": [100788, 420, 1]
+    },
+    "dRepActivity": 20,
+    "dRepDeposit": 500000000,
+    "dRepVotingThresholds": {
+        "committeeNoConfidence": 0.6,
+        "committeeNormal": 0.67,
+        "hardForkInitiation": 0.6,
+        "motionNoConfidence": 0.67,
+        "ppEconomicGroup": 0.67,
+        "ppGovGroup": 0.75,
+        "ppNetworkGroup": 0.67,
+        "ppTechnicalGroup": 0.67,
+        "treasuryWithdrawal": 0.67,
+        "updateToConstitution": 0.75
+    },
+    "executionUnitPrices": {
+        "priceMemory": 0.0577,
+        "priceSteps": 7.21e-5
+    },
+    "extraPraosEntropy": null,
+    "decentralization": null,
+    "govActionDeposit": 100000000000,
+    "govActionLifetime": 6,
+    "maxBlockBodySize": 90112,
+    "maxBlockExecutionUnits": {
+        "memory": 62000000,
+        "steps": 20000000000
+    },
+    "maxBlockHeaderSize": 1100,
+    "maxCollateralInputs": 3,
+    "maxTxExecutionUnits": {
+        "memory": 14000000,
+        "steps": 10000000000
+    },
+    "maxTxSize": 16384,
+    "maxValueSize": 5000,
+    "minFeeRefScriptCostPerByte": 15,
+    "minPoolCost": 170000000,
+    "monetaryExpansion": 3.0e-3,
+    "poolPledgeInfluence": 0.3,
+    "poolRetireMaxEpoch": 18,
+    "poolVotingThresholds": {
+        "committeeNoConfidence": 0.51,
+        "committeeNormal": 0.51,
+        "hardForkInitiation": 0.51,
+        "motionNoConfidence": 0.51,
+        "ppSecurityGroup": 0.51
+    },
+    "protocolVersion": {
+        "major": 10,
+        "minor": 0
+    },
+    "stakeAddressDeposit": 2000000,
+    "stakePoolDeposit": 500000000,
+    "stakePoolTargetNum": 500,
+    "treasuryCut": 0.2,
+    "txFeeFixed": 155381,
+    "txFeePerByte": 44,
+    "utxoCostPerByte": 4310
+}"#;
+
+const OGMIOS_JSON: &str = r#"{
+    "minFeeCoefficient": 44,
+    "minFeeConstant": { "ada": { "lovelace": 155381 } },
+    "minFeeReferenceScripts": { "range": 25600, "base": 15.0, "multiplier": 1.2 },
+    "maxBlockBodySize": { "bytes": 90112 },
+    "maxBlockHeaderSize": { "bytes": 1100 },
+    "maxTransactionSize": { "bytes": 16384 },
+    "stakeCredentialDeposit": { "ada": { "lovelace": 2000000 } },
+    "stakePoolDeposit": { "ada": { "lovelace": 500000000 } },
+    "stakePoolRetirementEpochBound": 18,
+    "desiredNumberOfStakePools": 500,
+    "stakePoolPledgeInfluence": "3/10",
+    "monetaryExpansion": "3/1000",
+    "treasuryExpansion": "1/5",
+    "minStakePoolCost": { "ada": { "lovelace": 170000000 } },
+    "minUtxoDepositConstant": { "ada": { "lovelace": 0 } },
+    "minUtxoDepositCoefficient": 4310,
+    "plutusCostModels": {
+        "plutus:v1": [100788, 420, 1, 1, -1000],
+        "plutus:v2": [100788, 420, 1, 1, 1000, 173],
+        "plutus:v3": [100788, 420, 1]
+    },
+    "scriptExecutionPrices": { "memory": "577/10000", "cpu": "721/10000000" },
+    "maxExecutionUnitsPerTransaction": { "memory": 14000000, "cpu": 10000000000 },
+    "maxExecutionUnitsPerBlock": { "memory": 62000000, "cpu": 20000000000 },
+    "maxValueSize": { "bytes": 5000 },
+    "collateralPercentage": 150,
+    "maxCollateralInputs": 3,
+    "version": { "major": 10, "minor": 0 },
+    "stakePoolVotingThresholds": {
+        "noConfidence": "51/100",
+        "constitutionalCommittee": { "default": "51/100", "stateOfNoConfidence": "51/100" },
+        "hardForkInitiation": "51/100",
+        "protocolParametersUpdate": { "security": "51/100" }
+    },
+    "delegateRepresentativeVotingThresholds": {
+        "noConfidence": "67/100",
+        "constitutionalCommittee": { "default": "67/100", "stateOfNoConfidence": "3/5" },
+        "constitution": "3/4",
+        "hardForkInitiation": "3/5",
+        "protocolParametersUpdate": {
+            "network": "67/100",
+            "economic": "67/100",
+            "technical": "67/100",
+            "governance": "3/4"
+        },
+        "treasuryWithdrawals": "67/100"
+    },
+    "constitutionalCommitteeMinSize": 7,
+    "constitutionalCommitteeMaxTermLength": 146,
+    "governanceActionLifetime": 6,
+    "governanceActionDeposit": { "ada": { "lovelace": 100000000000 } },
+    "delegateRepresentativeDeposit": { "ada": { "lovelace": 500000000 } },
+    "delegateRepresentativeMaxIdleTime": 20
+}"#;
+
+const BLOCKFROST_JSON: &str = r#"{
+    "epoch": 530,
+    "min_fee_a": 44,
+    "min_fee_b": 155381,
+    "max_block_size": 90112,
+    "max_tx_size": 16384,
+    "max_block_header_size": 1100,
+    "key_deposit": "2000000",
+    "pool_deposit": "500000000",
+    "e_max": 18,
+    "n_opt": 500,
+    "a0": 0.3,
+    "rho": 0.003,
+    "tau": 0.2,
+    "decentralisation_param": null,
+    "extra_entropy": null,
+    "protocol_major_ver": 10,
+    "protocol_minor_ver": 0,
+    "min_utxo": "4310",
+    "min_pool_cost": "170000000",
+    "nonce": "1a3be38bcbb7911969283716ad7aa550250226b76a61fc51cc9a9a35d9276d81",
+    "cost_models": {
+        "PlutusV1": { "addInteger-cpu-arguments-intercept": 1 }
+    },
+    "cost_models_raw": {
+        "PlutusV1": [100788, 420, 1, 1, -1000],
+        "PlutusV2": [100788, 420, 1, 1, 1000, 173],
+        "PlutusV3": [100788, 420, 1]
+    },
+    "price_mem": 0.0577,
+    "price_step": 0.0000721,
+    "max_tx_ex_mem": "14000000",
+    "max_tx_ex_steps": "10000000000",
+    "max_block_ex_mem": "62000000",
+    "max_block_ex_steps": "20000000000",
+    "max_val_size": "5000",
+    "collateral_percent": 150,
+    "max_collateral_inputs": 3,
+    "coins_per_utxo_size": "4310",
+    "coins_per_utxo_word": "4310",
+    "pvt_motion_no_confidence": 0.51,
+    "pvt_committee_normal": 0.51,
+    "pvt_committee_no_confidence": 0.51,
+    "pvt_hard_fork_initiation": 0.51,
+    "dvt_motion_no_confidence": 0.67,
+    "dvt_committee_normal": 0.67,
+    "dvt_committee_no_confidence": 0.6,
+    "dvt_update_to_constitution": 0.75,
+    "dvt_hard_fork_initiation": 0.6,
+    "dvt_p_p_network_group": 0.67,
+    "dvt_p_p_economic_group": 0.67,
+    "dvt_p_p_technical_group": 0.67,
+    "dvt_p_p_gov_group": 0.75,
+    "dvt_treasury_withdrawal": 0.67,
+    "committee_min_size": "7",
+    "committee_max_term_length": "146",
+    "gov_action_lifetime": "6",
+    "gov_action_deposit": "100000000000",
+    "drep_deposit": "500000000",
+    "drep_activity": "20",
+    "pvtpp_security_group": 0.51,
+    "pvt_p_p_security_group": 0.51,
+    "min_fee_ref_script_cost_per_byte": 15
+}"#;
+
+fn interval(numerator: u64, denominator: u64) -> UnitInterval {
+    UnitInterval::new(&BigNum(numerator), &BigNum(denominator))
+}
+
+#[test]
+fn protocol_parameters_from_cardano_cli_json() {
+    let params = ProtocolParameters::from_cardano_cli_json(CARDANO_CLI_JSON).unwrap();
+    assert_eq!(params.minfee_a(), BigNum(44));
+    assert_eq!(params.minfee_b(), BigNum(155381));
+    assert_eq!(params.max_tx_size(), 16384);
+    assert_eq!(params.ada_per_utxo_byte(), BigNum(4310));
+    assert_eq!(
+        params.execution_costs(),
+        ExUnitPrices::new(&interval(577, 10000), &interval(721, 10000000))
+    );
+    assert_eq!(params.expansion_rate(), interval(3, 1000));
+    assert_eq!(params.pool_pledge_influence(), interval(3, 10));
+    assert_eq!(
+        params.max_block_ex_units(),
+        ExUnits::new(&BigNum(62000000), &BigNum(20000000000))
+    );
+    assert_eq!(params.protocol_version(), ProtocolVersion::new(10, 0));
+    assert_eq!(params.d(), None);
+    assert_eq!(params.extra_entropy(), None);
+
+    let cost_models = params.cost_models();
+    assert_eq!(cost_models.len(), 3);
+    let v1 = cost_models.get(&Language::new_plutus_v1()).unwrap();
+    assert_eq!(v1.len(), 5);
+    assert_eq!(v1.get(4).unwrap(), Int::new_i32(-1000));
+
+    assert_eq!(params.drep_deposit(), Some(BigNum(500000000)));
+    assert_eq!(params.governance_action_validity_period(), Some(6));
+    assert_eq!(params.ref_script_coins_per_byte(), Some(interval(15, 1)));
+    assert_eq!(
+        params.drep_voting_thresholds().unwrap().pp_governance_group(),
+        interval(3, 4)
+    );
+    assert_eq!(
+        params.pool_voting_thresholds().unwrap().security_relevant_threshold(),
+        interval(51, 100)
+    );
+}
+
+#[test]
+fn protocol_parameters_from_all_sources_are_equal() {
+    let cli = ProtocolParameters::from_cardano_cli_json(CARDANO_CLI_JSON).unwrap();
+    let ogmios = ProtocolParameters::from_ogmios_json(OGMIOS_JSON).unwrap();
+    let blockfrost = ProtocolParameters::from_blockfrost_json(BLOCKFROST_JSON).unwrap();
+    assert_eq!(cli, ogmios);
+    assert_eq!(cli, blockfrost);
+}
+
+#[test]
+fn protocol_parameters_json_round_trip() {
+    let params = ProtocolParameters::from_ogmios_json(OGMIOS_JSON).unwrap();
+    let restored = ProtocolParameters::from_json(&params.to_json().unwrap()).unwrap();
+    assert_eq!(params, restored);
+}
+
+#[test]
+fn protocol_parameters_before_conway() {
+    let mut json: serde_json::Value = serde_json::from_str(CARDANO_CLI_JSON).unwrap();
+    let obj = json.as_object_mut().unwrap();
+    for key in [
+        "committeeMaxTermLength",
+        "committeeMinSize",
+        "dRepActivity",
+        "dRepDeposit",
+        "dRepVotingThresholds",
+        "govActionDeposit",
+        "govActionLifetime",
+        "minFeeRefScriptCostPerByte",
+        "poolVotingThresholds",
+        "utxoCostPerByte",
+    ]
+    .iter()
+    {
+        obj.remove(*key);
+    }
+    obj.insert("utxoCostPerWord".to_string(), serde_json::json!(34480));
+    obj.insert("decentralization".to_string(), serde_json::json!(0.5));
+
+    let params = ProtocolParameters::from_cardano_cli_json(&json.to_string()).unwrap();
+    assert_eq!(params.ada_per_utxo_byte(), BigNum(4310));
+    assert_eq!(params.d(), Some(interval(1, 2)));
+    assert_eq!(params.pool_voting_thresholds(), None);
+    assert_eq!(params.drep_deposit(), None);
+    assert_eq!(params.ref_script_coins_per_byte(), None);
+    assert!(params.to_tx_builder_config().is_ok());
+}
+
+#[test]
+fn protocol_parameters_reports_missing_fields() {
+    let mut json: serde_json::Value = serde_json::from_str(OGMIOS_JSON).unwrap();
+    json["scriptExecutionPrices"]
+        .as_object_mut()
+        .unwrap()
+        .remove("cpu");
+    let err = ProtocolParameters::from_ogmios_json(&json.to_string()).unwrap_err();
+    assert!(format!("{:?}", err).contains("scriptExecutionPrices.cpu"));
+
+    assert!(ProtocolParameters::from_blockfrost_json(CARDANO_CLI_JSON).is_err());
+    assert!(ProtocolParameters::from_cardano_cli_json("[]").is_err());
+}
+
+#[test]
+fn protocol_parameters_reject_named_plutus_v3_costs() {
+    let mut json: serde_json::Value = serde_json::from_str(CARDANO_CLI_JSON).unwrap();
+    json["costModels"]["PlutusV2"] = serde_json::json!({
+        "addInteger-cpu-arguments-intercept": 100788,
+        "addInteger-cpu-arguments-slope": 420
+    });
+    let params = ProtocolParameters::from_cardano_cli_json(&json.to_string()).unwrap();
+    assert_eq!(
+        params.cost_models().get(&Language::new_plutus_v2()),
+        Some(CostModel::from(vec![100788, 420]))
+    );
+
+    json["costModels"]["PlutusV3"] = serde_json::json!({
+        "addInteger-cpu-arguments-intercept": 100788,
+        "addInteger-cpu-arguments-slope": 420
+    });
+    let err = ProtocolParameters::from_cardano_cli_json(&json.to_string()).unwrap_err();
+    assert!(format!("{:?}", err).contains("costModels.PlutusV3"));
+}
+
+#[test]
+fn protocol_parameters_apply_update() {
+    let params = ProtocolParameters::from_cardano_cli_json(CARDANO_CLI_JSON).unwrap();
+    let mut update = ProtocolParamUpdate::new();
+    update.set_minfee_a(&BigNum(45));
+    update.set_max_tx_size(20000);
+    update.set_drep_deposit(&BigNum(1000));
+    let mut cost_models = Costmdls::new();
+    cost_models.insert(&Language::new_plutus_v2(), &CostModel::from(vec![1, 2, 3]));
+    update.set_cost_models(&cost_models);
+
+    let updated = params.apply_update(&update);
+    assert_eq!(updated.minfee_a(), BigNum(45));
+    assert_eq!(updated.minfee_b(), params.minfee_b());
+    assert_eq!(updated.max_tx_size(), 20000);
+    assert_eq!(updated.drep_deposit(), Some(BigNum(1000)));
+    assert_eq!(
+        updated.cost_models().get(&Language::new_plutus_v2()),
+        Some(CostModel::from(vec![1, 2, 3]))
+    );
+    assert_eq!(
+        updated.cost_models().get(&Language::new_plutus_v1()),
+        params.cost_models().get(&Language::new_plutus_v1())
+    );
+    assert_eq!(params.apply_update(&ProtocolParamUpdate::new()), params);
+}
+
+#[test]
+fn protocol_parameters_to_tx_builder_config() {
+    let params = ProtocolParameters::from_cardano_cli_json(CARDANO_CLI_JSON).unwrap();
+    let config = params.to_tx_builder_config().unwrap();
+    assert_eq!(config.fee_algo.coefficient(), BigNum(44));
+    assert_eq!(config.fee_algo.constant(), BigNum(155381));
+    assert_eq!(config.pool_deposit, BigNum(500000000));
+    assert_eq!(config.key_deposit, BigNum(2000000));
+    assert_eq!(config.max_value_size, 5000);
+    assert_eq!(config.max_tx_size, 16384);
+    assert_eq!(config.data_cost.coins_per_byte(), BigNum(4310));
+    assert_eq!(config.ex_unit_prices, Some(params.execution_costs()));
+    assert_eq!(config.ref_script_coins_per_byte, Some(interval(15, 1)));
+    assert_eq!(config.collateral_percentage, Some(150));
+    assert_eq!(config.max_collateral_inputs, Some(3));
+
+    let config = params
+        .to_tx_builder_config_builder()
+        .prefer_pure_change(true)
+        .build()
+        .unwrap();
+    assert!(config.prefer_pure_change);
+}
@@ -0,0 +1,54 @@
+use crate::*;
+
+#[test]
+fn slot_config_mainnet() {
+    let config = SlotConfig::mainnet();
+    assert_eq!(config.slot_to_posix_time(&BigNum(0)).unwrap(), BigNum(1506203091000));
+    assert_eq!(config.slot_to_posix_time(&BigNum(4492799)).unwrap(), BigNum(1596059071000));
+    assert_eq!(config.slot_to_posix_time(&BigNum(4492800)).unwrap(), BigNum(1596059091000));
+    assert_eq!(config.slot_to_posix_time(&BigNum(4492801)).unwrap(), BigNum(1596059092000));
+
+    // inside a byron slot
+    assert_eq!(config.posix_time_to_slot(&BigNum(1596059075000)).unwrap(), BigNum(4492799));
+    assert_eq!(config.posix_time_to_slot_ceil(&BigNum(1596059075000)).unwrap(), BigNum(4492800));
+    assert_eq!(config.posix_time_to_slot(&BigNum(1596059091000)).unwrap(), BigNum(4492800));
+    assert_eq!(config.posix_time_to_slot_ceil(&BigNum(1596059091000)).unwrap(), BigNum(4492800));
+    assert_eq!(config.posix_time_to_slot(&BigNum(1596059091999)).unwrap(), BigNum(4492800));
+    assert_eq!(config.posix_time_to_slot_ceil(&BigNum(1596059091001)).unwrap(), BigNum(4492801));
+}
+
+#[test]
+fn slot_config_testnets() {
+    let preprod = SlotConfig::testnet_preprod();
+    assert_eq!(preprod.slot_to_posix_time(&BigNum(86400)).unwrap(), BigNum(1655769600000));
+    assert_eq!(preprod.posix_time_to_slot(&BigNum(1655769600000 + 1000 * 500)).unwrap(), BigNum(86900));
+
+    let preview = SlotConfig::testnet_preview();
+    assert_eq!(preview.slot_to_posix_time(&BigNum(0)).unwrap(), BigNum(1666656000000));
+    assert_eq!(preview.posix_time_to_slot(&BigNum(1666656000000 + 42_500)).unwrap(), BigNum(42));
+    assert!(preview.posix_time_to_slot(&BigNum(1666655999999)).is_err());
+}
+
+#[test]
+fn slot_config_round_trip() {
+    let config = SlotConfig::mainnet();
+    for slot in [0u64, 1, 4492799, 4492800, 4492801, 140_000_000].iter() {
+        let time = config.slot_to_posix_time(&BigNum(*slot)).unwrap();
+        assert_eq!(config.posix_time_to_slot(&time).unwrap(), BigNum(*slot));
+        assert_eq!(config.posix_time_to_slot_ceil(&time).unwrap(), BigNum(*slot));
+    }
+}
+
+#[test]
+fn slot_config_custom_eras() {
+    let mut config = SlotConfig::new(&BigNum(1_000_000), &BigNum(10), 2000).unwrap();
+    config.add_era(&BigNum(20), 500).unwrap();
+    assert_eq!(config.slot_to_posix_time(&BigNum(20)).unwrap(), BigNum(1_020_000));
+    assert_eq!(config.slot_to_posix_time(&BigNum(24)).unwrap(), BigNum(1_022_000));
+    assert_eq!(config.posix_time_to_slot(&BigNum(1_019_999)).unwrap(), BigNum(19));
+    assert!(config.slot_to_posix_time(&BigNum(9)).is_err());
+
+    assert!(config.add_era(&BigNum(20), 1000).is_err());
+    assert!(config.add_era(&BigNum(30), 0).is_err());
+    assert!(SlotConfig::new(&BigNum(0), &BigNum(0), 0).is_err());
+}
@@ -0,0 +1,206 @@
+use crate::*;
+use cbor_event::Special;
+
+const TX_ID: [u8; 32] = [7; 32];
+
+fn byron_address() -> ByronAddress {
+    ByronAddress::from_base58("Ae2tdPwUPEZ4YjgvykNpoFeYUxoyhNj2kg8KfKWN2FizsSpLUPv68MpTVDo").unwrap()
+}
+
+// Tx with indefinite-length input and output lists, like the wallets of the Byron era wrote them
+fn byron_tx_body_bytes() -> Vec<u8> {
+    let mut input = Serializer::new_vec();
+    input.write_array(Len::Len(2)).unwrap();
+    input.write_bytes(TX_ID).unwrap();
+    input.write_unsigned_integer(1).unwrap();
+
+    let mut body = Serializer::new_vec();
+    body.write_array(Len::Len(3)).unwrap();
+    body.write_array(Len::Indefinite).unwrap();
+    body.write_array(Len::Len(2)).unwrap();
+    body.write_unsigned_integer(0).unwrap();
+    body.write_tag(24).unwrap();
+    body.write_bytes(input.finalize()).unwrap();
+    body.write_special(Special::Break).unwrap();
+    body.write_array(Len::Indefinite).unwrap();
+    body.write_array(Len::Len(2)).unwrap();
+    body.write_raw_bytes(&byron_address().to_bytes()).unwrap();
+    body.write_unsigned_integer(1_000_000).unwrap();
+    body.write_special(Special::Break).unwrap();
+    body.write_map(Len::Len(0)).unwrap();
+    body.finalize()
+}
+
+fn byron_tx_bytes() -> Vec<u8> {
+    let mut key_and_signature = Serializer::new_vec();
+    key_and_signature.write_array(Len::Len(2)).unwrap();
+    key_and_signature.write_bytes(vec![1; 64]).unwrap();
+    key_and_signature.write_bytes(vec![2; 64]).unwrap();
+
+    let mut tx = Serializer::new_vec();
+    tx.write_array(Len::Len(2)).unwrap();
+    tx.write_raw_bytes(&byron_tx_body_bytes()).unwrap();
+    tx.write_array(Len::Len(1)).unwrap();
+    tx.write_array(Len::Len(2)).unwrap();
+    tx.write_unsigned_integer(0).unwrap();
+    tx.write_tag(24).unwrap();
+    tx.write_bytes(key_and_signature.finalize()).unwrap();
+    tx.finalize()
+}
+
+fn main_block_header_bytes() -> Vec<u8> {
+    let mut header = Serializer::new_vec();
+    header.write_array(Len::Len(5)).unwrap();
+    header.write_unsigned_integer(764824073).unwrap();
+    header.write_bytes(vec![3; 32]).unwrap();
+    // body proof
+    header.write_array(Len::Len(1)).unwrap();
+    header.write_bytes(vec![4; 32]).unwrap();
+    // consensus data
+    header.write_array(Len::Len(4)).unwrap();
+    header.write_array(Len::Len(2)).unwrap();
+    header.write_unsigned_integer(5).unwrap();
+    header.write_unsigned_integer(42).unwrap();
+    header.write_bytes(vec![5; 64]).unwrap();
+    header.write_array(Len::Len(1)).unwrap();
+    header.write_unsigned_integer(108042).unwrap();
+    header.write_array(Len::Len(2)).unwrap();
+    header.write_unsigned_integer(0).unwrap();
+    header.write_bytes(vec![6; 64]).unwrap();
+    // extra data
+    header.write_array(Len::Len(0)).unwrap();
+    header.finalize()
+}
+
+fn main_block_bytes() -> Vec<u8> {
+    let mut block = Serializer::new_vec();
+    block.write_array(Len::Len(2)).unwrap();
+    block.write_unsigned_integer(1).unwrap();
+    block.write_array(Len::Len(3)).unwrap();
+    block.write_raw_bytes(&main_block_header_bytes()).unwrap();
+    block.write_array(Len::Len(4)).unwrap();
+    block.write_array(Len::Indefinite).unwrap();
+    block.write_raw_bytes(&byron_tx_bytes()).unwrap();
+    block.write_special(Special::Break).unwrap();
+    // shared seed, delegation and update payloads
+    block.write_array(Len::Len(2)).unwrap();
+    block.write_unsigned_integer(3).unwrap();
+    block.write_tag(258).unwrap();
+    block.write_array(Len::Len(0)).unwrap();
+    block.write_array(Len::Len(0)).unwrap();
+    block.write_array(Len::Len(2)).unwrap();
+    block.write_array(Len::Len(0)).unwrap();
+    block.write_array(Len::Len(0)).unwrap();
+    // extra
+    block.write_array(Len::Len(1)).unwrap();
+    block.write_map(Len::Len(0)).unwrap();
+    block.finalize()
+}
+
+fn epoch_boundary_block_bytes() -> Vec<u8> {
+    let mut header = Serializer::new_vec();
+    header.write_array(Len::Len(5)).unwrap();
+    header.write_unsigned_integer(764824073).unwrap();
+    header.write_bytes(vec![8; 32]).unwrap();
+    header.write_bytes(vec![9; 32]).unwrap();
+    header.write_array(Len::Len(2)).unwrap();
+    header.write_unsigned_integer(6).unwrap();
+    header.write_array(Len::Len(1)).unwrap();
+    header.write_unsigned_integer(108043).unwrap();
+    header.write_array(Len::Len(1)).unwrap();
+    header.write_map(Len::Len(0)).unwrap();
+    let header = header.finalize();
+
+    let mut block = Serializer::new_vec();
+    block.write_array(Len::Len(2)).unwrap();
+    block.write_unsigned_integer(0).unwrap();
+    block.write_array(Len::Len(3)).unwrap();
+    block.write_raw_bytes(&header).unwrap();
+    block.write_array(Len::Len(1)).unwrap();
+    block.write_bytes(vec![10; 28]).unwrap();
+    block.write_array(Len::Len(1)).unwrap();
+    block.write_map(Len::Len(0)).unwrap();
+    block.finalize()
+}
+
+// blake2b-256 of [era code, header] computed outside of the library
+const MAIN_BLOCK_HASH: &str = "93664060122afd06c561b9c5cb280372a4e38ab1b5fa2d6886dd7391aa7386e6";
+const EPOCH_BOUNDARY_BLOCK_HASH: &str =
+    "81aaded9cb427968ce241389138835936380cacaf0d61ce1964842748ba4f538";
+
+#[test]
+fn byron_transaction_hash_uses_original_bytes() {
+    let tx = ByronTransaction::from_bytes(byron_tx_bytes()).unwrap();
+    let body_bytes = byron_tx_body_bytes();
+
+    assert_eq!(tx.tx_hash(), TransactionHash::from(blake2b256(&body_bytes)));
+    assert_eq!(tx.body().original_bytes(), body_bytes);
+    assert_eq!(tx.to_bytes(), byron_tx_bytes());
+
+    let inputs = tx.body().inputs();
+    assert_eq!(inputs.len(), 1);
+    assert_eq!(inputs.get(0), TransactionInput::new(&TransactionHash::from(TX_ID), 1));
+    let outputs = tx.body().outputs();
+    assert!(!outputs.is_empty());
+    assert_eq!(outputs.len(), 1);
+    assert_eq!(outputs.get(0).address(), byron_address());
+    assert_eq!(outputs.get(0).amount(), Coin::from(1_000_000u64));
+
+    let witnesses = tx.witnesses();
+    assert!(!witnesses.is_empty());
+    assert_eq!(witnesses.len(), 1);
+    assert_eq!(witnesses.get(0).kind(), ByronTxWitnessKind::PublicKey);
+    assert_eq!(witnesses.get(0).public_key(), Some(vec![1; 64]));
+    assert_eq!(witnesses.get(0).signature(), Some(vec![2; 64]));
+}
+
+#[test]
+fn byron_main_block_decodes_through_versioned_block() {
+    let bytes = main_block_bytes();
+    let versioned = VersionedBlock::from_bytes(bytes.clone()).unwrap();
+    assert_eq!(versioned.era(), BlockEra::Byron);
+    assert!(versioned.shelley_block().is_none());
+    assert_eq!(versioned.to_bytes(), bytes);
+
+    let block = versioned.byron_block().unwrap();
+    assert_eq!(block.kind(), ByronBlockKind::Main);
+    assert_eq!(block.block_hash().to_hex(), MAIN_BLOCK_HASH);
+    assert_eq!(block.prev_block(), BlockHash::from([3; 32]));
+
+    let main = block.as_main_block().unwrap();
+    assert_eq!(main.protocol_magic(), 764824073);
+    assert_eq!(main.epoch(), BigNum::from(5u64));
+    assert_eq!(main.slot(), BigNum::from(42u64));
+    assert_eq!(main.absolute_slot(), BigNum::from(5 * 21600 + 42u64));
+    assert_eq!(main.difficulty(), BigNum::from(108042u64));
+    assert_eq!(main.issuer_public_key(), vec![5; 64]);
+    assert_eq!(main.transactions().len(), 1);
+    assert_eq!(
+        main.transactions().get(0).tx_hash(),
+        TransactionHash::from(blake2b256(&byron_tx_body_bytes()))
+    );
+}
+
+#[test]
+fn byron_epoch_boundary_block_decodes_through_versioned_block() {
+    let bytes = epoch_boundary_block_bytes();
+    let versioned = VersionedBlock::from_bytes(bytes.clone()).unwrap();
+    assert_eq!(versioned.era(), BlockEra::Byron);
+    assert_eq!(versioned.to_bytes(), bytes);
+
+    let block = versioned.byron_block().unwrap();
+    assert_eq!(block.kind(), ByronBlockKind::EpochBoundary);
+    assert_eq!(block.block_hash().to_hex(), EPOCH_BOUNDARY_BLOCK_HASH);
+    assert_eq!(block.epoch(), BigNum::from(6u64));
+    assert!(block.transactions().is_empty());
+    assert_eq!(block.as_epoch_boundary_block().unwrap().difficulty(), BigNum::from(108043u64));
+    assert_eq!(VersionedBlock::new_byron(&block), versioned);
+}
+
+#[test]
+fn byron_block_json_round_trip() {
+    let versioned = VersionedBlock::from_bytes(main_block_bytes()).unwrap();
+    let json = versioned.to_json().unwrap();
+    assert_eq!(VersionedBlock::from_json(&json).unwrap(), versioned);
+}
+
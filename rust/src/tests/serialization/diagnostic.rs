@@ -0,0 +1,83 @@
+use crate::tests::fakes::fake_key_hash;
+use crate::*;
+
+#[test]
+fn plutus_data_diagnostic_marks_indefinite_lengths_and_tags() {
+    // constr 0 with an indefinite-length list of fields
+    let data = PlutusData::from_bytes(hex::decode("d8799f0102ff").unwrap()).unwrap();
+    assert_eq!(
+        data.to_annotated_diagnostic().unwrap(),
+        "121(/ constr 0 / [_\n  1,\n  2\n])"
+    );
+}
+
+#[test]
+fn tx_body_diagnostic_names_keys_and_flags_long_integers() {
+    // the fee is encoded with 4 bytes instead of 1
+    let body = TransactionBody::from_bytes(hex::decode("a300800180021a00000005").unwrap()).unwrap();
+    assert_eq!(
+        body.to_annotated_diagnostic().unwrap(),
+        "{\n  0 / inputs /: [],\n  1 / outputs /: [],\n  2 / fee /: 5_2\n}"
+    );
+}
+
+#[test]
+fn certificate_diagnostic_names_variant() {
+    let credential = Credential::from_keyhash(&fake_key_hash(1));
+    let cert = Certificate::new_stake_registration(&StakeRegistration::new(&credential));
+    let diagnostic = cert.to_annotated_diagnostic().unwrap();
+    assert!(diagnostic.starts_with("[\n  0 / stake_registration /,\n  [\n    0,\n    h'"));
+
+    let mut certs = Certificates::new();
+    certs.add(&cert);
+    let mut body = TransactionBody::new_tx_body(
+        &TransactionInputs::new(),
+        &TransactionOutputs::new(),
+        &Coin::from(5u64),
+    );
+    body.set_certs(&certs);
+    let diagnostic = body.to_annotated_diagnostic().unwrap();
+    assert!(diagnostic.contains("4 / certificates /: "));
+    assert!(diagnostic.contains("0 / stake_registration /"));
+}
+
+#[test]
+fn diagnostic_rejects_truncated_cbor() {
+    let truncated = hex::decode("83010203").unwrap();
+    assert!(crate::serialization::to_annotated_diagnostic(
+        &truncated[..3],
+        crate::serialization::DiagnosticSchema::Any
+    )
+    .is_err());
+    assert!(crate::serialization::to_annotated_diagnostic(
+        &truncated,
+        crate::serialization::DiagnosticSchema::Any
+    )
+    .is_ok());
+}
+
+#[test]
+fn header_body_diagnostic_names_both_layouts() {
+    let diagnostic = |bytes: &[u8]| {
+        crate::serialization::to_annotated_diagnostic(
+            bytes,
+            crate::serialization::DiagnosticSchema::HeaderBody,
+        )
+        .unwrap()
+    };
+
+    let mut babbage = vec![0x8a];
+    babbage.extend([0x00; 10]);
+    let babbage = diagnostic(&babbage);
+    assert!(babbage.contains("/ vrf_result / 0,"));
+    assert!(babbage.contains("/ operational_cert / 0,"));
+    assert!(babbage.ends_with("/ protocol_version / 0\n]"));
+
+    let mut shelley = vec![0x8f];
+    shelley.extend([0x00; 15]);
+    let shelley = diagnostic(&shelley);
+    assert!(shelley.contains("/ nonce_vrf / 0,\n  / leader_vrf / 0,"));
+    assert!(shelley.contains("/ hot_vkey / 0,\n  / sequence_number / 0,"));
+    assert!(shelley.contains("/ kes_period / 0,\n  / sigma / 0,"));
+    assert!(shelley.ends_with("/ protocol_major / 0,\n  / protocol_minor / 0\n]"));
+}
@@ -3,3 +3,7 @@ pub mod governance;
 pub mod transaction_body;
 pub mod protocol_param_update;
 pub mod general;
+pub mod original_encoding;
+pub mod diagnostic;
+pub mod byron;
+pub mod multi_era_tx;
@@ -0,0 +1,160 @@
+use crate::tests::fakes::{fake_base_address, fake_data_hash, fake_policy_id, fake_tx_input};
+use crate::tests::helpers::pre_conway_body;
+use crate::*;
+
+fn body_with_output(output: &TransactionOutput) -> TransactionBody {
+    let mut outputs = TransactionOutputs::new();
+    outputs.add(output);
+    let mut inputs = TransactionInputs::new();
+    inputs.add(&fake_tx_input(1));
+    let mut body = TransactionBody::new_tx_body(&inputs, &outputs, &Coin::from(200_000u64));
+    body.set_ttl(&SlotBigNum::from(1000u64));
+    body
+}
+
+fn ada_output() -> TransactionOutput {
+    TransactionOutput::new(&fake_base_address(1), &Value::new(&Coin::from(2_000_000u64)))
+}
+
+fn metadata() -> GeneralTransactionMetadata {
+    let mut metadata = GeneralTransactionMetadata::new();
+    metadata.insert(
+        &BigNum::from(674u64),
+        &TransactionMetadatum::new_text("multi era".to_string()).unwrap(),
+    );
+    metadata
+}
+
+// Shelley to Mary transactions have no is_valid flag
+fn pre_alonzo_tx_bytes(body: &TransactionBody, auxiliary_data: Option<&AuxiliaryData>) -> Vec<u8> {
+    let mut serializer = Serializer::new_vec();
+    serializer.write_array(Len::Len(3)).unwrap();
+    serializer.write_raw_bytes(&pre_conway_body(body).to_bytes()).unwrap();
+    serializer
+        .write_raw_bytes(&TransactionWitnessSet::new().to_bytes())
+        .unwrap();
+    match auxiliary_data {
+        Some(auxiliary_data) => serializer.write_raw_bytes(&auxiliary_data.to_bytes()).unwrap(),
+        None => serializer.write_special(CBORSpecial::Null).unwrap(),
+    };
+    serializer.finalize()
+}
+
+fn era_failure(error: &DeserializeError) -> (BlockEra, BlockEra, BlockEra) {
+    match error.failure() {
+        DeserializeFailure::InvalidForEra {
+            era,
+            valid_from,
+            valid_until,
+        } => (*era, *valid_from, *valid_until),
+        failure => panic!("unexpected failure {:?}", failure),
+    }
+}
+
+#[test]
+fn shelley_transaction_era_is_detected() {
+    let mut auxiliary_data = AuxiliaryData::new();
+    auxiliary_data.set_metadata(&metadata());
+    let bytes = pre_alonzo_tx_bytes(&body_with_output(&ada_output()), Some(&auxiliary_data));
+
+    let tx = MultiEraTransaction::from_bytes(bytes.clone(), None).unwrap();
+    assert_eq!(tx.era(), BlockEra::Shelley);
+    assert_eq!(tx.to_bytes(), bytes);
+    assert_eq!(
+        MultiEraTransaction::from_bytes(bytes.clone(), Some(BlockEra::Mary)).unwrap().era(),
+        BlockEra::Mary
+    );
+
+    // the is_valid flag is mandatory since Alonzo
+    let err = MultiEraTransaction::from_bytes(bytes, Some(BlockEra::Alonzo)).unwrap_err();
+    assert_eq!(era_failure(&err), (BlockEra::Alonzo, BlockEra::Shelley, BlockEra::Mary));
+}
+
+#[test]
+fn multiasset_output_requires_mary() {
+    let mut assets = Assets::new();
+    assets.insert(&AssetName::new(vec![1]).unwrap(), &BigNum::from(5u64));
+    let mut multiasset = MultiAsset::new();
+    multiasset.insert(&fake_policy_id(1), &assets);
+    let output = TransactionOutput::new(
+        &fake_base_address(1),
+        &Value::new_with_assets(&Coin::from(2_000_000u64), &multiasset),
+    );
+    let bytes = pre_alonzo_tx_bytes(&body_with_output(&output), None);
+
+    assert_eq!(MultiEraTransaction::from_bytes(bytes.clone(), None).unwrap().era(), BlockEra::Mary);
+    let err = MultiEraTransaction::from_bytes(bytes, Some(BlockEra::Allegra)).unwrap_err();
+    assert_eq!(err.path(), "body.outputs[0].amount");
+    assert_eq!(era_failure(&err), (BlockEra::Allegra, BlockEra::Mary, BlockEra::Conway));
+}
+
+#[test]
+fn update_is_rejected_in_conway() {
+    let mut output = ada_output();
+    output.set_plutus_data(&PlutusData::new_integer(&BigInt::from(1)));
+    let mut body = body_with_output(&output);
+    body.set_update(&Update::new(&ProposedProtocolParameterUpdates::new(), 200));
+    let tx = Transaction::new(&pre_conway_body(&body), &TransactionWitnessSet::new(), None);
+
+    let babbage = MultiEraTransaction::from_bytes(tx.to_bytes(), None).unwrap();
+    assert_eq!(babbage.era(), BlockEra::Babbage);
+    assert_eq!(babbage.transaction(), tx);
+
+    let err = MultiEraTransaction::from_bytes(tx.to_bytes(), Some(BlockEra::Conway)).unwrap_err();
+    assert_eq!(err.path(), "body.update");
+    assert_eq!(era_failure(&err), (BlockEra::Conway, BlockEra::Shelley, BlockEra::Babbage));
+}
+
+#[test]
+fn update_and_donation_leave_no_valid_era() {
+    let mut body = body_with_output(&ada_output());
+    body.set_update(&Update::new(&ProposedProtocolParameterUpdates::new(), 200));
+    body.set_donation(&Coin::from(1_000_000u64));
+    let tx = Transaction::new(&pre_conway_body(&body), &TransactionWitnessSet::new(), None);
+
+    let err = MultiEraTransaction::from_bytes(tx.to_bytes(), None).unwrap_err();
+    assert_eq!(err.path(), "body.update");
+    assert_eq!(era_failure(&err), (BlockEra::Conway, BlockEra::Shelley, BlockEra::Babbage));
+    assert!(tx.to_cli_view_json().is_err());
+}
+
+#[test]
+fn byron_era_hint_is_rejected() {
+    let bytes = pre_alonzo_tx_bytes(&body_with_output(&ada_output()), None);
+    assert!(MultiEraTransaction::from_bytes(bytes, Some(BlockEra::Byron)).is_err());
+}
+
+#[test]
+fn legacy_outputs_and_auxiliary_data_convert_to_current_format() {
+    let mut output = ada_output();
+    output.set_data_hash(&fake_data_hash(1));
+    let mut scripts = NativeScripts::new();
+    scripts.add(&NativeScript::new_timelock_start(&TimelockStart::new(10)));
+    let mut auxiliary_data = AuxiliaryData::new();
+    auxiliary_data.set_metadata(&metadata());
+    auxiliary_data.set_native_scripts(&scripts);
+    let body = body_with_output(&output);
+    let tx = Transaction::new(
+        &pre_conway_body(&body),
+        &TransactionWitnessSet::new(),
+        Some(auxiliary_data.clone()),
+    );
+
+    let alonzo = MultiEraTransaction::from_bytes(tx.to_bytes(), None).unwrap();
+    assert_eq!(alonzo.era(), BlockEra::Alonzo);
+    let decoded_output = alonzo.transaction().body().outputs().get(0);
+    assert_eq!(decoded_output.serialization_format(), Some(CborContainerType::Array));
+
+    let current = alonzo.to_current_format();
+    let current_output = current.body().outputs().get(0);
+    assert_eq!(current_output.serialization_format(), Some(CborContainerType::Map));
+    assert_eq!(current_output.data_hash(), Some(fake_data_hash(1)));
+    assert_eq!(current_output.to_bytes()[0], 0xa3);
+    let current_auxiliary_data = current.auxiliary_data().unwrap();
+    assert_eq!(current_auxiliary_data, auxiliary_data);
+    assert_eq!(current_auxiliary_data.to_bytes()[..3], [0xd9, 0x01, 0x03]);
+
+    // the converted body is written again with the inputs tagged as a set
+    let converted = MultiEraTransaction::from_bytes(current.to_bytes(), None).unwrap();
+    assert_eq!(converted.era(), BlockEra::Conway);
+}
@@ -0,0 +1,300 @@
+use crate::tests::fakes::{
+    fake_base_address, fake_data_hash, fake_key_hash, fake_reward_address, fake_script_hash,
+};
+use crate::*;
+
+fn cbor_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut serializer = Serializer::new_vec();
+    serializer.write_bytes(bytes).unwrap();
+    serializer.finalize()
+}
+
+#[test]
+fn tx_output_keeps_original_encoding() {
+    let address = fake_base_address(0);
+    // indefinite-length array with the coin as a 4 bytes integer
+    let mut original = vec![0x9f];
+    original.extend(cbor_bytes(&address.to_bytes()));
+    original.extend([0x1a, 0x00, 0x00, 0x00, 0x05, 0xff]);
+
+    let output = TransactionOutput::from_bytes(original.clone()).unwrap();
+    assert_eq!(output.to_bytes(), original);
+
+    let mut canonical = vec![0x82];
+    canonical.extend(cbor_bytes(&address.to_bytes()));
+    canonical.push(0x05);
+    assert_eq!(output.to_canonical_bytes().unwrap(), canonical);
+    assert_eq!(output, TransactionOutput::from_bytes(canonical).unwrap());
+
+    // a changed output is written with the library encoding, the unchanged amount keeps its encoding
+    let mut changed = output.clone();
+    changed.set_data_hash(&fake_data_hash(1));
+    let mut expected = vec![0x83];
+    expected.extend(cbor_bytes(&address.to_bytes()));
+    expected.extend([0x1a, 0x00, 0x00, 0x00, 0x05]);
+    expected.extend(cbor_bytes(&fake_data_hash(1).to_bytes()));
+    assert_eq!(changed.to_bytes(), expected);
+
+    let mut outputs = TransactionOutputs::new();
+    outputs.add(&output);
+    assert_eq!(outputs.to_bytes()[1..], original[..]);
+}
+
+#[test]
+fn native_script_hash_survives_round_trip() {
+    let key_hash = fake_key_hash(1);
+    // script_all with an indefinite-length list of scripts
+    let mut original = vec![0x82, 0x01, 0x9f, 0x82, 0x00];
+    original.extend(cbor_bytes(&key_hash.to_bytes()));
+    original.push(0xff);
+
+    let script = NativeScript::from_bytes(original.clone()).unwrap();
+    assert_eq!(script.to_bytes(), original);
+    let mut preimage = vec![ScriptHashNamespace::NativeScript as u8];
+    preimage.extend(&original);
+    assert_eq!(script.hash(), ScriptHash::from(blake2b224(&preimage)));
+
+    let mut canonical_script = NativeScripts::new();
+    canonical_script.add(&NativeScript::new_script_pubkey(&ScriptPubkey::new(
+        &key_hash,
+    )));
+    let canonical = NativeScript::new_script_all(&ScriptAll::new(&canonical_script));
+    assert_eq!(script, canonical);
+    assert_eq!(script.to_canonical_bytes().unwrap(), canonical.to_bytes());
+    assert_ne!(script.hash(), canonical.hash());
+
+    // scripts nested in a witness set keep their encoding as well
+    let mut witness_set_bytes = vec![0xa1, 0x01, 0x81];
+    witness_set_bytes.extend(&original);
+    let witness_set = TransactionWitnessSet::from_bytes(witness_set_bytes).unwrap();
+    assert_eq!(
+        witness_set.native_scripts().unwrap().get(0).hash(),
+        script.hash()
+    );
+}
+
+#[test]
+fn auxiliary_data_hash_survives_round_trip() {
+    // metadata label 1 encoded as a 2 bytes integer
+    let original = vec![0xa1, 0x19, 0x00, 0x01, 0x01];
+    let auxiliary_data = AuxiliaryData::from_bytes(original.clone()).unwrap();
+    assert_eq!(auxiliary_data.to_bytes(), original);
+    assert_eq!(
+        hash_auxiliary_data(&auxiliary_data),
+        AuxiliaryDataHash::from(blake2b256(&original))
+    );
+    assert_eq!(auxiliary_data.to_canonical_bytes().unwrap(), vec![0xa1, 0x01, 0x01]);
+}
+
+#[test]
+fn redeemers_keep_original_encoding() {
+    // indefinite-length array of redeemers
+    let original = vec![0x9f, 0x84, 0x00, 0x00, 0x00, 0x82, 0x00, 0x00, 0xff];
+    let redeemers = Redeemers::from_bytes(original.clone()).unwrap();
+    assert_eq!(redeemers.to_bytes(), original);
+    assert_eq!(
+        redeemers.to_canonical_bytes().unwrap(),
+        vec![0x81, 0x84, 0x00, 0x00, 0x00, 0x82, 0x00, 0x00]
+    );
+
+    let mut changed = redeemers.clone();
+    changed.add(&redeemers.get(0));
+    assert_eq!(changed.to_bytes()[0], 0x82);
+}
+
+#[test]
+fn tx_keeps_original_body_encoding() {
+    // fee 100 encoded as a 4 bytes integer
+    let body = vec![
+        0xa3, 0x00, 0x80, 0x01, 0x80, 0x02, 0x1a, 0x00, 0x00, 0x00, 0x64,
+    ];
+    let mut original = vec![0x84];
+    original.extend(&body);
+    original.extend([0xa0, 0xf5, 0xf6]);
+
+    let tx = Transaction::from_bytes(original.clone()).unwrap();
+    assert_eq!(tx.to_bytes(), original);
+    assert_eq!(tx.body().to_bytes(), body);
+    assert_eq!(
+        FixedTransaction::from_bytes(original.clone())
+            .unwrap()
+            .transaction_hash(),
+        TransactionHash::from(blake2b256(&tx.body().to_bytes()))
+    );
+
+    // the library writes the inputs with the set tag
+    let canonical = vec![
+        0x84, 0xa3, 0x00, 0xd9, 0x01, 0x02, 0x80, 0x01, 0x80, 0x02, 0x18, 0x64, 0xa0, 0xf5, 0xf6,
+    ];
+    assert_eq!(tx.to_canonical_bytes().unwrap(), canonical);
+    assert_eq!(
+        FixedTransaction::from_bytes(original)
+            .unwrap()
+            .to_canonical_bytes()
+            .unwrap(),
+        canonical
+    );
+
+    let mut changed_body = tx.body();
+    changed_body.set_ttl(&BigNum::from(10u64));
+    assert_eq!(changed_body.to_bytes()[..2], [0xa4, 0x00]);
+}
+
+#[test]
+fn canonical_bytes_sort_map_keys() {
+    let mut addresses = [fake_reward_address(0), fake_reward_address(1)];
+    addresses.sort_by_key(|address| address.to_address().to_bytes());
+    let mut withdrawals = Withdrawals::new();
+    withdrawals.insert(&addresses[1], &Coin::from(1u64));
+    withdrawals.insert(&addresses[0], &Coin::from(2u64));
+    let mut body = TransactionBody::new_tx_body(
+        &TransactionInputs::new(),
+        &TransactionOutputs::new(),
+        &Coin::from(100u64),
+    );
+    body.set_withdrawals(&withdrawals);
+
+    let mut sorted = vec![0xa2];
+    sorted.extend(cbor_bytes(&addresses[0].to_address().to_bytes()));
+    sorted.push(0x02);
+    sorted.extend(cbor_bytes(&addresses[1].to_address().to_bytes()));
+    sorted.push(0x01);
+    let contains_sorted = |bytes: &[u8]| bytes.windows(sorted.len()).any(|w| w == &sorted[..]);
+    assert!(!contains_sorted(&body.to_bytes()));
+    assert!(contains_sorted(&body.to_canonical_bytes().unwrap()));
+}
+
+fn asset_name_bytes() -> Vec<u8> {
+    cbor_bytes(&AssetName::new(vec![1, 2, 3]).unwrap().name())
+}
+
+// {policy: {name: 1}} with indefinite-length maps
+fn indefinite_multiasset_bytes() -> Vec<u8> {
+    let mut bytes = vec![0xbf];
+    bytes.extend(cbor_bytes(&fake_script_hash(1).to_bytes()));
+    bytes.push(0xbf);
+    bytes.extend(asset_name_bytes());
+    bytes.extend([0x01, 0xff, 0xff]);
+    bytes
+}
+
+fn canonical_multiasset_bytes() -> Vec<u8> {
+    let mut bytes = vec![0xa1];
+    bytes.extend(cbor_bytes(&fake_script_hash(1).to_bytes()));
+    bytes.push(0xa1);
+    bytes.extend(asset_name_bytes());
+    bytes.push(0x01);
+    bytes
+}
+
+#[test]
+fn multiasset_keeps_original_encoding() {
+    let original = indefinite_multiasset_bytes();
+    let multiasset = MultiAsset::from_bytes(original.clone()).unwrap();
+    assert_eq!(multiasset.to_bytes(), original);
+    assert_eq!(multiasset.to_canonical_bytes().unwrap(), canonical_multiasset_bytes());
+
+    let mut changed = multiasset.clone();
+    changed.set_asset(
+        &fake_script_hash(1),
+        &AssetName::new(vec![1, 2, 3]).unwrap(),
+        &BigNum::from(2u64),
+    );
+    let mut expected = canonical_multiasset_bytes();
+    *expected.last_mut().unwrap() = 0x02;
+    assert_eq!(changed.to_bytes(), expected);
+}
+
+#[test]
+fn value_keeps_original_encoding() {
+    // coin 5 encoded as a 4 bytes integer
+    let mut original = vec![0x82, 0x1a, 0x00, 0x00, 0x00, 0x05];
+    original.extend(indefinite_multiasset_bytes());
+    let value = Value::from_bytes(original.clone()).unwrap();
+    assert_eq!(value.to_bytes(), original);
+
+    let mut canonical = vec![0x82, 0x05];
+    canonical.extend(canonical_multiasset_bytes());
+    assert_eq!(value.to_canonical_bytes().unwrap(), canonical);
+    assert_eq!(value, Value::from_bytes(canonical).unwrap());
+
+    // the multiasset of a value keeps its encoding as well
+    assert_eq!(
+        value.multiasset().unwrap().to_bytes(),
+        indefinite_multiasset_bytes()
+    );
+}
+
+#[test]
+fn mint_keeps_original_encoding() {
+    // the minted amount encoded as a 2 bytes integer
+    let mut original = vec![0xa1];
+    original.extend(cbor_bytes(&fake_script_hash(1).to_bytes()));
+    original.push(0xbf);
+    original.extend(asset_name_bytes());
+    original.extend([0x19, 0x00, 0x01, 0xff]);
+    let mint = Mint::from_bytes(original.clone()).unwrap();
+    assert_eq!(mint.to_bytes(), original);
+
+    let mut canonical = vec![0xa1];
+    canonical.extend(cbor_bytes(&fake_script_hash(1).to_bytes()));
+    canonical.push(0xa1);
+    canonical.extend(asset_name_bytes());
+    canonical.push(0x01);
+    assert_eq!(mint.to_canonical_bytes().unwrap(), canonical);
+}
+
+#[test]
+fn certificates_keep_original_encoding() {
+    let key_hash = fake_key_hash(1);
+    // tagged indefinite-length set with a stake registration
+    let mut original = vec![0xd9, 0x01, 0x02, 0x9f, 0x82, 0x00, 0x82, 0x00];
+    original.extend(cbor_bytes(&key_hash.to_bytes()));
+    original.push(0xff);
+    let certificates = Certificates::from_bytes(original.clone()).unwrap();
+    assert_eq!(certificates.to_bytes(), original);
+
+    let mut canonical = vec![0xd9, 0x01, 0x02, 0x81, 0x82, 0x00, 0x82, 0x00];
+    canonical.extend(cbor_bytes(&key_hash.to_bytes()));
+    assert_eq!(certificates.to_canonical_bytes().unwrap(), canonical);
+}
+
+#[test]
+fn plutus_script_keeps_original_encoding() {
+    // the length of the script encoded with an additional byte
+    let original = vec![0x58, 0x03, 0x01, 0x02, 0x03];
+    let script = PlutusScript::from_bytes(original.clone()).unwrap();
+    assert_eq!(script.to_bytes(), original);
+    assert_eq!(script.bytes(), vec![1, 2, 3]);
+    assert_eq!(
+        script.to_canonical_bytes().unwrap(),
+        vec![0x43, 0x01, 0x02, 0x03]
+    );
+}
+
+#[test]
+fn canonical_bytes_keep_plutus_data() {
+    // map keys of the datum are not in the canonical order, the int key sorts first
+    let datum = vec![0xa2, 0x42, 0x01, 0x02, 0x00, 0x01, 0x00];
+    let mut witness_set = vec![0xa1, 0x04, 0x81];
+    witness_set.extend(&datum);
+    let mut original = vec![0x84, 0xa3, 0x00, 0x80, 0x01, 0x80, 0x02, 0x00];
+    original.extend(&witness_set);
+    original.extend([0xf5, 0xf6]);
+
+    let tx = Transaction::from_bytes(original).unwrap();
+    let data = PlutusData::from_bytes(datum.clone()).unwrap();
+    let data_hash = hash_plutus_data(&data);
+    assert_eq!(data.to_canonical_bytes().unwrap(), datum);
+    for canonical in [
+        tx.to_canonical_bytes().unwrap(),
+        tx.witness_set().to_canonical_bytes().unwrap(),
+    ] {
+        assert!(canonical.windows(datum.len()).any(|w| w == &datum[..]));
+    }
+    let canonical_tx = Transaction::from_bytes(tx.to_canonical_bytes().unwrap()).unwrap();
+    assert_eq!(
+        hash_plutus_data(&canonical_tx.witness_set().plutus_data().unwrap().get(0)),
+        data_hash
+    );
+}
@@ -0,0 +1,114 @@
+use crate::tests::fakes::{fake_base_address, fake_root_key, fake_tx_hash, fake_tx_input};
+use crate::tests::helpers::pre_conway_body;
+use crate::*;
+
+fn fake_transaction() -> Transaction {
+    let mut inputs = TransactionInputs::new();
+    inputs.add(&fake_tx_input(1));
+    let mut outputs = TransactionOutputs::new();
+    outputs.add(&TransactionOutput::new(
+        &fake_base_address(1),
+        &Value::new(&Coin::from(2_000_000u64)),
+    ));
+    let body = TransactionBody::new_tx_body(&inputs, &outputs, &Coin::from(200_000u64));
+    // tagged sets would make it a Conway only transaction
+    Transaction::new(&pre_conway_body(&body), &TransactionWitnessSet::new(), None)
+}
+
+#[test]
+fn signing_key_round_trip() {
+    let key = PrivateKey::from_normal_bytes(&[7; 32]).unwrap();
+    let envelope = TextEnvelope::new_signing_key(&key).unwrap();
+    assert_eq!(envelope.envelope_type(), "PaymentSigningKeyShelley_ed25519");
+    assert_eq!(envelope.cbor_hex(), format!("5820{}", hex::encode([7; 32])));
+
+    let parsed = TextEnvelope::from_json(&envelope.to_json().unwrap()).unwrap();
+    assert_eq!(parsed.as_signing_key().unwrap().as_bytes(), key.as_bytes());
+    assert!(parsed.as_bip32_signing_key().is_err());
+    assert!(parsed.as_verification_key().is_err());
+}
+
+#[test]
+fn extended_signing_key_uses_128_bytes_xprv() {
+    let key = fake_root_key().derive(0);
+    let envelope = TextEnvelope::new_bip32_signing_key(&key);
+    assert_eq!(envelope.envelope_type(), "PaymentExtendedSigningKeyShelley_ed25519_bip32");
+    assert_eq!(envelope.cbor_hex(), format!("5880{}", hex::encode(key.to_128_xprv())));
+
+    assert_eq!(envelope.as_bip32_signing_key().unwrap().as_bytes(), key.as_bytes());
+    assert_eq!(envelope.as_signing_key().unwrap().as_bytes(), key.to_raw_key().as_bytes());
+
+    let extended_private_key = key.to_raw_key();
+    assert!(TextEnvelope::new_signing_key(&extended_private_key).is_err());
+}
+
+#[test]
+fn verification_keys_round_trip() {
+    let key = fake_root_key().derive(0).to_public();
+    let envelope = TextEnvelope::new_bip32_verification_key(&key);
+    assert_eq!(envelope.envelope_type(), "PaymentExtendedVerificationKeyShelley_ed25519_bip32");
+    assert_eq!(envelope.as_bip32_verification_key().unwrap().as_bytes(), key.as_bytes());
+    assert_eq!(envelope.as_verification_key().unwrap(), key.to_raw_key());
+
+    let envelope = TextEnvelope::new_verification_key(&key.to_raw_key());
+    assert_eq!(envelope.envelope_type(), "PaymentVerificationKeyShelley_ed25519");
+    assert_eq!(envelope.as_verification_key().unwrap(), key.to_raw_key());
+    assert!(envelope.as_bip32_verification_key().is_err());
+
+    // the declared type has to match the length of the key
+    let truncated = TextEnvelope::new(
+        "PaymentVerificationKeyShelley_ed25519",
+        "Payment Verification Key",
+        vec![0x58, 0x10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    );
+    assert!(truncated.as_verification_key().is_err());
+}
+
+#[test]
+fn vkeywitness_is_wrapped_as_key_witness() {
+    let key = PrivateKey::from_normal_bytes(&[7; 32]).unwrap();
+    let witness = make_vkey_witness(&fake_tx_hash(1), &key);
+    let envelope = TextEnvelope::new_vkeywitness(&witness);
+    assert_eq!(envelope.envelope_type(), "TxWitness ConwayEra");
+    assert!(envelope.cbor_hex().starts_with("8200825820"));
+    assert_eq!(envelope.as_vkeywitness().unwrap(), witness);
+
+    let mut bootstrap = hex::decode(envelope.cbor_hex()).unwrap();
+    bootstrap[1] = 0x01;
+    let bootstrap = TextEnvelope::new("TxWitness ConwayEra", "", bootstrap);
+    assert!(bootstrap.as_vkeywitness().is_err());
+}
+
+#[test]
+fn transaction_era_must_match_the_declared_type() {
+    let tx = fake_transaction();
+    let envelope = TextEnvelope::new_transaction(&tx);
+    assert_eq!(envelope.envelope_type(), "Tx ConwayEra");
+    assert_eq!(envelope.as_transaction().unwrap(), tx);
+    assert_eq!(envelope.as_fixed_transaction().unwrap().to_bytes(), tx.to_bytes());
+
+    let unwitnessed = TextEnvelope::new("Unwitnessed Tx BabbageEra", "", tx.to_bytes());
+    assert_eq!(unwitnessed.as_transaction().unwrap(), tx);
+
+    // a transaction with an is_valid flag can't be a Shelley transaction
+    let shelley = TextEnvelope::new("Tx ShelleyEra", "", tx.to_bytes());
+    assert!(shelley.as_transaction().is_err());
+    assert!(envelope.as_vkeywitness().is_err());
+}
+
+#[test]
+fn scripts_round_trip() {
+    let script = PlutusScript::new_v2(vec![0x4d, 0x01, 0x00, 0x00]);
+    let envelope = TextEnvelope::new_plutus_script(&script);
+    assert_eq!(envelope.envelope_type(), "PlutusScriptV2");
+    assert_eq!(envelope.as_plutus_script().unwrap(), script);
+    assert!(envelope.as_native_script().is_err());
+
+    let v1 = TextEnvelope::new("PlutusScriptV1", "", script.to_bytes());
+    assert_eq!(v1.as_plutus_script().unwrap().language_version(), Language::new_plutus_v1());
+
+    let native_script = NativeScript::new_timelock_start(&TimelockStart::new(10));
+    let envelope = TextEnvelope::new_native_script(&native_script);
+    assert_eq!(envelope.envelope_type(), "SimpleScript");
+    assert_eq!(envelope.as_native_script().unwrap(), native_script);
+}
@@ -26,6 +26,7 @@ fn subtract_values() {
     let assets1 = Value {
         coin: BigNum(1555554),
         multiasset: Some(token_bundle1),
+        original_encoding: OriginalEncoding::default(),
     };
 
     let mut token_bundle2 = MultiAsset::new();
@@ -49,6 +50,7 @@ fn subtract_values() {
     let assets2 = Value {
         coin: BigNum(2555554),
         multiasset: Some(token_bundle2),
+        original_encoding: OriginalEncoding::default(),
     };
 
     let result = assets1.clamped_sub(&assets2);
@@ -95,6 +97,7 @@ fn compare_values() {
         let a = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle1),
+            original_encoding: OriginalEncoding::default(),
         };
         let b = Value::new(&BigNum(1));
         assert_eq!(a.partial_cmp(&b).unwrap(), std::cmp::Ordering::Greater);
@@ -108,6 +111,7 @@ fn compare_values() {
         let b = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle1),
+            original_encoding: OriginalEncoding::default(),
         };
         assert_eq!(a.partial_cmp(&b).unwrap(), std::cmp::Ordering::Less);
     }
@@ -120,6 +124,7 @@ fn compare_values() {
         let a = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle1),
+            original_encoding: OriginalEncoding::default(),
         };
 
         let mut token_bundle2 = MultiAsset::new();
@@ -129,6 +134,7 @@ fn compare_values() {
         let b = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle2),
+            original_encoding: OriginalEncoding::default(),
         };
         assert_eq!(a.partial_cmp(&b).unwrap(), std::cmp::Ordering::Equal);
     }
@@ -140,6 +146,7 @@ fn compare_values() {
         let a = Value {
             coin: BigNum(2),
             multiasset: Some(token_bundle1),
+            original_encoding: OriginalEncoding::default(),
         };
 
         let mut token_bundle2 = MultiAsset::new();
@@ -149,6 +156,7 @@ fn compare_values() {
         let b = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle2),
+            original_encoding: OriginalEncoding::default(),
         };
         assert_eq!(a.partial_cmp(&b).unwrap(), std::cmp::Ordering::Greater);
     }
@@ -160,6 +168,7 @@ fn compare_values() {
         let a = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle1),
+            original_encoding: OriginalEncoding::default(),
         };
 
         let mut token_bundle2 = MultiAsset::new();
@@ -169,6 +178,7 @@ fn compare_values() {
         let b = Value {
             coin: BigNum(2),
             multiasset: Some(token_bundle2),
+            original_encoding: OriginalEncoding::default(),
         };
         assert_eq!(a.partial_cmp(&b).unwrap(), std::cmp::Ordering::Less);
     }
@@ -180,6 +190,7 @@ fn compare_values() {
         let a = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle1),
+            original_encoding: OriginalEncoding::default(),
         };
 
         let mut token_bundle2 = MultiAsset::new();
@@ -189,6 +200,7 @@ fn compare_values() {
         let b = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle2),
+            original_encoding: OriginalEncoding::default(),
         };
         assert_eq!(a.partial_cmp(&b).unwrap(), std::cmp::Ordering::Greater);
     }
@@ -200,6 +212,7 @@ fn compare_values() {
         let a = Value {
             coin: BigNum(2),
             multiasset: Some(token_bundle1),
+            original_encoding: OriginalEncoding::default(),
         };
 
         let mut token_bundle2 = MultiAsset::new();
@@ -209,6 +222,7 @@ fn compare_values() {
         let b = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle2),
+            original_encoding: OriginalEncoding::default(),
         };
         assert_eq!(a.partial_cmp(&b).unwrap(), std::cmp::Ordering::Greater);
     }
@@ -220,6 +234,7 @@ fn compare_values() {
         let a = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle1),
+            original_encoding: OriginalEncoding::default(),
         };
 
         let mut token_bundle2 = MultiAsset::new();
@@ -229,6 +244,7 @@ fn compare_values() {
         let b = Value {
             coin: BigNum(2),
             multiasset: Some(token_bundle2),
+            original_encoding: OriginalEncoding::default(),
         };
         assert_eq!(a.partial_cmp(&b), None);
     }
@@ -240,6 +256,7 @@ fn compare_values() {
         let a = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle1),
+            original_encoding: OriginalEncoding::default(),
         };
 
         let mut token_bundle2 = MultiAsset::new();
@@ -249,6 +266,7 @@ fn compare_values() {
         let b = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle2),
+            original_encoding: OriginalEncoding::default(),
         };
         assert_eq!(a.partial_cmp(&b).unwrap(), std::cmp::Ordering::Less);
     }
@@ -260,6 +278,7 @@ fn compare_values() {
         let a = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle1),
+            original_encoding: OriginalEncoding::default(),
         };
 
         let mut token_bundle2 = MultiAsset::new();
@@ -269,6 +288,7 @@ fn compare_values() {
         let b = Value {
             coin: BigNum(2),
             multiasset: Some(token_bundle2),
+            original_encoding: OriginalEncoding::default(),
         };
         assert_eq!(a.partial_cmp(&b).unwrap(), std::cmp::Ordering::Less);
     }
@@ -280,6 +300,7 @@ fn compare_values() {
         let a = Value {
             coin: BigNum(2),
             multiasset: Some(token_bundle1),
+            original_encoding: OriginalEncoding::default(),
         };
 
         let mut token_bundle2 = MultiAsset::new();
@@ -289,6 +310,7 @@ fn compare_values() {
         let b = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle2),
+            original_encoding: OriginalEncoding::default(),
         };
         assert_eq!(a.partial_cmp(&b), None);
     }
@@ -300,6 +322,7 @@ fn compare_values() {
         let a = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle1),
+            original_encoding: OriginalEncoding::default(),
         };
 
         let mut token_bundle2 = MultiAsset::new();
@@ -309,6 +332,7 @@ fn compare_values() {
         let b = Value {
             coin: BigNum(1),
             multiasset: Some(token_bundle2),
+            original_encoding: OriginalEncoding::default(),
         };
         assert_eq!(a.partial_cmp(&b), None);
     }
@@ -665,6 +689,16 @@ fn int_full_range() {
     // cbor_event's nint API worked via i64 but we now have a workaround for it
     // so these tests are here to make sure that workaround works.
 
+    // i64::MIN itself
+    let bytes_min = vec![0x3b, 0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+    let min = Int::from_bytes(bytes_min.clone()).unwrap();
+    assert_eq!(min.to_str(), "-9223372036854775808");
+    assert_eq!(bytes_min, min.to_bytes());
+    assert_eq!(
+        bytes_min,
+        BigInt::from_str("-9223372036854775808").unwrap().to_bytes()
+    );
+
     // first nint below of i64::MIN
     let bytes_x = vec![0x3b, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
     let x = Int::from_bytes(bytes_x.clone()).unwrap();
@@ -676,6 +710,10 @@ fn int_full_range() {
     let y = Int::from_bytes(bytes_y.clone()).unwrap();
     assert_eq!(y.to_str(), "-18446744073709551616");
     assert_eq!(bytes_y, y.to_bytes());
+    assert_eq!(
+        bytes_y,
+        BigInt::from_str("-18446744073709551616").unwrap().to_bytes()
+    );
 }
 
 #[test]
@@ -779,14 +817,17 @@ fn value_empty_asset_equal() {
     let a = Value {
         coin: BigNum(0),
         multiasset: None,
+        original_encoding: OriginalEncoding::default(),
     };
     let b = Value {
         coin: BigNum(0),
         multiasset: Some(MultiAsset::new()),
+        original_encoding: OriginalEncoding::default(),
     };
     let c = Value {
         coin: BigNum(0),
         multiasset: None,
+        original_encoding: OriginalEncoding::default(),
     };
 
     assert_eq!(a, b);
@@ -798,14 +839,17 @@ fn value_empty_asset_not_equal() {
     let a = Value {
         coin: BigNum(1),
         multiasset: None,
+        original_encoding: OriginalEncoding::default(),
     };
     let b = Value {
         coin: BigNum(2),
         multiasset: Some(MultiAsset::new()),
+        original_encoding: OriginalEncoding::default(),
     };
     let c = Value {
         coin: BigNum(3),
         multiasset: None,
+        original_encoding: OriginalEncoding::default(),
     };
 
     assert_ne!(a, b);
@@ -0,0 +1,610 @@
+use crate::tests::fakes::*;
+use crate::tests::helpers::harden;
+use crate::*;
+
+fn fake_utxos(utxos: &[(TransactionInput, TransactionOutput)]) -> TransactionUnspentOutputs {
+    let mut result = TransactionUnspentOutputs::new();
+    for (input, output) in utxos {
+        result.add(&TransactionUnspentOutput::new(input, output));
+    }
+    result
+}
+
+fn signed_tx(
+    body: &TransactionBody,
+    witness_set: &TransactionWitnessSet,
+    keys: &[PrivateKey],
+) -> Transaction {
+    let tx_hash = TransactionHash::from(blake2b256(&body.to_bytes()));
+    let mut witness_set = witness_set.clone();
+    let mut vkeys = Vkeywitnesses::new();
+    for key in keys {
+        vkeys.add(&make_vkey_witness(&tx_hash, key));
+    }
+    witness_set.set_vkeys(&vkeys);
+    Transaction::new(body, &witness_set, None)
+}
+
+fn error_kinds(errors: &ValidationErrors) -> Vec<ValidationErrorKind> {
+    errors.0.iter().map(|error| error.kind()).collect()
+}
+
+fn fake_key_input_utxo() -> (TransactionInput, TransactionOutput) {
+    (
+        fake_tx_input(1),
+        TransactionOutput::new(&fake_base_address(0), &Value::new(&BigNum(10_000_000))),
+    )
+}
+
+fn fake_simple_body() -> TransactionBody {
+    let mut outputs = TransactionOutputs::new();
+    outputs.add(&TransactionOutput::new(
+        &fake_base_address(1),
+        &Value::new(&BigNum(9_800_000)),
+    ));
+    let mut body = TransactionBody::new_tx_body(
+        &TransactionInputs::from_vec(vec![fake_tx_input(1)]),
+        &outputs,
+        &BigNum(200_000),
+    );
+    body.set_ttl(&BigNum(1000));
+    body
+}
+
+// spends a Plutus V1 script output with a datum hash, the collateral comes from a key address
+fn fake_plutus_tx_parts() -> (
+    TransactionBody,
+    TransactionWitnessSet,
+    TransactionUnspentOutputs,
+) {
+    let script = fake_plutus_script(1, &Language::new_plutus_v1());
+    let script_address =
+        EnterpriseAddress::new(0, &Credential::from_scripthash(&script.hash())).to_address();
+    let datum = PlutusData::new_integer(&BigInt::from(42));
+    let mut script_output = TransactionOutput::new(&script_address, &Value::new(&BigNum(10_000_000)));
+    script_output.set_data_hash(&hash_plutus_data(&datum));
+    let collateral_output = TransactionOutput::new(&fake_base_address(0), &Value::new(&BigNum(5_000_000)));
+    let utxos = fake_utxos(&[
+        (fake_tx_input(1), script_output),
+        (fake_tx_input(2), collateral_output),
+    ]);
+
+    let mut redeemers = Redeemers::new();
+    redeemers.add(&Redeemer::new(
+        &RedeemerTag::new_spend(),
+        &BigNum(0),
+        &PlutusData::new_empty_constr_plutus_data(&BigNum(0)),
+        &ExUnits::new(&BigNum(10000), &BigNum(1000000)),
+    ));
+    let mut datums = PlutusList::new();
+    datums.add(&datum);
+    let mut scripts = PlutusScripts::new();
+    scripts.add(&script);
+    let mut witness_set = TransactionWitnessSet::new();
+    witness_set.set_plutus_scripts(&scripts);
+    witness_set.set_redeemers(&redeemers);
+    witness_set.set_plutus_data(&datums);
+
+    let mut outputs = TransactionOutputs::new();
+    outputs.add(&TransactionOutput::new(
+        &fake_base_address(1),
+        &Value::new(&BigNum(9_000_000)),
+    ));
+    let mut body = TransactionBody::new_tx_body(
+        &TransactionInputs::from_vec(vec![fake_tx_input(1)]),
+        &outputs,
+        &BigNum(1_000_000),
+    );
+    body.set_collateral(&TransactionInputs::from_vec(vec![fake_tx_input(2)]));
+    let cost_models = fake_protocol_parameters()
+        .cost_models
+        .retain_language_versions(&Languages(vec![Language::new_plutus_v1()]));
+    body.set_script_data_hash(&hash_script_data(&redeemers, &cost_models, Some(datums)));
+    (body, witness_set, utxos)
+}
+
+#[test]
+fn validate_transaction_accepts_valid_tx() {
+    let tx = signed_tx(
+        &fake_simple_body(),
+        &TransactionWitnessSet::new(),
+        &[fake_payment_key(0)],
+    );
+    let utxos = fake_utxos(&[fake_key_input_utxo()]);
+    let errors =
+        validate_transaction(&tx, &utxos, &fake_protocol_parameters(), &BigNum(500)).unwrap();
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn validate_transaction_reports_every_violation() {
+    let mut body = fake_simple_body();
+    let mut outputs = body.outputs();
+    outputs.add(&TransactionOutput::new(&fake_base_address(2), &Value::new(&BigNum(1))));
+    body.outputs = outputs;
+    body.fee = BigNum(1000);
+    let tx = signed_tx(&body, &TransactionWitnessSet::new(), &[]);
+    let utxos = fake_utxos(&[fake_key_input_utxo()]);
+    let errors =
+        validate_transaction(&tx, &utxos, &fake_protocol_parameters(), &BigNum(1000)).unwrap();
+    assert_eq!(
+        error_kinds(&errors),
+        vec![
+            ValidationErrorKind::OutsideValidityInterval,
+            ValidationErrorKind::FeeTooSmall,
+            ValidationErrorKind::ValueNotConserved,
+            ValidationErrorKind::OutputTooSmall,
+            ValidationErrorKind::MissingVkeyWitnesses,
+        ]
+    );
+}
+
+#[test]
+fn validate_transaction_reports_unresolved_inputs() {
+    let mut body = fake_simple_body();
+    body.set_reference_inputs(&TransactionInputs::from_vec(vec![fake_tx_input(3)]));
+    let tx = signed_tx(&body, &TransactionWitnessSet::new(), &[fake_payment_key(0)]);
+    let errors = validate_transaction(
+        &tx,
+        &TransactionUnspentOutputs::new(),
+        &fake_protocol_parameters(),
+        &BigNum(500),
+    )
+    .unwrap();
+    // the key witness is extraneous without the resolved input, but it isn't a violation
+    assert_eq!(
+        error_kinds(&errors),
+        vec![ValidationErrorKind::BadInputs, ValidationErrorKind::BadInputs]
+    );
+}
+
+#[test]
+fn validate_transaction_checks_signatures() {
+    let body = fake_simple_body();
+    let mut tx = signed_tx(&body, &TransactionWitnessSet::new(), &[fake_payment_key(1)]);
+    let utxos = fake_utxos(&[fake_key_input_utxo()]);
+    let params = fake_protocol_parameters();
+    let errors = validate_transaction(&tx, &utxos, &params, &BigNum(500)).unwrap();
+    assert_eq!(
+        error_kinds(&errors),
+        vec![ValidationErrorKind::MissingVkeyWitnesses]
+    );
+
+    let mut vkeys = Vkeywitnesses::new();
+    vkeys.add(&Vkeywitness::new(
+        &Vkey::new(&fake_payment_key(0).to_public()),
+        &fake_payment_key(0).sign(&[1, 2, 3]),
+    ));
+    tx.witness_set.set_vkeys(&vkeys);
+    let errors = validate_transaction(&tx, &utxos, &params, &BigNum(500)).unwrap();
+    assert_eq!(error_kinds(&errors), vec![ValidationErrorKind::InvalidWitnesses]);
+}
+
+#[test]
+fn validate_transaction_checks_byron_witnesses() {
+    let byron_output = TransactionOutput::new(&fake_byron_address(), &Value::new(&BigNum(10_000_000)));
+    let utxos = fake_utxos(&[(fake_tx_input(1), byron_output)]);
+    let tx = signed_tx(&fake_simple_body(), &TransactionWitnessSet::new(), &[]);
+    let errors = validate_transaction(&tx, &utxos, &fake_protocol_parameters(), &BigNum(500)).unwrap();
+    assert_eq!(
+        error_kinds(&errors),
+        vec![ValidationErrorKind::MissingVkeyWitnesses]
+    );
+    assert!(errors.get(0).message().contains("bootstrap"));
+}
+
+#[test]
+fn validate_transaction_checks_auxiliary_data() {
+    let mut metadata = GeneralTransactionMetadata::new();
+    metadata.insert(&BigNum(1), &TransactionMetadatum::new_int(&Int::new_i32(1)));
+    let mut auxiliary_data = AuxiliaryData::new();
+    auxiliary_data.set_metadata(&metadata);
+    let utxos = fake_utxos(&[fake_key_input_utxo()]);
+    let params = fake_protocol_parameters();
+
+    let mut body = fake_simple_body();
+    body.set_auxiliary_data_hash(&fake_auxiliary_data_hash(1));
+    let mut tx = signed_tx(&body, &TransactionWitnessSet::new(), &[fake_payment_key(0)]);
+    let errors = validate_transaction(&tx, &utxos, &params, &BigNum(500)).unwrap();
+    assert_eq!(error_kinds(&errors), vec![ValidationErrorKind::MissingAuxiliaryData]);
+
+    tx.auxiliary_data = Some(auxiliary_data.clone());
+    let errors = validate_transaction(&tx, &utxos, &params, &BigNum(500)).unwrap();
+    assert_eq!(
+        error_kinds(&errors),
+        vec![ValidationErrorKind::ConflictingAuxiliaryDataHash]
+    );
+
+    let tx = signed_tx(&fake_simple_body(), &TransactionWitnessSet::new(), &[fake_payment_key(0)]);
+    let tx = Transaction::new(&tx.body, &tx.witness_set, Some(auxiliary_data));
+    let errors = validate_transaction(&tx, &utxos, &params, &BigNum(500)).unwrap();
+    assert_eq!(
+        error_kinds(&errors),
+        vec![ValidationErrorKind::MissingAuxiliaryDataHash]
+    );
+}
+
+#[test]
+fn validate_transaction_accepts_valid_plutus_tx() {
+    let (body, witness_set, utxos) = fake_plutus_tx_parts();
+    let tx = signed_tx(&body, &witness_set, &[fake_payment_key(0)]);
+    let errors =
+        validate_transaction(&tx, &utxos, &fake_protocol_parameters(), &BigNum(500)).unwrap();
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn validate_transaction_checks_plutus_witnesses() {
+    let (body, mut witness_set, utxos) = fake_plutus_tx_parts();
+    let params = fake_protocol_parameters();
+
+    let mut redeemers = witness_set.redeemers().unwrap();
+    redeemers.redeemers[0].index = BigNum(1);
+    witness_set.set_redeemers(&redeemers);
+    witness_set.plutus_data = None;
+    let mut native_scripts = NativeScripts::new();
+    native_scripts.add(&NativeScript::new_timelock_start(&TimelockStart::new_timelockstart(
+        &BigNum(1),
+    )));
+    witness_set.set_native_scripts(&native_scripts);
+    let tx = signed_tx(&body, &witness_set, &[fake_payment_key(0)]);
+    let errors = validate_transaction(&tx, &utxos, &params, &BigNum(500)).unwrap();
+    assert_eq!(
+        error_kinds(&errors),
+        vec![
+            ValidationErrorKind::ExtraneousScriptWitnesses,
+            ValidationErrorKind::MissingRedeemers,
+            ValidationErrorKind::ExtraRedeemers,
+            ValidationErrorKind::MissingRequiredDatums,
+            ValidationErrorKind::ScriptDataHashMismatch,
+        ]
+    );
+
+    let (body, mut witness_set, utxos) = fake_plutus_tx_parts();
+    witness_set.plutus_scripts = None;
+    let tx = signed_tx(&body, &witness_set, &[fake_payment_key(0)]);
+    let errors = validate_transaction(&tx, &utxos, &params, &BigNum(500)).unwrap();
+    assert_eq!(
+        error_kinds(&errors),
+        vec![ValidationErrorKind::MissingScriptWitnesses]
+    );
+}
+
+#[test]
+fn validate_transaction_accepts_reference_scripts_and_supplemental_datums() {
+    let (mut body, mut witness_set, utxos) = fake_plutus_tx_parts();
+    let script = witness_set.plutus_scripts().unwrap().get(0);
+    witness_set.plutus_scripts = None;
+
+    let mut utxos = utxos;
+    let mut reference_output = TransactionOutput::new(&fake_base_address(3), &Value::new(&BigNum(5_000_000)));
+    reference_output.set_script_ref(&ScriptRef::new_plutus_script(&script));
+    utxos.add(&TransactionUnspentOutput::new(&fake_tx_input(3), &reference_output));
+    body.set_reference_inputs(&TransactionInputs::from_vec(vec![fake_tx_input(3)]));
+
+    // a datum for a datum hash of a new output can be supplied in the witness set
+    let supplemental_datum = PlutusData::new_integer(&BigInt::from(7));
+    let mut outputs = body.outputs();
+    let mut output = outputs.get(0);
+    output.set_data_hash(&hash_plutus_data(&supplemental_datum));
+    outputs.0[0] = output;
+    body.outputs = outputs;
+    let mut datums = witness_set.plutus_data().unwrap();
+    datums.add(&supplemental_datum);
+    witness_set.set_plutus_data(&datums);
+    let cost_models = fake_protocol_parameters()
+        .cost_models
+        .retain_language_versions(&Languages(vec![Language::new_plutus_v1()]));
+    body.set_script_data_hash(&hash_script_data(
+        &witness_set.redeemers().unwrap(),
+        &cost_models,
+        Some(datums.clone()),
+    ));
+
+    let tx = signed_tx(&body, &witness_set, &[fake_payment_key(0)]);
+    let errors =
+        validate_transaction(&tx, &utxos, &fake_protocol_parameters(), &BigNum(500)).unwrap();
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    datums.add(&PlutusData::new_integer(&BigInt::from(8)));
+    witness_set.set_plutus_data(&datums);
+    body.set_script_data_hash(&hash_script_data(
+        &witness_set.redeemers().unwrap(),
+        &cost_models,
+        Some(datums),
+    ));
+    let tx = signed_tx(&body, &witness_set, &[fake_payment_key(0)]);
+    let errors =
+        validate_transaction(&tx, &utxos, &fake_protocol_parameters(), &BigNum(500)).unwrap();
+    assert_eq!(
+        error_kinds(&errors),
+        vec![ValidationErrorKind::NotAllowedSupplementalDatums]
+    );
+}
+
+#[test]
+fn validate_transaction_checks_collateral() {
+    let params = fake_protocol_parameters();
+
+    let (mut body, witness_set, utxos) = fake_plutus_tx_parts();
+    body.collateral = None;
+    let tx = signed_tx(&body, &witness_set, &[]);
+    let errors = validate_transaction(&tx, &utxos, &params, &BigNum(500)).unwrap();
+    assert_eq!(error_kinds(&errors), vec![ValidationErrorKind::NoCollateralInputs]);
+
+    // collateral with assets must return them, 1.4 ADA is less than 150% of the fee
+    let (mut body, witness_set, _) = fake_plutus_tx_parts();
+    let script_output = fake_plutus_tx_parts().2.get(0).output();
+    let mut assets = MultiAsset::new();
+    assets.set_asset(&fake_policy_id(1), &fake_asset_name(1), &BigNum(10));
+    let collateral_output = TransactionOutput::new(
+        &fake_base_address(0),
+        &Value::new_with_assets(&BigNum(3_000_000), &assets),
+    );
+    let utxos = fake_utxos(&[
+        (fake_tx_input(1), script_output),
+        (fake_tx_input(2), collateral_output),
+    ]);
+    body.set_collateral_return(&TransactionOutput::new(
+        &fake_base_address(0),
+        &Value::new(&BigNum(1_600_000)),
+    ));
+    body.set_total_collateral(&BigNum(1_500_000));
+    let tx = signed_tx(&body, &witness_set, &[fake_payment_key(0)]);
+    let errors = validate_transaction(&tx, &utxos, &params, &BigNum(500)).unwrap();
+    assert_eq!(
+        error_kinds(&errors),
+        vec![
+            ValidationErrorKind::CollateralContainsNonAda,
+            ValidationErrorKind::InsufficientCollateral,
+            ValidationErrorKind::IncorrectTotalCollateral,
+        ]
+    );
+}
+
+#[test]
+fn required_signers_report_lists_signatures() {
+    let policy = NativeScript::new_script_pubkey(&ScriptPubkey::new(
+        &fake_payment_key(4).to_public().hash(),
+    ));
+    let mut body = fake_simple_body();
+    body.set_mint(&Mint::new_from_entry(
+        &policy.hash(),
+        &MintAssets::new_from_entry(&AssetName::new(vec![1]).unwrap(), &Int::new_i32(1)).unwrap(),
+    ));
+    body.set_required_signers(&Ed25519KeyHashes::from_vec(vec![
+        fake_payment_key(3).to_public().hash(),
+    ]));
+    let mut witness_set = TransactionWitnessSet::new();
+    witness_set.set_native_scripts(&NativeScripts::from(vec![policy]));
+    let mut tx = signed_tx(&body, &witness_set, &[fake_payment_key(0), fake_payment_key(5)]);
+    let mut vkeys = tx.witness_set.vkeys.clone().unwrap();
+    vkeys.add(&Vkeywitness::new(
+        &Vkey::new(&fake_payment_key(3).to_public()),
+        &fake_payment_key(3).sign(&[1, 2, 3]),
+    ));
+    tx.witness_set.set_vkeys(&vkeys);
+
+    let key_hashes = |indexes: &[u32]| {
+        let mut key_hashes: Vec<Ed25519KeyHash> = indexes
+            .iter()
+            .map(|index| fake_payment_key(*index).to_public().hash())
+            .collect();
+        key_hashes.sort();
+        Ed25519KeyHashes::from_vec(key_hashes)
+    };
+    let report = tx.required_signers_report(&fake_utxos(&[fake_key_input_utxo()])).unwrap();
+    assert_eq!(report.required(), key_hashes(&[0, 3, 4]));
+    assert_eq!(report.signed(), key_hashes(&[0]));
+    assert_eq!(report.missing(), key_hashes(&[3, 4]));
+    assert_eq!(report.invalid(), key_hashes(&[3]));
+    assert_eq!(report.superfluous(), key_hashes(&[5]));
+    assert!(!report.is_complete());
+    assert!(!tx.verify_witnesses());
+}
+
+#[test]
+fn required_signers_report_requires_resolved_inputs() {
+    let tx = signed_tx(&fake_simple_body(), &TransactionWitnessSet::new(), &[fake_payment_key(0)]);
+    assert!(tx.required_signers_report(&TransactionUnspentOutputs::new()).is_err());
+
+    let report = tx.required_signers_report(&fake_utxos(&[fake_key_input_utxo()])).unwrap();
+    assert!(report.is_complete());
+    assert_eq!(report.superfluous().len(), 0);
+    assert!(tx.verify_witnesses());
+}
+
+#[test]
+fn required_signers_report_checks_byron_witnesses() {
+    let key = fake_root_key()
+        .derive(harden(44))
+        .derive(harden(1815))
+        .derive(harden(0))
+        .derive(0)
+        .derive(0);
+    let address = ByronAddress::icarus_from_key(&key.to_public(), NetworkInfo::mainnet().protocol_magic());
+    let byron_output = TransactionOutput::new(&address.to_address(), &Value::new(&BigNum(10_000_000)));
+    let utxos = fake_utxos(&[(fake_tx_input(1), byron_output)]);
+
+    let body = fake_simple_body();
+    let mut tx = signed_tx(&body, &TransactionWitnessSet::new(), &[]);
+    let report = tx.required_signers_report(&utxos).unwrap();
+    assert_eq!(report.missing().len(), 1);
+
+    let tx_hash = TransactionHash::from(blake2b256(&body.to_bytes()));
+    let mut bootstraps = BootstrapWitnesses::new();
+    bootstraps.add(&make_icarus_bootstrap_witness(&tx_hash, &address, &key));
+    tx.witness_set.set_bootstraps(&bootstraps);
+    let report = tx.required_signers_report(&utxos).unwrap();
+    assert!(report.is_complete());
+    assert_eq!(report.signed(), report.required());
+    assert_eq!(
+        error_kinds(&validate_transaction(&tx, &utxos, &fake_protocol_parameters(), &BigNum(500)).unwrap()),
+        vec![]
+    );
+}
+
+fn fake_native_mint_body(policy: &NativeScript, ttl: u64) -> TransactionBody {
+    let mint_assets =
+        MintAssets::new_from_entry(&AssetName::new(vec![1]).unwrap(), &Int::new_i32(1)).unwrap();
+    let mint = Mint::new_from_entry(&policy.hash(), &mint_assets);
+    let mut body = fake_simple_body();
+    let mut value = Value::new(&BigNum(9_800_000));
+    value.set_multiasset(&mint.as_positive_multiasset());
+    let mut outputs = TransactionOutputs::new();
+    outputs.add(&TransactionOutput::new(&fake_base_address(1), &value));
+    body.outputs = outputs;
+    body.set_mint(&mint);
+    body.set_ttl(&BigNum(ttl));
+    body
+}
+
+#[test]
+fn validate_transaction_evaluates_native_scripts() {
+    let policy = NativeScript::new_script_all(&ScriptAll::new(&NativeScripts::from(vec![
+        &NativeScript::new_script_pubkey(&ScriptPubkey::new(&fake_payment_key(4).to_public().hash())),
+        &NativeScript::new_timelock_expiry(&TimelockExpiry::new_timelockexpiry(&BigNum(800))),
+    ])));
+    let mut witness_set = TransactionWitnessSet::new();
+    witness_set.set_native_scripts(&NativeScripts::from(vec![&policy]));
+    let utxos = fake_utxos(&[fake_key_input_utxo()]);
+    let params = fake_protocol_parameters();
+    let keys = [fake_payment_key(0), fake_payment_key(4)];
+
+    let tx = signed_tx(&fake_native_mint_body(&policy, 1000), &witness_set, &keys);
+    let errors = validate_transaction(&tx, &utxos, &params, &BigNum(500)).unwrap();
+    assert_eq!(
+        error_kinds(&errors),
+        vec![ValidationErrorKind::ScriptWitnessNotValidating]
+    );
+
+    let tx = signed_tx(&fake_native_mint_body(&policy, 800), &witness_set, &keys[..1]);
+    let errors = validate_transaction(&tx, &utxos, &params, &BigNum(500)).unwrap();
+    assert_eq!(
+        error_kinds(&errors),
+        vec![ValidationErrorKind::ScriptWitnessNotValidating]
+    );
+
+    let tx = signed_tx(&fake_native_mint_body(&policy, 800), &witness_set, &keys);
+    let errors = validate_transaction(&tx, &utxos, &params, &BigNum(500)).unwrap();
+    assert_eq!(error_kinds(&errors), vec![]);
+}
+
+#[test]
+fn required_signers_report_picks_native_script_signer_set() {
+    let pubkey = |index: u32| {
+        NativeScript::new_script_pubkey(&ScriptPubkey::new(&fake_payment_key(index).to_public().hash()))
+    };
+    let policy = NativeScript::new_script_n_of_k(&ScriptNOfK::new(
+        2,
+        &NativeScripts::from(vec![&pubkey(6), &pubkey(7), &pubkey(8)]),
+    ));
+    let mut witness_set = TransactionWitnessSet::new();
+    witness_set.set_native_scripts(&NativeScripts::from(vec![&policy]));
+    let body = fake_native_mint_body(&policy, 1000);
+    let utxos = fake_utxos(&[fake_key_input_utxo()]);
+
+    let tx = signed_tx(&body, &witness_set, &[fake_payment_key(0), fake_payment_key(8)]);
+    let report = tx.required_signers_report(&utxos).unwrap();
+    assert_eq!(report.required().len(), 3);
+    assert_eq!(report.signed().len(), 2);
+    assert_eq!(report.missing().len(), 1);
+    assert!(!report.missing().contains(&fake_payment_key(8).to_public().hash()));
+
+    let tx = signed_tx(
+        &body,
+        &witness_set,
+        &[fake_payment_key(0), fake_payment_key(7), fake_payment_key(8)],
+    );
+    let report = tx.required_signers_report(&utxos).unwrap();
+    assert!(report.is_complete());
+    assert_eq!(report.superfluous().len(), 0);
+}
+
+fn fake_cip21_body(output_address: &Address) -> TransactionBody {
+    let mut inputs = TransactionInputs::new();
+    inputs.add(&fake_tx_input(1));
+    let mut outputs = TransactionOutputs::new();
+    outputs.add(&TransactionOutput::new(
+        output_address,
+        &Value::new(&Coin::from(2_000_000u64)),
+    ));
+    let mut body = TransactionBody::new_tx_body(&inputs, &outputs, &Coin::from(200_000u64));
+    body.set_network_id(&NetworkId::testnet());
+    body
+}
+
+#[test]
+fn cip21_accepts_canonical_tx() {
+    let tx = Transaction::new(
+        &fake_cip21_body(&fake_base_address(0)),
+        &TransactionWitnessSet::new(),
+        None,
+    );
+    assert!(check_cip21_compliance(&tx).is_empty());
+}
+
+#[test]
+fn cip21_reports_non_canonical_body() {
+    // the fee (2) comes before the inputs (0) and the outputs (1) and is a 4 bytes integer
+    let body = TransactionBody::from_bytes(vec![
+        0xa3, 0x02, 0x1a, 0x00, 0x00, 0x00, 0x64, 0x00, 0x80, 0x01, 0x80,
+    ])
+    .unwrap();
+    let tx = Transaction::new(&body, &TransactionWitnessSet::new(), None);
+    let violations = check_cip21_compliance(&tx);
+    assert_eq!(violations.len(), 1);
+    assert!(violations.contains_kind(Cip21ViolationKind::NonCanonicalBody));
+
+    let canonical = body.to_canonical_bytes().unwrap();
+    assert_eq!(
+        canonical,
+        vec![0xa3, 0x00, 0xd9, 0x01, 0x02, 0x80, 0x01, 0x80, 0x02, 0x18, 0x64]
+    );
+    let tx = Transaction::new(
+        &TransactionBody::from_bytes(canonical).unwrap(),
+        &TransactionWitnessSet::new(),
+        None,
+    );
+    assert!(check_cip21_compliance(&tx).is_empty());
+}
+
+#[test]
+fn cip21_reports_unsupported_fields() {
+    let mut body = fake_cip21_body(&fake_byron_address());
+    let mut certs = Certificates::new();
+    certs.add(&Certificate::new_pool_registration(&PoolRegistration::new(
+        &fake_full_pool_params(),
+    )));
+    body.set_certs(&certs);
+    let mut withdrawals = Withdrawals::new();
+    withdrawals.insert(&fake_reward_address(0), &Coin::from(1u64));
+    body.set_withdrawals(&withdrawals);
+    let mut assets = Assets::new();
+    assets.insert(&fake_asset_name(1), &BigNum::zero());
+    let mut multiasset = MultiAsset::new();
+    multiasset.insert(&fake_policy_id(1), &assets);
+    let mut value = Value::new(&Coin::from(2_000_000u64));
+    value.set_multiasset(&multiasset);
+    body.set_collateral_return(&TransactionOutput::new(&fake_byron_address(), &value));
+    let mut auxiliary_data = AuxiliaryData::new();
+    auxiliary_data.set_metadata(&GeneralTransactionMetadata::new());
+
+    let mut tx = Transaction::new(&body, &TransactionWitnessSet::new(), Some(auxiliary_data));
+    let violations = check_cip21_compliance(&tx);
+    assert!(violations.contains_kind(Cip21ViolationKind::PoolRegistrationMixedWithOtherFields));
+    assert!(violations.contains_kind(Cip21ViolationKind::EmptyOrZeroAssets));
+    // the Byron output is a mainnet address
+    assert!(violations.contains_kind(Cip21ViolationKind::NetworkIdMismatch));
+    assert!(!violations.contains_kind(Cip21ViolationKind::UnsupportedOutputFormat));
+    assert!(!violations.contains_kind(Cip21ViolationKind::NonCanonicalBody));
+
+    let mut output = body.outputs().get(0);
+    output.set_data_hash(&fake_data_hash(1));
+    let mut outputs = TransactionOutputs::new();
+    outputs.add(&output);
+    let mut body = tx.body();
+    body.outputs = outputs;
+    tx = Transaction::new(&body, &TransactionWitnessSet::new(), None);
+    assert!(check_cip21_compliance(&tx).contains_kind(Cip21ViolationKind::UnsupportedOutputFormat));
+}
@@ -0,0 +1,167 @@
+use crate::tests::fakes::{fake_bytes_32, fake_signature, fake_vkey};
+use crate::*;
+
+// draft-irtf-cfrg-vrf-03 test vectors of ECVRF-ED25519-SHA512-Elligator2
+const VECTOR_1_PK: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+const VECTOR_1_PI: &str = "b6b4699f87d56126c9117a7da55bd0085246f4c56dbc95d20172612e9d38e8d7ca65e573a126ed88d4e30a46f80a666854d675cf3ba81de0de043c3774f061560f55edc256a787afe701677c0f602900";
+const VECTOR_1_BETA: &str = "5b49b554d05c0cd5a5325376b3387de59d924fd1e13ded44648ab33c21349a603f25b84ec5ed887995b33da5e3bfcb87cd2f64521c4c62cf825cffabbe5d31cc";
+const VECTOR_2_PK: &str = "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c";
+const VECTOR_2_PI: &str = "ae5b66bdf04b4c010bfe32b2fc126ead2107b697634f6f7337b9bff8785ee111200095ece87dde4dbe87343f6df3b107d91798c8a7eb1245d3bb9c5aafb093358c13e6ae1111a55717e895fd15f99f07";
+const VECTOR_2_BETA: &str = "94f4487e1b2fec954309ef1289ecb2e15043a2461ecc7b2ae7d4470607ef82eb1cfa97d84991fe4a7bfdfd715606bc27e2967a6c557cfb5875879b671740b7d8";
+
+// proofs of the secret key of the first vector for the header below
+const HEADER_SLOT: u64 = 4492800;
+const PRAOS_PROOF: &str = "246851d933b13b48875cd4a1a66d6b29a2fe4dd1fc322eed5c7f5abf99446cf66685d6d7140a4334a794c953e58f30bf7499ba1b07c167e7884baa146dae4911d691d271da164ada182f00102dba4207";
+const PRAOS_OUTPUT: &str = "28bcd43fc80b3fd68e098f33e5fe87cedf7c0de1b1aa8bc0816ef50d933bd4fade17a4c250438a4a158698ae2941fdf4d1b9d627bf0b9b2a761130149b5c0c86";
+const PRAOS_LEADER_VALUE: &str = "280d1a5a2134a520a22c2688e465b5b53d99d14c6e3f8ca87d6e27f45ca0bc0d";
+const PRAOS_NONCE_VALUE: &str = "b9e8517f6a847f099cac4769504c135ddd86ace01ed10014ba55f6ccced1b229";
+const TPRAOS_NONCE_PROOF: &str = "37765fbabd47b734b2bcf81109a23372f3540e8eaf5a6b9a77f5c1cde1240d81a1d7bd0b5b091e1efb06a6b5e70fad1c73c89ae157192217565e72f9cab7f5a9b198c665334aa1658e14519ffd9d6104";
+const TPRAOS_NONCE_OUTPUT: &str = "9094edbd06f2942d62797d2426755a839ed664aad35fa3f964934e52dae62c5d50dd5bc8f29e37d2c25a94316921ee4dcfa2adb8a96577e1bd6d503fd60d25f0";
+const TPRAOS_NONCE_VALUE: &str = "ad05acd3cc0b65552e4e9bda5d28eca67c74a07e0e4a5009854fdcd1d7481e18";
+const TPRAOS_LEADER_PROOF: &str = "1130c37eba37d9c5c69f4d26104443249feaa330fa5486bdec44a90927c11a2c69059afad27d7de0eae041d95e80a118ab0635646db0d3491d900b7469b75dd133bea9709fd8ba23443e6a11a9566c02";
+const TPRAOS_LEADER_OUTPUT: &str = "3db257337891f24f302b91c3fffaef1231e8175c43530c4bd230f74f3ba2bde8408686581e316e92f0c4f4c866357f281f4108ac6eaf59c3943686c4e51fa07b";
+
+fn cert(output: &str, proof: &str) -> VRFCert {
+    VRFCert::new(hex::decode(output).unwrap(), hex::decode(proof).unwrap()).unwrap()
+}
+
+fn vkey(public_key: &str) -> VRFVKey {
+    VRFVKey::from_bytes(hex::decode(public_key).unwrap()).unwrap()
+}
+
+fn epoch_nonce() -> Nonce {
+    Nonce::new_from_hash(vec![7; 32]).unwrap()
+}
+
+fn header_body(leader_cert: HeaderLeaderCertEnum) -> HeaderBody {
+    HeaderBody {
+        block_number: 123,
+        slot: BigNum(HEADER_SLOT),
+        prev_hash: Some(BlockHash::from_bytes(fake_bytes_32(1)).unwrap()),
+        issuer_vkey: fake_vkey(),
+        vrf_vkey: vkey(VECTOR_1_PK),
+        leader_cert,
+        block_body_size: 123456,
+        block_body_hash: BlockHash::from_bytes(fake_bytes_32(4)).unwrap(),
+        operational_cert: OperationalCert::new(
+            &KESVKey::from_bytes(fake_bytes_32(5)).unwrap(),
+            123,
+            456,
+            &fake_signature(6),
+        ),
+        protocol_version: ProtocolVersion::new(8, 0),
+    }
+}
+
+fn praos_header_body() -> HeaderBody {
+    header_body(HeaderLeaderCertEnum::VrfResult(cert(
+        PRAOS_OUTPUT,
+        PRAOS_PROOF,
+    )))
+}
+
+fn tpraos_header_body() -> HeaderBody {
+    header_body(HeaderLeaderCertEnum::NonceAndLeader(
+        cert(TPRAOS_NONCE_OUTPUT, TPRAOS_NONCE_PROOF),
+        cert(TPRAOS_LEADER_OUTPUT, TPRAOS_LEADER_PROOF),
+    ))
+}
+
+#[test]
+fn vrf_cert_verify_test_vectors() {
+    assert!(cert(VECTOR_1_BETA, VECTOR_1_PI).verify(&vkey(VECTOR_1_PK), &[]));
+    assert!(cert(VECTOR_2_BETA, VECTOR_2_PI).verify(&vkey(VECTOR_2_PK), &[0x72]));
+}
+
+#[test]
+fn vrf_cert_verify_rejects_wrong_input_key_or_output() {
+    let vector_1 = cert(VECTOR_1_BETA, VECTOR_1_PI);
+    assert!(!vector_1.verify(&vkey(VECTOR_1_PK), &[0x72]));
+    assert!(!vector_1.verify(&vkey(VECTOR_2_PK), &[]));
+    assert!(!cert(VECTOR_2_BETA, VECTOR_1_PI).verify(&vkey(VECTOR_1_PK), &[]));
+}
+
+#[test]
+fn vrf_cert_verify_rejects_tampered_proof() {
+    let mut proof = hex::decode(VECTOR_1_PI).unwrap();
+    for position in [0, 40, 79] {
+        proof[position] ^= 1;
+        let tampered = VRFCert::new(hex::decode(VECTOR_1_BETA).unwrap(), proof.clone()).unwrap();
+        assert!(!tampered.verify(&vkey(VECTOR_1_PK), &[]));
+        proof[position] ^= 1;
+    }
+}
+
+#[test]
+fn praos_header_verify_vrf() {
+    let body = praos_header_body();
+    assert!(body.verify_vrf(&epoch_nonce()));
+    assert!(!body.verify_vrf(&Nonce::new_identity()));
+    assert!(!body.verify_vrf(&Nonce::new_from_hash(vec![8; 32]).unwrap()));
+}
+
+#[test]
+fn tpraos_header_verify_vrf() {
+    let body = tpraos_header_body();
+    assert!(body.verify_vrf(&epoch_nonce()));
+    assert!(!body.verify_vrf(&Nonce::new_from_hash(vec![8; 32]).unwrap()));
+
+    // the nonce proof doesn't prove the leader seed
+    let swapped = header_body(HeaderLeaderCertEnum::NonceAndLeader(
+        cert(TPRAOS_LEADER_OUTPUT, TPRAOS_LEADER_PROOF),
+        cert(TPRAOS_NONCE_OUTPUT, TPRAOS_NONCE_PROOF),
+    ));
+    assert!(!swapped.verify_vrf(&epoch_nonce()));
+}
+
+#[test]
+fn praos_header_vrf_values() {
+    let body = praos_header_body();
+    assert_eq!(
+        body.vrf_leader_value(),
+        hex::decode(PRAOS_LEADER_VALUE).unwrap()
+    );
+    assert_eq!(
+        body.vrf_nonce_value().get_hash(),
+        Some(hex::decode(PRAOS_NONCE_VALUE).unwrap())
+    );
+}
+
+#[test]
+fn tpraos_header_vrf_values() {
+    let body = tpraos_header_body();
+    assert_eq!(
+        body.vrf_leader_value(),
+        hex::decode(TPRAOS_LEADER_OUTPUT).unwrap()
+    );
+    assert_eq!(
+        body.vrf_nonce_value().get_hash(),
+        Some(hex::decode(TPRAOS_NONCE_VALUE).unwrap())
+    );
+}
+
+#[test]
+fn header_is_leader() {
+    let active_slot_coefficient = UnitInterval::new(&BigNum(1), &BigNum(20));
+    let no_stake = UnitInterval::new(&BigNum(0), &BigNum(1));
+    let all_stake = UnitInterval::new(&BigNum(1), &BigNum(1));
+    let some_stake = UnitInterval::new(&BigNum(1), &BigNum(1000));
+
+    let lowest = header_body(HeaderLeaderCertEnum::NonceAndLeader(
+        VRFCert::new(vec![0; 64], vec![0; 80]).unwrap(),
+        VRFCert::new(vec![0; 64], vec![0; 80]).unwrap(),
+    ));
+    assert!(lowest.is_leader(&some_stake, &active_slot_coefficient));
+    assert!(!lowest.is_leader(&no_stake, &active_slot_coefficient));
+
+    let highest = header_body(HeaderLeaderCertEnum::NonceAndLeader(
+        VRFCert::new(vec![0; 64], vec![0; 80]).unwrap(),
+        VRFCert::new(vec![0xff; 64], vec![0; 80]).unwrap(),
+    ));
+    assert!(!highest.is_leader(&all_stake, &active_slot_coefficient));
+
+    // the leader value 0x280d.. is about 0.156, above the 1/20 of a pool with all the stake
+    let praos = praos_header_body();
+    assert!(!praos.is_leader(&all_stake, &active_slot_coefficient));
+    assert!(praos.is_leader(&all_stake, &UnitInterval::new(&BigNum(1), &BigNum(5))));
+}
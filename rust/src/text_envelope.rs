@@ -0,0 +1,315 @@
+use crate::impl_mockchain::key::EitherEd25519SecretKey;
+use crate::*;
+
+// The TextEnvelope JSON files of cardano-cli and cardano-api:
+// {"type": "...", "description": "...", "cborHex": "..."}
+// The type strings and default descriptions are the ones cardano-cli writes.
+
+const SHELLEY_BASED_ERAS: [(&str, BlockEra); 6] = [
+    ("ShelleyEra", BlockEra::Shelley),
+    ("AllegraEra", BlockEra::Allegra),
+    ("MaryEra", BlockEra::Mary),
+    ("AlonzoEra", BlockEra::Alonzo),
+    ("BabbageEra", BlockEra::Babbage),
+    ("ConwayEra", BlockEra::Conway),
+];
+
+const TX_TYPE_PREFIXES: [&str; 3] = ["Tx ", "Witnessed Tx ", "Unwitnessed Tx "];
+const TX_WITNESS_TYPE_PREFIX: &str = "TxWitness ";
+const LEGACY_TX_WITNESS_TYPE: &str = "TxWitnessShelley";
+
+const SIGNING_KEY_TYPES: [&str; 2] = [
+    "PaymentSigningKeyShelley_ed25519",
+    "StakeSigningKeyShelley_ed25519",
+];
+const EXTENDED_SIGNING_KEY_TYPES: [&str; 2] = [
+    "PaymentExtendedSigningKeyShelley_ed25519_bip32",
+    "StakeExtendedSigningKeyShelley_ed25519_bip32",
+];
+const VERIFICATION_KEY_TYPES: [&str; 2] = [
+    "PaymentVerificationKeyShelley_ed25519",
+    "StakeVerificationKeyShelley_ed25519",
+];
+const EXTENDED_VERIFICATION_KEY_TYPES: [&str; 2] = [
+    "PaymentExtendedVerificationKeyShelley_ed25519_bip32",
+    "StakeExtendedVerificationKeyShelley_ed25519_bip32",
+];
+
+const NATIVE_SCRIPT_TYPE: &str = "SimpleScript";
+const PLUTUS_SCRIPT_V1_TYPE: &str = "PlutusScriptV1";
+const PLUTUS_SCRIPT_V2_TYPE: &str = "PlutusScriptV2";
+const PLUTUS_SCRIPT_V3_TYPE: &str = "PlutusScriptV3";
+
+// key witnesses are wrapped as [0, vkeywitness], bootstrap witnesses as [1, bootstrap_witness]
+const KEY_WITNESS_TAG: u64 = 0;
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, JsonSchema)]
+/// A cardano-cli TextEnvelope file.
+/// The new_* constructors use the type strings and descriptions written by cardano-cli,
+/// the as_* getters check that the type matches the requested value before decoding the payload.
+pub struct TextEnvelope {
+    #[serde(rename = "type")]
+    envelope_type: String,
+    description: String,
+    #[serde(rename = "cborHex")]
+    cbor_hex: String,
+}
+
+to_from_json!(TextEnvelope);
+
+#[wasm_bindgen]
+impl TextEnvelope {
+    pub fn new(envelope_type: &str, description: &str, cbor_bytes: Vec<u8>) -> TextEnvelope {
+        TextEnvelope {
+            envelope_type: envelope_type.to_string(),
+            description: description.to_string(),
+            cbor_hex: hex::encode(cbor_bytes),
+        }
+    }
+
+    pub fn envelope_type(&self) -> String {
+        self.envelope_type.clone()
+    }
+
+    pub fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    pub fn set_description(&mut self, description: &str) {
+        self.description = description.to_string()
+    }
+
+    pub fn cbor_hex(&self) -> String {
+        self.cbor_hex.clone()
+    }
+
+    pub fn cbor_bytes(&self) -> Result<Vec<u8>, JsError> {
+        hex::decode(&self.cbor_hex)
+            .map_err(|e| JsError::from_str(&format!("Invalid cborHex: {}", e)))
+    }
+
+    /// Type "Tx ConwayEra"
+    pub fn new_transaction(tx: &Transaction) -> TextEnvelope {
+        Self::new("Tx ConwayEra", "Ledger Cddl Format", tx.to_bytes())
+    }
+
+    /// Type "Tx ConwayEra", the transaction is written with its original bytes
+    pub fn new_fixed_transaction(tx: &FixedTransaction) -> TextEnvelope {
+        Self::new("Tx ConwayEra", "Ledger Cddl Format", tx.to_bytes())
+    }
+
+    /// Type "TxWitness ConwayEra", the format of `cardano-cli transaction witness`
+    pub fn new_vkeywitness(witness: &Vkeywitness) -> TextEnvelope {
+        let mut serializer = Serializer::new_vec();
+        serializer.write_array(Len::Len(2)).unwrap();
+        serializer.write_unsigned_integer(KEY_WITNESS_TAG).unwrap();
+        witness.serialize(&mut serializer).unwrap();
+        Self::new("TxWitness ConwayEra", "Key Witness ShelleyEra", serializer.finalize())
+    }
+
+    /// Type "PaymentSigningKeyShelley_ed25519".
+    /// Fails for extended keys, cardano-cli stores them with their chain code, see new_bip32_signing_key()
+    pub fn new_signing_key(key: &PrivateKey) -> Result<TextEnvelope, JsError> {
+        match &key.0 {
+            EitherEd25519SecretKey::Normal(_) => Ok(Self::new(
+                SIGNING_KEY_TYPES[0],
+                "Payment Signing Key",
+                cbor_byte_string(&key.as_bytes()),
+            )),
+            EitherEd25519SecretKey::Extended(_) => Err(JsError::from_str(
+                "Extended private keys are written without their chain code, use new_bip32_signing_key",
+            )),
+        }
+    }
+
+    /// Type "PaymentExtendedSigningKeyShelley_ed25519_bip32" with the 128 bytes xprv format, see Bip32PrivateKey::to_128_xprv()
+    pub fn new_bip32_signing_key(key: &Bip32PrivateKey) -> TextEnvelope {
+        Self::new(
+            EXTENDED_SIGNING_KEY_TYPES[0],
+            "Payment Signing Key",
+            cbor_byte_string(&key.to_128_xprv()),
+        )
+    }
+
+    /// Type "PaymentVerificationKeyShelley_ed25519"
+    pub fn new_verification_key(key: &PublicKey) -> TextEnvelope {
+        Self::new(
+            VERIFICATION_KEY_TYPES[0],
+            "Payment Verification Key",
+            cbor_byte_string(&key.as_bytes()),
+        )
+    }
+
+    /// Type "PaymentExtendedVerificationKeyShelley_ed25519_bip32"
+    pub fn new_bip32_verification_key(key: &Bip32PublicKey) -> TextEnvelope {
+        Self::new(
+            EXTENDED_VERIFICATION_KEY_TYPES[0],
+            "Payment Verification Key",
+            cbor_byte_string(&key.as_bytes()),
+        )
+    }
+
+    /// Type "SimpleScript"
+    pub fn new_native_script(script: &NativeScript) -> TextEnvelope {
+        Self::new(NATIVE_SCRIPT_TYPE, "", script.to_bytes())
+    }
+
+    /// Type "PlutusScriptV1", "PlutusScriptV2" or "PlutusScriptV3", the payload is the CBOR encoded script bytes
+    pub fn new_plutus_script(script: &PlutusScript) -> TextEnvelope {
+        let envelope_type = match script.language {
+            LanguageKind::PlutusV1 => PLUTUS_SCRIPT_V1_TYPE,
+            LanguageKind::PlutusV2 => PLUTUS_SCRIPT_V2_TYPE,
+            LanguageKind::PlutusV3 => PLUTUS_SCRIPT_V3_TYPE,
+        };
+        Self::new(envelope_type, "", script.to_bytes())
+    }
+
+    /// Accepts "Tx <era>", "Witnessed Tx <era>" and "Unwitnessed Tx <era>" of the Shelley based eras.
+    /// Fails if the transaction is not valid in the era of the type.
+    pub fn as_transaction(&self) -> Result<Transaction, JsError> {
+        let era = self.transaction_era()?;
+        Ok(MultiEraTransaction::from_bytes(self.cbor_bytes()?, Some(era))?.transaction)
+    }
+
+    /// Same as as_transaction() but keeps the original bytes of the body, witness set and auxiliary data
+    pub fn as_fixed_transaction(&self) -> Result<FixedTransaction, JsError> {
+        let era = self.transaction_era()?;
+        let bytes = self.cbor_bytes()?;
+        MultiEraTransaction::from_bytes(bytes.clone(), Some(era))?;
+        Ok(FixedTransaction::from_bytes(bytes)?)
+    }
+
+    /// Accepts "TxWitness <era>" and the legacy "TxWitnessShelley" holding a key witness,
+    /// bootstrap witnesses are rejected
+    pub fn as_vkeywitness(&self) -> Result<Vkeywitness, JsError> {
+        let is_tx_witness = self.envelope_type == LEGACY_TX_WITNESS_TYPE
+            || self
+                .envelope_type
+                .strip_prefix(TX_WITNESS_TYPE_PREFIX)
+                .is_some_and(|era| era_by_name(era).is_some());
+        if !is_tx_witness {
+            return Err(self.type_mismatch("a transaction witness"));
+        }
+        let mut raw = Deserializer::from(std::io::Cursor::new(self.cbor_bytes()?));
+        let witness = (|| -> Result<_, DeserializeError> {
+            raw.array()?;
+            let tag = raw.unsigned_integer()?;
+            if tag != KEY_WITNESS_TAG {
+                return Err(DeserializeFailure::FixedValueMismatch {
+                    found: Key::Uint(tag),
+                    expected: Key::Uint(KEY_WITNESS_TAG),
+                }
+                .into());
+            }
+            Vkeywitness::deserialize(&mut raw)
+        })()
+        .map_err(|e| e.annotate("TxWitness"))?;
+        Ok(witness)
+    }
+
+    /// Accepts the payment and stake signing keys, normal or extended.
+    /// The chain code of extended keys is dropped.
+    pub fn as_signing_key(&self) -> Result<PrivateKey, JsError> {
+        if SIGNING_KEY_TYPES.contains(&self.envelope_type.as_str()) {
+            PrivateKey::from_normal_bytes(&self.key_bytes(32)?)
+        } else if EXTENDED_SIGNING_KEY_TYPES.contains(&self.envelope_type.as_str()) {
+            Ok(self.as_bip32_signing_key()?.to_raw_key())
+        } else {
+            Err(self.type_mismatch("a signing key"))
+        }
+    }
+
+    /// Accepts the extended payment and stake signing keys
+    pub fn as_bip32_signing_key(&self) -> Result<Bip32PrivateKey, JsError> {
+        if !EXTENDED_SIGNING_KEY_TYPES.contains(&self.envelope_type.as_str()) {
+            return Err(self.type_mismatch("an extended signing key"));
+        }
+        Bip32PrivateKey::from_128_xprv(&self.key_bytes(128)?)
+    }
+
+    /// Accepts the payment and stake verification keys, normal or extended.
+    /// The chain code of extended keys is dropped.
+    pub fn as_verification_key(&self) -> Result<PublicKey, JsError> {
+        if VERIFICATION_KEY_TYPES.contains(&self.envelope_type.as_str()) {
+            PublicKey::from_bytes(&self.key_bytes(32)?)
+        } else if EXTENDED_VERIFICATION_KEY_TYPES.contains(&self.envelope_type.as_str()) {
+            Ok(self.as_bip32_verification_key()?.to_raw_key())
+        } else {
+            Err(self.type_mismatch("a verification key"))
+        }
+    }
+
+    /// Accepts the extended payment and stake verification keys
+    pub fn as_bip32_verification_key(&self) -> Result<Bip32PublicKey, JsError> {
+        if !EXTENDED_VERIFICATION_KEY_TYPES.contains(&self.envelope_type.as_str()) {
+            return Err(self.type_mismatch("an extended verification key"));
+        }
+        Bip32PublicKey::from_bytes(&self.key_bytes(64)?)
+    }
+
+    pub fn as_native_script(&self) -> Result<NativeScript, JsError> {
+        if self.envelope_type != NATIVE_SCRIPT_TYPE {
+            return Err(self.type_mismatch("a native script"));
+        }
+        Ok(NativeScript::from_bytes(self.cbor_bytes()?)?)
+    }
+
+    /// The language of the script is taken from the type
+    pub fn as_plutus_script(&self) -> Result<PlutusScript, JsError> {
+        let language = match self.envelope_type.as_str() {
+            PLUTUS_SCRIPT_V1_TYPE => Language::new_plutus_v1(),
+            PLUTUS_SCRIPT_V2_TYPE => Language::new_plutus_v2(),
+            PLUTUS_SCRIPT_V3_TYPE => Language::new_plutus_v3(),
+            _ => return Err(self.type_mismatch("a Plutus script")),
+        };
+        PlutusScript::from_bytes_with_version(self.cbor_bytes()?, &language)
+    }
+}
+
+impl TextEnvelope {
+    fn transaction_era(&self) -> Result<BlockEra, JsError> {
+        TX_TYPE_PREFIXES
+            .iter()
+            .filter_map(|prefix| self.envelope_type.strip_prefix(prefix))
+            .find_map(era_by_name)
+            .ok_or_else(|| self.type_mismatch("a transaction"))
+    }
+
+    // keys are written as a CBOR byte string
+    fn key_bytes(&self, expected_len: usize) -> Result<Vec<u8>, JsError> {
+        let mut raw = Deserializer::from(std::io::Cursor::new(self.cbor_bytes()?));
+        let bytes = raw
+            .bytes()
+            .map_err(|e| DeserializeError::from(e).annotate("cborHex"))?;
+        if bytes.len() != expected_len {
+            return Err(JsError::from_str(&format!(
+                "{} must hold {} bytes, found {}",
+                self.envelope_type,
+                expected_len,
+                bytes.len()
+            )));
+        }
+        Ok(bytes)
+    }
+
+    fn type_mismatch(&self, expected: &str) -> JsError {
+        JsError::from_str(&format!(
+            "TextEnvelope of type \"{}\" does not hold {}",
+            self.envelope_type, expected
+        ))
+    }
+}
+
+fn era_by_name(name: &str) -> Option<BlockEra> {
+    SHELLEY_BASED_ERAS
+        .iter()
+        .find(|(era_name, _)| *era_name == name)
+        .map(|(_, era)| *era)
+}
+
+fn cbor_byte_string(bytes: &[u8]) -> Vec<u8> {
+    let mut serializer = Serializer::new_vec();
+    serializer.write_bytes(bytes).unwrap();
+    serializer.finalize()
+}
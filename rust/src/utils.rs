@@ -156,9 +156,13 @@ impl<'a> IntoIterator for &'a TransactionUnspentOutputs {
 pub struct Value {
     pub(crate) coin: Coin,
     pub(crate) multiasset: Option<MultiAsset>,
+
+    #[serde(skip)]
+    pub(crate) original_encoding: OriginalEncoding,
 }
 
 impl_to_from!(Value);
+to_canonical_bytes!(Value);
 
 #[wasm_bindgen]
 impl Value {
@@ -166,6 +170,7 @@ impl Value {
         Self {
             coin: coin.clone(),
             multiasset: None,
+            original_encoding: OriginalEncoding::default(),
         }
     }
 
@@ -179,6 +184,7 @@ impl Value {
             false => Self {
                 coin: coin.clone(),
                 multiasset: Some(multiasset.clone()),
+                original_encoding: OriginalEncoding::default(),
             },
         }
     }
@@ -252,7 +258,11 @@ impl Value {
             (None, Some(ma)) => Some(ma.clone()),
         };
 
-        Ok(Value { coin, multiasset })
+        Ok(Value {
+            coin,
+            multiasset,
+            original_encoding: OriginalEncoding::default(),
+        })
     }
 
     pub fn checked_sub(&self, rhs_value: &Value) -> Result<Value, JsError> {
@@ -267,7 +277,11 @@ impl Value {
             (None, None) => None,
         };
 
-        Ok(Value { coin, multiasset })
+        Ok(Value {
+            coin,
+            multiasset,
+            original_encoding: OriginalEncoding::default(),
+        })
     }
 
     pub fn clamped_sub(&self, rhs_value: &Value) -> Value {
@@ -282,7 +296,11 @@ impl Value {
             (None, None) => None,
         };
 
-        Value { coin, multiasset }
+        Value {
+            coin,
+            multiasset,
+            original_encoding: OriginalEncoding::default(),
+        }
     }
 
     /// note: values are only partially comparable
@@ -341,6 +359,23 @@ impl cbor_event::se::Serialize for Value {
     fn serialize<'se, W: Write>(
         &self,
         serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serialize_preserving_encoding(self, serializer)
+    }
+}
+
+impl PreservesEncoding for Value {
+    fn original_encoding(&self) -> &OriginalEncoding {
+        &self.original_encoding
+    }
+
+    fn original_encoding_mut(&mut self) -> &mut OriginalEncoding {
+        &mut self.original_encoding
+    }
+
+    fn serialize_own_encoding<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
         let multiasset = self.multiasset
             .as_ref()
@@ -361,6 +396,14 @@ impl cbor_event::se::Serialize for Value {
 
 impl Deserialize for Value {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        deserialize_preserving_encoding(raw, Self::deserialize_own_encoding)
+    }
+}
+
+impl Value {
+    fn deserialize_own_encoding<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+    ) -> Result<Self, DeserializeError> {
         (|| -> Result<_, DeserializeError> {
             match raw.cbor_type()? {
                 cbor_event::Type::UnsignedInteger => Ok(Value::new(&Coin::deserialize(raw)?)),
@@ -375,6 +418,7 @@ impl Deserialize for Value {
                     let ret = Ok(Self {
                         coin,
                         multiasset: Some(multiasset),
+                        original_encoding: OriginalEncoding::default(),
                     });
                     match len {
                         cbor_event::Len::Len(n) => match n {
@@ -0,0 +1,312 @@
+use crate::*;
+
+/// Rules of CIP-21 that hardware wallets enforce before they sign a transaction
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Cip21ViolationKind {
+    /// The body is not in the canonical CBOR format: indefinite lengths, non-minimal integers or unsorted map keys
+    NonCanonicalBody,
+    /// The auxiliary data is not in the canonical CBOR format
+    NonCanonicalAuxiliaryData,
+    /// The body contains a protocol parameters update proposal
+    UpdateProposal,
+    /// A pool registration certificate is combined with other certificates or with fields
+    /// hardware wallets don't allow next to it
+    PoolRegistrationMixedWithOtherFields,
+    /// An output with a Byron address is written in the post-Alonzo format or carries a datum or a script reference
+    UnsupportedOutputFormat,
+    /// A multi-asset value contains an empty policy or an asset with a zero quantity
+    EmptyOrZeroAssets,
+    /// An address does not belong to the network set in the body
+    NetworkIdMismatch,
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Cip21Violation {
+    pub(crate) kind: Cip21ViolationKind,
+    pub(crate) message: String,
+}
+
+#[wasm_bindgen]
+impl Cip21Violation {
+    pub fn kind(&self) -> Cip21ViolationKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+}
+
+impl Display for Cip21Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.kind, self.message)
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Cip21Violations(pub(crate) Vec<Cip21Violation>);
+
+#[wasm_bindgen]
+impl Cip21Violations {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> Cip21Violation {
+        self.0[index].clone()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns true if there is at least one violation of the kind
+    pub fn contains_kind(&self, kind: Cip21ViolationKind) -> bool {
+        self.0.iter().any(|violation| violation.kind == kind)
+    }
+}
+
+/// Checks the transaction against the CIP-21 rules for transactions signed by hardware wallets
+/// and returns every violation found. An empty list means hardware wallets accept the transaction unchanged.
+/// The body and the auxiliary data are checked as they are encoded now, a deserialized transaction keeps
+/// its original encoding. Use to_canonical_bytes() to get the canonical encoding of a body.
+#[wasm_bindgen]
+pub fn check_cip21_compliance(tx: &Transaction) -> Cip21Violations {
+    Cip21Checker::new(tx).check()
+}
+
+struct Cip21Checker<'a> {
+    body: &'a TransactionBody,
+    auxiliary_data: Option<&'a AuxiliaryData>,
+    violations: Vec<Cip21Violation>,
+}
+
+impl<'a> Cip21Checker<'a> {
+    fn new(tx: &'a Transaction) -> Self {
+        Self {
+            body: &tx.body,
+            auxiliary_data: tx.auxiliary_data.as_ref(),
+            violations: Vec::new(),
+        }
+    }
+
+    fn check(mut self) -> Cip21Violations {
+        self.check_canonical_encoding();
+        self.check_update();
+        self.check_pool_registration();
+        self.check_outputs();
+        self.check_mint();
+        self.check_network_id();
+        Cip21Violations(self.violations)
+    }
+
+    fn report(&mut self, kind: Cip21ViolationKind, message: String) {
+        self.violations.push(Cip21Violation { kind, message });
+    }
+
+    fn check_canonical_encoding(&mut self) {
+        if !is_canonical_cbor(&self.body.to_bytes()) {
+            self.report(
+                Cip21ViolationKind::NonCanonicalBody,
+                "transaction body is not encoded as canonical CBOR".to_string(),
+            );
+        }
+        if let Some(auxiliary_data) = self.auxiliary_data {
+            if !is_canonical_cbor(&auxiliary_data.to_bytes()) {
+                self.report(
+                    Cip21ViolationKind::NonCanonicalAuxiliaryData,
+                    "auxiliary data is not encoded as canonical CBOR".to_string(),
+                );
+            }
+        }
+    }
+
+    fn check_update(&mut self) {
+        if self.body.update.is_some() {
+            self.report(
+                Cip21ViolationKind::UpdateProposal,
+                "update proposals are not supported".to_string(),
+            );
+        }
+    }
+
+    fn check_pool_registration(&mut self) {
+        let certs = match &self.body.certs {
+            Some(certs) => certs,
+            None => return,
+        };
+        let has_pool_registration = certs
+            .certs
+            .iter()
+            .any(|cert| cert.kind() == CertificateKind::PoolRegistration);
+        if !has_pool_registration {
+            return;
+        }
+        let mut mixed_fields = Vec::new();
+        if certs.len() > 1 {
+            mixed_fields.push("other certificates");
+        }
+        if self.body.withdrawals.is_some() {
+            mixed_fields.push("withdrawals");
+        }
+        if self.body.mint.is_some() {
+            mixed_fields.push("mint");
+        }
+        if self.body.script_data_hash.is_some() {
+            mixed_fields.push("script data hash");
+        }
+        if self.body.collateral.is_some()
+            || self.body.collateral_return.is_some()
+            || self.body.total_collateral.is_some()
+        {
+            mixed_fields.push("collateral");
+        }
+        if self.body.required_signers.is_some() {
+            mixed_fields.push("required signers");
+        }
+        if self.body.reference_inputs.is_some() {
+            mixed_fields.push("reference inputs");
+        }
+        if self.body.voting_procedures.is_some() {
+            mixed_fields.push("voting procedures");
+        }
+        if self.body.voting_proposals.is_some() {
+            mixed_fields.push("voting proposals");
+        }
+        if !mixed_fields.is_empty() {
+            self.report(
+                Cip21ViolationKind::PoolRegistrationMixedWithOtherFields,
+                format!(
+                    "pool registration certificate is combined with {}",
+                    mixed_fields.join(", ")
+                ),
+            );
+        }
+    }
+
+    fn check_outputs(&mut self) {
+        let body = self.body;
+        let outputs = body
+            .outputs
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, output)| (format!("output #{}", i), output))
+            .chain(
+                body.collateral_return
+                    .iter()
+                    .map(|output| ("collateral return".to_string(), output)),
+            );
+        for (name, output) in outputs {
+            if ByronAddress::from_address(&output.address).is_some()
+                && (output.plutus_data.is_some()
+                    || output.script_ref.is_some()
+                    || output.serialization_format == Some(CborContainerType::Map))
+            {
+                self.report(
+                    Cip21ViolationKind::UnsupportedOutputFormat,
+                    format!(
+                        "{} has a Byron address and must use the legacy format without datum and script reference",
+                        name
+                    ),
+                );
+            }
+            if let Some(multiasset) = &output.amount.multiasset {
+                for (policy_id, assets) in &multiasset.0 {
+                    if assets.0.is_empty() {
+                        self.report(
+                            Cip21ViolationKind::EmptyOrZeroAssets,
+                            format!("{} has no assets under policy {}", name, policy_id),
+                        );
+                    }
+                    for (asset_name, quantity) in &assets.0 {
+                        if quantity.is_zero() {
+                            self.report(
+                                Cip21ViolationKind::EmptyOrZeroAssets,
+                                format!(
+                                    "{} has a zero quantity of {}.{}",
+                                    name,
+                                    policy_id,
+                                    hex::encode(&asset_name.0)
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_mint(&mut self) {
+        let mint = match &self.body.mint {
+            Some(mint) => mint,
+            None => return,
+        };
+        for (policy_id, assets) in &mint.0 {
+            if assets.0.is_empty() {
+                self.report(
+                    Cip21ViolationKind::EmptyOrZeroAssets,
+                    format!("mint has no assets under policy {}", policy_id),
+                );
+            }
+            for (asset_name, quantity) in &assets.0 {
+                if quantity.0 == 0 {
+                    self.report(
+                        Cip21ViolationKind::EmptyOrZeroAssets,
+                        format!(
+                            "mint has a zero quantity of {}.{}",
+                            policy_id,
+                            hex::encode(&asset_name.0)
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_network_id(&mut self) {
+        let network_id = match &self.body.network_id {
+            Some(network_id) => match network_id.kind() {
+                NetworkIdKind::Testnet => NetworkInfo::testnet_preprod().network_id(),
+                NetworkIdKind::Mainnet => NetworkInfo::mainnet().network_id(),
+            },
+            None => return,
+        };
+        let body = self.body;
+        for (i, output) in body.outputs.0.iter().enumerate() {
+            // Byron addresses of unknown testnets and malformed addresses have no network to compare
+            if let Ok(address_network_id) = output.address.network_id() {
+                if address_network_id != network_id {
+                    self.report(
+                        Cip21ViolationKind::NetworkIdMismatch,
+                        format!(
+                            "output #{} has an address of network {} but the body is for network {}",
+                            i, address_network_id, network_id
+                        ),
+                    );
+                }
+            }
+        }
+        if let Some(withdrawals) = &body.withdrawals {
+            for reward_address in withdrawals.0.keys() {
+                if reward_address.network != network_id {
+                    self.report(
+                        Cip21ViolationKind::NetworkIdMismatch,
+                        format!(
+                            "withdrawal from {} is for network {} but the body is for network {}",
+                            reward_address
+                                .to_address()
+                                .to_bech32(None)
+                                .unwrap_or_default(),
+                            reward_address.network,
+                            network_id
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
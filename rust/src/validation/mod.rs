@@ -0,0 +1,13 @@
+mod validation_error;
+pub use validation_error::*;
+
+mod tx_context;
+
+mod tx_validator;
+pub use tx_validator::*;
+
+mod required_signers_report;
+pub use required_signers_report::*;
+
+mod cip21;
+pub use cip21::*;
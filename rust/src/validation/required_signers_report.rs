@@ -0,0 +1,90 @@
+use super::tx_context::*;
+use crate::*;
+use std::collections::BTreeSet;
+
+/// Key hashes a transaction has to be signed with compared to the signatures it already has.
+/// Byron inputs are listed by the root of their address, which is the key hash of a matching bootstrap witness.
+/// For every native script run by the transaction the required keys are the signer set
+/// that fits the validity interval of the transaction and needs the fewest new signatures.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequiredSignersReport {
+    pub(crate) required: Ed25519KeyHashes,
+    pub(crate) signed: Ed25519KeyHashes,
+    pub(crate) missing: Ed25519KeyHashes,
+    pub(crate) invalid: Ed25519KeyHashes,
+    pub(crate) superfluous: Ed25519KeyHashes,
+}
+
+#[wasm_bindgen]
+impl RequiredSignersReport {
+    /// Key hashes the transaction has to be signed with
+    pub fn required(&self) -> Ed25519KeyHashes {
+        self.required.clone()
+    }
+
+    /// Required key hashes that have a valid signature
+    pub fn signed(&self) -> Ed25519KeyHashes {
+        self.signed.clone()
+    }
+
+    /// Required key hashes without a valid signature
+    pub fn missing(&self) -> Ed25519KeyHashes {
+        self.missing.clone()
+    }
+
+    /// Key hashes of the witnesses whose signature doesn't match the transaction hash
+    pub fn invalid(&self) -> Ed25519KeyHashes {
+        self.invalid.clone()
+    }
+
+    /// Key hashes with a valid signature that the transaction doesn't require
+    pub fn superfluous(&self) -> Ed25519KeyHashes {
+        self.superfluous.clone()
+    }
+
+    /// True when every required key has a valid signature and no signature is invalid
+    pub fn is_complete(&self) -> bool {
+        self.missing.len() == 0 && self.invalid.len() == 0
+    }
+}
+
+impl RequiredSignersReport {
+    pub(crate) fn new(
+        tx_hash: &TransactionHash,
+        body: &TransactionBody,
+        witness_set: &TransactionWitnessSet,
+        resolved_utxos: &TransactionUnspentOutputs,
+    ) -> Result<RequiredSignersReport, JsError> {
+        let ctx = TxContext::new(body, witness_set, resolved_utxos);
+        let mut inputs = ctx.spent_inputs();
+        inputs.extend(ctx.collateral_inputs());
+        if let Some(input) = inputs.iter().find(|input| !ctx.utxos.contains_key(*input)) {
+            return Err(JsError::from_str(&format!(
+                "Input {} is not in the resolved UTxOs",
+                input
+            )));
+        }
+
+        let witness_keys = WitnessKeys::verify(tx_hash, witness_set);
+        let mut required = ctx.required_key_hashes();
+        required.extend(ctx.native_script_key_hashes(&witness_keys.valid));
+        let to_key_hashes = |keys: BTreeSet<&Ed25519KeyHash>| {
+            Ed25519KeyHashes::from_vec(keys.into_iter().cloned().collect())
+        };
+        Ok(RequiredSignersReport {
+            required: to_key_hashes(required.iter().collect()),
+            signed: to_key_hashes(required.intersection(&witness_keys.valid).collect()),
+            missing: to_key_hashes(required.difference(&witness_keys.valid).collect()),
+            invalid: to_key_hashes(witness_keys.invalid.iter().collect()),
+            superfluous: to_key_hashes(witness_keys.valid.difference(&required).collect()),
+        })
+    }
+}
+
+pub(crate) fn has_valid_witness_signatures(
+    tx_hash: &TransactionHash,
+    witness_set: &TransactionWitnessSet,
+) -> bool {
+    WitnessKeys::verify(tx_hash, witness_set).is_valid()
+}
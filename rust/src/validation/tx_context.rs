@@ -0,0 +1,297 @@
+use crate::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Clone, Copy)]
+pub(super) enum KnownScript<'a> {
+    Native(&'a NativeScript),
+    Plutus(Language),
+}
+
+pub(super) struct ScriptPurpose {
+    pub(super) tag: RedeemerTagKind,
+    pub(super) index: BigNum,
+    pub(super) script_hash: ScriptHash,
+}
+
+// key hashes of the vkey and bootstrap witnesses split by the validity of their signatures
+pub(super) struct WitnessKeys {
+    pub(super) valid: BTreeSet<Ed25519KeyHash>,
+    pub(super) invalid: BTreeSet<Ed25519KeyHash>,
+}
+
+impl WitnessKeys {
+    pub(super) fn verify(tx_hash: &TransactionHash, witness_set: &TransactionWitnessSet) -> Self {
+        let mut keys = WitnessKeys {
+            valid: BTreeSet::new(),
+            invalid: BTreeSet::new(),
+        };
+        if let Some(vkeys) = &witness_set.vkeys {
+            for i in 0..vkeys.len() {
+                let witness = vkeys.get(i);
+                let public_key = witness.vkey.public_key();
+                keys.add(public_key.hash(), public_key.verify(&tx_hash.0, &witness.signature));
+            }
+        }
+        if let Some(bootstraps) = &witness_set.bootstraps {
+            for i in 0..bootstraps.len() {
+                let witness = bootstraps.get(i);
+                let public_key = witness.vkey.public_key();
+                let signed = public_key.verify(&tx_hash.0, &witness.signature);
+                match witness.key_hash() {
+                    Some(key_hash) => keys.add(key_hash, signed),
+                    None => keys.add(public_key.hash(), false),
+                }
+            }
+        }
+        keys
+    }
+
+    fn add(&mut self, key_hash: Ed25519KeyHash, signed: bool) {
+        if signed {
+            self.valid.insert(key_hash);
+        } else {
+            self.invalid.insert(key_hash);
+        }
+    }
+
+    pub(super) fn is_valid(&self) -> bool {
+        self.invalid.is_empty()
+    }
+}
+
+// the parts of a transaction needed to find its required witnesses
+pub(super) struct TxContext<'a> {
+    pub(super) body: &'a TransactionBody,
+    pub(super) witness_set: &'a TransactionWitnessSet,
+    pub(super) utxos: BTreeMap<&'a TransactionInput, &'a TransactionOutput>,
+}
+
+impl<'a> TxContext<'a> {
+    pub(super) fn new(
+        body: &'a TransactionBody,
+        witness_set: &'a TransactionWitnessSet,
+        resolved_utxos: &'a TransactionUnspentOutputs,
+    ) -> Self {
+        let utxos = resolved_utxos
+            .0
+            .iter()
+            .map(|utxo| (&utxo.input, &utxo.output))
+            .collect();
+        Self {
+            body,
+            witness_set,
+            utxos,
+        }
+    }
+
+    pub(super) fn spent_inputs(&self) -> BTreeSet<&'a TransactionInput> {
+        self.body.inputs.into_iter().collect()
+    }
+
+    pub(super) fn collateral_inputs(&self) -> BTreeSet<&'a TransactionInput> {
+        self.body
+            .collateral
+            .iter()
+            .flat_map(|inputs| inputs.into_iter())
+            .collect()
+    }
+
+    pub(super) fn reference_inputs(&self) -> BTreeSet<&'a TransactionInput> {
+        self.body
+            .reference_inputs
+            .iter()
+            .flat_map(|inputs| inputs.into_iter())
+            .collect()
+    }
+
+    // outputs of the spent and the reference inputs, scripts of these outputs can be referenced
+    pub(super) fn referenced_outputs(&self) -> Vec<&'a TransactionOutput> {
+        let mut inputs = self.spent_inputs();
+        inputs.extend(self.reference_inputs());
+        inputs
+            .into_iter()
+            .filter_map(|input| self.utxos.get(input).copied())
+            .collect()
+    }
+
+    pub(super) fn all_resolved(&self, inputs: &BTreeSet<&'a TransactionInput>) -> bool {
+        inputs.iter().all(|input| self.utxos.contains_key(input))
+    }
+
+    // comes from witsVKeyNeeded in the Ledger spec, Byron inputs need the root of their address
+    pub(super) fn required_key_hashes(&self) -> BTreeSet<Ed25519KeyHash> {
+        let body = self.body;
+        let mut keys = BTreeSet::new();
+
+        let mut inputs = self.spent_inputs();
+        inputs.extend(self.collateral_inputs());
+        for input in inputs {
+            if let Some(output) = self.utxos.get(input) {
+                match &output.address.0 {
+                    AddrType::Byron(address) => {
+                        keys.insert(address.key_hash());
+                    }
+                    _ => {
+                        if let Some(key) = output.address.payment_cred().and_then(|c| c.to_keyhash())
+                        {
+                            keys.insert(key);
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(withdrawals) = &body.withdrawals {
+            for address in withdrawals.0.keys() {
+                if let Some(key) = address.payment_cred().to_keyhash() {
+                    keys.insert(key);
+                }
+            }
+        }
+        if let Some(certs) = &body.certs {
+            for cert in &certs.certs {
+                keys.extend(witness_keys_for_cert(cert).into_iter().cloned());
+            }
+        }
+        if let Some(required_signers) = &body.required_signers {
+            keys.extend(required_signers.into_iter().cloned());
+        }
+        if let Some(voting_procedures) = &body.voting_procedures {
+            keys.extend(voting_procedures.0.keys().filter_map(|voter| voter.to_key_hash()));
+        }
+        keys
+    }
+
+    // keys of the native scripts the transaction runs, for every script it's the signer set
+    // fitting the validity interval that needs the fewest keys besides {signed}
+    pub(super) fn native_script_key_hashes(
+        &self,
+        signed: &BTreeSet<Ed25519KeyHash>,
+    ) -> BTreeSet<Ed25519KeyHash> {
+        let (scripts, _) = self.known_scripts();
+        let needed_scripts: BTreeSet<ScriptHash> = self
+            .script_purposes()
+            .into_iter()
+            .map(|purpose| purpose.script_hash)
+            .collect();
+        let validity_interval = ValidityInterval::from_transaction_body(self.body);
+        let mut keys = BTreeSet::new();
+        for hash in &needed_scripts {
+            if let Some(KnownScript::Native(script)) = scripts.get(hash) {
+                // a script with too many signer sets to enumerate can need any of its keys as well
+                let signer_set = script.minimal_signer_sets().ok().and_then(|sets| {
+                    sets.0
+                        .into_iter()
+                        .filter(|set| validity_interval.is_within(&set.validity_interval))
+                        .min_by_key(|set| (set.signers.difference(signed).count(), set.signers.len()))
+                });
+                match signer_set {
+                    Some(signer_set) => keys.extend(signer_set.signers),
+                    // the script can't succeed in the validity interval, any of its keys can be needed
+                    None => keys.extend(script.get_required_signers().into_iter().cloned()),
+                }
+            }
+        }
+        keys
+    }
+
+    // script purposes in the order used by redeemer indexes
+    pub(super) fn script_purposes(&self) -> Vec<ScriptPurpose> {
+        let body = self.body;
+        let mut purposes = Vec::new();
+        let mut add = |tag: RedeemerTagKind, index: usize, script_hash: ScriptHash| {
+            purposes.push(ScriptPurpose {
+                tag,
+                index: BigNum::from(index),
+                script_hash,
+            })
+        };
+
+        for (i, input) in self.spent_inputs().into_iter().enumerate() {
+            if let Some(output) = self.utxos.get(input) {
+                if let Some(hash) = output.address.payment_cred().and_then(|c| c.to_scripthash()) {
+                    add(RedeemerTagKind::Spend, i, hash);
+                }
+            }
+        }
+        if let Some(mint) = &body.mint {
+            let policy_ids: BTreeSet<&PolicyID> = mint.0.iter().map(|(policy_id, _)| policy_id).collect();
+            for (i, policy_id) in policy_ids.into_iter().enumerate() {
+                add(RedeemerTagKind::Mint, i, policy_id.clone());
+            }
+        }
+        if let Some(certs) = &body.certs {
+            for (i, cert) in certs.certs.iter().enumerate() {
+                if let Some(hash) = cert.required_script_hash() {
+                    add(RedeemerTagKind::Cert, i, hash);
+                }
+            }
+        }
+        if let Some(withdrawals) = &body.withdrawals {
+            let addresses: BTreeSet<&RewardAddress> = withdrawals.0.keys().collect();
+            for (i, address) in addresses.into_iter().enumerate() {
+                if let Some(hash) = address.payment_cred().to_scripthash() {
+                    add(RedeemerTagKind::Reward, i, hash);
+                }
+            }
+        }
+        if let Some(voting_procedures) = &body.voting_procedures {
+            for (i, voter) in voting_procedures.0.keys().enumerate() {
+                let hash = match &voter.0 {
+                    VoterEnum::ConstitutionalCommitteeHotCred(cred) => cred.to_scripthash(),
+                    VoterEnum::DRep(cred) => cred.to_scripthash(),
+                    VoterEnum::StakingPool(_) => None,
+                };
+                if let Some(hash) = hash {
+                    add(RedeemerTagKind::Vote, i, hash);
+                }
+            }
+        }
+        if let Some(proposals) = &body.voting_proposals {
+            for i in 0..proposals.len() {
+                let hash = match proposals.get(i).governance_action.0 {
+                    GovernanceActionEnum::ParameterChangeAction(action) => action.policy_hash,
+                    GovernanceActionEnum::TreasuryWithdrawalsAction(action) => action.policy_hash,
+                    _ => None,
+                };
+                if let Some(hash) = hash {
+                    add(RedeemerTagKind::VotingProposal, i, hash);
+                }
+            }
+        }
+        purposes
+    }
+
+    // scripts available to the transaction and the hashes of the scripts from the witness set
+    pub(super) fn known_scripts(
+        &self,
+    ) -> (BTreeMap<ScriptHash, KnownScript<'a>>, BTreeSet<ScriptHash>) {
+        let witness_set = self.witness_set;
+        let mut scripts = BTreeMap::new();
+        let mut witness_scripts = BTreeSet::new();
+        if let Some(native_scripts) = &witness_set.native_scripts {
+            for script in native_scripts.iter() {
+                scripts.insert(script.hash(), KnownScript::Native(script));
+                witness_scripts.insert(script.hash());
+            }
+        }
+        if let Some(plutus_scripts) = &witness_set.plutus_scripts {
+            for i in 0..plutus_scripts.len() {
+                let script = plutus_scripts.get(i);
+                scripts.insert(script.hash(), KnownScript::Plutus(script.language_version()));
+                witness_scripts.insert(script.hash());
+            }
+        }
+        for output in self.referenced_outputs() {
+            match output.script_ref.as_ref().map(|script_ref| &script_ref.0) {
+                Some(ScriptRefEnum::NativeScript(script)) => {
+                    scripts.insert(script.hash(), KnownScript::Native(script));
+                }
+                Some(ScriptRefEnum::PlutusScript(script)) => {
+                    scripts.insert(script.hash(), KnownScript::Plutus(script.language_version()));
+                }
+                None => {}
+            }
+        }
+        (scripts, witness_scripts)
+    }
+}
@@ -0,0 +1,630 @@
+use super::tx_context::*;
+use crate::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Checks the transaction against the phase-1 predicates of the Conway UTXO and UTXOW rules
+/// and returns every violation found. An empty list means the transaction passes phase-1 validation.
+/// {resolved_utxos} must contain the outputs of all inputs, collateral inputs and reference inputs
+/// of the transaction, {slot} is the slot at which the transaction is going to be submitted.
+/// Native scripts are evaluated against the witness keys and the validity interval, Plutus scripts are not executed.
+/// Witness signatures are checked against the hash of the re-serialized body,
+/// use FixedTransaction to keep the original body bytes of a deserialized transaction.
+#[wasm_bindgen]
+pub fn validate_transaction(
+    tx: &Transaction,
+    resolved_utxos: &TransactionUnspentOutputs,
+    params: &ProtocolParameters,
+    slot: &SlotBigNum,
+) -> Result<ValidationErrors, JsError> {
+    TxValidator::new(tx, resolved_utxos, params, slot).validate()
+}
+
+fn native_assets(value: &Value) -> Value {
+    Value {
+        coin: Coin::zero(),
+        multiasset: value.multiasset.clone(),
+        original_encoding: OriginalEncoding::default(),
+    }
+}
+
+struct TxValidator<'a> {
+    tx: &'a Transaction,
+    ctx: TxContext<'a>,
+    params: &'a ProtocolParameters,
+    slot: SlotBigNum,
+    errors: Vec<ValidationError>,
+}
+
+impl<'a> TxValidator<'a> {
+    fn new(
+        tx: &'a Transaction,
+        resolved_utxos: &'a TransactionUnspentOutputs,
+        params: &'a ProtocolParameters,
+        slot: &SlotBigNum,
+    ) -> Self {
+        Self {
+            tx,
+            ctx: TxContext::new(&tx.body, &tx.witness_set, resolved_utxos),
+            params,
+            slot: *slot,
+            errors: Vec::new(),
+        }
+    }
+
+    fn validate(mut self) -> Result<ValidationErrors, JsError> {
+        self.check_inputs();
+        self.check_validity_interval();
+        self.check_tx_size();
+        self.check_fee()?;
+        self.check_value_conservation()?;
+        self.check_outputs()?;
+        self.check_ex_units()?;
+        self.check_collateral()?;
+        self.check_vkey_witnesses();
+        self.check_scripts();
+        self.check_auxiliary_data();
+        Ok(ValidationErrors(self.errors))
+    }
+
+    fn report(&mut self, kind: ValidationErrorKind, message: String) {
+        self.errors.push(ValidationError::new(kind, message));
+    }
+
+    fn tx_hash(&self) -> TransactionHash {
+        TransactionHash::from(blake2b256(&self.ctx.body.to_bytes()))
+    }
+
+    fn check_inputs(&mut self) {
+        if self.ctx.body.inputs.len() == 0 {
+            self.report(
+                ValidationErrorKind::InputSetEmpty,
+                "The transaction has no inputs".to_string(),
+            );
+        }
+        let mut inputs = self.ctx.spent_inputs();
+        inputs.extend(self.ctx.collateral_inputs());
+        inputs.extend(self.ctx.reference_inputs());
+        for input in inputs {
+            if !self.ctx.utxos.contains_key(input) {
+                self.report(
+                    ValidationErrorKind::BadInputs,
+                    format!("Input {} is not in the resolved UTxOs", input),
+                );
+            }
+        }
+    }
+
+    fn check_validity_interval(&mut self) {
+        let slot = self.slot;
+        if let Some(start) = self.ctx.body.validity_start_interval {
+            if slot < start {
+                self.report(
+                    ValidationErrorKind::OutsideValidityInterval,
+                    format!("The transaction is valid from slot {}, the slot is {}", start, slot),
+                );
+            }
+        }
+        if let Some(ttl) = self.ctx.body.ttl {
+            if slot >= ttl {
+                self.report(
+                    ValidationErrorKind::OutsideValidityInterval,
+                    format!("The transaction is valid before slot {}, the slot is {}", ttl, slot),
+                );
+            }
+        }
+    }
+
+    fn check_tx_size(&mut self) {
+        let size = self.tx.to_bytes().len();
+        let max_size = self.params.max_tx_size as usize;
+        if size > max_size {
+            self.report(
+                ValidationErrorKind::MaxTxSizeExceeded,
+                format!("The transaction size {} exceeds the maximum {}", size, max_size),
+            );
+        }
+    }
+
+    fn ref_scripts_size(&self) -> usize {
+        self.ctx.referenced_outputs()
+            .iter()
+            .filter_map(|output| output.script_ref.as_ref())
+            .map(|script_ref| match &script_ref.0 {
+                ScriptRefEnum::NativeScript(script) => script.to_bytes().len(),
+                ScriptRefEnum::PlutusScript(script) => script.bytes.len(),
+            })
+            .sum()
+    }
+
+    fn check_fee(&mut self) -> Result<(), JsError> {
+        let linear_fee = LinearFee::new(&self.params.minfee_a, &self.params.minfee_b);
+        let mut min_tx_fee = min_fee(self.tx, &linear_fee)?
+            .checked_add(&min_script_fee(self.tx, &self.params.execution_costs)?)?;
+        if let Some(coins_per_byte) = &self.params.ref_script_coins_per_byte {
+            let ref_scripts_size = self.ref_scripts_size();
+            if ref_scripts_size > 0 {
+                min_tx_fee =
+                    min_tx_fee.checked_add(&min_ref_script_fee(ref_scripts_size, coins_per_byte)?)?;
+            }
+        }
+        let fee = self.ctx.body.fee;
+        if fee < min_tx_fee {
+            self.report(
+                ValidationErrorKind::FeeTooSmall,
+                format!("The fee {} is less than the minimum fee {}", fee, min_tx_fee),
+            );
+        }
+        Ok(())
+    }
+
+    fn check_value_conservation(&mut self) -> Result<(), JsError> {
+        let inputs = self.ctx.spent_inputs();
+        // the balance is meaningless with unknown inputs, they are reported as bad inputs
+        if !self.ctx.all_resolved(&inputs) {
+            return Ok(());
+        }
+        let body = self.ctx.body;
+        let params = self.params;
+
+        let mut consumed = get_implicit_input(body, &params.pool_deposit, &params.key_deposit)?;
+        for input in inputs {
+            consumed = consumed.checked_add(&self.ctx.utxos[input].amount)?;
+        }
+
+        let mut produced = Value::new(&body.fee);
+        for output in &body.outputs {
+            produced = produced.checked_add(&output.amount)?;
+        }
+        let mut deposit = get_deposit(body, &params.pool_deposit, &params.key_deposit)?;
+        if let Some(proposals) = &body.voting_proposals {
+            for i in 0..proposals.len() {
+                deposit = deposit.checked_add(&proposals.get(i).deposit)?;
+            }
+        }
+        if let Some(donation) = &body.donation {
+            deposit = deposit.checked_add(donation)?;
+        }
+        produced = produced.checked_add(&Value::new(&deposit))?;
+
+        if let Some(mint) = &body.mint {
+            consumed = consumed.checked_add(&Value::new_from_assets(&mint.as_positive_multiasset()))?;
+            produced = produced.checked_add(&Value::new_from_assets(&mint.as_negative_multiasset()))?;
+        }
+
+        if consumed != produced {
+            let assets_message = if native_assets(&consumed) == native_assets(&produced) {
+                ""
+            } else {
+                ", native assets are not balanced"
+            };
+            self.report(
+                ValidationErrorKind::ValueNotConserved,
+                format!(
+                    "Consumed {} lovelace, produced {} lovelace{}",
+                    consumed.coin, produced.coin, assets_message
+                ),
+            );
+        }
+        Ok(())
+    }
+
+    fn check_outputs(&mut self) -> Result<(), JsError> {
+        let body = self.ctx.body;
+        for (i, output) in body.outputs.into_iter().enumerate() {
+            self.check_output(&format!("Output {}", i), output)?;
+        }
+        if let Some(collateral_return) = &body.collateral_return {
+            self.check_output("Collateral return", collateral_return)?;
+        }
+        Ok(())
+    }
+
+    fn check_output(&mut self, name: &str, output: &TransactionOutput) -> Result<(), JsError> {
+        let data_cost = DataCost::new_coins_per_byte(&self.params.ada_per_utxo_byte);
+        let min_ada = MinOutputAdaCalculator::calc_required_coin(output, &data_cost)?;
+        if output.amount.coin < min_ada {
+            self.report(
+                ValidationErrorKind::OutputTooSmall,
+                format!(
+                    "{} has {} lovelace, the minimum is {}",
+                    name, output.amount.coin, min_ada
+                ),
+            );
+        }
+        let value_size = output.amount.to_bytes().len();
+        let max_value_size = self.params.max_value_size as usize;
+        if value_size > max_value_size {
+            self.report(
+                ValidationErrorKind::OutputTooBig,
+                format!(
+                    "{} value size {} exceeds the maximum {}",
+                    name, value_size, max_value_size
+                ),
+            );
+        }
+        Ok(())
+    }
+
+    fn check_ex_units(&mut self) -> Result<(), JsError> {
+        if let Some(redeemers) = &self.ctx.witness_set.redeemers {
+            let total = redeemers.total_ex_units()?;
+            let max = &self.params.max_tx_ex_units;
+            if total.mem > max.mem || total.steps > max.steps {
+                self.report(
+                    ValidationErrorKind::ExUnitsTooBig,
+                    format!(
+                        "Execution units (mem {}, steps {}) exceed the maximum (mem {}, steps {})",
+                        total.mem, total.steps, max.mem, max.steps
+                    ),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn check_collateral(&mut self) -> Result<(), JsError> {
+        let has_redeemers = self
+            .ctx
+            .witness_set
+            .redeemers
+            .as_ref()
+            .is_some_and(|redeemers| redeemers.len() > 0);
+        if !has_redeemers {
+            return Ok(());
+        }
+        let inputs = self.ctx.collateral_inputs();
+        if inputs.is_empty() {
+            self.report(
+                ValidationErrorKind::NoCollateralInputs,
+                "The transaction has redeemers but no collateral inputs".to_string(),
+            );
+            return Ok(());
+        }
+        let max_inputs = self.params.max_collateral_inputs as usize;
+        if inputs.len() > max_inputs {
+            self.report(
+                ValidationErrorKind::TooManyCollateralInputs,
+                format!(
+                    "The transaction has {} collateral inputs, the maximum is {}",
+                    inputs.len(),
+                    max_inputs
+                ),
+            );
+        }
+
+        let mut balance = Value::zero();
+        for input in &inputs {
+            if let Some(output) = self.ctx.utxos.get(input).copied() {
+                if output.address.payment_cred().is_some_and(|cred| cred.has_script_hash()) {
+                    self.report(
+                        ValidationErrorKind::CollateralLockedByScript,
+                        format!("Collateral input {} is locked by a script", input),
+                    );
+                }
+                balance = balance.checked_add(&output.amount)?;
+            }
+        }
+        if !self.ctx.all_resolved(&inputs) {
+            return Ok(());
+        }
+
+        let body = self.ctx.body;
+        let returned = body
+            .collateral_return
+            .as_ref()
+            .map(|output| output.amount.clone())
+            .unwrap_or_else(Value::zero);
+        if native_assets(&balance) != native_assets(&returned) {
+            self.report(
+                ValidationErrorKind::CollateralContainsNonAda,
+                "Native assets of the collateral inputs are not returned by the collateral return"
+                    .to_string(),
+            );
+        }
+
+        let collateral = balance.coin.clamped_sub(&returned.coin);
+        let percentage = self.params.collateral_percentage;
+        if (u64::from(collateral) as u128) * 100
+            < (u64::from(body.fee) as u128) * (percentage as u128)
+        {
+            self.report(
+                ValidationErrorKind::InsufficientCollateral,
+                format!(
+                    "The collateral {} is less than {}% of the fee {}",
+                    collateral, percentage, body.fee
+                ),
+            );
+        }
+        if let Some(total_collateral) = body.total_collateral {
+            if total_collateral != collateral {
+                self.report(
+                    ValidationErrorKind::IncorrectTotalCollateral,
+                    format!(
+                        "The total collateral field is {}, the collateral balance is {}",
+                        total_collateral, collateral
+                    ),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn check_vkey_witnesses(&mut self) {
+        let witness_keys = WitnessKeys::verify(&self.tx_hash(), self.ctx.witness_set);
+        for key in &witness_keys.invalid {
+            self.report(
+                ValidationErrorKind::InvalidWitnesses,
+                format!("Witness of key {} has an invalid signature", key),
+            );
+        }
+        let provided_keys: BTreeSet<&Ed25519KeyHash> =
+            witness_keys.valid.iter().chain(&witness_keys.invalid).collect();
+        let mut inputs = self.ctx.spent_inputs();
+        inputs.extend(self.ctx.collateral_inputs());
+        let byron_addresses: BTreeMap<Ed25519KeyHash, &ByronAddress> = inputs
+            .into_iter()
+            .filter_map(|input| match &self.ctx.utxos.get(input)?.address.0 {
+                AddrType::Byron(address) => Some((address.key_hash(), address)),
+                _ => None,
+            })
+            .collect();
+        for key in self.ctx.required_key_hashes() {
+            if provided_keys.contains(&key) {
+                continue;
+            }
+            let message = match byron_addresses.get(&key) {
+                Some(address) => {
+                    format!("Missing bootstrap witness for address {}", address.to_base58())
+                }
+                None => format!("Missing vkey witness for key {}", key),
+            };
+            self.report(ValidationErrorKind::MissingVkeyWitnesses, message);
+        }
+    }
+
+    fn check_scripts(&mut self) {
+        let purposes = self.ctx.script_purposes();
+        let (scripts, witness_scripts) = self.ctx.known_scripts();
+
+        let mut reported = BTreeSet::new();
+        for purpose in &purposes {
+            if !scripts.contains_key(&purpose.script_hash) && reported.insert(&purpose.script_hash) {
+                self.report(
+                    ValidationErrorKind::MissingScriptWitnesses,
+                    format!(
+                        "Script {} required by {:?} #{} is not provided",
+                        purpose.script_hash, purpose.tag, purpose.index
+                    ),
+                );
+            }
+        }
+        let needed_scripts: BTreeSet<&ScriptHash> =
+            purposes.iter().map(|purpose| &purpose.script_hash).collect();
+        for hash in &witness_scripts {
+            if !needed_scripts.contains(hash) {
+                self.report(
+                    ValidationErrorKind::ExtraneousScriptWitnesses,
+                    format!("Script {} is not required by the transaction", hash),
+                );
+            }
+        }
+
+        // the ledger evaluates native scripts with the key hashes of all witnesses
+        let witness_keys = WitnessKeys::verify(&self.tx_hash(), self.ctx.witness_set);
+        let signers = Ed25519KeyHashes::from_vec(
+            witness_keys.valid.into_iter().chain(witness_keys.invalid).collect(),
+        );
+        let validity_interval = ValidityInterval::from_transaction_body(self.ctx.body);
+        for hash in &needed_scripts {
+            if let Some(KnownScript::Native(script)) = scripts.get(*hash) {
+                if !script.evaluate(&signers, &validity_interval) {
+                    self.report(
+                        ValidationErrorKind::ScriptWitnessNotValidating,
+                        format!("Native script {} fails with the given witnesses and validity interval", hash),
+                    );
+                }
+            }
+        }
+
+        let mut languages = BTreeSet::new();
+        let mut plutus_purposes = BTreeMap::new();
+        let mut unknown_purposes = BTreeSet::new();
+        for purpose in &purposes {
+            match scripts.get(&purpose.script_hash) {
+                Some(KnownScript::Plutus(language)) => {
+                    languages.insert(*language);
+                    plutus_purposes.insert((purpose.tag, purpose.index), (purpose, *language));
+                }
+                Some(KnownScript::Native(_)) => {}
+                None => {
+                    unknown_purposes.insert((purpose.tag, purpose.index));
+                }
+            }
+        }
+        self.check_redeemers(&plutus_purposes, &unknown_purposes);
+        self.check_datums(&plutus_purposes, &unknown_purposes);
+        // the languages are unknown with a missing script, which is already reported
+        if unknown_purposes.is_empty() {
+            self.check_script_data_hash(languages);
+        }
+    }
+
+    fn check_redeemers(
+        &mut self,
+        plutus_purposes: &BTreeMap<(RedeemerTagKind, BigNum), (&ScriptPurpose, Language)>,
+        unknown_purposes: &BTreeSet<(RedeemerTagKind, BigNum)>,
+    ) {
+        let pointers: BTreeSet<(RedeemerTagKind, BigNum)> = self
+            .ctx
+            .witness_set
+            .redeemers
+            .iter()
+            .flat_map(|redeemers| redeemers.redeemers.iter())
+            .map(|redeemer| (redeemer.tag.0, redeemer.index))
+            .collect();
+        for (pointer, (purpose, _)) in plutus_purposes {
+            if !pointers.contains(pointer) {
+                self.report(
+                    ValidationErrorKind::MissingRedeemers,
+                    format!(
+                        "Missing redeemer {:?} #{} for script {}",
+                        purpose.tag, purpose.index, purpose.script_hash
+                    ),
+                );
+            }
+        }
+        // redeemers of purposes with a missing script are covered by the missing script error
+        for (tag, index) in pointers {
+            if !plutus_purposes.contains_key(&(tag, index))
+                && !unknown_purposes.contains(&(tag, index))
+            {
+                self.report(
+                    ValidationErrorKind::ExtraRedeemers,
+                    format!("Redeemer {:?} #{} doesn't point to a Plutus script", tag, index),
+                );
+            }
+        }
+    }
+
+    fn check_datums(
+        &mut self,
+        plutus_purposes: &BTreeMap<(RedeemerTagKind, BigNum), (&ScriptPurpose, Language)>,
+        unknown_purposes: &BTreeSet<(RedeemerTagKind, BigNum)>,
+    ) {
+        let spent_inputs: Vec<&TransactionInput> = self.ctx.spent_inputs().into_iter().collect();
+        let mut required = BTreeSet::new();
+        // a datum of an input locked by a missing script is allowed, the script can be a Plutus one
+        let mut allowed = BTreeSet::new();
+        for (tag, index) in unknown_purposes {
+            if *tag == RedeemerTagKind::Spend {
+                let input = spent_inputs[u64::from(*index) as usize];
+                if let Some(DataOption::DataHash(hash)) = &self.ctx.utxos[input].plutus_data {
+                    allowed.insert(hash.clone());
+                }
+            }
+        }
+        for ((tag, index), (_, language)) in plutus_purposes {
+            if *tag != RedeemerTagKind::Spend {
+                continue;
+            }
+            let input = spent_inputs[u64::from(*index) as usize];
+            match &self.ctx.utxos[input].plutus_data {
+                Some(DataOption::DataHash(hash)) => {
+                    required.insert(hash.clone());
+                }
+                Some(DataOption::Data(_)) => {}
+                None => {
+                    // datums are optional since Plutus V3
+                    if language.kind() != LanguageKind::PlutusV3 {
+                        self.report(
+                            ValidationErrorKind::UnspendableUtxoNoDatumHash,
+                            format!(
+                                "Input {} is locked by a Plutus {:?} script but has no datum",
+                                input,
+                                language.kind()
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        let witness_datums: BTreeSet<DataHash> = self
+            .ctx
+            .witness_set
+            .plutus_data
+            .iter()
+            .flat_map(|datums| datums.elems.iter())
+            .map(hash_plutus_data)
+            .collect();
+        for hash in required.difference(&witness_datums) {
+            self.report(
+                ValidationErrorKind::MissingRequiredDatums,
+                format!("Datum {} of a spent input is not in the witness set", hash),
+            );
+        }
+
+        allowed.extend(required);
+        let reference_outputs = self
+            .ctx
+            .reference_inputs()
+            .into_iter()
+            .filter_map(|input| self.ctx.utxos.get(input).copied());
+        for output in self.ctx.body.outputs.into_iter().chain(reference_outputs) {
+            if let Some(DataOption::DataHash(hash)) = &output.plutus_data {
+                allowed.insert(hash.clone());
+            }
+        }
+        for hash in witness_datums.difference(&allowed) {
+            self.report(
+                ValidationErrorKind::NotAllowedSupplementalDatums,
+                format!("Datum {} is not required by the transaction", hash),
+            );
+        }
+    }
+
+    fn check_script_data_hash(&mut self, languages: BTreeSet<Language>) {
+        let witness_set = self.ctx.witness_set;
+        let redeemers = witness_set
+            .redeemers
+            .as_ref()
+            .filter(|redeemers| redeemers.len() > 0);
+        let datums = witness_set.plutus_data.as_ref().filter(|datums| datums.len() > 0);
+        let expected = if redeemers.is_none() && datums.is_none() {
+            None
+        } else {
+            let cost_models = self
+                .params
+                .cost_models
+                .retain_language_versions(&Languages(languages.into_iter().collect()));
+            Some(hash_script_data(
+                &redeemers.cloned().unwrap_or_else(Redeemers::new),
+                &cost_models,
+                datums.cloned(),
+            ))
+        };
+        let message = match (&expected, &self.ctx.body.script_data_hash) {
+            (Some(expected), Some(actual)) if expected != actual => format!(
+                "The script data hash is {}, the expected hash is {}",
+                actual, expected
+            ),
+            (Some(expected), None) => format!(
+                "The script data hash is missing, the expected hash is {}",
+                expected
+            ),
+            (None, Some(actual)) => format!(
+                "The script data hash is {}, but the transaction has no redeemers and datums",
+                actual
+            ),
+            _ => return,
+        };
+        self.report(ValidationErrorKind::ScriptDataHashMismatch, message);
+    }
+
+    fn check_auxiliary_data(&mut self) {
+        match (&self.ctx.body.auxiliary_data_hash, &self.tx.auxiliary_data) {
+            (Some(hash), None) => self.report(
+                ValidationErrorKind::MissingAuxiliaryData,
+                format!("The body has auxiliary data hash {}, but no auxiliary data", hash),
+            ),
+            (None, Some(_)) => self.report(
+                ValidationErrorKind::MissingAuxiliaryDataHash,
+                "The transaction has auxiliary data, but no auxiliary data hash in the body"
+                    .to_string(),
+            ),
+            (Some(hash), Some(auxiliary_data)) => {
+                let expected = hash_auxiliary_data(auxiliary_data);
+                if &expected != hash {
+                    self.report(
+                        ValidationErrorKind::ConflictingAuxiliaryDataHash,
+                        format!(
+                            "The auxiliary data hash is {}, the expected hash is {}",
+                            hash, expected
+                        ),
+                    );
+                }
+            }
+            (None, None) => {}
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::*;
+
+/// Phase-1 ledger rule violations, the names follow the predicate failures of the Conway
+/// UTXO and UTXOW rules
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ValidationErrorKind {
+    InputSetEmpty,
+    BadInputs,
+    OutsideValidityInterval,
+    MaxTxSizeExceeded,
+    FeeTooSmall,
+    ValueNotConserved,
+    OutputTooSmall,
+    OutputTooBig,
+    ExUnitsTooBig,
+    NoCollateralInputs,
+    TooManyCollateralInputs,
+    CollateralLockedByScript,
+    CollateralContainsNonAda,
+    InsufficientCollateral,
+    IncorrectTotalCollateral,
+    MissingVkeyWitnesses,
+    InvalidWitnesses,
+    MissingScriptWitnesses,
+    ExtraneousScriptWitnesses,
+    ScriptWitnessNotValidating,
+    MissingRedeemers,
+    ExtraRedeemers,
+    MissingRequiredDatums,
+    NotAllowedSupplementalDatums,
+    UnspendableUtxoNoDatumHash,
+    ScriptDataHashMismatch,
+    MissingAuxiliaryDataHash,
+    MissingAuxiliaryData,
+    ConflictingAuxiliaryDataHash,
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ValidationError {
+    pub(crate) kind: ValidationErrorKind,
+    pub(crate) message: String,
+}
+
+#[wasm_bindgen]
+impl ValidationError {
+    pub fn kind(&self) -> ValidationErrorKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+}
+
+impl ValidationError {
+    pub(crate) fn new(kind: ValidationErrorKind, message: String) -> Self {
+        Self { kind, message }
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.kind, self.message)
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ValidationErrors(pub(crate) Vec<ValidationError>);
+
+#[wasm_bindgen]
+impl ValidationErrors {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> ValidationError {
+        self.0[index].clone()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns true if there is at least one violation of the kind
+    pub fn contains_kind(&self, kind: ValidationErrorKind) -> bool {
+        self.0.iter().any(|error| error.kind == kind)
+    }
+}
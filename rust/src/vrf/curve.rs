@@ -0,0 +1,366 @@
+// Arithmetic on the edwards25519 curve needed to verify VRF proofs.
+// Only public values are handled so nothing here is constant time.
+
+const MASK: u64 = (1 << 51) - 1;
+
+// little-endian exponents
+const P_MINUS_2: [u8; 32] = exponent(0xeb, 0x7f);
+const P_MINUS_5_DIV_8: [u8; 32] = exponent(0xfd, 0x0f);
+const P_MINUS_1_DIV_2: [u8; 32] = exponent(0xf6, 0x3f);
+
+const D: [u8; 32] = [
+    163, 120, 89, 19, 202, 77, 235, 117, 171, 216, 65, 65, 77, 10, 112, 0, 152, 232, 121, 119, 121,
+    64, 199, 140, 115, 254, 111, 43, 238, 108, 3, 82,
+];
+const SQRT_MINUS_1: [u8; 32] = [
+    176, 160, 14, 74, 39, 27, 238, 196, 120, 228, 47, 173, 6, 24, 67, 47, 167, 215, 251, 61, 153,
+    0, 77, 43, 11, 223, 193, 79, 128, 36, 131, 43,
+];
+// y = 4/5
+const BASE_POINT: [u8; 32] = [
+    0x58, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+    0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+];
+// order of the base point, 2^252 + 27742317777372353535851937790883648493
+const ORDER: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+// coefficient A of the Montgomery form curve25519
+const MONTGOMERY_A: u64 = 486_662;
+
+// 2^255 - 19 - k for a small k: {low} byte, 30 bytes 0xff and the {high} byte
+const fn exponent(low: u8, high: u8) -> [u8; 32] {
+    let mut bytes = [0xff; 32];
+    bytes[0] = low;
+    bytes[31] = high;
+    bytes
+}
+
+/// Element of the field of integers modulo 2^255 - 19, 5 limbs of 51 bits
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FieldElement([u64; 5]);
+
+impl FieldElement {
+    pub(crate) const ZERO: FieldElement = FieldElement([0; 5]);
+    pub(crate) const ONE: FieldElement = FieldElement([1, 0, 0, 0, 0]);
+
+    pub(crate) fn from_u64(value: u64) -> FieldElement {
+        FieldElement([value & MASK, value >> 51, 0, 0, 0])
+    }
+
+    /// The highest bit is ignored, values from 2^255 - 19 to 2^255 - 1 are reduced
+    pub(crate) fn from_bytes(bytes: &[u8; 32]) -> FieldElement {
+        let load = |i: usize| {
+            let mut word = [0; 8];
+            word.copy_from_slice(&bytes[i..i + 8]);
+            u64::from_le_bytes(word)
+        };
+        FieldElement([
+            load(0) & MASK,
+            (load(6) >> 3) & MASK,
+            (load(12) >> 6) & MASK,
+            (load(19) >> 1) & MASK,
+            (load(24) >> 12) & MASK,
+        ])
+    }
+
+    /// Canonical encoding, the highest bit is 0
+    pub(crate) fn to_bytes(self) -> [u8; 32] {
+        let mut limbs = reduce(self.0);
+        // limbs + 19 overflows 2^255 exactly when the value is at least 2^255 - 19
+        let mut q = (limbs[0] + 19) >> 51;
+        for limb in limbs.iter().skip(1) {
+            q = (limb + q) >> 51;
+        }
+        limbs[0] += 19 * q;
+        for i in 0..4 {
+            limbs[i + 1] += limbs[i] >> 51;
+            limbs[i] &= MASK;
+        }
+        limbs[4] &= MASK;
+
+        let mut bytes = [0; 32];
+        let mut buffer = 0u128;
+        let mut buffered_bits = 0;
+        let mut position = 0;
+        for limb in limbs.iter() {
+            buffer |= (*limb as u128) << buffered_bits;
+            buffered_bits += 51;
+            while buffered_bits >= 8 {
+                bytes[position] = buffer as u8;
+                buffer >>= 8;
+                buffered_bits -= 8;
+                position += 1;
+            }
+        }
+        bytes[position] = buffer as u8;
+        bytes
+    }
+
+    pub(crate) fn add(&self, other: &FieldElement) -> FieldElement {
+        let mut limbs = self.0;
+        for (limb, other) in limbs.iter_mut().zip(other.0.iter()) {
+            *limb += other;
+        }
+        FieldElement(reduce(limbs))
+    }
+
+    pub(crate) fn sub(&self, other: &FieldElement) -> FieldElement {
+        // adds 16p so that every limb stays positive
+        let mut limbs = [
+            self.0[0] + 36_028_797_018_963_664,
+            self.0[1] + 36_028_797_018_963_952,
+            self.0[2] + 36_028_797_018_963_952,
+            self.0[3] + 36_028_797_018_963_952,
+            self.0[4] + 36_028_797_018_963_952,
+        ];
+        for (limb, other) in limbs.iter_mut().zip(other.0.iter()) {
+            *limb -= other;
+        }
+        FieldElement(reduce(limbs))
+    }
+
+    pub(crate) fn neg(&self) -> FieldElement {
+        FieldElement::ZERO.sub(self)
+    }
+
+    pub(crate) fn mul(&self, other: &FieldElement) -> FieldElement {
+        let a = &self.0;
+        let b = &other.0;
+        let m = |x: u64, y: u64| (x as u128) * (y as u128);
+        let b1 = b[1] * 19;
+        let b2 = b[2] * 19;
+        let b3 = b[3] * 19;
+        let b4 = b[4] * 19;
+        let c0 = m(a[0], b[0]) + m(a[4], b1) + m(a[3], b2) + m(a[2], b3) + m(a[1], b4);
+        let mut c1 = m(a[1], b[0]) + m(a[0], b[1]) + m(a[4], b2) + m(a[3], b3) + m(a[2], b4);
+        let mut c2 = m(a[2], b[0]) + m(a[1], b[1]) + m(a[0], b[2]) + m(a[4], b3) + m(a[3], b4);
+        let mut c3 = m(a[3], b[0]) + m(a[2], b[1]) + m(a[1], b[2]) + m(a[0], b[3]) + m(a[4], b4);
+        let mut c4 = m(a[4], b[0]) + m(a[3], b[1]) + m(a[2], b[2]) + m(a[1], b[3]) + m(a[0], b[4]);
+
+        c1 += c0 >> 51;
+        c2 += c1 >> 51;
+        c3 += c2 >> 51;
+        c4 += c3 >> 51;
+        let carry = (c4 >> 51) as u64;
+        let mut limbs = [
+            c0 as u64 & MASK,
+            c1 as u64 & MASK,
+            c2 as u64 & MASK,
+            c3 as u64 & MASK,
+            c4 as u64 & MASK,
+        ];
+        limbs[0] += carry * 19;
+        limbs[1] += limbs[0] >> 51;
+        limbs[0] &= MASK;
+        FieldElement(limbs)
+    }
+
+    pub(crate) fn square(&self) -> FieldElement {
+        self.mul(self)
+    }
+
+    fn pow(&self, exponent: &[u8; 32]) -> FieldElement {
+        let mut result = FieldElement::ONE;
+        for i in (0..256).rev() {
+            result = result.square();
+            if (exponent[i / 8] >> (i % 8)) & 1 == 1 {
+                result = result.mul(self);
+            }
+        }
+        result
+    }
+
+    /// 0 has no inverse, 0 is returned for it
+    pub(crate) fn invert(&self) -> FieldElement {
+        self.pow(&P_MINUS_2)
+    }
+
+    /// Legendre symbol: 1 for squares, -1 for non-squares and 0 for 0
+    pub(crate) fn legendre(&self) -> FieldElement {
+        self.pow(&P_MINUS_1_DIV_2)
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.to_bytes() == [0; 32]
+    }
+
+    /// The sign of x in the encoding of points
+    pub(crate) fn is_negative(&self) -> bool {
+        self.to_bytes()[0] & 1 == 1
+    }
+
+    pub(crate) fn equals(&self, other: &FieldElement) -> bool {
+        self.to_bytes() == other.to_bytes()
+    }
+}
+
+// carries the bits above 51 of every limb into the next one
+fn reduce(mut limbs: [u64; 5]) -> [u64; 5] {
+    let carries = [
+        limbs[0] >> 51,
+        limbs[1] >> 51,
+        limbs[2] >> 51,
+        limbs[3] >> 51,
+        limbs[4] >> 51,
+    ];
+    for limb in limbs.iter_mut() {
+        *limb &= MASK;
+    }
+    limbs[0] += carries[4] * 19;
+    limbs[1] += carries[0];
+    limbs[2] += carries[1];
+    limbs[3] += carries[2];
+    limbs[4] += carries[3];
+    limbs
+}
+
+/// Point of edwards25519 in extended coordinates: x = X/Z, y = Y/Z and x * y = T/Z
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct EdwardsPoint {
+    x: FieldElement,
+    y: FieldElement,
+    z: FieldElement,
+    t: FieldElement,
+}
+
+impl EdwardsPoint {
+    pub(crate) const IDENTITY: EdwardsPoint = EdwardsPoint {
+        x: FieldElement::ZERO,
+        y: FieldElement::ONE,
+        z: FieldElement::ONE,
+        t: FieldElement::ZERO,
+    };
+
+    pub(crate) fn base_point() -> EdwardsPoint {
+        EdwardsPoint::decompress(&BASE_POINT).expect("the base point is on the curve")
+    }
+
+    /// None if y is not canonical or if there is no point with this y
+    pub(crate) fn decompress(bytes: &[u8; 32]) -> Option<EdwardsPoint> {
+        let sign = bytes[31] >> 7 == 1;
+        let mut y_bytes = *bytes;
+        y_bytes[31] &= 0x7f;
+        let y = FieldElement::from_bytes(&y_bytes);
+        if y.to_bytes() != y_bytes {
+            return None;
+        }
+        // x^2 = (y^2 - 1) / (d y^2 + 1)
+        let d = FieldElement::from_bytes(&D);
+        let y2 = y.square();
+        let u = y2.sub(&FieldElement::ONE);
+        let v = d.mul(&y2).add(&FieldElement::ONE);
+        // candidate root x = u v^3 (u v^7)^((p - 5) / 8)
+        let v3 = v.square().mul(&v);
+        let v7 = v3.square().mul(&v);
+        let mut x = u.mul(&v3).mul(&u.mul(&v7).pow(&P_MINUS_5_DIV_8));
+        let vx2 = v.mul(&x.square());
+        if !vx2.equals(&u) {
+            if !vx2.equals(&u.neg()) {
+                return None;
+            }
+            x = x.mul(&FieldElement::from_bytes(&SQRT_MINUS_1));
+        }
+        if x.is_zero() && sign {
+            return None;
+        }
+        if x.is_negative() != sign {
+            x = x.neg();
+        }
+        Some(EdwardsPoint {
+            x,
+            y,
+            z: FieldElement::ONE,
+            t: x.mul(&y),
+        })
+    }
+
+    pub(crate) fn compress(&self) -> [u8; 32] {
+        let z_inverse = self.z.invert();
+        let x = self.x.mul(&z_inverse);
+        let mut bytes = self.y.mul(&z_inverse).to_bytes();
+        if x.is_negative() {
+            bytes[31] |= 0x80;
+        }
+        bytes
+    }
+
+    pub(crate) fn add(&self, other: &EdwardsPoint) -> EdwardsPoint {
+        // add-2008-hwcd-3, complete for a = -1
+        let d2 = FieldElement::from_bytes(&D).add(&FieldElement::from_bytes(&D));
+        let a = self.y.sub(&self.x).mul(&other.y.sub(&other.x));
+        let b = self.y.add(&self.x).mul(&other.y.add(&other.x));
+        let c = self.t.mul(&d2).mul(&other.t);
+        let d = self.z.add(&self.z).mul(&other.z);
+        let e = b.sub(&a);
+        let f = d.sub(&c);
+        let g = d.add(&c);
+        let h = b.add(&a);
+        EdwardsPoint {
+            x: e.mul(&f),
+            y: g.mul(&h),
+            z: f.mul(&g),
+            t: e.mul(&h),
+        }
+    }
+
+    pub(crate) fn neg(&self) -> EdwardsPoint {
+        EdwardsPoint {
+            x: self.x.neg(),
+            y: self.y,
+            z: self.z,
+            t: self.t.neg(),
+        }
+    }
+
+    /// {scalar} is a little-endian integer of any length, it is not reduced
+    pub(crate) fn mul(&self, scalar: &[u8]) -> EdwardsPoint {
+        let mut result = EdwardsPoint::IDENTITY;
+        for i in (0..scalar.len() * 8).rev() {
+            result = result.add(&result);
+            if (scalar[i / 8] >> (i % 8)) & 1 == 1 {
+                result = result.add(self);
+            }
+        }
+        result
+    }
+
+    pub(crate) fn mul_by_cofactor(&self) -> EdwardsPoint {
+        self.mul(&[8])
+    }
+
+    pub(crate) fn is_small_order(&self) -> bool {
+        self.mul_by_cofactor().compress() == EdwardsPoint::IDENTITY.compress()
+    }
+}
+
+/// True if the little-endian {scalar} is below the order of the base point
+pub(crate) fn is_canonical_scalar(scalar: &[u8; 32]) -> bool {
+    for (byte, order_byte) in scalar.iter().zip(ORDER.iter()).rev() {
+        if byte != order_byte {
+            return byte < order_byte;
+        }
+    }
+    false
+}
+
+/// Elligator 2 map of {r} to a point of curve25519 converted to edwards25519 with a positive x.
+/// The point is not multiplied by the cofactor.
+pub(crate) fn elligator2(r: &FieldElement) -> EdwardsPoint {
+    let a = FieldElement::from_u64(MONTGOMERY_A);
+    // u = -A / (1 + 2 r^2)
+    let r2 = r.square();
+    let denominator = r2.add(&r2).add(&FieldElement::ONE);
+    let mut u = a.neg().mul(&denominator.invert());
+    // when u^3 + A u^2 + u is not a square the other root -A - u is used
+    let w = u.mul(&u.square().add(&a.mul(&u)).add(&FieldElement::ONE));
+    if w.legendre().equals(&FieldElement::ONE.neg()) {
+        u = u.neg().sub(&a);
+    }
+    // birational map to edwards25519: y = (u - 1) / (u + 1)
+    let y = u
+        .sub(&FieldElement::ONE)
+        .mul(&u.add(&FieldElement::ONE).invert());
+    EdwardsPoint::decompress(&y.to_bytes()).expect("the Montgomery point maps to the curve")
+}
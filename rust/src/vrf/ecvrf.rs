@@ -0,0 +1,79 @@
+use super::curve::{elligator2, is_canonical_scalar, EdwardsPoint, FieldElement};
+use cryptoxide::digest::Digest;
+use cryptoxide::sha2::Sha512;
+
+// ECVRF-ED25519-SHA512-Elligator2 of draft-irtf-cfrg-vrf-03, the variant of the libsodium fork
+// the Cardano node verifies block headers with.
+// https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-vrf-03
+
+pub(crate) const PROOF_LEN: usize = 80;
+pub(crate) const OUTPUT_LEN: usize = 64;
+
+const SUITE: u8 = 0x04;
+const HASH_TO_CURVE: u8 = 0x01;
+const HASH_POINTS: u8 = 0x02;
+const PROOF_TO_HASH: u8 = 0x03;
+const CHALLENGE_LEN: usize = 16;
+
+/// Returns the VRF output if {proof} is a valid proof of {public_key} for {input}
+pub(crate) fn verify(public_key: &[u8], proof: &[u8], input: &[u8]) -> Option<[u8; OUTPUT_LEN]> {
+    let public_key_bytes = to_array(public_key)?;
+    let public_key = EdwardsPoint::decompress(&public_key_bytes)?;
+    if public_key.is_small_order() || proof.len() != PROOF_LEN {
+        return None;
+    }
+    let gamma = EdwardsPoint::decompress(&to_array(&proof[..32])?)?;
+    let challenge = &proof[32..32 + CHALLENGE_LEN];
+    let response = to_array(&proof[32 + CHALLENGE_LEN..])?;
+    if !is_canonical_scalar(&response) {
+        return None;
+    }
+
+    let h = hash_to_curve(&public_key_bytes, input);
+    // U = s B - c Y, V = s H - c Gamma
+    let u = EdwardsPoint::base_point()
+        .mul(&response)
+        .add(&public_key.mul(challenge).neg());
+    let v = h.mul(&response).add(&gamma.mul(challenge).neg());
+    let expected = sha512(&[
+        &[SUITE, HASH_POINTS],
+        &h.compress(),
+        &gamma.compress(),
+        &u.compress(),
+        &v.compress(),
+    ]);
+    if expected[..CHALLENGE_LEN] != *challenge {
+        return None;
+    }
+    Some(sha512(&[
+        &[SUITE, PROOF_TO_HASH],
+        &gamma.mul_by_cofactor().compress(),
+    ]))
+}
+
+fn hash_to_curve(public_key: &[u8; 32], input: &[u8]) -> EdwardsPoint {
+    let hash = sha512(&[&[SUITE, HASH_TO_CURVE], public_key, input]);
+    let mut r = [0; 32];
+    r.copy_from_slice(&hash[..32]);
+    r[31] &= 0x7f;
+    elligator2(&FieldElement::from_bytes(&r)).mul_by_cofactor()
+}
+
+fn sha512(parts: &[&[u8]]) -> [u8; 64] {
+    let mut hasher = Sha512::new();
+    for part in parts {
+        hasher.input(part);
+    }
+    let mut hash = [0; 64];
+    hasher.result(&mut hash);
+    hash
+}
+
+fn to_array(bytes: &[u8]) -> Option<[u8; 32]> {
+    if bytes.len() != 32 {
+        return None;
+    }
+    let mut array = [0; 32];
+    array.copy_from_slice(bytes);
+    Some(array)
+}
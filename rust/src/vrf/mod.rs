@@ -0,0 +1,90 @@
+mod curve;
+mod ecvrf;
+
+use crate::*;
+
+// Leader election of Ouroboros Praos with the VRF proofs of block headers.
+// Shelley to Alonzo headers (TPraos) have a nonce proof and a leader proof, each for its own seed.
+// From Babbage on headers have a single proof and both values are hashes of its output
+// with a domain prefix.
+
+// the TPraos seeds are xored with the hash of the number of the purpose
+const TPRAOS_NONCE_PURPOSE: u64 = 0;
+const TPRAOS_LEADER_PURPOSE: u64 = 1;
+const PRAOS_LEADER_PREFIX: &[u8] = b"L";
+const PRAOS_NONCE_PREFIX: &[u8] = b"N";
+
+/// Returns the output of the proof if {proof} is a valid proof of {public_key} for {input}
+pub(crate) fn verify_vrf_proof(public_key: &[u8], proof: &[u8], input: &[u8]) -> Option<Vec<u8>> {
+    ecvrf::verify(public_key, proof, input).map(|output| output.to_vec())
+}
+
+/// Input of the VRF of a Babbage header: hash of the slot and the epoch nonce
+pub(crate) fn praos_vrf_input(slot: u64, epoch_nonce: &Nonce) -> [u8; 32] {
+    let mut bytes = slot.to_be_bytes().to_vec();
+    if let Some(hash) = &epoch_nonce.hash {
+        bytes.extend_from_slice(hash);
+    }
+    blake2b256(&bytes)
+}
+
+fn tpraos_seed(purpose: u64, slot: u64, epoch_nonce: &Nonce) -> [u8; 32] {
+    let purpose_hash = blake2b256(&purpose.to_be_bytes());
+    let mut seed = praos_vrf_input(slot, epoch_nonce);
+    for (byte, purpose_byte) in seed.iter_mut().zip(purpose_hash.iter()) {
+        *byte ^= purpose_byte;
+    }
+    seed
+}
+
+/// Input of the nonce VRF of a Shelley to Alonzo header
+pub(crate) fn tpraos_nonce_seed(slot: u64, epoch_nonce: &Nonce) -> [u8; 32] {
+    tpraos_seed(TPRAOS_NONCE_PURPOSE, slot, epoch_nonce)
+}
+
+/// Input of the leader VRF of a Shelley to Alonzo header
+pub(crate) fn tpraos_leader_seed(slot: u64, epoch_nonce: &Nonce) -> [u8; 32] {
+    tpraos_seed(TPRAOS_LEADER_PURPOSE, slot, epoch_nonce)
+}
+
+/// Leader value of a Babbage header, a 256 bits number
+pub(crate) fn praos_leader_value(output: &[u8]) -> Vec<u8> {
+    blake2b256(&[PRAOS_LEADER_PREFIX, output].concat()).to_vec()
+}
+
+/// Nonce contribution of a Babbage header, the prefixed output is hashed twice
+pub(crate) fn praos_nonce_value(output: &[u8]) -> Nonce {
+    let hash = blake2b256(&[PRAOS_NONCE_PREFIX, output].concat());
+    Nonce {
+        hash: Some(blake2b256(&hash)),
+    }
+}
+
+/// Nonce contribution of a Shelley to Alonzo header
+pub(crate) fn tpraos_nonce_value(output: &[u8]) -> Nonce {
+    Nonce {
+        hash: Some(blake2b256(output)),
+    }
+}
+
+/// The pool is leader if the leader value, as a fraction of its maximum, is below 1 - (1 - f)^σ
+/// with f the active slot coefficient and σ the relative stake of the pool.
+/// The node compares with a 34 digits fixed point approximation, this uses f64 so values
+/// within about 1e-15 of the threshold may be decided differently.
+pub(crate) fn is_leader_value(
+    leader_value: &[u8],
+    relative_stake: &UnitInterval,
+    active_slot_coefficient: &UnitInterval,
+) -> bool {
+    let ratio = |interval: &UnitInterval| {
+        u64::from(interval.numerator()) as f64 / u64::from(interval.denominator()) as f64
+    };
+    // the first 8 bytes are enough for the precision of f64
+    let value = leader_value
+        .iter()
+        .take(8)
+        .fold(0.0, |value, byte| value * 256.0 + *byte as f64)
+        / 2f64.powi(8 * leader_value.len().min(8) as i32);
+    let threshold = 1.0 - (1.0 - ratio(active_slot_coefficient)).powf(ratio(relative_stake));
+    value < threshold
+}